@@ -1,4 +1,7 @@
-use std::ops::Deref;
+use std::{
+    ops::Deref,
+    sync::atomic::{AtomicU32, Ordering},
+};
 
 mod arena;
 
@@ -8,6 +11,9 @@ use bumpalo::Bump;
 #[derive(Debug)]
 pub struct Allocator {
     bump: Bump,
+
+    /// The next id returned by [`Allocator::next_id`]
+    next_id: AtomicU32,
 }
 
 // SAFETY: Make Bump Sync and Send, it's our responsibility to never
@@ -17,7 +23,15 @@ unsafe impl Sync for Allocator {}
 
 impl Default for Allocator {
     fn default() -> Self {
-        Self { bump: Bump::new() }
+        Self { bump: Bump::new(), next_id: AtomicU32::new(0) }
+    }
+}
+
+impl Allocator {
+    /// A number which is unique among the calls on this allocator,
+    /// used for identifying the AST nodes allocated in it.
+    pub fn next_id(&self) -> u32 {
+        self.next_id.fetch_add(1, Ordering::Relaxed)
     }
 }
 
@@ -2,15 +2,17 @@ use std::fmt::Display;
 
 use num_bigint::BigUint;
 use oxc_allocator::{Box, Vec};
+use oxc_macros::CloneIn;
 #[cfg(feature = "serde")]
 use serde::Serialize;
 
 #[allow(clippy::wildcard_imports)]
-use crate::{ast::*, Atom, SourceType, Span};
+use crate::{ast::*, Atom, NodeId, SourceType, Span};
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 pub struct Program<'a> {
     pub span: Span,
+    pub node_id: NodeId,
     pub directives: Vec<'a, Directive<'a>>,
     pub body: Vec<'a, Statement<'a>>,
     pub source_type: SourceType,
@@ -30,7 +32,7 @@ impl<'a> Program<'a> {
 }
 
 /// Section 13 Expression
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum Expression<'a> {
     BooleanLiteral(Box<'a, BooleanLiteral>),
@@ -226,81 +228,97 @@ impl<'a> Expression<'a> {
 }
 
 /// Section 12.6 `IdentifierName`
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct IdentifierName {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub name: Atom,
 }
 
 /// Section 13.1 `IdentifierReference`
-#[derive(Debug, Clone, PartialEq, Hash, Eq)]
+#[derive(Debug, Clone, PartialEq, Hash, Eq, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct IdentifierReference {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub name: Atom,
 }
 
 /// Section 13.1 `BindingIdentifier`
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct BindingIdentifier {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub name: Atom,
 }
 
 /// Section 13.1 `LabelIdentifier`
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct LabelIdentifier {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub name: Atom,
 }
 
 /// Section 13.2.2 This Expression
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ThisExpression {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
 }
 
 /// Section 13.2.5 Array Expression
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ArrayExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub elements: Vec<'a, Option<Argument<'a>>>,
     pub trailing_comma: Option<Span>,
 }
 
 /// Section 13.2.6 Object Expression
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ObjectExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub properties: Vec<'a, ObjectProperty<'a>>,
     pub trailing_comma: Option<Span>,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum ObjectProperty<'a> {
     Property(Box<'a, Property<'a>>),
     SpreadProperty(Box<'a, SpreadElement<'a>>),
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct Property<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub kind: PropertyKind,
     pub key: PropertyKey<'a>,
     pub value: PropertyValue<'a>,
@@ -309,7 +327,7 @@ pub struct Property<'a> {
     pub computed: bool,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum PropertyKey<'a> {
     Identifier(Box<'a, IdentifierName>),
@@ -343,7 +361,7 @@ impl<'a> PropertyKey<'a> {
     }
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum PropertyValue<'a> {
     // For AssignmentProperty in ObjectPattern <https://github.com/estree/estree/blob/master/es2015.md#objectpattern>
@@ -351,7 +369,7 @@ pub enum PropertyValue<'a> {
     Expression(Expression<'a>),
 }
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(rename_all = "lowercase"))]
 pub enum PropertyKind {
     Init,
@@ -360,11 +378,13 @@ pub enum PropertyKind {
 }
 
 /// Section 13.2.9 Template Literal
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct TemplateLiteral<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub quasis: Vec<'a, TemplateElement>,
     pub expressions: Vec<'a, Expression<'a>>,
 }
@@ -382,26 +402,30 @@ impl<'a> TemplateLiteral<'a> {
     }
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct TaggedTemplateExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub tag: Expression<'a>,
     pub quasi: TemplateLiteral<'a>,
     pub type_parameters: Option<Box<'a, TSTypeParameterInstantiation<'a>>>,
 }
 
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct TemplateElement {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub tail: bool,
     pub value: TemplateElementValue,
 }
 
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct TemplateElementValue {
     pub raw: Atom,
@@ -409,7 +433,7 @@ pub struct TemplateElementValue {
 }
 
 /// Section 13.3 Member Expression
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 pub enum MemberExpression<'a> {
     ComputedMemberExpression(ComputedMemberExpression<'a>),
     StaticMemberExpression(StaticMemberExpression<'a>),
@@ -462,36 +486,41 @@ impl<'a> MemberExpression<'a> {
     }
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 pub struct ComputedMemberExpression<'a> {
     pub span: Span,
+    pub node_id: NodeId,
     pub object: Expression<'a>,
     pub expression: Expression<'a>,
     pub optional: bool, // for optional chaining
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 pub struct StaticMemberExpression<'a> {
     pub span: Span,
+    pub node_id: NodeId,
     pub object: Expression<'a>,
     pub property: IdentifierName,
     pub optional: bool, // for optional chaining
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 pub struct PrivateFieldExpression<'a> {
     pub span: Span,
+    pub node_id: NodeId,
     pub object: Expression<'a>,
     pub field: PrivateIdentifier,
     pub optional: bool, // for optional chaining
 }
 
 /// Section 13.3 Call Expression
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct CallExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub callee: Expression<'a>,
     pub arguments: Vec<'a, Argument<'a>>,
     pub optional: bool, // for optional chaining
@@ -544,11 +573,13 @@ impl<'a> CallExpression<'a> {
 }
 
 /// Section 13.3 New Expression
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct NewExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub callee: Expression<'a>,
     pub arguments: Vec<'a, Argument<'a>>,
     pub type_parameters: Option<Box<'a, TSTypeParameterInstantiation<'a>>>,
@@ -556,26 +587,30 @@ pub struct NewExpression<'a> {
 
 /// Section 13.3 Meta Property
 /// `new.target` | `import.meta`
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct MetaProperty {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub meta: IdentifierName,
     pub property: IdentifierName,
 }
 
 /// Section 13.3 Spread Element
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct SpreadElement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub argument: Expression<'a>,
 }
 
 /// Section 13.3 Argument
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum Argument<'a> {
     SpreadElement(Box<'a, SpreadElement<'a>>),
@@ -583,33 +618,39 @@ pub enum Argument<'a> {
 }
 
 /// Section 13.4 Update Expression
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct UpdateExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub operator: UpdateOperator,
     pub prefix: bool,
     pub argument: SimpleAssignmentTarget<'a>,
 }
 
 /// Section 13.5 Unary Expression
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct UnaryExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub operator: UnaryOperator,
     pub prefix: bool,
     pub argument: Expression<'a>,
 }
 
 /// Section 13.6 - 13.13 Binary Expression
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct BinaryExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub left: Expression<'a>,
     pub operator: BinaryOperator,
     pub right: Expression<'a>,
@@ -617,51 +658,59 @@ pub struct BinaryExpression<'a> {
 
 /// `RelationalExpression`[In, Yield, Await] :
 ///     [+In] `PrivateIdentifier` in `ShiftExpression`[?Yield, ?Await]
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct PrivateInExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub left: PrivateIdentifier,
     pub operator: BinaryOperator, // BinaryOperator::In
     pub right: Expression<'a>,
 }
 
 /// Section 13.13 Binary Logical Operators
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct LogicalExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub left: Expression<'a>,
     pub operator: LogicalOperator,
     pub right: Expression<'a>,
 }
 
 /// Section 13.14 Conditional Expression
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ConditionalExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub test: Expression<'a>,
     pub consequent: Expression<'a>,
     pub alternate: Expression<'a>,
 }
 
 /// Section 13.15 Assignment Expression
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct AssignmentExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub operator: AssignmentOperator,
     pub left: AssignmentTarget<'a>,
     pub right: Expression<'a>,
 }
 
 /// 13.15.5 Destructuring Assignment
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum AssignmentTarget<'a> {
     SimpleAssignmentTarget(SimpleAssignmentTarget<'a>),
@@ -675,7 +724,7 @@ impl<'a> AssignmentTarget<'a> {
     }
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum SimpleAssignmentTarget<'a> {
     AssignmentTargetIdentifier(Box<'a, IdentifierReference>),
@@ -697,33 +746,37 @@ impl<'a> SimpleAssignmentTarget<'a> {
     }
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum AssignmentTargetPattern<'a> {
     ArrayAssignmentTarget(Box<'a, ArrayAssignmentTarget<'a>>),
     ObjectAssignmentTarget(Box<'a, ObjectAssignmentTarget<'a>>),
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ArrayAssignmentTarget<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub elements: Vec<'a, Option<AssignmentTargetMaybeDefault<'a>>>,
     pub rest: Option<AssignmentTarget<'a>>,
     pub trailing_comma: Option<Span>,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ObjectAssignmentTarget<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub properties: Vec<'a, AssignmentTargetProperty<'a>>,
     pub rest: Option<AssignmentTarget<'a>>,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum AssignmentTargetMaybeDefault<'a> {
     AssignmentTarget(Box<'a, AssignmentTarget<'a>>),
@@ -749,16 +802,18 @@ impl<'a> AssignmentTargetMaybeDefault<'a> {
     }
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct AssignmentTargetWithDefault<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub binding: AssignmentTarget<'a>,
     pub init: Expression<'a>,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum AssignmentTargetProperty<'a> {
     AssignmentTargetPropertyIdentifier(Box<'a, AssignmentTargetPropertyIdentifier<'a>>),
@@ -767,60 +822,72 @@ pub enum AssignmentTargetProperty<'a> {
 
 /// `AssignmentProperty`[Yield, Await] :
 ///     `IdentifierReference`[?Yield, ?Await] Initializer[+In, ?Yield, ?Await]opt
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct AssignmentTargetPropertyIdentifier<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub binding: IdentifierReference,
     pub init: Option<Expression<'a>>,
 }
 
 /// `AssignmentProperty`[Yield, Await] :
 ///     `PropertyName`[?Yield, ?Await] : `AssignmentElement`[?Yield, ?Await]
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct AssignmentTargetPropertyProperty<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub name: PropertyKey<'a>,
     pub binding: AssignmentTargetMaybeDefault<'a>,
 }
 
 /// Section 13.16 Sequence Expression
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct SequenceExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub expressions: Vec<'a, Expression<'a>>,
 }
 
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct Super {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
 }
 
 /// Section 15.8 Await Expression
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct AwaitExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub argument: Expression<'a>,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ChainExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub expression: ChainElement<'a>,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum ChainElement<'a> {
     CallExpression(Box<'a, CallExpression<'a>>),
@@ -828,16 +895,18 @@ pub enum ChainElement<'a> {
 }
 
 // Section 13.2 ParenthesizedExpression
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ParenthesizedExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub expression: Expression<'a>,
 }
 
 /// Section 14 Statements
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum Statement<'a> {
     // Statements
@@ -865,7 +934,7 @@ pub enum Statement<'a> {
 }
 
 /// Section 11.2.1 Directive Prologue
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(
     feature = "serde",
     derive(Serialize),
@@ -874,22 +943,26 @@ pub enum Statement<'a> {
 pub struct Directive<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub expression: StringLiteral,
     // directives should always use the unescaped raw string
     pub directive: &'a str,
 }
 
 /// Section 14.2 Block Statement
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct BlockStatement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub body: Vec<'a, Statement<'a>>,
 }
 
 /// Section 14.3 Declarations and the Variable Statement
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum Declaration<'a> {
     VariableDeclaration(Box<'a, VariableDeclaration<'a>>),
@@ -916,18 +989,20 @@ impl<'a> Declaration<'a> {
 }
 
 /// Section 14.3.2 Variable Declaration
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct VariableDeclaration<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub kind: VariableDeclarationKind,
     pub declarations: Vec<'a, VariableDeclarator<'a>>,
     /// Valid Modifiers: `export`, `declare`
     pub modifiers: Modifiers<'a>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(rename_all = "lowercase"))]
 pub enum VariableDeclarationKind {
     Var,
@@ -958,12 +1033,14 @@ impl Display for VariableDeclarationKind {
     }
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct VariableDeclarator<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
     #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub kind: VariableDeclarationKind,
     pub id: BindingPattern<'a>,
     pub init: Option<Expression<'a>>,
@@ -971,66 +1048,78 @@ pub struct VariableDeclarator<'a> {
 }
 
 /// Section 14.4 Empty Statement
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct EmptyStatement {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
 }
 
 /// Section 14.5 Expression Statement
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ExpressionStatement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub expression: Expression<'a>,
 }
 
 /// Section 14.6 If Statement
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct IfStatement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub test: Expression<'a>,
     pub consequent: Statement<'a>,
     pub alternate: Option<Statement<'a>>,
 }
 
 /// Section 14.7.2 Do-While Statement
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct DoWhileStatement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub body: Statement<'a>,
     pub test: Expression<'a>,
 }
 
 /// Section 14.7.3 While Statement
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct WhileStatement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub test: Expression<'a>,
     pub body: Statement<'a>,
 }
 
 /// Section 14.7.4 For Statement
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ForStatement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub init: Option<ForStatementInit<'a>>,
     pub test: Option<Expression<'a>>,
     pub update: Option<Expression<'a>>,
     pub body: Statement<'a>,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum ForStatementInit<'a> {
     VariableDeclaration(Box<'a, VariableDeclaration<'a>>),
@@ -1038,29 +1127,33 @@ pub enum ForStatementInit<'a> {
 }
 
 /// Section 14.7.5 For-In Statement
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ForInStatement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub left: ForStatementLeft<'a>,
     pub right: Expression<'a>,
     pub body: Statement<'a>,
 }
 
 /// Section 14.7.5 For-Of Statement
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ForOfStatement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub r#await: bool,
     pub left: ForStatementLeft<'a>,
     pub right: Expression<'a>,
     pub body: Statement<'a>,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum ForStatementLeft<'a> {
     VariableDeclaration(Box<'a, VariableDeclaration<'a>>),
@@ -1068,57 +1161,69 @@ pub enum ForStatementLeft<'a> {
 }
 
 /// Section 14.8 Continue Statement
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ContinueStatement {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub label: Option<LabelIdentifier>,
 }
 
 /// Section 14.9 Break Statement
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct BreakStatement {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub label: Option<LabelIdentifier>,
 }
 
 /// Section 14.10 Return Statement
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ReturnStatement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub argument: Option<Expression<'a>>,
 }
 
 /// Section 14.11 With Statement
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct WithStatement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub object: Expression<'a>,
     pub body: Statement<'a>,
 }
 
 /// Section 14.12 Switch Statement
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct SwitchStatement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub discriminant: Expression<'a>,
     pub cases: Vec<'a, SwitchCase<'a>>,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct SwitchCase<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub test: Option<Expression<'a>>,
     pub consequent: Vec<'a, Statement<'a>>,
 }
@@ -1131,54 +1236,64 @@ impl<'a> SwitchCase<'a> {
 }
 
 /// Section 14.13 Labelled Statement
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct LabeledStatement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub label: LabelIdentifier,
     pub body: Statement<'a>,
 }
 
 /// Section 14.14 Throw Statement
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ThrowStatement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub argument: Expression<'a>,
 }
 
 /// Section 14.15 Try Statement
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct TryStatement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub block: Box<'a, BlockStatement<'a>>,
     pub handler: Option<Box<'a, CatchClause<'a>>>,
     pub finalizer: Option<Box<'a, BlockStatement<'a>>>,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct CatchClause<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub param: Option<BindingPattern<'a>>,
     pub body: Box<'a, BlockStatement<'a>>,
 }
 
 /// Section 14.16 Debugger Statement
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct DebuggerStatement {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
 }
 
 /// Section 14.3.3 Destructuring Binding Patterns
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(rename_all = "camelCase"))]
 pub struct BindingPattern<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -1187,7 +1302,7 @@ pub struct BindingPattern<'a> {
     pub optional: bool,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum BindingPatternKind<'a> {
     BindingIdentifier(Box<'a, BindingIdentifier>),
@@ -1209,54 +1324,64 @@ impl<'a> BindingPatternKind<'a> {
     }
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct AssignmentPattern<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub left: BindingPattern<'a>,
     pub right: Expression<'a>,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ObjectPattern<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub properties: Vec<'a, ObjectPatternProperty<'a>>,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum ObjectPatternProperty<'a> {
     Property(Box<'a, Property<'a>>),
     RestElement(Box<'a, RestElement<'a>>),
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ArrayPattern<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub elements: Vec<'a, Option<BindingPattern<'a>>>,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct RestElement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub argument: BindingPattern<'a>,
 }
 
 /// Section 15.2 Function Definitions
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(rename_all = "camelCase"))]
 #[allow(clippy::struct_excessive_bools)]
 pub struct Function<'a> {
     pub r#type: FunctionType,
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub id: Option<BindingIdentifier>,
     pub expression: bool,
     pub generator: bool,
@@ -1296,7 +1421,7 @@ impl<'a> Function<'a> {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum FunctionType {
     FunctionDeclaration,
@@ -1304,18 +1429,21 @@ pub enum FunctionType {
     TSDeclareFunction,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 pub struct FormalParameters<'a> {
     pub span: Span,
+    pub node_id: NodeId,
     pub kind: FormalParameterKind,
     pub items: Vec<'a, FormalParameter<'a>>,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct FormalParameter<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub pattern: BindingPattern<'a>,
     pub accessibility: Option<TSAccessibility>,
@@ -1323,7 +1451,7 @@ pub struct FormalParameter<'a> {
     pub decorators: Vec<'a, Decorator<'a>>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn)]
 pub enum FormalParameterKind {
     /// <https://tc39.es/ecma262/#prod-FormalParameters>
     FormalParameter,
@@ -1342,9 +1470,10 @@ impl<'a> FormalParameters<'a> {
     }
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 pub struct FunctionBody<'a> {
     pub span: Span,
+    pub node_id: NodeId,
     pub directives: Vec<'a, Directive<'a>>,
     pub statements: Vec<'a, Statement<'a>>,
 }
@@ -1357,9 +1486,10 @@ impl<'a> FunctionBody<'a> {
 }
 
 /// Section 15.3 Arrow Function Definitions
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 pub struct ArrowExpression<'a> {
     pub span: Span,
+    pub node_id: NodeId,
     pub expression: bool,
     pub generator: bool,
     pub r#async: bool,
@@ -1380,22 +1510,26 @@ impl<'a> ArrowExpression<'a> {
 }
 
 /// Section 15.5 Generator Function Definitions
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct YieldExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub delegate: bool,
     pub argument: Option<Expression<'a>>,
 }
 
 /// Section 15.7 Class Definitions
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(rename_all = "camelCase"))]
 pub struct Class<'a> {
     pub r#type: ClassType,
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub id: Option<BindingIdentifier>,
     pub super_class: Option<Expression<'a>>,
     pub body: Box<'a, ClassBody<'a>>,
@@ -1424,22 +1558,24 @@ impl<'a> Class<'a> {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum ClassType {
     ClassDeclaration,
     ClassExpression,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ClassBody<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub body: Vec<'a, ClassElement<'a>>,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum ClassElement<'a> {
     StaticBlock(Box<'a, StaticBlock<'a>>),
@@ -1527,12 +1663,14 @@ impl<'a> ClassElement<'a> {
     }
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 #[allow(clippy::struct_excessive_bools)]
 pub struct MethodDefinition<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub key: PropertyKey<'a>,
     pub value: Box<'a, Function<'a>>, // FunctionExpression
     pub kind: MethodDefinitionKind,
@@ -1542,14 +1680,18 @@ pub struct MethodDefinition<'a> {
     pub optional: bool,
     pub accessibility: Option<TSAccessibility>,
     pub decorators: Vec<'a, Decorator<'a>>,
+    /// The modifiers as written, including the ones which are not allowed on methods
+    pub modifiers: Modifiers<'a>,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 #[allow(clippy::struct_excessive_bools)]
 pub struct PropertyDefinition<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub key: PropertyKey<'a>,
     pub value: Option<Expression<'a>>,
     pub computed: bool,
@@ -1562,9 +1704,11 @@ pub struct PropertyDefinition<'a> {
     pub type_annotation: Option<Box<'a, TSTypeAnnotation<'a>>>,
     pub accessibility: Option<TSAccessibility>,
     pub decorators: Vec<'a, Decorator<'a>>,
+    /// The modifiers as written, including duplicated accessibility modifiers
+    pub modifiers: Modifiers<'a>,
 }
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(rename_all = "lowercase"))]
 pub enum MethodDefinitionKind {
     Constructor,
@@ -1573,33 +1717,39 @@ pub enum MethodDefinitionKind {
     Set,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct PrivateIdentifier {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub name: Atom,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct StaticBlock<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub body: Vec<'a, Statement<'a>>,
 }
 
 /// Section 16.2.2 Imports
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct ModuleDeclaration<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub kind: ModuleDeclarationKind<'a>,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum ModuleDeclarationKind<'a> {
     ImportDeclaration(Box<'a, ImportDeclaration<'a>>),
@@ -1625,27 +1775,31 @@ impl<'a> ModuleDeclarationKind<'a> {
     }
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct AccessorProperty<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub key: PropertyKey<'a>,
     pub value: Option<Expression<'a>>,
     pub computed: bool,
     pub r#static: bool,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ImportExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub source: Expression<'a>,
     pub arguments: Vec<'a, Expression<'a>>,
 }
 
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct ImportDeclaration<'a> {
     pub specifiers: Vec<'a, ImportDeclarationSpecifier>,
@@ -1654,7 +1808,7 @@ pub struct ImportDeclaration<'a> {
     pub import_kind: Option<ImportOrExportKind>,      // `import type { foo } from 'bar'`
 }
 
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum ImportDeclarationSpecifier {
     ImportSpecifier(ImportSpecifier),
@@ -1664,43 +1818,51 @@ pub enum ImportDeclarationSpecifier {
 
 // import {imported} from "source"
 // import {imported as local} from "source"
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ImportSpecifier {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub imported: ModuleExportName,
     pub local: BindingIdentifier,
 }
 
 // import local from "source"
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ImportDefaultSpecifier {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub local: BindingIdentifier,
 }
 
 // import * as local from "source"
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ImportNamespaceSpecifier {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub local: BindingIdentifier,
 }
 
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ImportAttribute {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub key: ImportAttributeKey,
     pub value: StringLiteral,
 }
 
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum ImportAttributeKey {
     Identifier(IdentifierName),
@@ -1719,7 +1881,7 @@ impl ImportAttributeKey {
 
 /// Exports
 /// [tc39/ecma262#sec-exports](https://tc39.es/ecma262/#sec-exports)
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ExportNamedDeclaration<'a> {
     pub declaration: Option<Declaration<'a>>,
@@ -1736,14 +1898,14 @@ impl<'a> ExportNamedDeclaration<'a> {
     }
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ExportDefaultDeclaration<'a> {
     pub declaration: ExportDefaultDeclarationKind<'a>,
     pub exported: ModuleExportName, // `default`
 }
 
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ExportAllDeclaration<'a> {
     pub exported: Option<ModuleExportName>,
@@ -1752,16 +1914,18 @@ pub struct ExportAllDeclaration<'a> {
     pub export_kind: Option<ImportOrExportKind>,      // `export type *`
 }
 
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct ExportSpecifier {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub local: ModuleExportName,
     pub exported: ModuleExportName,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum ExportDefaultDeclarationKind<'a> {
     Expression(Expression<'a>),
@@ -1794,7 +1958,7 @@ impl<'a> ExportDefaultDeclarationKind<'a> {
 // support:
 //   import {"\0 any unicode" as foo} from "";
 //   export {foo as "\0 any unicode"};
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum ModuleExportName {
     Identifier(IdentifierName),
@@ -1,22 +1,27 @@
 //! [`JSDoc`](https://github.com/microsoft/TypeScript/blob/54a554d8af2657630307cbfa8a3e4f3946e36507/src/compiler/types.ts#L393)
 
+use oxc_macros::CloneIn;
 #[cfg(feature = "serde")]
 use serde::Serialize;
 
-use crate::{ast::TSType, Span};
+use crate::{ast::TSType, NodeId, Span};
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct JSDocNullableType<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub type_annotation: TSType<'a>,
     pub postfix: bool,
 }
 
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct JSDocUnknownType {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
 }
@@ -1,22 +1,25 @@
 //! [JSX](https://facebook.github.io/jsx)
 
 use oxc_allocator::{Box, Vec};
+use oxc_macros::CloneIn;
 #[cfg(feature = "serde")]
 use serde::Serialize;
 
 #[allow(clippy::wildcard_imports)]
-use crate::{ast::*, Atom, Span};
+use crate::{ast::*, Atom, NodeId, Span};
 
 // 1.2 JSX Elements
 
 /// `JSXElement` :
 ///   `JSXSelfClosingElement`
 ///   `JSXOpeningElement` `JSXChildren_opt` `JSXClosingElement`
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct JSXElement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub opening_element: Box<'a, JSXOpeningElement<'a>>,
     pub closing_element: Option<Box<'a, JSXClosingElement<'a>>>,
     pub children: Vec<'a, JSXChild<'a>>,
@@ -24,11 +27,13 @@ pub struct JSXElement<'a> {
 
 /// `JSXOpeningElement` :
 ///   < `JSXElementName` `JSXAttributes_opt` >
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct JSXOpeningElement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub self_closing: bool,
     pub name: JSXElementName<'a>,
     pub attributes: Vec<'a, JSXAttributeItem<'a>>,
@@ -37,45 +42,53 @@ pub struct JSXOpeningElement<'a> {
 
 /// `JSXClosingElement` :
 ///     < / `JSXElementName` >
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct JSXClosingElement<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub name: JSXElementName<'a>,
 }
 
 /// `JSXFragment` :
 ///   < > `JSXChildren_opt` < / >
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct JSXFragment<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub opening_fragment: JSXOpeningFragment,
     pub closing_fragment: JSXClosingFragment,
     pub children: Vec<'a, JSXChild<'a>>,
 }
 
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct JSXOpeningFragment {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
 }
 
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct JSXClosingFragment {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
 }
 
 /// `JSXElementName` :
 ///   `JSXIdentifier`
 ///   `JSXNamespacedName`
 ///   `JSXMemberExpression`
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum JSXElementName<'a> {
     Identifier(JSXIdentifier),
@@ -85,11 +98,13 @@ pub enum JSXElementName<'a> {
 
 /// `JSXNamespacedName` :
 ///   `JSXIdentifier` : `JSXIdentifier`
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct JSXNamespacedName {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub namespace: JSXIdentifier,
     pub property: JSXIdentifier,
 }
@@ -97,11 +112,13 @@ pub struct JSXNamespacedName {
 /// `JSXMemberExpression` :
 /// `JSXIdentifier` . `JSXIdentifier`
 /// `JSXMemberExpression` . `JSXIdentifier`
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct JSXMemberExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub object: JSXMemberExpressionObject<'a>,
     pub property: JSXIdentifier,
 }
@@ -116,33 +133,37 @@ impl<'a> JSXMemberExpression<'a> {
     }
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum JSXMemberExpressionObject<'a> {
     Identifier(JSXIdentifier),
     MemberExpression(Box<'a, JSXMemberExpression<'a>>),
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct JSXExpressionContainer<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub expression: JSXExpression<'a>,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum JSXExpression<'a> {
     Expression(Expression<'a>),
     EmptyExpression(JSXEmptyExpression),
 }
 
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct JSXEmptyExpression {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
 }
 
 // 1.3 JSX Attributes
@@ -150,7 +171,7 @@ pub struct JSXEmptyExpression {
 /// `JSXAttributes` :
 ///   `JSXSpreadAttribute` `JSXAttributes_opt`
 ///   `JSXAttribute` `JSXAttributes_opt`
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum JSXAttributeItem<'a> {
     Attribute(Box<'a, JSXAttribute<'a>>),
@@ -159,29 +180,33 @@ pub enum JSXAttributeItem<'a> {
 
 /// `JSXAttribute` :
 ///   `JSXAttributeName` `JSXAttributeInitializer_opt`
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct JSXAttribute<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub name: JSXAttributeName<'a>,
     pub value: Option<JSXAttributeValue<'a>>,
 }
 
 /// `JSXSpreadAttribute` :
 ///   { ... `AssignmentExpression` }
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct JSXSpreadAttribute<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub argument: Expression<'a>,
 }
 
 /// `JSXAttributeName` :
 ///   `JSXIdentifier`
 ///   `JSXNamespacedName`
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum JSXAttributeName<'a> {
     Identifier(JSXIdentifier),
@@ -194,7 +219,7 @@ pub enum JSXAttributeName<'a> {
 ///   { `AssignmentExpression` }
 ///   `JSXElement`
 ///   `JSXFragment`
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum JSXAttributeValue<'a> {
     StringLiteral(StringLiteral),
@@ -203,11 +228,13 @@ pub enum JSXAttributeValue<'a> {
     Fragment(Box<'a, JSXFragment<'a>>),
 }
 
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct JSXIdentifier {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub name: Atom,
 }
 
@@ -218,7 +245,7 @@ pub struct JSXIdentifier {
 ///   `JSXElement`
 ///   `JSXFragment`
 ///   { `JSXChildExpression_opt` }
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum JSXChild<'a> {
     Text(JSXText),
@@ -228,11 +255,13 @@ pub enum JSXChild<'a> {
     Spread(JSXSpreadChild<'a>),
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct JSXSpreadChild<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub expression: Expression<'a>,
 }
 
@@ -240,10 +269,12 @@ pub struct JSXSpreadChild<'a> {
 ///   `JSXTextCharacter` `JSXTextopt`
 /// `JSXTextCharacter` ::
 ///   `JSXStringCharacter` but not one of { or < or > or }
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
 pub struct JSXText {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub value: Atom,
 }
@@ -8,16 +8,19 @@ use std::{
 use bitflags::bitflags;
 use num_bigint::BigUint;
 use ordered_float::NotNan;
+use oxc_macros::CloneIn;
 #[cfg(feature = "serde")]
 use serde::Serialize;
 
-use crate::{Atom, Span};
+use crate::{Atom, NodeId, Span};
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename = "Literal"))]
 pub struct BooleanLiteral {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub value: bool,
 }
 
@@ -28,9 +31,10 @@ impl BooleanLiteral {
     }
 }
 
-#[derive(Debug, Clone, Eq)]
+#[derive(Debug, Clone, Eq, CloneIn)]
 pub struct NullLiteral {
     pub span: Span,
+    pub node_id: NodeId,
 }
 
 impl Hash for NullLiteral {
@@ -45,11 +49,13 @@ impl PartialEq for NullLiteral {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename = "Literal"))]
 pub struct NumberLiteral<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub value: NotNan<f64>, // using NotNan for `Hash`
     #[cfg_attr(feature = "serde", serde(skip))]
     pub raw: &'a str,
@@ -59,9 +65,9 @@ pub struct NumberLiteral<'a> {
 
 impl<'a> NumberLiteral<'a> {
     #[must_use]
-    pub fn new(span: Span, value: f64, raw: &'a str, base: NumberBase) -> Self {
+    pub fn new(span: Span, node_id: NodeId, value: f64, raw: &'a str, base: NumberBase) -> Self {
         let value = unsafe { NotNan::new_unchecked(value) };
-        Self { span, value, raw, base }
+        Self { span, node_id, value, raw, base }
     }
 }
 
@@ -72,27 +78,31 @@ impl<'a> Hash for NumberLiteral<'a> {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename = "Literal"))]
 pub struct BigintLiteral {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::serialize_bigint"))]
     pub value: BigUint,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename = "Literal"))]
 pub struct RegExpLiteral {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     // valid regex is printed as {}
     // invalid regex is printed as null, which we can't implement yet
     pub value: EmptyObject,
     pub regex: RegExp,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct RegExp {
     pub pattern: Atom,
@@ -149,15 +159,17 @@ impl fmt::Display for RegExpFlags {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct EmptyObject;
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename = "Literal"))]
 pub struct StringLiteral {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub node_id: NodeId,
     pub value: Atom,
 }
 
@@ -181,7 +193,7 @@ impl StringLiteral {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn)]
 pub enum NumberBase {
     Decimal,
     Binary,
@@ -1,9 +1,10 @@
 use std::fmt::{Display, Formatter, Result};
 
+use oxc_macros::CloneIn;
 #[cfg(feature = "serde")]
 use serde::Serialize;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum Operator {
     AssignmentOperator(AssignmentOperator),
@@ -43,7 +44,7 @@ impl From<UpdateOperator> for Operator {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum AssignmentOperator {
     #[cfg_attr(feature = "serde", serde(rename = "="))]
@@ -142,7 +143,7 @@ impl Display for AssignmentOperator {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum BinaryOperator {
     #[cfg_attr(feature = "serde", serde(rename = "=="))]
@@ -285,7 +286,7 @@ impl Display for BinaryOperator {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum LogicalOperator {
     #[cfg_attr(feature = "serde", serde(rename = "||"))]
@@ -314,7 +315,7 @@ impl Display for LogicalOperator {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum UnaryOperator {
     #[cfg_attr(feature = "serde", serde(rename = "-"))]
@@ -375,7 +376,7 @@ impl Display for UnaryOperator {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum UpdateOperator {
     #[cfg_attr(feature = "serde", serde(rename = "++"))]
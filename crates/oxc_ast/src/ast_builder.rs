@@ -3,7 +3,7 @@
 use oxc_allocator::{Allocator, Box, String, Vec};
 
 #[allow(clippy::wildcard_imports)]
-use crate::{ast::*, Atom, SourceType, Span};
+use crate::{ast::*, Atom, NodeId, SourceType, Span};
 
 /// AST builder for creating AST spans
 pub struct AstBuilder<'a> {
@@ -15,6 +15,13 @@ impl<'a> AstBuilder<'a> {
         Self { allocator }
     }
 
+    /// A new [`NodeId`] for a node created by this builder
+    #[must_use]
+    #[inline]
+    pub fn node_id(&self) -> NodeId {
+        NodeId::new(self.allocator)
+    }
+
     #[inline]
     pub fn alloc<T>(&self, value: T) -> Box<'a, T> {
         Box(self.allocator.alloc(value))
@@ -53,7 +60,7 @@ impl<'a> AstBuilder<'a> {
         body: Vec<'a, Statement<'a>>,
         source_type: SourceType,
     ) -> Program<'a> {
-        Program { span, directives, body, source_type }
+        Program { span, node_id: self.node_id(), directives, body, source_type }
     }
 
     /* ---------- Literals ---------- */
@@ -112,39 +119,51 @@ impl<'a> AstBuilder<'a> {
         expression: StringLiteral,
         directive: &'a str,
     ) -> Directive<'a> {
-        Directive { span, expression, directive }
+        Directive { span, node_id: self.node_id(), expression, directive }
     }
 
     #[must_use]
     #[inline]
     pub fn block(&self, span: Span, body: Vec<'a, Statement<'a>>) -> Box<'a, BlockStatement<'a>> {
-        self.alloc(BlockStatement { span, body })
+        self.alloc(BlockStatement { span, node_id: self.node_id(), body })
     }
 
     #[must_use]
     #[inline]
     pub fn block_statement(&self, block: Box<'a, BlockStatement<'a>>) -> Statement<'a> {
-        Statement::BlockStatement(
-            self.alloc(BlockStatement { span: block.span, body: block.unbox().body }),
-        )
+        Statement::BlockStatement(self.alloc(BlockStatement {
+            span: block.span,
+            node_id: block.node_id,
+            body: block.unbox().body,
+        }))
     }
 
     #[must_use]
     #[inline]
     pub fn break_statement(&self, span: Span, label: Option<LabelIdentifier>) -> Statement<'a> {
-        Statement::BreakStatement(self.alloc(BreakStatement { span, label }))
+        Statement::BreakStatement(self.alloc(BreakStatement {
+            span,
+            node_id: self.node_id(),
+            label,
+        }))
     }
 
     #[must_use]
     #[inline]
     pub fn continue_statement(&self, span: Span, label: Option<LabelIdentifier>) -> Statement<'a> {
-        Statement::ContinueStatement(self.alloc(ContinueStatement { span, label }))
+        Statement::ContinueStatement(self.alloc(ContinueStatement {
+            span,
+            node_id: self.node_id(),
+            label,
+        }))
     }
 
     #[must_use]
     #[inline]
     pub fn debugger_statement(&self, span: Span) -> Statement<'a> {
-        Statement::DebuggerStatement(self.alloc(DebuggerStatement { span }))
+        Statement::DebuggerStatement(
+            self.alloc(DebuggerStatement { span, node_id: self.node_id() }),
+        )
     }
 
     #[must_use]
@@ -155,19 +174,28 @@ impl<'a> AstBuilder<'a> {
         body: Statement<'a>,
         test: Expression<'a>,
     ) -> Statement<'a> {
-        Statement::DoWhileStatement(self.alloc(DoWhileStatement { span, body, test }))
+        Statement::DoWhileStatement(self.alloc(DoWhileStatement {
+            span,
+            node_id: self.node_id(),
+            body,
+            test,
+        }))
     }
 
     #[must_use]
     #[inline]
     pub fn empty_statement(&self, span: Span) -> Statement<'a> {
-        Statement::EmptyStatement(self.alloc(EmptyStatement { span }))
+        Statement::EmptyStatement(self.alloc(EmptyStatement { span, node_id: self.node_id() }))
     }
 
     #[must_use]
     #[inline]
     pub fn expression_statement(&self, span: Span, expression: Expression<'a>) -> Statement<'a> {
-        Statement::ExpressionStatement(self.alloc(ExpressionStatement { span, expression }))
+        Statement::ExpressionStatement(self.alloc(ExpressionStatement {
+            span,
+            node_id: self.node_id(),
+            expression,
+        }))
     }
 
     #[must_use]
@@ -179,7 +207,13 @@ impl<'a> AstBuilder<'a> {
         right: Expression<'a>,
         body: Statement<'a>,
     ) -> Statement<'a> {
-        Statement::ForInStatement(self.alloc(ForInStatement { span, left, right, body }))
+        Statement::ForInStatement(self.alloc(ForInStatement {
+            span,
+            node_id: self.node_id(),
+            left,
+            right,
+            body,
+        }))
     }
 
     #[must_use]
@@ -192,7 +226,14 @@ impl<'a> AstBuilder<'a> {
         right: Expression<'a>,
         body: Statement<'a>,
     ) -> Statement<'a> {
-        Statement::ForOfStatement(self.alloc(ForOfStatement { span, r#await, left, right, body }))
+        Statement::ForOfStatement(self.alloc(ForOfStatement {
+            span,
+            node_id: self.node_id(),
+            r#await,
+            left,
+            right,
+            body,
+        }))
     }
 
     #[must_use]
@@ -205,7 +246,14 @@ impl<'a> AstBuilder<'a> {
         update: Option<Expression<'a>>,
         body: Statement<'a>,
     ) -> Statement<'a> {
-        Statement::ForStatement(self.alloc(ForStatement { span, init, test, update, body }))
+        Statement::ForStatement(self.alloc(ForStatement {
+            span,
+            node_id: self.node_id(),
+            init,
+            test,
+            update,
+            body,
+        }))
     }
 
     #[must_use]
@@ -217,7 +265,13 @@ impl<'a> AstBuilder<'a> {
         consequent: Statement<'a>,
         alternate: Option<Statement<'a>>,
     ) -> Statement<'a> {
-        Statement::IfStatement(self.alloc(IfStatement { span, test, consequent, alternate }))
+        Statement::IfStatement(self.alloc(IfStatement {
+            span,
+            node_id: self.node_id(),
+            test,
+            consequent,
+            alternate,
+        }))
     }
 
     #[must_use]
@@ -228,13 +282,22 @@ impl<'a> AstBuilder<'a> {
         label: LabelIdentifier,
         body: Statement<'a>,
     ) -> Statement<'a> {
-        Statement::LabeledStatement(self.alloc(LabeledStatement { span, label, body }))
+        Statement::LabeledStatement(self.alloc(LabeledStatement {
+            span,
+            node_id: self.node_id(),
+            label,
+            body,
+        }))
     }
 
     #[must_use]
     #[inline]
     pub fn return_statement(&self, span: Span, argument: Option<Expression<'a>>) -> Statement<'a> {
-        Statement::ReturnStatement(self.alloc(ReturnStatement { span, argument }))
+        Statement::ReturnStatement(self.alloc(ReturnStatement {
+            span,
+            node_id: self.node_id(),
+            argument,
+        }))
     }
 
     #[must_use]
@@ -245,7 +308,12 @@ impl<'a> AstBuilder<'a> {
         discriminant: Expression<'a>,
         cases: Vec<'a, SwitchCase<'a>>,
     ) -> Statement<'a> {
-        Statement::SwitchStatement(self.alloc(SwitchStatement { span, discriminant, cases }))
+        Statement::SwitchStatement(self.alloc(SwitchStatement {
+            span,
+            node_id: self.node_id(),
+            discriminant,
+            cases,
+        }))
     }
 
     #[must_use]
@@ -256,13 +324,17 @@ impl<'a> AstBuilder<'a> {
         test: Option<Expression<'a>>,
         consequent: Vec<'a, Statement<'a>>,
     ) -> SwitchCase<'a> {
-        SwitchCase { span, test, consequent }
+        SwitchCase { span, node_id: self.node_id(), test, consequent }
     }
 
     #[must_use]
     #[inline]
     pub fn throw_statement(&self, span: Span, argument: Expression<'a>) -> Statement<'a> {
-        Statement::ThrowStatement(self.alloc(ThrowStatement { span, argument }))
+        Statement::ThrowStatement(self.alloc(ThrowStatement {
+            span,
+            node_id: self.node_id(),
+            argument,
+        }))
     }
 
     #[must_use]
@@ -274,7 +346,13 @@ impl<'a> AstBuilder<'a> {
         handler: Option<Box<'a, CatchClause<'a>>>,
         finalizer: Option<Box<'a, BlockStatement<'a>>>,
     ) -> Statement<'a> {
-        Statement::TryStatement(self.alloc(TryStatement { span, block, handler, finalizer }))
+        Statement::TryStatement(self.alloc(TryStatement {
+            span,
+            node_id: self.node_id(),
+            block,
+            handler,
+            finalizer,
+        }))
     }
 
     #[must_use]
@@ -285,7 +363,7 @@ impl<'a> AstBuilder<'a> {
         param: Option<BindingPattern<'a>>,
         body: Box<'a, BlockStatement<'a>>,
     ) -> Box<'a, CatchClause<'a>> {
-        self.alloc(CatchClause { span, param, body })
+        self.alloc(CatchClause { span, node_id: self.node_id(), param, body })
     }
 
     #[must_use]
@@ -296,7 +374,12 @@ impl<'a> AstBuilder<'a> {
         test: Expression<'a>,
         body: Statement<'a>,
     ) -> Statement<'a> {
-        Statement::WhileStatement(self.alloc(WhileStatement { span, test, body }))
+        Statement::WhileStatement(self.alloc(WhileStatement {
+            span,
+            node_id: self.node_id(),
+            test,
+            body,
+        }))
     }
 
     #[must_use]
@@ -307,7 +390,12 @@ impl<'a> AstBuilder<'a> {
         object: Expression<'a>,
         body: Statement<'a>,
     ) -> Statement<'a> {
-        Statement::WithStatement(self.alloc(WithStatement { span, object, body }))
+        Statement::WithStatement(self.alloc(WithStatement {
+            span,
+            node_id: self.node_id(),
+            object,
+            body,
+        }))
     }
 
     /* ---------- Expressions ---------- */
@@ -315,7 +403,7 @@ impl<'a> AstBuilder<'a> {
     #[must_use]
     #[inline]
     pub fn super_(&self, span: Span) -> Expression<'a> {
-        Expression::Super(self.alloc(Super { span }))
+        Expression::Super(self.alloc(Super { span, node_id: self.node_id() }))
     }
 
     #[must_use]
@@ -326,7 +414,12 @@ impl<'a> AstBuilder<'a> {
         meta: IdentifierName,
         property: IdentifierName,
     ) -> Expression<'a> {
-        Expression::MetaProperty(self.alloc(MetaProperty { span, meta, property }))
+        Expression::MetaProperty(self.alloc(MetaProperty {
+            span,
+            node_id: self.node_id(),
+            meta,
+            property,
+        }))
     }
 
     #[must_use]
@@ -337,7 +430,12 @@ impl<'a> AstBuilder<'a> {
         elements: Vec<'a, Option<Argument<'a>>>,
         trailing_comma: Option<Span>,
     ) -> Expression<'a> {
-        Expression::ArrayExpression(self.alloc(ArrayExpression { span, elements, trailing_comma }))
+        Expression::ArrayExpression(self.alloc(ArrayExpression {
+            span,
+            node_id: self.node_id(),
+            elements,
+            trailing_comma,
+        }))
     }
 
     #[must_use]
@@ -355,6 +453,7 @@ impl<'a> AstBuilder<'a> {
     ) -> Expression<'a> {
         Expression::ArrowFunctionExpression(self.alloc(ArrowExpression {
             span,
+            node_id: self.node_id(),
             expression,
             generator,
             r#async,
@@ -376,6 +475,7 @@ impl<'a> AstBuilder<'a> {
     ) -> Expression<'a> {
         Expression::AssignmentExpression(self.alloc(AssignmentExpression {
             span,
+            node_id: self.node_id(),
             operator,
             left,
             right,
@@ -385,7 +485,11 @@ impl<'a> AstBuilder<'a> {
     #[must_use]
     #[inline]
     pub fn await_expression(&self, span: Span, argument: Expression<'a>) -> Expression<'a> {
-        Expression::AwaitExpression(self.alloc(AwaitExpression { span, argument }))
+        Expression::AwaitExpression(self.alloc(AwaitExpression {
+            span,
+            node_id: self.node_id(),
+            argument,
+        }))
     }
 
     #[must_use]
@@ -397,7 +501,13 @@ impl<'a> AstBuilder<'a> {
         operator: BinaryOperator,
         right: Expression<'a>,
     ) -> Expression<'a> {
-        Expression::BinaryExpression(self.alloc(BinaryExpression { span, left, operator, right }))
+        Expression::BinaryExpression(self.alloc(BinaryExpression {
+            span,
+            node_id: self.node_id(),
+            left,
+            operator,
+            right,
+        }))
     }
 
     #[must_use]
@@ -412,6 +522,7 @@ impl<'a> AstBuilder<'a> {
     ) -> Expression<'a> {
         Expression::CallExpression(self.alloc(CallExpression {
             span,
+            node_id: self.node_id(),
             callee,
             arguments,
             optional,
@@ -422,7 +533,11 @@ impl<'a> AstBuilder<'a> {
     #[must_use]
     #[inline]
     pub fn chain_expression(&self, span: Span, expression: ChainElement<'a>) -> Expression<'a> {
-        Expression::ChainExpression(self.alloc(ChainExpression { span, expression }))
+        Expression::ChainExpression(self.alloc(ChainExpression {
+            span,
+            node_id: self.node_id(),
+            expression,
+        }))
     }
 
     #[must_use]
@@ -442,6 +557,7 @@ impl<'a> AstBuilder<'a> {
     ) -> Expression<'a> {
         Expression::ConditionalExpression(self.alloc(ConditionalExpression {
             span,
+            node_id: self.node_id(),
             test,
             consequent,
             alternate,
@@ -462,7 +578,12 @@ impl<'a> AstBuilder<'a> {
         source: Expression<'a>,
         arguments: Vec<'a, Expression<'a>>,
     ) -> Expression<'a> {
-        Expression::ImportExpression(self.alloc(ImportExpression { span, source, arguments }))
+        Expression::ImportExpression(self.alloc(ImportExpression {
+            span,
+            node_id: self.node_id(),
+            source,
+            arguments,
+        }))
     }
 
     #[must_use]
@@ -474,7 +595,13 @@ impl<'a> AstBuilder<'a> {
         operator: LogicalOperator,
         right: Expression<'a>,
     ) -> Expression<'a> {
-        Expression::LogicalExpression(self.alloc(LogicalExpression { span, left, operator, right }))
+        Expression::LogicalExpression(self.alloc(LogicalExpression {
+            span,
+            node_id: self.node_id(),
+            left,
+            operator,
+            right,
+        }))
     }
 
     #[must_use]
@@ -489,6 +616,7 @@ impl<'a> AstBuilder<'a> {
         Expression::MemberExpression(self.alloc({
             MemberExpression::ComputedMemberExpression(ComputedMemberExpression {
                 span,
+                node_id: self.node_id(),
                 object,
                 expression,
                 optional,
@@ -508,6 +636,7 @@ impl<'a> AstBuilder<'a> {
         Expression::MemberExpression(self.alloc({
             MemberExpression::StaticMemberExpression(StaticMemberExpression {
                 span,
+                node_id: self.node_id(),
                 object,
                 property,
                 optional,
@@ -527,6 +656,7 @@ impl<'a> AstBuilder<'a> {
         Expression::MemberExpression(self.alloc({
             MemberExpression::PrivateFieldExpression(PrivateFieldExpression {
                 span,
+                node_id: self.node_id(),
                 object,
                 field,
                 optional,
@@ -545,6 +675,7 @@ impl<'a> AstBuilder<'a> {
     ) -> Expression<'a> {
         Expression::NewExpression(self.alloc(NewExpression {
             span,
+            node_id: self.node_id(),
             callee,
             arguments,
             type_parameters,
@@ -561,6 +692,7 @@ impl<'a> AstBuilder<'a> {
     ) -> Expression<'a> {
         Expression::ObjectExpression(self.alloc(ObjectExpression {
             span,
+            node_id: self.node_id(),
             properties,
             trailing_comma,
         }))
@@ -573,9 +705,11 @@ impl<'a> AstBuilder<'a> {
         span: Span,
         expression: Expression<'a>,
     ) -> Expression<'a> {
-        Expression::ParenthesizedExpression(
-            self.alloc(ParenthesizedExpression { span, expression }),
-        )
+        Expression::ParenthesizedExpression(self.alloc(ParenthesizedExpression {
+            span,
+            node_id: self.node_id(),
+            expression,
+        }))
     }
 
     #[must_use]
@@ -585,7 +719,11 @@ impl<'a> AstBuilder<'a> {
         span: Span,
         expressions: Vec<'a, Expression<'a>>,
     ) -> Expression<'a> {
-        Expression::SequenceExpression(self.alloc(SequenceExpression { span, expressions }))
+        Expression::SequenceExpression(self.alloc(SequenceExpression {
+            span,
+            node_id: self.node_id(),
+            expressions,
+        }))
     }
 
     #[must_use]
@@ -599,6 +737,7 @@ impl<'a> AstBuilder<'a> {
     ) -> Expression<'a> {
         Expression::TaggedTemplateExpression(self.alloc(TaggedTemplateExpression {
             span,
+            node_id: self.node_id(),
             tag,
             quasi,
             type_parameters,
@@ -617,7 +756,7 @@ impl<'a> AstBuilder<'a> {
     #[must_use]
     #[inline]
     pub fn this_expression(&self, span: Span) -> Expression<'a> {
-        Expression::ThisExpression(self.alloc(ThisExpression { span }))
+        Expression::ThisExpression(self.alloc(ThisExpression { span, node_id: self.node_id() }))
     }
 
     #[must_use]
@@ -631,6 +770,7 @@ impl<'a> AstBuilder<'a> {
     ) -> Expression<'a> {
         Expression::UnaryExpression(self.alloc(UnaryExpression {
             span,
+            node_id: self.node_id(),
             operator,
             prefix,
             argument,
@@ -648,6 +788,7 @@ impl<'a> AstBuilder<'a> {
     ) -> Expression<'a> {
         Expression::UpdateExpression(self.alloc(UpdateExpression {
             span,
+            node_id: self.node_id(),
             operator,
             prefix,
             argument,
@@ -662,7 +803,12 @@ impl<'a> AstBuilder<'a> {
         delegate: bool,
         argument: Option<Expression<'a>>,
     ) -> Expression<'a> {
-        Expression::YieldExpression(self.alloc(YieldExpression { span, delegate, argument }))
+        Expression::YieldExpression(self.alloc(YieldExpression {
+            span,
+            node_id: self.node_id(),
+            delegate,
+            argument,
+        }))
     }
 
     /* ---------- Functions ---------- */
@@ -680,7 +826,7 @@ impl<'a> AstBuilder<'a> {
         kind: FormalParameterKind,
         items: Vec<'a, FormalParameter<'a>>,
     ) -> Box<'a, FormalParameters<'a>> {
-        self.alloc(FormalParameters { span, kind, items })
+        self.alloc(FormalParameters { span, node_id: self.node_id(), kind, items })
     }
 
     #[must_use]
@@ -693,7 +839,14 @@ impl<'a> AstBuilder<'a> {
         readonly: bool,
         decorators: Vec<'a, Decorator<'a>>,
     ) -> FormalParameter<'a> {
-        FormalParameter { span, pattern, accessibility, readonly, decorators }
+        FormalParameter {
+            span,
+            node_id: self.node_id(),
+            pattern,
+            accessibility,
+            readonly,
+            decorators,
+        }
     }
 
     #[must_use]
@@ -716,6 +869,7 @@ impl<'a> AstBuilder<'a> {
         self.alloc(Function {
             r#type,
             span,
+            node_id: self.node_id(),
             id,
             expression,
             generator,
@@ -736,7 +890,7 @@ impl<'a> AstBuilder<'a> {
         directives: Vec<'a, Directive>,
         statements: Vec<'a, Statement<'a>>,
     ) -> Box<'a, FunctionBody<'a>> {
-        self.alloc(FunctionBody { span, directives, statements })
+        self.alloc(FunctionBody { span, node_id: self.node_id(), directives, statements })
     }
 
     /* ---------- Class ---------- */
@@ -759,6 +913,7 @@ impl<'a> AstBuilder<'a> {
         self.alloc(Class {
             r#type,
             span,
+            node_id: self.node_id(),
             id,
             super_class,
             body,
@@ -777,7 +932,7 @@ impl<'a> AstBuilder<'a> {
         span: Span,
         body: Vec<'a, ClassElement<'a>>,
     ) -> Box<'a, ClassBody<'a>> {
-        self.alloc(ClassBody { span, body })
+        self.alloc(ClassBody { span, node_id: self.node_id(), body })
     }
 
     #[must_use]
@@ -789,7 +944,7 @@ impl<'a> AstBuilder<'a> {
     #[must_use]
     #[inline]
     pub fn static_block(&self, span: Span, body: Vec<'a, Statement<'a>>) -> ClassElement<'a> {
-        ClassElement::StaticBlock(self.alloc(StaticBlock { span, body }))
+        ClassElement::StaticBlock(self.alloc(StaticBlock { span, node_id: self.node_id(), body }))
     }
 
     #[must_use]
@@ -804,6 +959,7 @@ impl<'a> AstBuilder<'a> {
     ) -> ClassElement<'a> {
         ClassElement::AccessorProperty(self.alloc(AccessorProperty {
             span,
+            node_id: self.node_id(),
             key,
             value,
             computed,
@@ -822,7 +978,13 @@ impl<'a> AstBuilder<'a> {
         declarations: Vec<'a, VariableDeclarator<'a>>,
         modifiers: Modifiers<'a>,
     ) -> Box<'a, VariableDeclaration<'a>> {
-        self.alloc(VariableDeclaration { span, kind, declarations, modifiers })
+        self.alloc(VariableDeclaration {
+            span,
+            node_id: self.node_id(),
+            kind,
+            declarations,
+            modifiers,
+        })
     }
 
     #[must_use]
@@ -835,7 +997,7 @@ impl<'a> AstBuilder<'a> {
         init: Option<Expression<'a>>,
         definite: bool,
     ) -> VariableDeclarator<'a> {
-        VariableDeclarator { span, kind, id, init, definite }
+        VariableDeclarator { span, node_id: self.node_id(), kind, id, init, definite }
     }
 
     /* ---------- Patterns ---------- */
@@ -864,7 +1026,11 @@ impl<'a> AstBuilder<'a> {
         span: Span,
         properties: Vec<'a, ObjectPatternProperty<'a>>,
     ) -> BindingPatternKind<'a> {
-        BindingPatternKind::ObjectPattern(self.alloc(ObjectPattern { span, properties }))
+        BindingPatternKind::ObjectPattern(self.alloc(ObjectPattern {
+            span,
+            node_id: self.node_id(),
+            properties,
+        }))
     }
 
     #[must_use]
@@ -874,7 +1040,7 @@ impl<'a> AstBuilder<'a> {
         span: Span,
         argument: Expression<'a>,
     ) -> Box<'a, SpreadElement<'a>> {
-        self.alloc(SpreadElement { span, argument })
+        self.alloc(SpreadElement { span, node_id: self.node_id(), argument })
     }
 
     #[must_use]
@@ -889,7 +1055,16 @@ impl<'a> AstBuilder<'a> {
         shorthand: bool,
         computed: bool,
     ) -> Box<'a, Property<'a>> {
-        self.alloc(Property { span, kind, key, value, method, shorthand, computed })
+        self.alloc(Property {
+            span,
+            node_id: self.node_id(),
+            kind,
+            key,
+            value,
+            method,
+            shorthand,
+            computed,
+        })
     }
 
     #[must_use]
@@ -899,7 +1074,11 @@ impl<'a> AstBuilder<'a> {
         span: Span,
         elements: Vec<'a, Option<BindingPattern<'a>>>,
     ) -> BindingPatternKind<'a> {
-        BindingPatternKind::ArrayPattern(self.alloc(ArrayPattern { span, elements }))
+        BindingPatternKind::ArrayPattern(self.alloc(ArrayPattern {
+            span,
+            node_id: self.node_id(),
+            elements,
+        }))
     }
 
     #[must_use]
@@ -910,7 +1089,7 @@ impl<'a> AstBuilder<'a> {
         left: BindingPattern<'a>,
         right: Expression<'a>,
     ) -> BindingPattern<'a> {
-        let pattern = self.alloc(AssignmentPattern { span, left, right });
+        let pattern = self.alloc(AssignmentPattern { span, node_id: self.node_id(), left, right });
         BindingPattern {
             kind: BindingPatternKind::AssignmentPattern(pattern),
             type_annotation: None,
@@ -925,7 +1104,7 @@ impl<'a> AstBuilder<'a> {
         span: Span,
         argument: BindingPattern<'a>,
     ) -> Box<'a, RestElement<'a>> {
-        self.alloc(RestElement { span, argument })
+        self.alloc(RestElement { span, node_id: self.node_id(), argument })
     }
 
     #[must_use]
@@ -943,7 +1122,11 @@ impl<'a> AstBuilder<'a> {
     #[must_use]
     #[inline]
     pub fn module_declaration(&self, span: Span, kind: ModuleDeclarationKind<'a>) -> Statement<'a> {
-        Statement::ModuleDeclaration(self.alloc(ModuleDeclaration { span, kind }))
+        Statement::ModuleDeclaration(self.alloc(ModuleDeclaration {
+            span,
+            node_id: self.node_id(),
+            kind,
+        }))
     }
 
     #[must_use]
@@ -1002,7 +1185,13 @@ impl<'a> AstBuilder<'a> {
         closing_element: Option<Box<'a, JSXClosingElement<'a>>>,
         children: Vec<'a, JSXChild<'a>>,
     ) -> Box<'a, JSXElement<'a>> {
-        self.alloc(JSXElement { span, opening_element, closing_element, children })
+        self.alloc(JSXElement {
+            span,
+            node_id: self.node_id(),
+            opening_element,
+            closing_element,
+            children,
+        })
     }
 
     #[must_use]
@@ -1015,7 +1204,14 @@ impl<'a> AstBuilder<'a> {
         attributes: Vec<'a, JSXAttributeItem<'a>>,
         type_parameters: Option<Box<'a, TSTypeParameterInstantiation<'a>>>,
     ) -> Box<'a, JSXOpeningElement<'a>> {
-        self.alloc(JSXOpeningElement { span, self_closing, name, attributes, type_parameters })
+        self.alloc(JSXOpeningElement {
+            span,
+            node_id: self.node_id(),
+            self_closing,
+            name,
+            attributes,
+            type_parameters,
+        })
     }
 
     #[must_use]
@@ -1025,7 +1221,7 @@ impl<'a> AstBuilder<'a> {
         span: Span,
         name: JSXElementName<'a>,
     ) -> Box<'a, JSXClosingElement<'a>> {
-        self.alloc(JSXClosingElement { span, name })
+        self.alloc(JSXClosingElement { span, node_id: self.node_id(), name })
     }
 
     #[must_use]
@@ -1037,19 +1233,25 @@ impl<'a> AstBuilder<'a> {
         closing_fragment: JSXClosingFragment,
         children: Vec<'a, JSXChild<'a>>,
     ) -> Box<'a, JSXFragment<'a>> {
-        self.alloc(JSXFragment { span, opening_fragment, closing_fragment, children })
+        self.alloc(JSXFragment {
+            span,
+            node_id: self.node_id(),
+            opening_fragment,
+            closing_fragment,
+            children,
+        })
     }
 
     #[must_use]
     #[inline]
     pub fn jsx_opening_fragment(&self, span: Span) -> JSXOpeningFragment {
-        JSXOpeningFragment { span }
+        JSXOpeningFragment { span, node_id: self.node_id() }
     }
 
     #[must_use]
     #[inline]
     pub fn jsx_closing_fragment(&self, span: Span) -> JSXClosingFragment {
-        JSXClosingFragment { span }
+        JSXClosingFragment { span, node_id: self.node_id() }
     }
 
     #[must_use]
@@ -1060,7 +1262,7 @@ impl<'a> AstBuilder<'a> {
         namespace: JSXIdentifier,
         property: JSXIdentifier,
     ) -> Box<'a, JSXNamespacedName> {
-        self.alloc(JSXNamespacedName { span, namespace, property })
+        self.alloc(JSXNamespacedName { span, node_id: self.node_id(), namespace, property })
     }
 
     #[must_use]
@@ -1071,7 +1273,7 @@ impl<'a> AstBuilder<'a> {
         object: JSXMemberExpressionObject<'a>,
         property: JSXIdentifier,
     ) -> Box<'a, JSXMemberExpression<'a>> {
-        self.alloc(JSXMemberExpression { span, object, property })
+        self.alloc(JSXMemberExpression { span, node_id: self.node_id(), object, property })
     }
 
     #[must_use]
@@ -1081,19 +1283,19 @@ impl<'a> AstBuilder<'a> {
         span: Span,
         expression: JSXExpression<'a>,
     ) -> JSXExpressionContainer<'a> {
-        JSXExpressionContainer { span, expression }
+        JSXExpressionContainer { span, node_id: self.node_id(), expression }
     }
 
     #[must_use]
     #[inline]
     pub fn jsx_spread_child(&self, span: Span, expression: Expression<'a>) -> JSXSpreadChild<'a> {
-        JSXSpreadChild { span, expression }
+        JSXSpreadChild { span, node_id: self.node_id(), expression }
     }
 
     #[must_use]
     #[inline]
     pub fn jsx_empty_expression(&self, span: Span) -> JSXEmptyExpression {
-        JSXEmptyExpression { span }
+        JSXEmptyExpression { span, node_id: self.node_id() }
     }
 
     #[must_use]
@@ -1104,7 +1306,7 @@ impl<'a> AstBuilder<'a> {
         name: JSXAttributeName<'a>,
         value: Option<JSXAttributeValue<'a>>,
     ) -> Box<'a, JSXAttribute<'a>> {
-        self.alloc(JSXAttribute { span, name, value })
+        self.alloc(JSXAttribute { span, node_id: self.node_id(), name, value })
     }
 
     #[must_use]
@@ -1114,19 +1316,19 @@ impl<'a> AstBuilder<'a> {
         span: Span,
         argument: Expression<'a>,
     ) -> Box<'a, JSXSpreadAttribute<'a>> {
-        self.alloc(JSXSpreadAttribute { span, argument })
+        self.alloc(JSXSpreadAttribute { span, node_id: self.node_id(), argument })
     }
 
     #[must_use]
     #[inline]
     pub fn jsx_identifier(&self, span: Span, name: Atom) -> JSXIdentifier {
-        JSXIdentifier { span, name }
+        JSXIdentifier { span, node_id: self.node_id(), name }
     }
 
     #[must_use]
     #[inline]
     pub fn jsx_text(&self, span: Span, value: Atom) -> JSXText {
-        JSXText { span, value }
+        JSXText { span, node_id: self.node_id(), value }
     }
 
     /* ---------- TypeScript ---------- */
@@ -1139,7 +1341,7 @@ impl<'a> AstBuilder<'a> {
         body: TSModuleDeclarationBody<'a>,
         modifiers: Modifiers<'a>,
     ) -> Box<'a, TSModuleDeclaration<'a>> {
-        self.alloc(TSModuleDeclaration { span, id, body, modifiers })
+        self.alloc(TSModuleDeclaration { span, node_id: self.node_id(), id, body, modifiers })
     }
 
     #[must_use]
@@ -1149,25 +1351,29 @@ impl<'a> AstBuilder<'a> {
         span: Span,
         type_annotation: TSType<'a>,
     ) -> Box<'a, TSTypeAnnotation<'a>> {
-        self.alloc(TSTypeAnnotation { span, type_annotation })
+        self.alloc(TSTypeAnnotation { span, node_id: self.node_id(), type_annotation })
     }
 
     #[must_use]
     #[inline]
     pub fn ts_literal_type(&self, span: Span, literal: TSLiteral<'a>) -> TSType<'a> {
-        TSType::TSLiteralType(self.alloc(TSLiteralType { span, literal }))
+        TSType::TSLiteralType(self.alloc(TSLiteralType { span, node_id: self.node_id(), literal }))
     }
 
     #[must_use]
     #[inline]
     pub fn ts_union_type(&self, span: Span, types: Vec<'a, TSType<'a>>) -> TSType<'a> {
-        TSType::TSUnionType(self.alloc(TSUnionType { span, types }))
+        TSType::TSUnionType(self.alloc(TSUnionType { span, node_id: self.node_id(), types }))
     }
 
     #[must_use]
     #[inline]
     pub fn ts_intersection_type(&self, span: Span, types: Vec<'a, TSType<'a>>) -> TSType<'a> {
-        TSType::TSIntersectionType(self.alloc(TSIntersectionType { span, types }))
+        TSType::TSIntersectionType(self.alloc(TSIntersectionType {
+            span,
+            node_id: self.node_id(),
+            types,
+        }))
     }
 
     #[must_use]
@@ -1180,6 +1386,7 @@ impl<'a> AstBuilder<'a> {
     ) -> TSType<'a> {
         TSType::TSTypeOperatorType(self.alloc(TSTypeOperatorType {
             span,
+            node_id: self.node_id(),
             operator,
             type_annotation,
         }))
@@ -1188,7 +1395,7 @@ impl<'a> AstBuilder<'a> {
     #[must_use]
     #[inline]
     pub fn ts_array_type(&self, span: Span, element_type: TSType<'a>) -> TSType<'a> {
-        TSType::TSArrayType(self.alloc(TSArrayType { span, element_type }))
+        TSType::TSArrayType(self.alloc(TSArrayType { span, node_id: self.node_id(), element_type }))
     }
 
     #[must_use]
@@ -1201,6 +1408,7 @@ impl<'a> AstBuilder<'a> {
     ) -> TSType<'a> {
         TSType::TSIndexedAccessType(self.alloc(TSIndexedAccessType {
             span,
+            node_id: self.node_id(),
             object_type,
             index_type,
         }))
@@ -1213,7 +1421,11 @@ impl<'a> AstBuilder<'a> {
         span: Span,
         element_types: Vec<'a, TSTupleElement<'a>>,
     ) -> TSType<'a> {
-        TSType::TSTupleType(self.alloc(TSTupleType { span, element_types }))
+        TSType::TSTupleType(self.alloc(TSTupleType {
+            span,
+            node_id: self.node_id(),
+            element_types,
+        }))
     }
 
     #[must_use]
@@ -1224,13 +1436,18 @@ impl<'a> AstBuilder<'a> {
         type_name: TSTypeName<'a>,
         type_parameters: Option<Box<'a, TSTypeParameterInstantiation<'a>>>,
     ) -> TSType<'a> {
-        TSType::TSTypeReference(self.alloc(TSTypeReference { span, type_name, type_parameters }))
+        TSType::TSTypeReference(self.alloc(TSTypeReference {
+            span,
+            node_id: self.node_id(),
+            type_name,
+            type_parameters,
+        }))
     }
 
     #[must_use]
     #[inline]
     pub fn ts_type_literal(&self, span: Span, members: Vec<'a, TSSignature<'a>>) -> TSType<'a> {
-        TSType::TSTypeLiteral(self.alloc(TSTypeLiteral { span, members }))
+        TSType::TSTypeLiteral(self.alloc(TSTypeLiteral { span, node_id: self.node_id(), members }))
     }
 
     #[must_use]
@@ -1241,7 +1458,7 @@ impl<'a> AstBuilder<'a> {
         expression: TSTypeName<'a>,
         type_parameters: Option<Box<'a, TSTypeParameterInstantiation<'a>>>,
     ) -> Box<'a, TSClassImplements<'a>> {
-        self.alloc(TSClassImplements { span, expression, type_parameters })
+        self.alloc(TSClassImplements { span, node_id: self.node_id(), expression, type_parameters })
     }
 
     #[must_use]
@@ -1255,7 +1472,15 @@ impl<'a> AstBuilder<'a> {
         r#in: bool,
         out: bool,
     ) -> Box<'a, TSTypeParameter<'a>> {
-        self.alloc(TSTypeParameter { span, name, constraint, default, r#in, out })
+        self.alloc(TSTypeParameter {
+            span,
+            node_id: self.node_id(),
+            name,
+            constraint,
+            default,
+            r#in,
+            out,
+        })
     }
 
     #[must_use]
@@ -1265,7 +1490,7 @@ impl<'a> AstBuilder<'a> {
         span: Span,
         params: Vec<'a, Box<'a, TSTypeParameter<'a>>>,
     ) -> Box<'a, TSTypeParameterDeclaration<'a>> {
-        self.alloc(TSTypeParameterDeclaration { span, params })
+        self.alloc(TSTypeParameterDeclaration { span, node_id: self.node_id(), params })
     }
 
     #[must_use]
@@ -1276,7 +1501,12 @@ impl<'a> AstBuilder<'a> {
     ) -> Vec<'a, Box<'a, TSInterfaceHeritage<'a>>> {
         Vec::from_iter_in(
             extends.into_iter().map(|(expression, type_parameters, span)| {
-                self.alloc(TSInterfaceHeritage { span, expression, type_parameters })
+                self.alloc(TSInterfaceHeritage {
+                    span,
+                    node_id: self.node_id(),
+                    expression,
+                    type_parameters,
+                })
             }),
             self.allocator,
         )
@@ -1289,7 +1519,7 @@ impl<'a> AstBuilder<'a> {
         span: Span,
         body: Vec<'a, TSSignature<'a>>,
     ) -> Box<'a, TSInterfaceBody<'a>> {
-        self.alloc(TSInterfaceBody { span, body })
+        self.alloc(TSInterfaceBody { span, node_id: self.node_id(), body })
     }
 
     #[must_use]
@@ -1302,6 +1532,7 @@ impl<'a> AstBuilder<'a> {
     ) -> TSSignature<'a> {
         TSSignature::TSIndexSignature(self.alloc(TSIndexSignature {
             span,
+            node_id: self.node_id(),
             parameters,
             type_annotation,
         }))
@@ -1320,6 +1551,7 @@ impl<'a> AstBuilder<'a> {
     ) -> TSSignature<'a> {
         TSSignature::TSPropertySignature(self.alloc(TSPropertySignature {
             span,
+            node_id: self.node_id(),
             computed,
             optional,
             readonly,
@@ -1339,6 +1571,7 @@ impl<'a> AstBuilder<'a> {
     ) -> TSSignature<'a> {
         TSSignature::TSCallSignatureDeclaration(self.alloc(TSCallSignatureDeclaration {
             span,
+            node_id: self.node_id(),
             params,
             return_type,
             type_parameters,
@@ -1356,6 +1589,7 @@ impl<'a> AstBuilder<'a> {
     ) -> TSSignature<'a> {
         TSSignature::TSConstructSignatureDeclaration(self.alloc(TSConstructSignatureDeclaration {
             span,
+            node_id: self.node_id(),
             params,
             return_type,
             type_parameters,
@@ -1377,6 +1611,7 @@ impl<'a> AstBuilder<'a> {
     ) -> TSSignature<'a> {
         TSSignature::TSMethodSignature(self.alloc(TSMethodSignature {
             span,
+            node_id: self.node_id(),
             key,
             computed,
             optional,
@@ -1394,7 +1629,7 @@ impl<'a> AstBuilder<'a> {
         span: Span,
         body: Vec<'a, Statement<'a>>,
     ) -> Box<'a, TSModuleBlock<'a>> {
-        self.alloc(TSModuleBlock { span, body })
+        self.alloc(TSModuleBlock { span, node_id: self.node_id(), body })
     }
 
     #[must_use]
@@ -1404,13 +1639,17 @@ impl<'a> AstBuilder<'a> {
         span: Span,
         params: Vec<'a, TSType<'a>>,
     ) -> Box<'a, TSTypeParameterInstantiation<'a>> {
-        self.alloc(TSTypeParameterInstantiation { span, params })
+        self.alloc(TSTypeParameterInstantiation { span, node_id: self.node_id(), params })
     }
 
     #[must_use]
     #[inline]
     pub fn ts_non_null_expression(&self, span: Span, expression: Expression<'a>) -> Expression<'a> {
-        Expression::TSNonNullExpression(self.alloc(TSNonNullExpression { span, expression }))
+        Expression::TSNonNullExpression(self.alloc(TSNonNullExpression {
+            span,
+            node_id: self.node_id(),
+            expression,
+        }))
     }
 
     #[must_use]
@@ -1423,6 +1662,7 @@ impl<'a> AstBuilder<'a> {
     ) -> Expression<'a> {
         Expression::TSTypeAssertion(self.alloc(TSTypeAssertion {
             span,
+            node_id: self.node_id(),
             type_annotation,
             expression,
         }))
@@ -1440,6 +1680,7 @@ impl<'a> AstBuilder<'a> {
     ) -> Declaration<'a> {
         Declaration::TSImportEqualsDeclaration(self.alloc(TSImportEqualsDeclaration {
             span,
+            node_id: self.node_id(),
             id,
             module_reference: self.alloc(module_reference),
             is_export,
@@ -1460,6 +1701,7 @@ impl<'a> AstBuilder<'a> {
     ) -> Declaration<'a> {
         Declaration::TSInterfaceDeclaration(self.alloc(TSInterfaceDeclaration {
             span,
+            node_id: self.node_id(),
             id,
             body,
             type_parameters,
@@ -1480,6 +1722,7 @@ impl<'a> AstBuilder<'a> {
     ) -> Declaration<'a> {
         Declaration::TSTypeAliasDeclaration(self.alloc(TSTypeAliasDeclaration {
             span,
+            node_id: self.node_id(),
             id,
             type_annotation,
             type_parameters,
@@ -1498,6 +1741,7 @@ impl<'a> AstBuilder<'a> {
     ) -> Declaration<'a> {
         Declaration::TSEnumDeclaration(self.alloc(TSEnumDeclaration {
             span,
+            node_id: self.node_id(),
             id,
             members,
             modifiers,
@@ -1507,85 +1751,85 @@ impl<'a> AstBuilder<'a> {
     #[must_use]
     #[inline]
     pub fn decorator(&self, span: Span, expression: Expression<'a>) -> Decorator<'a> {
-        Decorator { span, expression }
+        Decorator { span, node_id: self.node_id(), expression }
     }
 
     #[must_use]
     #[inline]
     pub fn ts_void_keyword(&self, span: Span) -> TSType<'a> {
-        TSType::TSVoidKeyword(self.alloc(TSVoidKeyword { span }))
+        TSType::TSVoidKeyword(self.alloc(TSVoidKeyword { span, node_id: self.node_id() }))
     }
 
     #[must_use]
     #[inline]
     pub fn ts_this_keyword(&self, span: Span) -> TSType<'a> {
-        TSType::TSThisKeyword(self.alloc(TSThisKeyword { span }))
+        TSType::TSThisKeyword(self.alloc(TSThisKeyword { span, node_id: self.node_id() }))
     }
 
     #[must_use]
     #[inline]
     pub fn ts_any_keyword(&self, span: Span) -> TSType<'a> {
-        TSType::TSAnyKeyword(self.alloc(TSAnyKeyword { span }))
+        TSType::TSAnyKeyword(self.alloc(TSAnyKeyword { span, node_id: self.node_id() }))
     }
 
     #[must_use]
     #[inline]
     pub fn ts_unknown_keyword(&self, span: Span) -> TSType<'a> {
-        TSType::TSUnknownKeyword(self.alloc(TSUnknownKeyword { span }))
+        TSType::TSUnknownKeyword(self.alloc(TSUnknownKeyword { span, node_id: self.node_id() }))
     }
 
     #[must_use]
     #[inline]
     pub fn ts_number_keyword(&self, span: Span) -> TSType<'a> {
-        TSType::TSNumberKeyword(self.alloc(TSNumberKeyword { span }))
+        TSType::TSNumberKeyword(self.alloc(TSNumberKeyword { span, node_id: self.node_id() }))
     }
 
     #[must_use]
     #[inline]
     pub fn ts_boolean_keyword(&self, span: Span) -> TSType<'a> {
-        TSType::TSBooleanKeyword(self.alloc(TSBooleanKeyword { span }))
+        TSType::TSBooleanKeyword(self.alloc(TSBooleanKeyword { span, node_id: self.node_id() }))
     }
 
     #[must_use]
     #[inline]
     pub fn ts_object_keyword(&self, span: Span) -> TSType<'a> {
-        TSType::TSObjectKeyword(self.alloc(TSObjectKeyword { span }))
+        TSType::TSObjectKeyword(self.alloc(TSObjectKeyword { span, node_id: self.node_id() }))
     }
 
     #[must_use]
     #[inline]
     pub fn ts_string_keyword(&self, span: Span) -> TSType<'a> {
-        TSType::TSStringKeyword(self.alloc(TSStringKeyword { span }))
+        TSType::TSStringKeyword(self.alloc(TSStringKeyword { span, node_id: self.node_id() }))
     }
 
     #[must_use]
     #[inline]
     pub fn ts_bigint_keyword(&self, span: Span) -> TSType<'a> {
-        TSType::TSBigIntKeyword(self.alloc(TSBigIntKeyword { span }))
+        TSType::TSBigIntKeyword(self.alloc(TSBigIntKeyword { span, node_id: self.node_id() }))
     }
 
     #[must_use]
     #[inline]
     pub fn ts_symbol_keyword(&self, span: Span) -> TSType<'a> {
-        TSType::TSSymbolKeyword(self.alloc(TSSymbolKeyword { span }))
+        TSType::TSSymbolKeyword(self.alloc(TSSymbolKeyword { span, node_id: self.node_id() }))
     }
 
     #[must_use]
     #[inline]
     pub fn ts_null_keyword(&self, span: Span) -> TSType<'a> {
-        TSType::TSNullKeyword(self.alloc(TSNullKeyword { span }))
+        TSType::TSNullKeyword(self.alloc(TSNullKeyword { span, node_id: self.node_id() }))
     }
 
     #[must_use]
     #[inline]
     pub fn ts_undefined_keyword(&self, span: Span) -> TSType<'a> {
-        TSType::TSUndefinedKeyword(self.alloc(TSUndefinedKeyword { span }))
+        TSType::TSUndefinedKeyword(self.alloc(TSUndefinedKeyword { span, node_id: self.node_id() }))
     }
 
     #[must_use]
     #[inline]
     pub fn ts_never_keyword(&self, span: Span) -> TSType<'a> {
-        TSType::TSNeverKeyword(self.alloc(TSNeverKeyword { span }))
+        TSType::TSNeverKeyword(self.alloc(TSNeverKeyword { span, node_id: self.node_id() }))
     }
 
     #[must_use]
@@ -1596,7 +1840,12 @@ impl<'a> AstBuilder<'a> {
         quasis: Vec<'a, TemplateElement>,
         types: Vec<'a, TSType<'a>>,
     ) -> TSType<'a> {
-        TSType::TSTemplateLiteralType(self.alloc(TSTemplateLiteralType { span, quasis, types }))
+        TSType::TSTemplateLiteralType(self.alloc(TSTemplateLiteralType {
+            span,
+            node_id: self.node_id(),
+            quasis,
+            types,
+        }))
     }
 
     #[must_use]
@@ -1607,7 +1856,12 @@ impl<'a> AstBuilder<'a> {
         expr_name: TSTypeName<'a>,
         type_parameters: Option<Box<'a, TSTypeParameterInstantiation<'a>>>,
     ) -> TSType<'a> {
-        TSType::TSTypeQuery(self.alloc(TSTypeQuery { span, expr_name, type_parameters }))
+        TSType::TSTypeQuery(self.alloc(TSTypeQuery {
+            span,
+            node_id: self.node_id(),
+            expr_name,
+            type_parameters,
+        }))
     }
 
     #[must_use]
@@ -1622,6 +1876,7 @@ impl<'a> AstBuilder<'a> {
     ) -> TSType<'a> {
         TSType::TSConditionalType(self.alloc(TSConditionalType {
             span,
+            node_id: self.node_id(),
             check_type,
             extends_type,
             true_type,
@@ -1642,6 +1897,7 @@ impl<'a> AstBuilder<'a> {
     ) -> TSType<'a> {
         TSType::TSMappedType(self.alloc(TSMappedType {
             span,
+            node_id: self.node_id(),
             type_parameter,
             name_type,
             type_annotation,
@@ -1662,6 +1918,7 @@ impl<'a> AstBuilder<'a> {
     ) -> TSType<'a> {
         TSType::TSImportType(self.alloc(TSImportType {
             span,
+            node_id: self.node_id(),
             is_type_of,
             parameter,
             qualifier,
@@ -1681,6 +1938,7 @@ impl<'a> AstBuilder<'a> {
     ) -> TSType<'a> {
         TSType::TSConstructorType(self.alloc(TSConstructorType {
             span,
+            node_id: self.node_id(),
             r#abstract,
             params,
             return_type,
@@ -1699,6 +1957,7 @@ impl<'a> AstBuilder<'a> {
     ) -> TSType<'a> {
         TSType::TSFunctionType(self.alloc(TSFunctionType {
             span,
+            node_id: self.node_id(),
             params,
             return_type,
             type_parameters,
@@ -1712,7 +1971,11 @@ impl<'a> AstBuilder<'a> {
         span: Span,
         type_parameter: Box<'a, TSTypeParameter<'a>>,
     ) -> TSType<'a> {
-        TSType::TSInferType(self.alloc(TSInferType { span, type_parameter }))
+        TSType::TSInferType(self.alloc(TSInferType {
+            span,
+            node_id: self.node_id(),
+            type_parameter,
+        }))
     }
 
     #[must_use]
@@ -1726,6 +1989,7 @@ impl<'a> AstBuilder<'a> {
     ) -> TSType<'a> {
         TSType::TSTypePredicate(self.alloc(TSTypePredicate {
             span,
+            node_id: self.node_id(),
             parameter_name,
             asserts,
             type_annotation,
@@ -1741,12 +2005,17 @@ impl<'a> AstBuilder<'a> {
         type_annotation: TSType<'a>,
         postfix: bool,
     ) -> TSType<'a> {
-        TSType::JSDocNullableType(self.alloc(JSDocNullableType { span, type_annotation, postfix }))
+        TSType::JSDocNullableType(self.alloc(JSDocNullableType {
+            span,
+            node_id: self.node_id(),
+            type_annotation,
+            postfix,
+        }))
     }
 
     #[must_use]
     #[inline]
     pub fn js_doc_unknown_type(&self, span: Span) -> TSType<'a> {
-        TSType::JSDocUnknownType(self.alloc(JSDocUnknownType { span }))
+        TSType::JSDocUnknownType(self.alloc(JSDocUnknownType { span, node_id: self.node_id() }))
     }
 }
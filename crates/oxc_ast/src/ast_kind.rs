@@ -1,5 +1,5 @@
 #[allow(clippy::wildcard_imports)]
-use crate::{ast::*, Atom, GetSpan, Span};
+use crate::{ast::*, Atom, GetNodeId, GetSpan, NodeId, Span};
 
 /// Untyped AST Node Kind
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -59,12 +59,15 @@ pub enum AstKind<'a> {
     AwaitExpression(&'a AwaitExpression<'a>),
     BinaryExpression(&'a BinaryExpression<'a>),
     CallExpression(&'a CallExpression<'a>),
+    ChainExpression(&'a ChainExpression<'a>),
     ConditionalExpression(&'a ConditionalExpression<'a>),
+    ImportExpression(&'a ImportExpression<'a>),
     LogicalExpression(&'a LogicalExpression<'a>),
     MemberExpression(&'a MemberExpression<'a>),
     NewExpression(&'a NewExpression<'a>),
     ObjectExpression(&'a ObjectExpression<'a>),
     ParenthesizedExpression(&'a ParenthesizedExpression<'a>),
+    PrivateInExpression(&'a PrivateInExpression<'a>),
     SequenceExpression(&'a SequenceExpression<'a>),
     TaggedTemplateExpression(&'a TaggedTemplateExpression<'a>),
     ThisExpression(&'a ThisExpression),
@@ -92,6 +95,7 @@ pub enum AstKind<'a> {
     StaticBlock(&'a StaticBlock<'a>),
     PropertyDefinition(&'a PropertyDefinition<'a>),
     MethodDefinition(&'a MethodDefinition<'a>),
+    AccessorProperty(&'a AccessorProperty<'a>),
 
     ArrayPattern(&'a ArrayPattern<'a>),
     ObjectPattern(&'a ObjectPattern<'a>),
@@ -100,6 +104,7 @@ pub enum AstKind<'a> {
     Decorator(&'a Decorator<'a>),
 
     ModuleDeclaration(&'a ModuleDeclaration<'a>),
+    ExportSpecifier(&'a ExportSpecifier),
 
     // JSX
     // Please make sure to add these to `is_jsx` below.
@@ -117,6 +122,10 @@ pub enum AstKind<'a> {
     TSNullKeyword(&'a TSNullKeyword),
     TSTypeLiteral(&'a TSTypeLiteral<'a>),
     TSTypeReference(&'a TSTypeReference<'a>),
+    TSTypeQuery(&'a TSTypeQuery<'a>),
+    TSImportType(&'a TSImportType<'a>),
+    TSInferType(&'a TSInferType<'a>),
+    TSTemplateLiteralType(&'a TSTemplateLiteralType<'a>),
     TSUnionType(&'a TSUnionType<'a>),
     TSVoidKeyword(&'a TSVoidKeyword),
 
@@ -129,6 +138,8 @@ pub enum AstKind<'a> {
     TSEnumMember(&'a TSEnumMember<'a>),
     TSImportEqualsDeclaration(&'a TSImportEqualsDeclaration<'a>),
     TSInterfaceDeclaration(&'a TSInterfaceDeclaration<'a>),
+    TSInterfaceHeritage(&'a TSInterfaceHeritage<'a>),
+    TSClassImplements(&'a TSClassImplements<'a>),
     TSModuleDeclaration(&'a TSModuleDeclaration<'a>),
     TSTypeAliasDeclaration(&'a TSTypeAliasDeclaration<'a>),
     TSTypeAnnotation(&'a TSTypeAnnotation<'a>),
@@ -136,6 +147,8 @@ pub enum AstKind<'a> {
     TSTypeParameter(&'a TSTypeParameter<'a>),
     TSTypeParameterDeclaration(&'a TSTypeParameterDeclaration<'a>),
     TSTypeParameterInstantiation(&'a TSTypeParameterInstantiation<'a>),
+    TSTypeName(&'a TSTypeName<'a>),
+    TSQualifiedName(&'a TSQualifiedName<'a>),
 
     TSPropertySignature(&'a TSPropertySignature<'a>),
 }
@@ -191,6 +204,8 @@ impl<'a> AstKind<'a> {
                 | Self::TSLiteralType(_)
                 | Self::TSTypeReference(_)
                 | Self::TSMethodSignature(_)
+                | Self::TSInferType(_)
+                | Self::TSTemplateLiteralType(_)
         )
     }
 
@@ -228,6 +243,446 @@ impl<'a> AstKind<'a> {
     pub fn is_jsx(self) -> bool {
         matches!(self, Self::JSXOpeningElement(_) | Self::JSXElementName(_))
     }
+
+    /// Name of the variant, e.g. `"CallExpression"`.
+    #[must_use]
+    #[allow(clippy::too_many_lines)]
+    pub fn debug_name(self) -> &'static str {
+        match self {
+            Self::Root => "Root",
+            Self::Program(_) => "Program",
+            Self::Directive(_) => "Directive",
+            Self::BlockStatement(_) => "BlockStatement",
+            Self::BreakStatement(_) => "BreakStatement",
+            Self::ContinueStatement(_) => "ContinueStatement",
+            Self::DebuggerStatement(_) => "DebuggerStatement",
+            Self::DoWhileStatement(_) => "DoWhileStatement",
+            Self::EmptyStatement(_) => "EmptyStatement",
+            Self::ExpressionStatement(_) => "ExpressionStatement",
+            Self::ForInStatement(_) => "ForInStatement",
+            Self::ForOfStatement(_) => "ForOfStatement",
+            Self::ForStatement(_) => "ForStatement",
+            Self::ForStatementInit(_) => "ForStatementInit",
+            Self::IfStatement(_) => "IfStatement",
+            Self::LabeledStatement(_) => "LabeledStatement",
+            Self::ReturnStatement(_) => "ReturnStatement",
+            Self::SwitchStatement(_) => "SwitchStatement",
+            Self::ThrowStatement(_) => "ThrowStatement",
+            Self::TryStatement(_) => "TryStatement",
+            Self::WhileStatement(_) => "WhileStatement",
+            Self::WithStatement(_) => "WithStatement",
+            Self::SwitchCase(_) => "SwitchCase",
+            Self::CatchClause(_) => "CatchClause",
+            Self::FinallyClause(_) => "FinallyClause",
+            Self::VariableDeclaration(_) => "VariableDeclaration",
+            Self::VariableDeclarator(_) => "VariableDeclarator",
+            Self::IdentifierName(_) => "IdentifierName",
+            Self::IdentifierReference(_) => "IdentifierReference",
+            Self::BindingIdentifier(_) => "BindingIdentifier",
+            Self::LabelIdentifier(_) => "LabelIdentifier",
+            Self::PrivateIdentifier(_) => "PrivateIdentifier",
+            Self::NumberLiteral(_) => "NumberLiteral",
+            Self::StringLiteral(_) => "StringLiteral",
+            Self::BooleanLiteral(_) => "BooleanLiteral",
+            Self::NullLiteral(_) => "NullLiteral",
+            Self::BigintLiteral(_) => "BigintLiteral",
+            Self::RegExpLiteral(_) => "RegExpLiteral",
+            Self::TemplateLiteral(_) => "TemplateLiteral",
+            Self::MetaProperty(_) => "MetaProperty",
+            Self::Super(_) => "Super",
+            Self::ArrayExpression(_) => "ArrayExpression",
+            Self::ArrowExpression(_) => "ArrowExpression",
+            Self::AssignmentExpression(_) => "AssignmentExpression",
+            Self::AwaitExpression(_) => "AwaitExpression",
+            Self::BinaryExpression(_) => "BinaryExpression",
+            Self::CallExpression(_) => "CallExpression",
+            Self::ChainExpression(_) => "ChainExpression",
+            Self::ConditionalExpression(_) => "ConditionalExpression",
+            Self::ImportExpression(_) => "ImportExpression",
+            Self::LogicalExpression(_) => "LogicalExpression",
+            Self::MemberExpression(_) => "MemberExpression",
+            Self::NewExpression(_) => "NewExpression",
+            Self::ObjectExpression(_) => "ObjectExpression",
+            Self::ParenthesizedExpression(_) => "ParenthesizedExpression",
+            Self::PrivateInExpression(_) => "PrivateInExpression",
+            Self::SequenceExpression(_) => "SequenceExpression",
+            Self::TaggedTemplateExpression(_) => "TaggedTemplateExpression",
+            Self::ThisExpression(_) => "ThisExpression",
+            Self::UnaryExpression(_) => "UnaryExpression",
+            Self::UpdateExpression(_) => "UpdateExpression",
+            Self::YieldExpression(_) => "YieldExpression",
+            Self::Property(_) => "Property",
+            Self::PropertyKey(_) => "PropertyKey",
+            Self::PropertyValue(_) => "PropertyValue",
+            Self::Argument(_) => "Argument",
+            Self::AssignmentTarget(_) => "AssignmentTarget",
+            Self::SimpleAssignmentTarget(_) => "SimpleAssignmentTarget",
+            Self::AssignmentTargetWithDefault(_) => "AssignmentTargetWithDefault",
+            Self::SpreadElement(_) => "SpreadElement",
+            Self::RestElement(_) => "RestElement",
+            Self::Function(_) => "Function",
+            Self::FunctionBody(_) => "FunctionBody",
+            Self::FormalParameters(_) => "FormalParameters",
+            Self::FormalParameter(_) => "FormalParameter",
+            Self::Class(_) => "Class",
+            Self::ClassHeritage(_) => "ClassHeritage",
+            Self::StaticBlock(_) => "StaticBlock",
+            Self::PropertyDefinition(_) => "PropertyDefinition",
+            Self::MethodDefinition(_) => "MethodDefinition",
+            Self::AccessorProperty(_) => "AccessorProperty",
+            Self::ArrayPattern(_) => "ArrayPattern",
+            Self::ObjectPattern(_) => "ObjectPattern",
+            Self::AssignmentPattern(_) => "AssignmentPattern",
+            Self::Decorator(_) => "Decorator",
+            Self::ModuleDeclaration(_) => "ModuleDeclaration",
+            Self::ExportSpecifier(_) => "ExportSpecifier",
+            Self::JSXOpeningElement(_) => "JSXOpeningElement",
+            Self::JSXElementName(_) => "JSXElementName",
+            Self::TSModuleBlock(_) => "TSModuleBlock",
+            Self::TSAnyKeyword(_) => "TSAnyKeyword",
+            Self::TSIntersectionType(_) => "TSIntersectionType",
+            Self::TSLiteralType(_) => "TSLiteralType",
+            Self::TSMethodSignature(_) => "TSMethodSignature",
+            Self::TSNullKeyword(_) => "TSNullKeyword",
+            Self::TSTypeLiteral(_) => "TSTypeLiteral",
+            Self::TSTypeReference(_) => "TSTypeReference",
+            Self::TSTypeQuery(_) => "TSTypeQuery",
+            Self::TSImportType(_) => "TSImportType",
+            Self::TSInferType(_) => "TSInferType",
+            Self::TSTemplateLiteralType(_) => "TSTemplateLiteralType",
+            Self::TSUnionType(_) => "TSUnionType",
+            Self::TSVoidKeyword(_) => "TSVoidKeyword",
+            Self::TSIndexedAccessType(_) => "TSIndexedAccessType",
+            Self::TSAsExpression(_) => "TSAsExpression",
+            Self::TSNonNullExpression(_) => "TSNonNullExpression",
+            Self::TSEnumDeclaration(_) => "TSEnumDeclaration",
+            Self::TSEnumMember(_) => "TSEnumMember",
+            Self::TSImportEqualsDeclaration(_) => "TSImportEqualsDeclaration",
+            Self::TSInterfaceDeclaration(_) => "TSInterfaceDeclaration",
+            Self::TSInterfaceHeritage(_) => "TSInterfaceHeritage",
+            Self::TSClassImplements(_) => "TSClassImplements",
+            Self::TSModuleDeclaration(_) => "TSModuleDeclaration",
+            Self::TSTypeAliasDeclaration(_) => "TSTypeAliasDeclaration",
+            Self::TSTypeAnnotation(_) => "TSTypeAnnotation",
+            Self::TSTypeAssertion(_) => "TSTypeAssertion",
+            Self::TSTypeParameter(_) => "TSTypeParameter",
+            Self::TSTypeParameterDeclaration(_) => "TSTypeParameterDeclaration",
+            Self::TSTypeParameterInstantiation(_) => "TSTypeParameterInstantiation",
+            Self::TSTypeName(_) => "TSTypeName",
+            Self::TSQualifiedName(_) => "TSQualifiedName",
+            Self::TSPropertySignature(_) => "TSPropertySignature",
+        }
+    }
+
+    /// The [`NodeId`] of the node, an enum has the id of the node it wraps.
+    /// `Root` is not an AST node and has no id.
+    #[must_use]
+    #[allow(clippy::match_same_arms, clippy::too_many_lines)]
+    pub fn node_id(self) -> Option<NodeId> {
+        let node_id = match self {
+            Self::Root => return None,
+
+            Self::Program(x) => x.node_id,
+            Self::Directive(x) => x.node_id,
+
+            Self::BlockStatement(x) => x.node_id,
+            Self::BreakStatement(x) => x.node_id,
+            Self::ContinueStatement(x) => x.node_id,
+            Self::DebuggerStatement(x) => x.node_id,
+            Self::DoWhileStatement(x) => x.node_id,
+            Self::EmptyStatement(x) => x.node_id,
+            Self::ExpressionStatement(x) => x.node_id,
+            Self::ForInStatement(x) => x.node_id,
+            Self::ForOfStatement(x) => x.node_id,
+            Self::ForStatement(x) => x.node_id,
+            Self::ForStatementInit(x) => x.node_id(),
+            Self::IfStatement(x) => x.node_id,
+            Self::LabeledStatement(x) => x.node_id,
+            Self::ReturnStatement(x) => x.node_id,
+            Self::SwitchStatement(x) => x.node_id,
+            Self::ThrowStatement(x) => x.node_id,
+            Self::TryStatement(x) => x.node_id,
+            Self::WhileStatement(x) => x.node_id,
+            Self::WithStatement(x) => x.node_id,
+
+            Self::SwitchCase(x) => x.node_id,
+            Self::CatchClause(x) => x.node_id,
+            Self::FinallyClause(x) => x.node_id,
+
+            Self::VariableDeclaration(x) => x.node_id,
+            Self::VariableDeclarator(x) => x.node_id,
+
+            Self::IdentifierName(x) => x.node_id,
+            Self::IdentifierReference(x) => x.node_id,
+            Self::BindingIdentifier(x) => x.node_id,
+            Self::LabelIdentifier(x) => x.node_id,
+            Self::PrivateIdentifier(x) => x.node_id,
+
+            Self::NumberLiteral(x) => x.node_id,
+            Self::StringLiteral(x) => x.node_id,
+            Self::BooleanLiteral(x) => x.node_id,
+            Self::NullLiteral(x) => x.node_id,
+            Self::BigintLiteral(x) => x.node_id,
+            Self::RegExpLiteral(x) => x.node_id,
+            Self::TemplateLiteral(x) => x.node_id,
+
+            Self::MetaProperty(x) => x.node_id,
+            Self::Super(x) => x.node_id,
+
+            Self::ArrayExpression(x) => x.node_id,
+            Self::ArrowExpression(x) => x.node_id,
+            Self::AssignmentExpression(x) => x.node_id,
+            Self::AwaitExpression(x) => x.node_id,
+            Self::BinaryExpression(x) => x.node_id,
+            Self::CallExpression(x) => x.node_id,
+            Self::ChainExpression(x) => x.node_id,
+            Self::ConditionalExpression(x) => x.node_id,
+            Self::ImportExpression(x) => x.node_id,
+            Self::LogicalExpression(x) => x.node_id,
+            Self::MemberExpression(x) => x.node_id(),
+            Self::NewExpression(x) => x.node_id,
+            Self::ObjectExpression(x) => x.node_id,
+            Self::ParenthesizedExpression(x) => x.node_id,
+            Self::PrivateInExpression(x) => x.node_id,
+            Self::SequenceExpression(x) => x.node_id,
+            Self::TaggedTemplateExpression(x) => x.node_id,
+            Self::ThisExpression(x) => x.node_id,
+            Self::UnaryExpression(x) => x.node_id,
+            Self::UpdateExpression(x) => x.node_id,
+            Self::YieldExpression(x) => x.node_id,
+
+            Self::Property(x) => x.node_id,
+            Self::PropertyKey(x) => x.node_id(),
+            Self::PropertyValue(x) => x.node_id(),
+            Self::Argument(x) => x.node_id(),
+            Self::AssignmentTarget(x) => x.node_id(),
+            Self::SimpleAssignmentTarget(x) => x.node_id(),
+            Self::AssignmentTargetWithDefault(x) => x.node_id,
+            Self::SpreadElement(x) => x.node_id,
+            Self::RestElement(x) => x.node_id,
+
+            Self::Function(x) => x.node_id,
+            Self::FunctionBody(x) => x.node_id,
+            Self::FormalParameters(x) => x.node_id,
+            Self::FormalParameter(x) => x.node_id,
+
+            Self::Class(x) => x.node_id,
+            Self::ClassHeritage(x) => x.node_id(),
+            Self::StaticBlock(x) => x.node_id,
+            Self::PropertyDefinition(x) => x.node_id,
+            Self::MethodDefinition(x) => x.node_id,
+            Self::AccessorProperty(x) => x.node_id,
+
+            Self::ArrayPattern(x) => x.node_id,
+            Self::ObjectPattern(x) => x.node_id,
+            Self::AssignmentPattern(x) => x.node_id,
+
+            Self::Decorator(x) => x.node_id,
+
+            Self::ModuleDeclaration(x) => x.node_id,
+            Self::ExportSpecifier(x) => x.node_id,
+
+            Self::JSXOpeningElement(x) => x.node_id,
+            Self::JSXElementName(x) => x.node_id(),
+
+            Self::TSModuleBlock(x) => x.node_id,
+
+            Self::TSAnyKeyword(x) => x.node_id,
+            Self::TSIntersectionType(x) => x.node_id,
+            Self::TSLiteralType(x) => x.node_id,
+            Self::TSMethodSignature(x) => x.node_id,
+            Self::TSNullKeyword(x) => x.node_id,
+            Self::TSTypeLiteral(x) => x.node_id,
+            Self::TSTypeReference(x) => x.node_id,
+            Self::TSTypeQuery(x) => x.node_id,
+            Self::TSImportType(x) => x.node_id,
+            Self::TSInferType(x) => x.node_id,
+            Self::TSTemplateLiteralType(x) => x.node_id,
+            Self::TSUnionType(x) => x.node_id,
+            Self::TSVoidKeyword(x) => x.node_id,
+
+            Self::TSIndexedAccessType(x) => x.node_id,
+
+            Self::TSAsExpression(x) => x.node_id,
+            Self::TSNonNullExpression(x) => x.node_id,
+
+            Self::TSEnumDeclaration(x) => x.node_id,
+            Self::TSEnumMember(x) => x.node_id,
+            Self::TSImportEqualsDeclaration(x) => x.node_id,
+            Self::TSInterfaceDeclaration(x) => x.node_id,
+            Self::TSInterfaceHeritage(x) => x.node_id,
+            Self::TSClassImplements(x) => x.node_id,
+            Self::TSModuleDeclaration(x) => x.node_id,
+            Self::TSTypeAliasDeclaration(x) => x.node_id,
+            Self::TSTypeAnnotation(x) => x.node_id,
+            Self::TSTypeAssertion(x) => x.node_id,
+            Self::TSTypeParameter(x) => x.node_id,
+            Self::TSTypeParameterDeclaration(x) => x.node_id,
+            Self::TSTypeParameterInstantiation(x) => x.node_id,
+            Self::TSTypeName(x) => x.node_id(),
+            Self::TSQualifiedName(x) => x.node_id,
+
+            Self::TSPropertySignature(x) => x.node_id,
+        };
+        Some(node_id)
+    }
+
+    /// The address of the node, unlike the [`NodeId`] it is unique across allocators.
+    /// An enum or a struct may share its address with the node it contains.
+    /// `Root` is not an AST node and has no address.
+    #[must_use]
+    #[allow(clippy::match_same_arms, clippy::too_many_lines)]
+    pub fn address(self) -> Option<*const ()> {
+        fn address<T>(node: &T) -> *const () {
+            std::ptr::addr_of!(*node).cast()
+        }
+        let address = match self {
+            Self::Root => return None,
+
+            Self::Program(x) => address(x),
+            Self::Directive(x) => address(x),
+
+            Self::BlockStatement(x) => address(x),
+            Self::BreakStatement(x) => address(x),
+            Self::ContinueStatement(x) => address(x),
+            Self::DebuggerStatement(x) => address(x),
+            Self::DoWhileStatement(x) => address(x),
+            Self::EmptyStatement(x) => address(x),
+            Self::ExpressionStatement(x) => address(x),
+            Self::ForInStatement(x) => address(x),
+            Self::ForOfStatement(x) => address(x),
+            Self::ForStatement(x) => address(x),
+            Self::ForStatementInit(x) => address(x),
+            Self::IfStatement(x) => address(x),
+            Self::LabeledStatement(x) => address(x),
+            Self::ReturnStatement(x) => address(x),
+            Self::SwitchStatement(x) => address(x),
+            Self::ThrowStatement(x) => address(x),
+            Self::TryStatement(x) => address(x),
+            Self::WhileStatement(x) => address(x),
+            Self::WithStatement(x) => address(x),
+
+            Self::SwitchCase(x) => address(x),
+            Self::CatchClause(x) => address(x),
+            Self::FinallyClause(x) => address(x),
+
+            Self::VariableDeclaration(x) => address(x),
+            Self::VariableDeclarator(x) => address(x),
+
+            Self::IdentifierName(x) => address(x),
+            Self::IdentifierReference(x) => address(x),
+            Self::BindingIdentifier(x) => address(x),
+            Self::LabelIdentifier(x) => address(x),
+            Self::PrivateIdentifier(x) => address(x),
+
+            Self::NumberLiteral(x) => address(x),
+            Self::StringLiteral(x) => address(x),
+            Self::BooleanLiteral(x) => address(x),
+            Self::NullLiteral(x) => address(x),
+            Self::BigintLiteral(x) => address(x),
+            Self::RegExpLiteral(x) => address(x),
+            Self::TemplateLiteral(x) => address(x),
+
+            Self::MetaProperty(x) => address(x),
+            Self::Super(x) => address(x),
+
+            Self::ArrayExpression(x) => address(x),
+            Self::ArrowExpression(x) => address(x),
+            Self::AssignmentExpression(x) => address(x),
+            Self::AwaitExpression(x) => address(x),
+            Self::BinaryExpression(x) => address(x),
+            Self::CallExpression(x) => address(x),
+            Self::ChainExpression(x) => address(x),
+            Self::ConditionalExpression(x) => address(x),
+            Self::ImportExpression(x) => address(x),
+            Self::LogicalExpression(x) => address(x),
+            Self::MemberExpression(x) => address(x),
+            Self::NewExpression(x) => address(x),
+            Self::ObjectExpression(x) => address(x),
+            Self::ParenthesizedExpression(x) => address(x),
+            Self::PrivateInExpression(x) => address(x),
+            Self::SequenceExpression(x) => address(x),
+            Self::TaggedTemplateExpression(x) => address(x),
+            Self::ThisExpression(x) => address(x),
+            Self::UnaryExpression(x) => address(x),
+            Self::UpdateExpression(x) => address(x),
+            Self::YieldExpression(x) => address(x),
+
+            Self::Property(x) => address(x),
+            Self::PropertyKey(x) => address(x),
+            Self::PropertyValue(x) => address(x),
+            Self::Argument(x) => address(x),
+            Self::AssignmentTarget(x) => address(x),
+            Self::SimpleAssignmentTarget(x) => address(x),
+            Self::AssignmentTargetWithDefault(x) => address(x),
+            Self::SpreadElement(x) => address(x),
+            Self::RestElement(x) => address(x),
+
+            Self::Function(x) => address(x),
+            Self::FunctionBody(x) => address(x),
+            Self::FormalParameters(x) => address(x),
+            Self::FormalParameter(x) => address(x),
+
+            Self::Class(x) => address(x),
+            Self::ClassHeritage(x) => address(x),
+            Self::StaticBlock(x) => address(x),
+            Self::PropertyDefinition(x) => address(x),
+            Self::MethodDefinition(x) => address(x),
+            Self::AccessorProperty(x) => address(x),
+
+            Self::ArrayPattern(x) => address(x),
+            Self::ObjectPattern(x) => address(x),
+            Self::AssignmentPattern(x) => address(x),
+
+            Self::Decorator(x) => address(x),
+
+            Self::ModuleDeclaration(x) => address(x),
+            Self::ExportSpecifier(x) => address(x),
+
+            Self::JSXOpeningElement(x) => address(x),
+            Self::JSXElementName(x) => address(x),
+
+            Self::TSModuleBlock(x) => address(x),
+
+            Self::TSAnyKeyword(x) => address(x),
+            Self::TSIntersectionType(x) => address(x),
+            Self::TSLiteralType(x) => address(x),
+            Self::TSMethodSignature(x) => address(x),
+            Self::TSNullKeyword(x) => address(x),
+            Self::TSTypeLiteral(x) => address(x),
+            Self::TSTypeReference(x) => address(x),
+            Self::TSTypeQuery(x) => address(x),
+            Self::TSImportType(x) => address(x),
+            Self::TSInferType(x) => address(x),
+            Self::TSTemplateLiteralType(x) => address(x),
+            Self::TSUnionType(x) => address(x),
+            Self::TSVoidKeyword(x) => address(x),
+
+            Self::TSIndexedAccessType(x) => address(x),
+
+            Self::TSAsExpression(x) => address(x),
+            Self::TSNonNullExpression(x) => address(x),
+
+            Self::TSEnumDeclaration(x) => address(x),
+            Self::TSEnumMember(x) => address(x),
+            Self::TSImportEqualsDeclaration(x) => address(x),
+            Self::TSInterfaceDeclaration(x) => address(x),
+            Self::TSInterfaceHeritage(x) => address(x),
+            Self::TSClassImplements(x) => address(x),
+            Self::TSModuleDeclaration(x) => address(x),
+            Self::TSTypeAliasDeclaration(x) => address(x),
+            Self::TSTypeAnnotation(x) => address(x),
+            Self::TSTypeAssertion(x) => address(x),
+            Self::TSTypeParameter(x) => address(x),
+            Self::TSTypeParameterDeclaration(x) => address(x),
+            Self::TSTypeParameterInstantiation(x) => address(x),
+            Self::TSTypeName(x) => address(x),
+            Self::TSQualifiedName(x) => address(x),
+
+            Self::TSPropertySignature(x) => address(x),
+        };
+        Some(address)
+    }
 }
 
 impl<'a> GetSpan for AstKind<'a> {
@@ -289,12 +744,15 @@ impl<'a> GetSpan for AstKind<'a> {
             Self::AwaitExpression(x) => x.span,
             Self::BinaryExpression(x) => x.span,
             Self::CallExpression(x) => x.span,
+            Self::ChainExpression(x) => x.span,
             Self::ConditionalExpression(x) => x.span,
+            Self::ImportExpression(x) => x.span,
             Self::LogicalExpression(x) => x.span,
             Self::MemberExpression(x) => x.span(),
             Self::NewExpression(x) => x.span,
             Self::ObjectExpression(x) => x.span,
             Self::ParenthesizedExpression(x) => x.span,
+            Self::PrivateInExpression(x) => x.span,
             Self::SequenceExpression(x) => x.span,
             Self::TaggedTemplateExpression(x) => x.span,
             Self::ThisExpression(x) => x.span,
@@ -322,6 +780,7 @@ impl<'a> GetSpan for AstKind<'a> {
             Self::StaticBlock(x) => x.span,
             Self::PropertyDefinition(x) => x.span,
             Self::MethodDefinition(x) => x.span,
+            Self::AccessorProperty(x) => x.span,
 
             Self::ArrayPattern(x) => x.span,
             Self::ObjectPattern(x) => x.span,
@@ -330,6 +789,7 @@ impl<'a> GetSpan for AstKind<'a> {
             Self::Decorator(x) => x.span,
 
             Self::ModuleDeclaration(x) => x.span,
+            Self::ExportSpecifier(x) => x.span,
 
             Self::JSXOpeningElement(x) => x.span,
             Self::JSXElementName(x) => x.span(),
@@ -343,6 +803,10 @@ impl<'a> GetSpan for AstKind<'a> {
             Self::TSNullKeyword(x) => x.span,
             Self::TSTypeLiteral(x) => x.span,
             Self::TSTypeReference(x) => x.span,
+            Self::TSTypeQuery(x) => x.span,
+            Self::TSImportType(x) => x.span,
+            Self::TSInferType(x) => x.span,
+            Self::TSTemplateLiteralType(x) => x.span,
             Self::TSUnionType(x) => x.span,
             Self::TSVoidKeyword(x) => x.span,
 
@@ -355,6 +819,8 @@ impl<'a> GetSpan for AstKind<'a> {
             Self::TSEnumMember(x) => x.span,
             Self::TSImportEqualsDeclaration(x) => x.span,
             Self::TSInterfaceDeclaration(x) => x.span,
+            Self::TSInterfaceHeritage(x) => x.span,
+            Self::TSClassImplements(x) => x.span,
             Self::TSModuleDeclaration(x) => x.span,
             Self::TSTypeAliasDeclaration(x) => x.span,
             Self::TSTypeAnnotation(x) => x.span,
@@ -362,6 +828,11 @@ impl<'a> GetSpan for AstKind<'a> {
             Self::TSTypeParameter(x) => x.span,
             Self::TSTypeParameterDeclaration(x) => x.span,
             Self::TSTypeParameterInstantiation(x) => x.span,
+            Self::TSTypeName(x) => match x {
+                TSTypeName::IdentifierName(name) => name.span,
+                TSTypeName::QualifiedName(name) => name.span,
+            },
+            Self::TSQualifiedName(x) => x.span,
 
             Self::TSPropertySignature(x) => x.span,
         }
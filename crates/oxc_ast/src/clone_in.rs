@@ -0,0 +1,73 @@
+use num_bigint::BigUint;
+use ordered_float::NotNan;
+use oxc_allocator::{Allocator, Box, Vec};
+
+use crate::{ast::RegExpFlags, Atom, NodeId, SourceType, Span};
+
+/// Deep clone of an AST node into an allocator.
+///
+/// AST nodes are not [`Clone`] because [`Box`] and [`Vec`] are owned by the allocator,
+/// this clones the whole subtree into `allocator` instead.
+/// Derived for the AST nodes with `#[derive(CloneIn)]`.
+pub trait CloneIn<'a>: Sized {
+    #[must_use]
+    fn clone_in(&self, allocator: &'a Allocator) -> Self;
+}
+
+macro_rules! impl_clone_in_for_copy {
+    ($($ty:ty),*) => {
+        $(
+            impl<'a> CloneIn<'a> for $ty {
+                #[inline]
+                fn clone_in(&self, _: &'a Allocator) -> Self {
+                    *self
+                }
+            }
+        )*
+    };
+}
+
+impl_clone_in_for_copy!(bool, u8, u32, f64, NotNan<f64>, Span, SourceType, RegExpFlags);
+
+/// The clone is a new node, with a new id
+impl<'a> CloneIn<'a> for NodeId {
+    fn clone_in(&self, allocator: &'a Allocator) -> Self {
+        Self::new(allocator)
+    }
+}
+
+impl<'a> CloneIn<'a> for &'a str {
+    fn clone_in(&self, _: &'a Allocator) -> Self {
+        self
+    }
+}
+
+impl<'a> CloneIn<'a> for Atom {
+    fn clone_in(&self, _: &'a Allocator) -> Self {
+        self.clone()
+    }
+}
+
+impl<'a> CloneIn<'a> for BigUint {
+    fn clone_in(&self, _: &'a Allocator) -> Self {
+        self.clone()
+    }
+}
+
+impl<'a, T: CloneIn<'a>> CloneIn<'a> for Option<T> {
+    fn clone_in(&self, allocator: &'a Allocator) -> Self {
+        self.as_ref().map(|value| value.clone_in(allocator))
+    }
+}
+
+impl<'a, T: CloneIn<'a>> CloneIn<'a> for Box<'a, T> {
+    fn clone_in(&self, allocator: &'a Allocator) -> Self {
+        Box(allocator.alloc(self.0.clone_in(allocator)))
+    }
+}
+
+impl<'a, T: CloneIn<'a>> CloneIn<'a> for Vec<'a, T> {
+    fn clone_in(&self, allocator: &'a Allocator) -> Self {
+        Vec::from_iter_in(self.iter().map(|value| value.clone_in(allocator)), allocator)
+    }
+}
@@ -11,10 +11,13 @@ mod serialize;
 pub mod ast;
 mod ast_builder;
 mod ast_kind;
+mod clone_in;
 pub mod module_record;
+mod node_id;
 mod source_type;
 mod span;
 pub mod syntax_directed_operations;
+pub mod traverse;
 mod trivia;
 pub mod visit;
 pub mod visit_mut;
@@ -24,6 +27,8 @@ pub use num_bigint::BigUint;
 
 pub use crate::ast_builder::AstBuilder;
 pub use crate::ast_kind::AstKind;
+pub use crate::clone_in::CloneIn;
+pub use crate::node_id::{GetNodeId, NodeId};
 pub use crate::source_type::{Language, LanguageVariant, ModuleKind, SourceType, VALID_EXTENSIONS};
 pub use crate::span::{GetSpan, Span};
 pub use crate::trivia::{CommentKind, Trivias};
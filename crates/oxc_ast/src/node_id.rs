@@ -0,0 +1,189 @@
+use std::hash::{Hash, Hasher};
+
+use oxc_allocator::Allocator;
+
+#[allow(clippy::wildcard_imports)]
+use crate::ast::*;
+
+/// Stable identifier of an AST node
+///
+/// Assigned when the node is created, by the parser through the [`AstBuilder`](crate::AstBuilder)
+/// or by [`CloneIn`](crate::CloneIn), and unique among the nodes of the same [`Allocator`].
+/// Side tables keyed by node can be vectors indexed by [`NodeId::index`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct NodeId(u32);
+
+impl NodeId {
+    #[must_use]
+    #[inline]
+    pub fn new(allocator: &Allocator) -> Self {
+        Self(allocator.next_id())
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+impl Hash for NodeId {
+    fn hash<H: Hasher>(&self, _state: &mut H) {
+        // hash to nothing so that ast nodes are comparable by content with hash, same as `Span`
+    }
+}
+
+/// The [`NodeId`] of a node, an enum has the id of the node it wraps
+pub trait GetNodeId {
+    fn node_id(&self) -> NodeId;
+}
+
+impl<'a> GetNodeId for Expression<'a> {
+    fn node_id(&self) -> NodeId {
+        match self {
+            Self::BooleanLiteral(e) => e.node_id,
+            Self::NullLiteral(e) => e.node_id,
+            Self::NumberLiteral(e) => e.node_id,
+            Self::BigintLiteral(e) => e.node_id,
+            Self::RegExpLiteral(e) => e.node_id,
+            Self::StringLiteral(e) => e.node_id,
+            Self::TemplateLiteral(e) => e.node_id,
+            Self::Identifier(e) => e.node_id,
+            Self::MetaProperty(e) => e.node_id,
+            Self::Super(e) => e.node_id,
+            Self::ArrayExpression(e) => e.node_id,
+            Self::ArrowFunctionExpression(e) => e.node_id,
+            Self::AssignmentExpression(e) => e.node_id,
+            Self::AwaitExpression(e) => e.node_id,
+            Self::BinaryExpression(e) => e.node_id,
+            Self::PrivateInExpression(e) => e.node_id,
+            Self::CallExpression(e) => e.node_id,
+            Self::ChainExpression(e) => e.node_id,
+            Self::ClassExpression(e) => e.node_id,
+            Self::ConditionalExpression(e) => e.node_id,
+            Self::FunctionExpression(e) => e.node_id,
+            Self::ImportExpression(e) => e.node_id,
+            Self::LogicalExpression(e) => e.node_id,
+            Self::MemberExpression(e) => e.node_id(),
+            Self::NewExpression(e) => e.node_id,
+            Self::ObjectExpression(e) => e.node_id,
+            Self::ParenthesizedExpression(e) => e.node_id,
+            Self::SequenceExpression(e) => e.node_id,
+            Self::TaggedTemplateExpression(e) => e.node_id,
+            Self::ThisExpression(e) => e.node_id,
+            Self::UnaryExpression(e) => e.node_id,
+            Self::UpdateExpression(e) => e.node_id,
+            Self::YieldExpression(e) => e.node_id,
+            Self::JSXElement(e) => e.node_id,
+            Self::JSXFragment(e) => e.node_id,
+            Self::TSAsExpression(e) => e.node_id,
+            Self::TSTypeAssertion(e) => e.node_id,
+            Self::TSNonNullExpression(e) => e.node_id,
+            Self::TSInstantiationExpression(e) => e.node_id,
+        }
+    }
+}
+
+impl<'a> GetNodeId for MemberExpression<'a> {
+    fn node_id(&self) -> NodeId {
+        match self {
+            Self::ComputedMemberExpression(expr) => expr.node_id,
+            Self::StaticMemberExpression(expr) => expr.node_id,
+            Self::PrivateFieldExpression(expr) => expr.node_id,
+        }
+    }
+}
+
+impl<'a> GetNodeId for Argument<'a> {
+    fn node_id(&self) -> NodeId {
+        match self {
+            Self::SpreadElement(elem) => elem.node_id,
+            Self::Expression(expr) => expr.node_id(),
+        }
+    }
+}
+
+impl<'a> GetNodeId for PropertyKey<'a> {
+    fn node_id(&self) -> NodeId {
+        match self {
+            Self::Identifier(ident) => ident.node_id,
+            Self::PrivateIdentifier(ident) => ident.node_id,
+            Self::Expression(expr) => expr.node_id(),
+        }
+    }
+}
+
+impl<'a> GetNodeId for PropertyValue<'a> {
+    fn node_id(&self) -> NodeId {
+        match self {
+            Self::Pattern(pat) => pat.node_id(),
+            Self::Expression(expr) => expr.node_id(),
+        }
+    }
+}
+
+impl<'a> GetNodeId for BindingPattern<'a> {
+    fn node_id(&self) -> NodeId {
+        match &self.kind {
+            BindingPatternKind::BindingIdentifier(ident) => ident.node_id,
+            BindingPatternKind::ObjectPattern(pat) => pat.node_id,
+            BindingPatternKind::ArrayPattern(pat) => pat.node_id,
+            BindingPatternKind::RestElement(elem) => elem.node_id,
+            BindingPatternKind::AssignmentPattern(pat) => pat.node_id,
+        }
+    }
+}
+
+impl<'a> GetNodeId for AssignmentTarget<'a> {
+    fn node_id(&self) -> NodeId {
+        match self {
+            Self::SimpleAssignmentTarget(target) => target.node_id(),
+            Self::AssignmentTargetPattern(AssignmentTargetPattern::ArrayAssignmentTarget(
+                target,
+            )) => target.node_id,
+            Self::AssignmentTargetPattern(AssignmentTargetPattern::ObjectAssignmentTarget(
+                target,
+            )) => target.node_id,
+        }
+    }
+}
+
+impl<'a> GetNodeId for SimpleAssignmentTarget<'a> {
+    fn node_id(&self) -> NodeId {
+        match self {
+            Self::AssignmentTargetIdentifier(ident) => ident.node_id,
+            Self::MemberAssignmentTarget(expr) => expr.node_id(),
+            Self::TSAsExpression(expr) => expr.node_id,
+            Self::TSNonNullExpression(expr) => expr.node_id,
+            Self::TSTypeAssertion(expr) => expr.node_id,
+        }
+    }
+}
+
+impl<'a> GetNodeId for ForStatementInit<'a> {
+    fn node_id(&self) -> NodeId {
+        match self {
+            Self::VariableDeclaration(decl) => decl.node_id,
+            Self::Expression(expr) => expr.node_id(),
+        }
+    }
+}
+
+impl<'a> GetNodeId for JSXElementName<'a> {
+    fn node_id(&self) -> NodeId {
+        match self {
+            Self::Identifier(ident) => ident.node_id,
+            Self::NamespacedName(name) => name.node_id,
+            Self::MemberExpression(expr) => expr.node_id,
+        }
+    }
+}
+
+impl<'a> GetNodeId for TSTypeName<'a> {
+    fn node_id(&self) -> NodeId {
+        match self {
+            Self::IdentifierName(ident) => ident.node_id,
+            Self::QualifiedName(name) => name.node_id,
+        }
+    }
+}
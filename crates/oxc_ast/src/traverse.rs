@@ -0,0 +1,170 @@
+//! AST Traversal with enter / leave hooks
+//!
+//! [`Traverse`] is a higher level alternative to [`Visit`] for analyses which only care about
+//! entering and leaving nodes. Every hook receives a [`TraverseCtx`] holding the ancestor stack,
+//! and returns a [`TraverseControl`] for skipping the children of a node or stopping early.
+//!
+//! ```ignore
+//! struct FindDebugger(bool);
+//!
+//! impl<'a> Traverse<'a> for FindDebugger {
+//!     fn enter_node(&mut self, kind: AstKind<'a>, _ctx: &TraverseCtx<'a>) -> TraverseControl {
+//!         if matches!(kind, AstKind::DebuggerStatement(_)) {
+//!             self.0 = true;
+//!             return TraverseControl::Stop;
+//!         }
+//!         TraverseControl::Continue
+//!     }
+//! }
+//! ```
+
+use oxc_allocator::Vec;
+
+#[allow(clippy::wildcard_imports)]
+use crate::{ast::*, ast_kind::AstKind, visit::Visit};
+
+/// Returned from the [`Traverse`] hooks to decide how the traversal proceeds.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum TraverseControl {
+    /// Visit the children of the node.
+    #[default]
+    Continue,
+    /// Do not visit the children of the node, `leave_node` is still called for the node itself.
+    /// Returning this from `leave_node` is the same as `Continue`.
+    Skip,
+    /// Stop the traversal, no more hooks are called.
+    Stop,
+}
+
+pub trait Traverse<'a> {
+    /// Called before the children of `kind` are visited.
+    /// `ctx` does not contain `kind` itself, `ctx.parent()` is the parent of `kind`.
+    fn enter_node(&mut self, _kind: AstKind<'a>, _ctx: &TraverseCtx<'a>) -> TraverseControl {
+        TraverseControl::Continue
+    }
+
+    /// Called after the children of `kind` are visited, or skipped.
+    /// `ctx` does not contain `kind` itself, `ctx.parent()` is the parent of `kind`.
+    fn leave_node(&mut self, _kind: AstKind<'a>, _ctx: &TraverseCtx<'a>) -> TraverseControl {
+        TraverseControl::Continue
+    }
+}
+
+/// Ancestor stack of the node being entered or left.
+#[derive(Debug, Default)]
+pub struct TraverseCtx<'a> {
+    ancestors: std::vec::Vec<AstKind<'a>>,
+}
+
+impl<'a> TraverseCtx<'a> {
+    /// The parent of the current node, `None` for the node the traversal started from.
+    #[must_use]
+    pub fn parent(&self) -> Option<AstKind<'a>> {
+        self.ancestors.last().copied()
+    }
+
+    /// Ancestors of the current node, starting from the parent.
+    pub fn ancestors(&self) -> impl Iterator<Item = AstKind<'a>> + '_ {
+        self.ancestors.iter().rev().copied()
+    }
+
+    /// Find the closest ancestor matching `f`.
+    pub fn find_ancestor<T, F: Fn(AstKind<'a>) -> Option<T>>(&self, f: F) -> Option<T> {
+        self.ancestors().find_map(f)
+    }
+
+    /// Number of ancestors of the current node.
+    #[must_use]
+    pub fn depth(&self) -> usize {
+        self.ancestors.len()
+    }
+}
+
+/// Drives a [`Traverse`] over the AST.
+/// This is a [`Visit`], so traversal can also start from any node with the `visit_*` methods.
+pub struct Traverser<'a, 't, T> {
+    traverse: &'t mut T,
+    ctx: TraverseCtx<'a>,
+    /// Number of nodes entered inside a skipped node, including the skipped node.
+    skip_depth: usize,
+    stopped: bool,
+}
+
+/// Traverse the whole program with `traverse`.
+pub fn traverse<'a, T: Traverse<'a>>(traverse: &mut T, program: &'a Program<'a>) {
+    Traverser::new(traverse).visit_program(program);
+}
+
+impl<'a, 't, T: Traverse<'a>> Traverser<'a, 't, T> {
+    pub fn new(traverse: &'t mut T) -> Self {
+        Self { traverse, ctx: TraverseCtx::default(), skip_depth: 0, stopped: false }
+    }
+
+    /// Whether `Stop` has been returned from a hook.
+    #[must_use]
+    pub fn is_stopped(&self) -> bool {
+        self.stopped
+    }
+
+    /// Children of skipped nodes and everything after a stop are not visited at all.
+    fn is_pruned(&self) -> bool {
+        self.stopped || self.skip_depth > 0
+    }
+}
+
+impl<'a, 't, T: Traverse<'a>> Visit<'a> for Traverser<'a, 't, T> {
+    fn enter_node(&mut self, kind: AstKind<'a>) {
+        if self.stopped {
+            return;
+        }
+        if self.skip_depth > 0 {
+            self.skip_depth += 1;
+            return;
+        }
+        match self.traverse.enter_node(kind, &self.ctx) {
+            TraverseControl::Continue => self.ctx.ancestors.push(kind),
+            TraverseControl::Skip => {
+                self.ctx.ancestors.push(kind);
+                self.skip_depth = 1;
+            }
+            TraverseControl::Stop => self.stopped = true,
+        }
+    }
+
+    fn leave_node(&mut self, kind: AstKind<'a>) {
+        if self.stopped {
+            return;
+        }
+        if self.skip_depth > 0 {
+            self.skip_depth -= 1;
+            if self.skip_depth > 0 {
+                return;
+            }
+        }
+        self.ctx.ancestors.pop();
+        if self.traverse.leave_node(kind, &self.ctx) == TraverseControl::Stop {
+            self.stopped = true;
+        }
+    }
+
+    fn visit_statements(&mut self, stmts: &'a Vec<'a, Statement<'a>>) {
+        for stmt in stmts {
+            if self.is_pruned() {
+                return;
+            }
+            self.visit_statement(stmt);
+        }
+    }
+
+    fn visit_statement(&mut self, stmt: &'a Statement<'a>) {
+        if !self.is_pruned() {
+            self.visit_statement_match(stmt);
+        }
+    }
+
+    fn visit_expression(&mut self, expr: &'a Expression<'a>) {
+        if !self.is_pruned() {
+            self.visit_expression_match(expr);
+        }
+    }
+}
@@ -378,10 +378,25 @@ pub trait Visit<'a>: Sized {
         if let Some(super_parameters) = &class.super_type_parameters {
             self.visit_ts_type_parameter_instantiation(super_parameters);
         }
+        if let Some(implements) = &class.implements {
+            for implement in implements {
+                self.visit_ts_class_implements(implement);
+            }
+        }
         self.visit_class_body(&class.body);
         self.leave_node(kind);
     }
 
+    fn visit_ts_class_implements(&mut self, implement: &'a TSClassImplements<'a>) {
+        let kind = AstKind::TSClassImplements(implement);
+        self.enter_node(kind);
+        self.visit_ts_type_name(&implement.expression);
+        if let Some(parameters) = &implement.type_parameters {
+            self.visit_ts_type_parameter_instantiation(parameters);
+        }
+        self.leave_node(kind);
+    }
+
     fn visit_class_heritage(&mut self, expr: &'a Expression<'a>) {
         let kind = AstKind::ClassHeritage(expr);
         self.enter_node(kind);
@@ -400,14 +415,14 @@ pub trait Visit<'a>: Sized {
             ClassElement::StaticBlock(block) => self.visit_static_block(block),
             ClassElement::MethodDefinition(def) => self.visit_method_definition(def),
             ClassElement::PropertyDefinition(def) => self.visit_property_definition(def),
-            ClassElement::AccessorProperty(_def) => { /* TODO */ }
+            ClassElement::AccessorProperty(def) => self.visit_accessor_property(def),
             ClassElement::TSAbstractMethodDefinition(def) => {
                 self.visit_method_definition(&def.method_definition);
             }
             ClassElement::TSAbstractPropertyDefinition(def) => {
                 self.visit_property_definition(&def.property_definition);
             }
-            ClassElement::TSIndexSignature(_def) => {}
+            ClassElement::TSIndexSignature(sig) => self.visit_ts_index_signature(sig),
         }
     }
 
@@ -445,6 +460,16 @@ pub trait Visit<'a>: Sized {
         self.leave_node(kind);
     }
 
+    fn visit_accessor_property(&mut self, def: &'a AccessorProperty<'a>) {
+        let kind = AstKind::AccessorProperty(def);
+        self.enter_node(kind);
+        self.visit_property_key(&def.key);
+        if let Some(value) = &def.value {
+            self.visit_expression(value);
+        }
+        self.leave_node(kind);
+    }
+
     /* ----------  Expression ---------- */
 
     fn visit_expression(&mut self, expr: &'a Expression<'a>) {
@@ -584,7 +609,10 @@ pub trait Visit<'a>: Sized {
     }
 
     fn visit_chain_expression(&mut self, expr: &'a ChainExpression<'a>) {
+        let kind = AstKind::ChainExpression(expr);
+        self.enter_node(kind);
         self.visit_chain_element(&expr.expression);
+        self.leave_node(kind);
     }
 
     fn visit_chain_element(&mut self, elem: &'a ChainElement<'a>) {
@@ -604,10 +632,13 @@ pub trait Visit<'a>: Sized {
     }
 
     fn visit_import_expression(&mut self, expr: &'a ImportExpression<'a>) {
+        let kind = AstKind::ImportExpression(expr);
+        self.enter_node(kind);
         self.visit_expression(&expr.source);
         for arg in &expr.arguments {
             self.visit_expression(arg);
         }
+        self.leave_node(kind);
     }
 
     fn visit_logical_expression(&mut self, expr: &'a LogicalExpression<'a>) {
@@ -718,8 +749,11 @@ pub trait Visit<'a>: Sized {
     }
 
     fn visit_private_in_expression(&mut self, expr: &'a PrivateInExpression<'a>) {
+        let kind = AstKind::PrivateInExpression(expr);
+        self.enter_node(kind);
         self.visit_private_identifier(&expr.left);
         self.visit_expression(&expr.right);
+        self.leave_node(kind);
     }
 
     fn visit_sequence_expression(&mut self, expr: &'a SequenceExpression<'a>) {
@@ -1151,8 +1185,20 @@ pub trait Visit<'a>: Sized {
         for specifier in &decl.specifiers {
             self.visit_import_declaration_specifier(specifier);
         }
-        // TODO: source
-        // TODO: assertions
+        self.visit_string_literal(&decl.source);
+        if let Some(assertions) = &decl.assertions {
+            for attribute in assertions {
+                self.visit_import_attribute(attribute);
+            }
+        }
+    }
+
+    fn visit_import_attribute(&mut self, attribute: &'a ImportAttribute) {
+        match &attribute.key {
+            ImportAttributeKey::Identifier(ident) => self.visit_identifier_name(ident),
+            ImportAttributeKey::StringLiteral(lit) => self.visit_string_literal(lit),
+        }
+        self.visit_string_literal(&attribute.value);
     }
 
     fn visit_import_declaration_specifier(&mut self, specifier: &'a ImportDeclarationSpecifier) {
@@ -1170,7 +1216,7 @@ pub trait Visit<'a>: Sized {
     }
 
     fn visit_import_specifier(&mut self, specifier: &'a ImportSpecifier) {
-        // TODO: imported
+        self.visit_module_export_name(&specifier.imported);
         self.visit_binding_identifier(&specifier.local);
     }
 
@@ -1182,7 +1228,17 @@ pub trait Visit<'a>: Sized {
         self.visit_binding_identifier(&specifier.local);
     }
 
-    fn visit_export_all_declaration(&mut self, _decl: &'a ExportAllDeclaration<'a>) {}
+    fn visit_export_all_declaration(&mut self, decl: &'a ExportAllDeclaration<'a>) {
+        if let Some(exported) = &decl.exported {
+            self.visit_module_export_name(exported);
+        }
+        self.visit_string_literal(&decl.source);
+        if let Some(assertions) = &decl.assertions {
+            for attribute in assertions {
+                self.visit_import_attribute(attribute);
+            }
+        }
+    }
 
     fn visit_export_default_declaration(&mut self, decl: &'a ExportDefaultDeclaration<'a>) {
         match &decl.declaration {
@@ -1191,7 +1247,10 @@ pub trait Visit<'a>: Sized {
                 self.visit_function(func);
             }
             ExportDefaultDeclarationKind::ClassDeclaration(class) => self.visit_class(class),
-            _ => {}
+            ExportDefaultDeclarationKind::TSInterfaceDeclaration(decl) => {
+                self.visit_ts_interface_declaration(decl);
+            }
+            ExportDefaultDeclarationKind::TSEnumDeclaration(decl) => self.visit_enum(decl),
         }
     }
 
@@ -1199,6 +1258,27 @@ pub trait Visit<'a>: Sized {
         if let Some(decl) = &decl.declaration {
             self.visit_declaration(decl);
         }
+        for specifier in &decl.specifiers {
+            self.visit_export_specifier(specifier);
+        }
+        if let Some(source) = &decl.source {
+            self.visit_string_literal(source);
+        }
+    }
+
+    fn visit_export_specifier(&mut self, specifier: &'a ExportSpecifier) {
+        let kind = AstKind::ExportSpecifier(specifier);
+        self.enter_node(kind);
+        self.visit_module_export_name(&specifier.local);
+        self.visit_module_export_name(&specifier.exported);
+        self.leave_node(kind);
+    }
+
+    fn visit_module_export_name(&mut self, name: &'a ModuleExportName) {
+        match name {
+            ModuleExportName::Identifier(ident) => self.visit_identifier_name(ident),
+            ModuleExportName::StringLiteral(lit) => self.visit_string_literal(lit),
+        }
     }
 
     fn visit_enum_member(&mut self, member: &'a TSEnumMember<'a>) {
@@ -1291,12 +1371,27 @@ pub trait Visit<'a>: Sized {
         if let Some(parameters) = &decl.type_parameters {
             self.visit_ts_type_parameter_declaration(parameters);
         }
+        if let Some(extends) = &decl.extends {
+            for heritage in extends {
+                self.visit_ts_interface_heritage(heritage);
+            }
+        }
         for signature in &decl.body.body {
             self.visit_ts_signature(signature);
         }
         self.leave_node(kind);
     }
 
+    fn visit_ts_interface_heritage(&mut self, heritage: &'a TSInterfaceHeritage<'a>) {
+        let kind = AstKind::TSInterfaceHeritage(heritage);
+        self.enter_node(kind);
+        self.visit_expression(&heritage.expression);
+        if let Some(parameters) = &heritage.type_parameters {
+            self.visit_ts_type_parameter_instantiation(parameters);
+        }
+        self.leave_node(kind);
+    }
+
     fn visit_ts_as_expression(&mut self, expr: &'a TSAsExpression<'a>) {
         let kind = AstKind::TSAsExpression(expr);
         self.enter_node(kind);
@@ -1351,8 +1446,66 @@ pub trait Visit<'a>: Sized {
             TSType::TSTypePredicate(ty) => self.visit_ts_type_predicate(ty),
             TSType::TSTypeLiteral(ty) => self.visit_ts_type_literal(ty),
             TSType::TSIndexedAccessType(ty) => self.visit_ts_indexed_access_type(ty),
-            _ => {}
+            TSType::TSQualifiedName(name) => self.visit_ts_qualified_name(name),
+            TSType::TSTypeQuery(ty) => self.visit_ts_type_query(ty),
+            TSType::TSImportType(ty) => self.visit_ts_import_type(ty),
+            TSType::TSInferType(ty) => self.visit_ts_infer_type(ty),
+            TSType::TSTemplateLiteralType(ty) => self.visit_ts_template_literal_type(ty),
+            TSType::JSDocNullableType(ty) => self.visit_ts_type(&ty.type_annotation),
+            TSType::TSBigIntKeyword(_)
+            | TSType::TSBooleanKeyword(_)
+            | TSType::TSNeverKeyword(_)
+            | TSType::TSNumberKeyword(_)
+            | TSType::TSObjectKeyword(_)
+            | TSType::TSStringKeyword(_)
+            | TSType::TSSymbolKeyword(_)
+            | TSType::TSThisKeyword(_)
+            | TSType::TSUndefinedKeyword(_)
+            | TSType::TSUnknownKeyword(_)
+            | TSType::JSDocUnknownType(_) => {}
+        }
+    }
+
+    fn visit_ts_type_query(&mut self, ty: &'a TSTypeQuery<'a>) {
+        let kind = AstKind::TSTypeQuery(ty);
+        self.enter_node(kind);
+        self.visit_ts_type_name(&ty.expr_name);
+        if let Some(parameters) = &ty.type_parameters {
+            self.visit_ts_type_parameter_instantiation(parameters);
         }
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_import_type(&mut self, ty: &'a TSImportType<'a>) {
+        let kind = AstKind::TSImportType(ty);
+        self.enter_node(kind);
+        self.visit_ts_type(&ty.parameter);
+        if let Some(qualifier) = &ty.qualifier {
+            self.visit_ts_type_name(qualifier);
+        }
+        if let Some(parameters) = &ty.type_parameters {
+            self.visit_ts_type_parameter_instantiation(parameters);
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_infer_type(&mut self, ty: &'a TSInferType<'a>) {
+        let kind = AstKind::TSInferType(ty);
+        self.enter_node(kind);
+        self.visit_ts_type_parameter(&ty.type_parameter);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_template_literal_type(&mut self, ty: &'a TSTemplateLiteralType<'a>) {
+        let kind = AstKind::TSTemplateLiteralType(ty);
+        self.enter_node(kind);
+        for elem in &ty.quasis {
+            self.visit_template_element(elem);
+        }
+        for ty in &ty.types {
+            self.visit_ts_type(ty);
+        }
+        self.leave_node(kind);
     }
 
     fn visit_ts_type_literal(&mut self, ty: &'a TSTypeLiteral<'a>) {
@@ -1416,6 +1569,7 @@ pub trait Visit<'a>: Sized {
     fn visit_ts_type_parameter(&mut self, ty: &'a TSTypeParameter<'a>) {
         let kind = AstKind::TSTypeParameter(ty);
         self.enter_node(kind);
+        self.visit_binding_identifier(&ty.name);
         if let Some(constraint) = &ty.constraint {
             self.visit_ts_type(constraint);
         }
@@ -1464,10 +1618,21 @@ pub trait Visit<'a>: Sized {
     }
 
     fn visit_ts_type_name(&mut self, name: &'a TSTypeName<'a>) {
+        let kind = AstKind::TSTypeName(name);
+        self.enter_node(kind);
         match &name {
             TSTypeName::IdentifierName(ident) => self.visit_identifier_name(ident),
-            TSTypeName::QualifiedName(_) => {}
+            TSTypeName::QualifiedName(name) => self.visit_ts_qualified_name(name),
         }
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_qualified_name(&mut self, name: &'a TSQualifiedName<'a>) {
+        let kind = AstKind::TSQualifiedName(name);
+        self.enter_node(kind);
+        self.visit_ts_type_name(&name.left);
+        self.visit_identifier_name(&name.right);
+        self.leave_node(kind);
     }
 
     fn visit_ts_null_keyword(&mut self, ty: &'a TSNullKeyword) {
@@ -1562,6 +1727,7 @@ pub trait Visit<'a>: Sized {
     fn visit_ts_method_signature(&mut self, signature: &'a TSMethodSignature<'a>) {
         let kind = AstKind::TSMethodSignature(signature);
         self.enter_node(kind);
+        self.visit_property_key(&signature.key);
         self.visit_formal_parameters(&signature.params);
         if let Some(parameters) = &signature.type_parameters {
             self.visit_ts_type_parameter_declaration(parameters);
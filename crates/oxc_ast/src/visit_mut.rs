@@ -294,7 +294,7 @@ pub trait VisitMut<'a, 'b>: Sized {
             ClassElement::StaticBlock(block) => self.visit_static_block(block),
             ClassElement::MethodDefinition(def) => self.visit_method_definition(def),
             ClassElement::PropertyDefinition(def) => self.visit_property_definition(def),
-            ClassElement::AccessorProperty(_def) => { /* TODO */ }
+            ClassElement::AccessorProperty(def) => self.visit_accessor_property(def),
             ClassElement::TSAbstractMethodDefinition(def) => {
                 self.visit_method_definition(&mut def.method_definition);
             }
@@ -330,6 +330,13 @@ pub trait VisitMut<'a, 'b>: Sized {
         }
     }
 
+    fn visit_accessor_property(&mut self, def: &'b mut AccessorProperty<'a>) {
+        self.visit_property_key(&mut def.key);
+        if let Some(value) = &mut def.value {
+            self.visit_expression(value);
+        }
+    }
+
     /* ----------  Expression ---------- */
 
     fn visit_expression(&mut self, expr: &'b mut Expression<'a>) {
@@ -0,0 +1,208 @@
+use oxc_allocator::Allocator;
+use oxc_ast::{
+    traverse::{traverse, Traverse, TraverseControl, TraverseCtx},
+    AstKind, SourceType,
+};
+use oxc_parser::Parser;
+
+fn kind_name(kind: AstKind) -> String {
+    let name = format!("{kind:?}");
+    name.split('(').next().unwrap().to_string()
+}
+
+/// Records `enter:Kind` / `leave:Kind` events
+#[derive(Default)]
+struct Recorder {
+    events: Vec<String>,
+    skip: Option<&'static str>,
+    stop: Option<&'static str>,
+}
+
+impl<'a> Traverse<'a> for Recorder {
+    fn enter_node(&mut self, kind: AstKind<'a>, _ctx: &TraverseCtx<'a>) -> TraverseControl {
+        let name = kind_name(kind);
+        self.events.push(format!("enter:{name}"));
+        if self.skip == Some(name.as_str()) {
+            return TraverseControl::Skip;
+        }
+        if self.stop == Some(name.as_str()) {
+            return TraverseControl::Stop;
+        }
+        TraverseControl::Continue
+    }
+
+    fn leave_node(&mut self, kind: AstKind<'a>, _ctx: &TraverseCtx<'a>) -> TraverseControl {
+        self.events.push(format!("leave:{}", kind_name(kind)));
+        TraverseControl::Continue
+    }
+}
+
+fn run<'a, T: Traverse<'a>>(allocator: &'a Allocator, source_text: &'a str, traverser: &mut T) {
+    let source_type = SourceType::from_path("test.ts").unwrap();
+    let ret = Parser::new(allocator, source_text, source_type).parse();
+    assert!(ret.errors.is_empty(), "{source_text}");
+    let program = allocator.alloc(ret.program);
+    traverse(traverser, program);
+}
+
+fn events(
+    source_text: &str,
+    skip: Option<&'static str>,
+    stop: Option<&'static str>,
+) -> Vec<String> {
+    let allocator = Allocator::default();
+    let mut recorder = Recorder { skip, stop, ..Recorder::default() };
+    run(&allocator, source_text, &mut recorder);
+    recorder.events
+}
+
+#[test]
+fn enter_leave_order() {
+    assert_eq!(
+        events("a;", None, None),
+        vec![
+            "enter:Program",
+            "enter:ExpressionStatement",
+            "enter:IdentifierReference",
+            "leave:IdentifierReference",
+            "leave:ExpressionStatement",
+            "leave:Program",
+        ]
+    );
+}
+
+#[test]
+fn skip_children() {
+    let events = events("if (a) { b } c;", Some("IfStatement"), None);
+    assert!(events.contains(&"enter:IfStatement".to_string()));
+    assert!(events.contains(&"leave:IfStatement".to_string()));
+    assert!(!events.iter().any(|e| e.ends_with(":BlockStatement")));
+    // siblings are still visited
+    assert!(events.contains(&"enter:ExpressionStatement".to_string()));
+    assert_eq!(events.iter().filter(|e| e.as_str() == "enter:IdentifierReference").count(), 1);
+}
+
+#[test]
+fn stop_early() {
+    let events = events("a; debugger; b;", None, Some("DebuggerStatement"));
+    assert_eq!(events.last().unwrap(), "enter:DebuggerStatement");
+    assert_eq!(events.iter().filter(|e| e.as_str() == "enter:IdentifierReference").count(), 1);
+}
+
+#[test]
+fn ancestors() {
+    struct Ancestors(Vec<String>);
+    impl<'a> Traverse<'a> for Ancestors {
+        fn enter_node(&mut self, kind: AstKind<'a>, ctx: &TraverseCtx<'a>) -> TraverseControl {
+            if matches!(kind, AstKind::NumberLiteral(_)) {
+                assert_eq!(ctx.depth(), ctx.ancestors().count());
+                self.0 = ctx.ancestors().map(kind_name).collect();
+                assert!(matches!(ctx.parent(), Some(AstKind::ReturnStatement(_))));
+                let func = ctx.find_ancestor(|kind| match kind {
+                    AstKind::Function(func) => func.id.as_ref().map(|id| id.name.to_string()),
+                    _ => None,
+                });
+                assert_eq!(func.as_deref(), Some("foo"));
+            }
+            TraverseControl::Continue
+        }
+    }
+    let allocator = Allocator::default();
+    let mut ancestors = Ancestors(vec![]);
+    run(&allocator, "function foo() { return 1 }", &mut ancestors);
+    assert_eq!(ancestors.0, vec!["ReturnStatement", "FunctionBody", "Function", "Program"]);
+}
+
+#[test]
+fn node_coverage() {
+    let cases = [
+        ("class A { accessor x = 1 }", "AccessorProperty"),
+        ("a?.b", "ChainExpression"),
+        ("import('a')", "ImportExpression"),
+        ("class A { #x; m() { #x in this } }", "PrivateInExpression"),
+        ("class A implements B {}", "TSClassImplements"),
+        ("interface A extends B {}", "TSInterfaceHeritage"),
+        ("type A<T> = T", "BindingIdentifier"),
+        ("type A = typeof b", "TSTypeQuery"),
+        ("type A = import('a').B", "TSImportType"),
+        ("type A = B.C", "TSQualifiedName"),
+        ("type A<T> = T extends (infer U)[] ? U : T", "TSInferType"),
+        ("type A = `a${B}`", "TSTemplateLiteralType"),
+        ("export * from 'a'", "StringLiteral"),
+        ("import { a as b } from 'c'", "IdentifierName"),
+        ("import a from 'b' assert { type: 'json' }", "IdentifierName"),
+        ("export * as a from 'b'", "IdentifierName"),
+        ("export default interface A { x: B }", "TSInterfaceDeclaration"),
+    ];
+    for (source_text, kind) in cases {
+        let events = events(source_text, None, None);
+        assert!(events.contains(&format!("enter:{kind}")), "{source_text} should visit {kind}");
+        assert!(events.contains(&format!("leave:{kind}")), "{source_text} should leave {kind}");
+    }
+}
+
+#[test]
+fn export_specifiers() {
+    let events = events("let a; export { a as b, a as 'c' }", None, None);
+    let start = events.iter().position(|e| e == "enter:ExportSpecifier").unwrap();
+    assert_eq!(
+        events[start..events.len() - 2],
+        [
+            "enter:ExportSpecifier",
+            "enter:IdentifierName",
+            "leave:IdentifierName",
+            "enter:IdentifierName",
+            "leave:IdentifierName",
+            "leave:ExportSpecifier",
+            "enter:ExportSpecifier",
+            "enter:IdentifierName",
+            "leave:IdentifierName",
+            "enter:StringLiteral",
+            "leave:StringLiteral",
+            "leave:ExportSpecifier",
+        ]
+    );
+}
+
+#[test]
+fn typescript_types() {
+    struct Parents(Vec<(String, String)>);
+    impl<'a> Traverse<'a> for Parents {
+        fn enter_node(&mut self, kind: AstKind<'a>, ctx: &TraverseCtx<'a>) -> TraverseControl {
+            if matches!(kind, AstKind::TSQualifiedName(_) | AstKind::TSInferType(_)) {
+                self.0.push((kind_name(kind), ctx.parent().map(kind_name).unwrap_or_default()));
+            }
+            TraverseControl::Continue
+        }
+    }
+    let source_text = "
+        interface A<T> extends B.C<T> {}
+        class D implements E.F.G<typeof h.i> {}
+        type J<T> = T extends `${infer U}.${string}` ? import('k').L<U> : never;
+    ";
+    let events = events(source_text, None, None);
+    let enters = events.iter().filter(|e| e.starts_with("enter:")).count();
+    assert_eq!(enters * 2, events.len(), "every entered node is left");
+    let count = |kind: &str| events.iter().filter(|e| **e == format!("enter:{kind}")).count();
+    assert_eq!(count("TSInterfaceHeritage"), 1);
+    assert_eq!(count("TSClassImplements"), 1);
+    // `E.F.G` and `h.i`
+    assert_eq!(count("TSQualifiedName"), 3);
+    assert_eq!(count("TSTypeQuery"), 1);
+    assert_eq!(count("TSTemplateLiteralType"), 1);
+    assert_eq!(count("TSInferType"), 1);
+    assert_eq!(count("TSImportType"), 1);
+
+    let allocator = Allocator::default();
+    let mut parents = Parents(vec![]);
+    run(&allocator, source_text, &mut parents);
+    assert_eq!(
+        parents.0,
+        vec![
+            ("TSQualifiedName".to_string(), "TSTypeName".to_string()),
+            ("TSQualifiedName".to_string(), "TSTypeName".to_string()),
+            ("TSQualifiedName".to_string(), "TSTypeName".to_string()),
+            ("TSInferType".to_string(), "TSTemplateLiteralType".to_string()),
+        ]
+    );
+}
@@ -1,25 +1,32 @@
 use clap::{builder::ValueParser, Arg, ArgAction, Command};
+use oxc_linter::GlobalEnvironment;
 
+#[allow(clippy::too_many_lines)]
 pub fn lint_command() -> Command {
     Command::new("lint")
             .alias("check")
             .about("Lint this repository.")
             .arg_required_else_help(true)
             .after_help(
-                "To allow or deny a rule, multiple -A <NAME> or -D <NAME>.
-For example: -D correctness -A no-debugger.
+                "To allow, warn or deny a rule, multiple -A <NAME>, -W <NAME> or -D <NAME>.
+For example: -D correctness -A no-debugger -W eqeqeq.
+Denied rules are reported as errors and warned rules as warnings.
 
 The categories are:
   * correctness - code that is outright wrong or useless
   * nursery     - new lints that are still under development
   * all         - all the categories listed above
 
-The default category is -D correctness.")
+A flat configuration file named oxc.config.json or oxc.config.toml is looked up from the current directory,
+it is an array of configuration objects with `files`, `ignores`, `rules`, `globals` and `settings`.
+Without it, configuration files named .eslintrc.json or .eslintrc are looked up from the directory of each file
+up to the first one with `root: true`. The options above are applied after the configuration files.
+Without a configuration file, the default category is -D correctness.")
             .arg(
                 Arg::new("path")
                     .value_name("PATH")
                     .num_args(1..)
-                    .required(true)
+                    .required_unless_present("print-config")
                     .value_parser(ValueParser::path_buf())
                     .help("File or Directory paths to scan. Directories are scanned recursively.")
             )
@@ -31,6 +38,14 @@ The default category is -D correctness.")
                 .action(ArgAction::Append)
                 .help("Allow a rule or a category")
             )
+            .arg(
+                Arg::new("warn")
+                .long("warn")
+                .short('W')
+                .required(false)
+                .action(ArgAction::Append)
+                .help("Warn a rule or a category")
+            )
             .arg(
                 Arg::new("deny")
                 .long("deny")
@@ -39,6 +54,44 @@ The default category is -D correctness.")
                 .action(ArgAction::Append)
                 .help("Deny a rule or a category")
             )
+            .arg(
+                Arg::new("env")
+                .long("env")
+                .required(false)
+                .action(ArgAction::Append)
+                .value_parser(GlobalEnvironment::ENVS)
+                .help("Enable the global variables of an environment, e.g. --env browser --env node")
+            )
+            .arg(
+                Arg::new("global")
+                .long("global")
+                .required(false)
+                .action(ArgAction::Append)
+                .help("Define global variables, read-only unless suffixed with :true, e.g. --global foo,bar:true")
+            )
+            .arg(
+                Arg::new("config")
+                .long("config")
+                .short('c')
+                .required(false)
+                .value_parser(ValueParser::path_buf())
+                .help("Use this flat configuration file instead of looking for oxc.config.json or oxc.config.toml")
+            )
+            .arg(
+                Arg::new("print-config")
+                .long("print-config")
+                .value_name("PATH")
+                .required(false)
+                .value_parser(ValueParser::path_buf())
+                .help("Print the configuration of a file instead of linting")
+            )
+            .arg(
+                Arg::new("no-eslintrc")
+                .long("no-eslintrc")
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .help("Disables use of configuration from .eslintrc.json and .eslintrc files")
+            )
             .arg(
                 Arg::new("fix")
                 .long("fix")
@@ -0,0 +1,275 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use oxc_diagnostics::Error;
+use oxc_linter::{
+    AllowWarnDeny, ESLintConfig, FlatConfig, LintConfig, Linter, ESLINTRC_FILE_NAMES,
+    FLAT_CONFIG_FILE_NAMES,
+};
+use rustc_hash::FxHashMap;
+
+use super::LintOptions;
+
+/// Where the configuration of the linted files comes from
+enum ConfigSource {
+    /// `.eslintrc` files in the directory of the file and its parents
+    Eslintrc,
+    /// `--no-eslintrc`, the default configuration
+    Default,
+    /// The flat configuration file of `--config`, or the one found from the current directory
+    Flat(FlatConfig),
+    /// An invalid flat configuration file, no file is linted
+    InvalidFlat,
+}
+
+/// The configuration file of a directory
+enum DirConfig {
+    None,
+    Valid(Arc<ESLintConfig>),
+    Invalid,
+}
+
+/// Resolves the configuration of the linted files.
+///
+/// A flat configuration file is used for all files,
+/// otherwise the `.eslintrc` files are found and cached from the directory of the file
+/// up to the first configuration with `root: true`.
+pub struct ConfigResolver {
+    source: ConfigSource,
+
+    dirs: FxHashMap<PathBuf, DirConfig>,
+
+    /// The linters built for the distinct resolved configurations, see [`ConfigResolver::linter`]
+    linters: Vec<(LintConfig, Arc<Linter>)>,
+
+    /// `--fix`
+    fix: bool,
+
+    /// Errors of the invalid configuration files loaded since the last `take_errors`
+    errors: Vec<(PathBuf, Vec<Error>)>,
+}
+
+impl ConfigResolver {
+    pub fn new(options: &LintOptions) -> Self {
+        let mut errors = vec![];
+        let flat_config_path = options.config.clone().or_else(|| {
+            let current_dir = env::current_dir().ok()?;
+            current_dir.ancestors().find_map(|dir| {
+                FLAT_CONFIG_FILE_NAMES
+                    .iter()
+                    .map(|file_name| dir.join(file_name))
+                    .find(|path| path.is_file())
+            })
+        });
+        let source = match flat_config_path {
+            Some(path) => {
+                // globs are matched against the canonicalized paths of the linted files
+                let path = fs::canonicalize(&path).unwrap_or(path);
+                match FlatConfig::from_path(&path) {
+                    Ok(config) => ConfigSource::Flat(config),
+                    Err(diagnostics) => {
+                        errors.push((path, diagnostics));
+                        ConfigSource::InvalidFlat
+                    }
+                }
+            }
+            None if options.no_eslintrc => ConfigSource::Default,
+            None => ConfigSource::Eslintrc,
+        };
+        Self { source, dirs: FxHashMap::default(), linters: vec![], fix: options.fix, errors }
+    }
+
+    /// Without any configuration file, `-D correctness`
+    fn default_config() -> LintConfig {
+        let mut config = LintConfig::default();
+        config.set_rules(AllowWarnDeny::Deny, "correctness");
+        config
+    }
+
+    /// The configuration of the file at `path`.
+    ///
+    /// Returns `None` when the file is ignored by the configuration or the configuration is invalid.
+    pub fn resolve(&mut self, path: &Path) -> Option<LintConfig> {
+        let path = fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+        match &self.source {
+            ConfigSource::Eslintrc => self.resolve_eslintrc(&path),
+            ConfigSource::Default => Some(Self::default_config()),
+            ConfigSource::Flat(config) => {
+                if config.is_ignored(&path) {
+                    return None;
+                }
+                let mut lint_config = LintConfig::default();
+                config.apply(&path, &mut lint_config);
+                Some(lint_config)
+            }
+            ConfigSource::InvalidFlat => None,
+        }
+    }
+
+    fn resolve_eslintrc(&mut self, path: &Path) -> Option<LintConfig> {
+        let mut configs = vec![];
+        for dir in path.ancestors().skip(1) {
+            match self.load(dir) {
+                DirConfig::None => {}
+                DirConfig::Valid(config) => {
+                    configs.push(Arc::clone(config));
+                    if config.is_root() {
+                        break;
+                    }
+                }
+                DirConfig::Invalid => return None,
+            }
+        }
+
+        if configs.iter().any(|config| config.is_ignored(path)) {
+            return None;
+        }
+
+        if configs.is_empty() {
+            return Some(Self::default_config());
+        }
+        let mut lint_config = LintConfig::default();
+        // from the root configuration to the nearest one
+        for config in configs.iter().rev() {
+            config.apply(path, &mut lint_config);
+        }
+        Some(lint_config)
+    }
+
+    /// The linter of a resolved configuration,
+    /// built once and shared by the files with an equal configuration.
+    pub fn linter(&mut self, config: &LintConfig) -> Arc<Linter> {
+        if let Some((_, linter)) = self.linters.iter().find(|(c, _)| c == config) {
+            return Arc::clone(linter);
+        }
+        let linter = Arc::new(config.linter().with_fix(self.fix));
+        self.linters.push((config.clone(), Arc::clone(&linter)));
+        linter
+    }
+
+    /// Take the errors of the invalid configuration files, each one is reported once.
+    pub fn take_errors(&mut self) -> Vec<(PathBuf, Vec<Error>)> {
+        std::mem::take(&mut self.errors)
+    }
+
+    fn load(&mut self, dir: &Path) -> &DirConfig {
+        if !self.dirs.contains_key(dir) {
+            let dir_config = ESLINTRC_FILE_NAMES
+                .iter()
+                .map(|file_name| dir.join(file_name))
+                .find(|path| path.is_file())
+                .map_or(DirConfig::None, |path| match ESLintConfig::from_path(&path) {
+                    Ok(config) => DirConfig::Valid(Arc::new(config)),
+                    Err(errors) => {
+                        self.errors.push((path, errors));
+                        DirConfig::Invalid
+                    }
+                });
+            self.dirs.insert(dir.to_path_buf(), dir_config);
+        }
+        &self.dirs[dir]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        path::{Path, PathBuf},
+        sync::Arc,
+    };
+
+    use oxc_ast::SourceType;
+    use oxc_linter::AllowWarnDeny;
+
+    use super::ConfigResolver;
+    use crate::lint::{lint_command, LintOptions};
+
+    fn fixture(path: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures").join(path)
+    }
+
+    fn new_resolver(args: &[&str]) -> ConfigResolver {
+        let args = ["lint", "."].iter().chain(args);
+        let matches = lint_command().try_get_matches_from(args).unwrap();
+        ConfigResolver::new(&LintOptions::from(&matches))
+    }
+
+    #[test]
+    fn hierarchy() {
+        let mut resolver = new_resolver(&[]);
+        let config = resolver.resolve(&fixture("eslintrc/src/index.js")).unwrap();
+        assert_eq!(config.rule_severity("no-debugger"), Some(AllowWarnDeny::Warn));
+        assert_eq!(config.rule_severity("no-caller"), None);
+        let source_type = SourceType::from_path("index.js").unwrap();
+        assert!(config.source_type(source_type).is_script());
+
+        // `src/nested` extends `eslint:recommended` and disables `no-debugger`
+        let config = resolver.resolve(&fixture("eslintrc/src/nested/index.js")).unwrap();
+        assert_eq!(config.rule_severity("no-debugger"), Some(AllowWarnDeny::Allow));
+        assert_eq!(config.rule_severity("no-caller"), Some(AllowWarnDeny::Deny));
+        assert_eq!(config.rule_severity("no-dupe-keys"), Some(AllowWarnDeny::Deny));
+        assert!(config.source_type(source_type).is_script());
+
+        // `overrides` of the test files
+        let config = resolver.resolve(&fixture("eslintrc/src/nested/index.test.js")).unwrap();
+        assert_eq!(config.rule_severity("no-dupe-keys"), Some(AllowWarnDeny::Allow));
+        assert!(resolver.take_errors().is_empty());
+    }
+
+    #[test]
+    fn ignore_and_invalid() {
+        let mut resolver = new_resolver(&[]);
+        assert!(resolver.resolve(&fixture("eslintrc/src/generated/index.js")).is_none());
+        assert!(resolver.take_errors().is_empty());
+
+        assert!(resolver.resolve(&fixture("eslintrc/invalid/index.js")).is_none());
+        let errors = resolver.take_errors();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[0].1[0].to_string().split(" in ").next(),
+            Some("Unknown rule `no-such-rule`")
+        );
+        // reported only once
+        assert!(resolver.resolve(&fixture("eslintrc/invalid/index.js")).is_none());
+        assert!(resolver.take_errors().is_empty());
+    }
+
+    #[test]
+    fn linter_per_config() {
+        let mut resolver = new_resolver(&[]);
+        let config = resolver.resolve(&fixture("eslintrc/src/index.js")).unwrap();
+        let linter = resolver.linter(&config);
+        let same_config = resolver.resolve(&fixture("eslintrc/src/other.js")).unwrap();
+        assert!(Arc::ptr_eq(&linter, &resolver.linter(&same_config)));
+        let nested_config = resolver.resolve(&fixture("eslintrc/src/nested/index.js")).unwrap();
+        assert!(!Arc::ptr_eq(&linter, &resolver.linter(&nested_config)));
+    }
+
+    #[test]
+    fn no_eslintrc() {
+        let mut resolver = new_resolver(&["--no-eslintrc"]);
+        let config = resolver.resolve(&fixture("eslintrc/src/nested/index.js")).unwrap();
+        assert_eq!(config.rule_severity("no-debugger"), Some(AllowWarnDeny::Deny));
+    }
+
+    #[test]
+    fn flat_config() {
+        let config_path = fixture("flat/oxc.config.toml");
+        let mut resolver = new_resolver(&["--config", config_path.to_str().unwrap()]);
+        let config = resolver.resolve(&fixture("flat/index.js")).unwrap();
+        assert_eq!(config.rule_severity("no-debugger"), Some(AllowWarnDeny::Warn));
+        assert_eq!(config.rule_severity("no-dupe-keys"), Some(AllowWarnDeny::Deny));
+        // `.eslintrc` files are not used with a flat configuration
+        let config = resolver.resolve(&fixture("eslintrc/src/nested/index.js")).unwrap();
+        assert_ne!(config.rule_severity("no-debugger"), Some(AllowWarnDeny::Allow));
+        assert!(resolver.resolve(&fixture("flat/generated/index.js")).is_none());
+        assert!(resolver.take_errors().is_empty());
+
+        let mut resolver = new_resolver(&["--config", "oxc.config.json"]);
+        assert!(resolver.resolve(&fixture("flat/index.js")).is_none());
+        assert_eq!(resolver.take_errors().len(), 1);
+    }
+}
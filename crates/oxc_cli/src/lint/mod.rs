@@ -1,43 +1,39 @@
 mod command;
+mod config;
 mod runner;
 
 use std::{collections::BTreeMap, path::PathBuf};
 
 use clap::ArgMatches;
+use oxc_linter::AllowWarnDeny;
 
 pub use self::{command::lint_command, runner::LintRunner};
 
 #[derive(Debug)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct LintOptions {
     pub paths: Vec<PathBuf>,
-    /// Allow / Deny rules in order. [("allow" / "deny", rule name)]
-    /// Defaults to [("deny", "correctness")]
+    /// Allow / Warn / Deny rules in order. [("allow" / "warn" / "deny", rule name)]
+    /// Applied on top of the `.eslintrc` files, or on top of [("deny", "correctness")] when there is none
     pub rules: Vec<(AllowWarnDeny, String)>,
+    /// Enabled environments, e.g. `browser`
+    pub envs: Vec<String>,
+    /// Custom global variables, `foo` or `foo:true` for writable
+    pub globals: Vec<String>,
     pub fix: bool,
     pub quiet: bool,
+    /// Do not look for `.eslintrc` files
+    pub no_eslintrc: bool,
+    /// The flat configuration file, `oxc.config.json` or `oxc.config.toml`
+    pub config: Option<PathBuf>,
+    /// Print the configuration of this file instead of linting
+    pub print_config: Option<PathBuf>,
     pub ignore_path: PathBuf,
     pub no_ignore: bool,
     pub ignore_pattern: Vec<String>,
     pub max_warnings: Option<usize>,
 }
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq)]
-pub enum AllowWarnDeny {
-    Allow,
-    // Warn,
-    Deny,
-}
-
-impl From<&'static str> for AllowWarnDeny {
-    fn from(s: &'static str) -> Self {
-        match s {
-            "allow" => Self::Allow,
-            "deny" => Self::Deny,
-            _ => unreachable!(),
-        }
-    }
-}
-
 impl<'a> From<&'a ArgMatches> for LintOptions {
     fn from(matches: &'a ArgMatches) -> Self {
         Self {
@@ -46,8 +42,21 @@ impl<'a> From<&'a ArgMatches> for LintOptions {
                 |paths| paths.into_iter().cloned().collect(),
             ),
             rules: Self::get_rules(matches),
+            envs: matches
+                .get_many::<String>("env")
+                .map(|envs| envs.into_iter().cloned().collect())
+                .unwrap_or_default(),
+            globals: matches
+                .get_many::<String>("global")
+                .map(|globals| {
+                    globals.into_iter().flat_map(|g| g.split(',')).map(String::from).collect()
+                })
+                .unwrap_or_default(),
             fix: matches.get_flag("fix"),
             quiet: matches.get_flag("quiet"),
+            no_eslintrc: matches.get_flag("no-eslintrc"),
+            config: matches.get_one::<PathBuf>("config").cloned(),
+            print_config: matches.get_one::<PathBuf>("print-config").cloned(),
             ignore_path: matches
                 .get_one::<PathBuf>("ignore-path")
                 .map_or_else(|| PathBuf::from(".eslintignore"), Clone::clone),
@@ -63,12 +72,11 @@ impl<'a> From<&'a ArgMatches> for LintOptions {
 
 impl LintOptions {
     /// Get all rules in order, e.g.
-    /// `-A all -D no-var -D -eqeqeq` => [("allow", "all"), ("deny", "no-var"), ("deny", "eqeqeq")]
-    /// Defaults to [("deny", "correctness")];
+    /// `-A all -D no-var -W eqeqeq` => [("allow", "all"), ("deny", "no-var"), ("warn", "eqeqeq")]
     fn get_rules(matches: &ArgMatches) -> Vec<(AllowWarnDeny, String)> {
         let mut map: BTreeMap<usize, (AllowWarnDeny, String)> = BTreeMap::new();
-        for key in ["allow", "deny"] {
-            let allow_warn_deny = AllowWarnDeny::from(key);
+        for key in ["allow", "warn", "deny"] {
+            let allow_warn_deny = AllowWarnDeny::try_from(key).unwrap();
             if let Some(values) = matches.get_many::<String>(key) {
                 let indices = matches.indices_of(key).unwrap();
                 let zipped =
@@ -76,11 +84,7 @@ impl LintOptions {
                 map.extend(zipped);
             }
         }
-        if map.is_empty() {
-            vec![(AllowWarnDeny::Deny, "correctness".into())]
-        } else {
-            map.into_values().collect()
-        }
+        map.into_values().collect()
     }
 }
 
@@ -88,7 +92,9 @@ impl LintOptions {
 mod test {
     use std::path::PathBuf;
 
-    use super::{lint_command, AllowWarnDeny, LintOptions};
+    use oxc_linter::AllowWarnDeny;
+
+    use super::{lint_command, LintOptions};
 
     #[test]
     fn verify_command() {
@@ -106,6 +112,10 @@ mod test {
         assert_eq!(options.paths, vec![PathBuf::from(".")]);
         assert!(!options.fix);
         assert!(!options.quiet);
+        assert!(!options.no_eslintrc);
+        assert_eq!(options.config, None);
+        assert_eq!(options.print_config, None);
+        assert!(options.rules.is_empty());
         assert_eq!(options.ignore_path, PathBuf::from(".eslintignore"));
         assert!(!options.no_ignore);
         assert!(options.ignore_pattern.is_empty());
@@ -137,12 +147,48 @@ mod test {
         );
     }
 
+    #[test]
+    fn rules_with_warn() {
+        let options = get_lint_options("lint src -D correctness -W no-debugger --warn eqeqeq");
+        assert_eq!(
+            options.rules,
+            vec![
+                (AllowWarnDeny::Deny, "correctness".into()),
+                (AllowWarnDeny::Warn, "no-debugger".into()),
+                (AllowWarnDeny::Warn, "eqeqeq".into())
+            ]
+        );
+    }
+
+    #[test]
+    fn envs_and_globals() {
+        let options =
+            get_lint_options("lint . --env browser --env node --global a,b:true --global c");
+        assert_eq!(options.envs, vec!["browser", "node"]);
+        assert_eq!(options.globals, vec!["a", "b:true", "c"]);
+        assert!(lint_command().try_get_matches_from(["lint", ".", "--env", "foo"]).is_err());
+    }
+
     #[test]
     fn quiet_true() {
         let options = get_lint_options("lint foo.js --quiet");
         assert!(options.quiet);
     }
 
+    #[test]
+    fn no_eslintrc() {
+        let options = get_lint_options("lint foo.js --no-eslintrc");
+        assert!(options.no_eslintrc);
+    }
+
+    #[test]
+    fn config_and_print_config() {
+        let options = get_lint_options("lint -c oxc.config.toml --print-config src/index.js");
+        assert_eq!(options.config, Some(PathBuf::from("oxc.config.toml")));
+        assert_eq!(options.print_config, Some(PathBuf::from("src/index.js")));
+        assert_eq!(options.paths, vec![PathBuf::from(".")]);
+    }
+
     #[test]
     fn fix_true() {
         let options = get_lint_options("lint foo.js --fix");
@@ -13,71 +13,36 @@ use miette::NamedSource;
 use oxc_allocator::Allocator;
 use oxc_ast::SourceType;
 use oxc_diagnostics::{Error, GraphicalReportHandler, MinifiedFileError, Severity};
-use oxc_linter::{Fixer, Linter, RuleCategory, RuleEnum, RULES};
+use oxc_linter::{Fixer, GlobalValue, LintConfig, Linter};
 use oxc_parser::Parser;
-use oxc_semantic::SemanticBuilder;
-use rustc_hash::FxHashSet;
 
-use super::{AllowWarnDeny, LintOptions};
+use super::{config::ConfigResolver, LintOptions};
 use crate::{CliRunResult, Walk};
 
 pub struct LintRunner {
-    options: LintOptions,
-
-    linter: Arc<Linter>,
+    options: Arc<LintOptions>,
 }
 
 impl LintRunner {
     #[must_use]
     pub fn new(options: LintOptions) -> Self {
-        let linter = Linter::from_rules(Self::derive_rules(&options)).with_fix(options.fix);
-        Self { options, linter: Arc::new(linter) }
+        Self { options: Arc::new(options) }
     }
 
-    fn derive_rules(options: &LintOptions) -> Vec<RuleEnum> {
-        let mut rules: FxHashSet<RuleEnum> = FxHashSet::default();
-
+    /// Apply the environments, globals and the allowed, warned and denied rules and categories
+    /// of the command line on top of the configuration files,
+    /// a later option overrides the severity of the rules matched by an earlier one.
+    fn apply_options(options: &LintOptions, config: &mut LintConfig) {
+        for env in &options.envs {
+            config.set_env(env, true);
+        }
+        for global in &options.globals {
+            let (name, writable) = global.split_once(':').unwrap_or((global, "false"));
+            config.set_global(name, GlobalValue::from(writable == "true"));
+        }
         for (allow_warn_deny, name_or_category) in &options.rules {
-            let maybe_category = RuleCategory::from(name_or_category.as_str());
-            match allow_warn_deny {
-                AllowWarnDeny::Deny => {
-                    match maybe_category {
-                        Some(category) => rules.extend(
-                            RULES.iter().filter(|rule| rule.category() == category).cloned(),
-                        ),
-                        None => {
-                            if name_or_category == "all" {
-                                rules.extend(RULES.iter().cloned());
-                            } else {
-                                rules.extend(
-                                    RULES
-                                        .iter()
-                                        .filter(|rule| rule.name() == name_or_category)
-                                        .cloned(),
-                                );
-                            }
-                        }
-                    };
-                }
-                AllowWarnDeny::Allow => {
-                    match maybe_category {
-                        Some(category) => rules.retain(|rule| rule.category() != category),
-                        None => {
-                            if name_or_category == "all" {
-                                rules.drain();
-                            } else {
-                                rules.retain(|rule| rule.name() == name_or_category);
-                            }
-                        }
-                    };
-                }
-            }
+            config.set_rules(*allow_warn_deny, name_or_category);
         }
-
-        let mut rules = rules.into_iter().collect::<Vec<_>>();
-        // for stable diagnostics output ordering
-        rules.sort_unstable_by_key(|rule| rule.name());
-        rules
     }
 
     /// # Panics
@@ -85,17 +50,22 @@ impl LintRunner {
     /// * When `mpsc::channel` fails to send.
     #[must_use]
     pub fn run(&self) -> CliRunResult {
+        if let Some(path) = &self.options.print_config {
+            return self.print_config(path);
+        }
+
         let now = std::time::Instant::now();
 
         let number_of_files = Arc::new(AtomicUsize::new(0));
+        let number_of_rules = Arc::new(AtomicUsize::new(0));
         let (tx_error, rx_error) = mpsc::channel::<(PathBuf, Vec<Error>)>();
 
-        self.process_paths(&number_of_files, tx_error);
+        self.process_paths(&number_of_files, &number_of_rules, tx_error);
         let (number_of_warnings, number_of_diagnostics) = self.process_diagnostics(&rx_error);
 
         CliRunResult::LintResult {
             duration: now.elapsed(),
-            number_of_rules: self.linter.number_of_rules(),
+            number_of_rules: number_of_rules.load(Ordering::Relaxed),
             number_of_files: number_of_files.load(Ordering::Relaxed),
             number_of_diagnostics,
             number_of_warnings,
@@ -106,36 +76,73 @@ impl LintRunner {
         }
     }
 
+    /// The configuration of the file at `path` with the command line options applied,
+    /// in the `.eslintrc` format
+    fn print_config(&self, path: &Path) -> CliRunResult {
+        let mut resolver = ConfigResolver::new(&self.options);
+        let config = resolver.resolve(path);
+        let errors = resolver.take_errors();
+        if !errors.is_empty() {
+            let handler = GraphicalReportHandler::new();
+            for diagnostic in errors.into_iter().flat_map(|(_, diagnostics)| diagnostics) {
+                let mut err = String::new();
+                handler.render_report(&mut err, diagnostic.as_ref()).unwrap();
+                print!("{err}");
+            }
+            return CliRunResult::InvalidConfig;
+        }
+        let config = config.map(|mut config| {
+            Self::apply_options(&self.options, &mut config);
+            serde_json::to_string_pretty(&config.to_json()).unwrap()
+        });
+        CliRunResult::PrintConfig { path: path.to_path_buf(), config }
+    }
+
     fn process_paths(
         &self,
         number_of_files: &Arc<AtomicUsize>,
+        number_of_rules: &Arc<AtomicUsize>,
         tx_error: mpsc::Sender<(PathBuf, Vec<Error>)>,
     ) {
         let (tx_path, rx_path) = mpsc::channel::<Box<Path>>();
 
         let walk = Walk::new(&self.options);
-        let number_of_files = Arc::clone(number_of_files);
         rayon::spawn(move || {
-            let mut count = 0;
             walk.iter().for_each(|path| {
-                count += 1;
                 tx_path.send(path).unwrap();
             });
-            number_of_files.store(count, Ordering::Relaxed);
         });
 
-        let linter = Arc::clone(&self.linter);
+        let options = Arc::clone(&self.options);
+        let number_of_files = Arc::clone(number_of_files);
+        let number_of_rules = Arc::clone(number_of_rules);
         rayon::spawn(move || {
+            let mut resolver = ConfigResolver::new(&options);
+            let mut files = 0;
+            let mut rules = 0;
             while let Ok(path) = rx_path.recv() {
+                let config = resolver.resolve(&path);
+                for diagnostics in resolver.take_errors() {
+                    tx_error.send(diagnostics).unwrap();
+                }
+                // ignored by the configuration, or an invalid configuration file
+                let Some(mut config) = config else { continue };
+                Self::apply_options(&options, &mut config);
+
+                let linter = resolver.linter(&config);
+                files += 1;
+                rules = rules.max(linter.number_of_rules());
+
                 let tx_error = tx_error.clone();
-                let linter = Arc::clone(&linter);
                 rayon::spawn(move || {
-                    if let Some(diagnostics) = Self::lint_path(&linter, &path) {
+                    if let Some(diagnostics) = Self::lint_path(&linter, &config, &path) {
                         tx_error.send(diagnostics).unwrap();
                     }
                     drop(tx_error);
                 });
             }
+            number_of_files.store(files, Ordering::Relaxed);
+            number_of_rules.store(rules, Ordering::Relaxed);
         });
     }
 
@@ -185,40 +192,63 @@ impl LintRunner {
         (number_of_warnings, number_of_diagnostics)
     }
 
-    fn lint_path(linter: &Linter, path: &Path) -> Option<(PathBuf, Vec<Error>)> {
+    fn lint_path(
+        linter: &Linter,
+        config: &LintConfig,
+        path: &Path,
+    ) -> Option<(PathBuf, Vec<Error>)> {
         let source_text = fs::read_to_string(path).unwrap_or_else(|_| panic!("{path:?} not found"));
+        let source_type = config.source_type(
+            SourceType::from_path(path).unwrap_or_else(|_| panic!("incorrect {path:?}")),
+        );
+        let (diagnostics, fixed_code) = Self::lint_source(linter, &source_text, source_type);
+        if let Some(fixed_code) = fixed_code {
+            fs::write(path, fixed_code.as_bytes()).unwrap();
+        }
+        if diagnostics.is_empty() {
+            return None;
+        }
+        Some(Self::wrap_diagnostics(path, &source_text, diagnostics))
+    }
+
+    /// Lint `source_text`, returning the diagnostics and the fixed code when fixing is enabled.
+    ///
+    /// Parser errors stop here, while the early errors of the semantic analysis
+    /// are reported along with the diagnostics of the lint rules.
+    fn lint_source(
+        linter: &Linter,
+        source_text: &str,
+        source_type: SourceType,
+    ) -> (Vec<Error>, Option<String>) {
         let allocator = Allocator::default();
-        let source_type =
-            SourceType::from_path(path).unwrap_or_else(|_| panic!("incorrect {path:?}"));
-        let ret = Parser::new(&allocator, &source_text, source_type).parse();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
 
         if !ret.errors.is_empty() {
-            return Some(Self::wrap_diagnostics(path, &source_text, ret.errors));
+            return (ret.errors, None);
         };
 
         let program = allocator.alloc(ret.program);
-        let semantic_ret =
-            SemanticBuilder::new(&source_text, source_type, &ret.trivias).build(program);
+        let semantic_ret = Linter::semantic_builder(source_text, source_type, &ret.trivias)
+            .with_check_syntax_error(true)
+            .build(program);
 
-        if !semantic_ret.errors.is_empty() {
-            return Some(Self::wrap_diagnostics(path, &source_text, semantic_ret.errors));
+        let mut diagnostics = semantic_ret.errors;
+        let result = match linter.run(&Rc::new(semantic_ret.semantic)) {
+            Ok(result) => result,
+            Err(error) => {
+                diagnostics.push(error.into());
+                return (diagnostics, None);
+            }
         };
 
-        let result = linter.run(&Rc::new(semantic_ret.semantic));
-
-        if result.is_empty() {
-            return None;
+        if linter.has_fix() && !result.is_empty() {
+            let fix_result = Fixer::new(source_text, result).fix();
+            diagnostics.extend(fix_result.messages.into_iter().map(|m| m.error));
+            return (diagnostics, Some(fix_result.fixed_code.into_owned()));
         }
 
-        if linter.has_fix() {
-            let fix_result = Fixer::new(&source_text, result).fix();
-            fs::write(path, fix_result.fixed_code.as_bytes()).unwrap();
-            let errors = fix_result.messages.into_iter().map(|m| m.error).collect();
-            return Some(Self::wrap_diagnostics(path, &source_text, errors));
-        }
-
-        let errors = result.into_iter().map(|diagnostic| diagnostic.error).collect();
-        Some(Self::wrap_diagnostics(path, &source_text, errors))
+        diagnostics.extend(result.into_iter().map(|diagnostic| diagnostic.error));
+        (diagnostics, None)
     }
 
     fn wrap_diagnostics(
@@ -234,3 +264,30 @@ impl LintRunner {
         (path.to_path_buf(), diagnostics)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use oxc_ast::SourceType;
+    use oxc_linter::{AllowWarnDeny, LintConfig};
+
+    use super::LintRunner;
+
+    #[test]
+    fn early_errors_and_lint_diagnostics() {
+        let mut config = LintConfig::default();
+        config.set_rules(AllowWarnDeny::Deny, "correctness");
+        let source_type = SourceType::from_path("test.js").unwrap();
+        let (diagnostics, fixed_code) =
+            LintRunner::lint_source(&config.linter(), "var x = 012;\ndebugger;", source_type);
+        let messages = diagnostics.iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(messages.len(), 2, "{messages:?}");
+        assert!(messages[0].contains("octal"), "{messages:?}");
+        assert!(messages[1].contains("debugger"), "{messages:?}");
+        assert!(fixed_code.is_none());
+
+        // parser errors skip the lint rules
+        let (diagnostics, _) =
+            LintRunner::lint_source(&config.linter(), "var;\ndebugger;", source_type);
+        assert_eq!(diagnostics.len(), 1);
+    }
+}
@@ -17,6 +17,13 @@ pub enum CliRunResult {
         number_of_diagnostics: usize,
         max_warnings_exceeded: bool,
     },
+    /// `--print-config`, `None` when the file is ignored
+    PrintConfig {
+        path: PathBuf,
+        config: Option<String>,
+    },
+    /// The errors of the configuration files are already printed
+    InvalidConfig,
 }
 
 impl Termination for CliRunResult {
@@ -46,14 +53,23 @@ impl Termination for CliRunResult {
                     return ExitCode::from(1);
                 }
 
+                // Warnings alone do not fail the run, see `--max-warnings`
+                let number_of_errors = number_of_diagnostics - number_of_warnings;
                 if number_of_diagnostics > 0 {
-                    println!("Found {number_of_diagnostics} errors.");
-                    return ExitCode::from(1);
+                    println!("Found {number_of_warnings} warnings and {number_of_errors} errors.");
+                } else {
+                    println!("Found no errors.");
                 }
 
-                println!("Found no errors.");
+                ExitCode::from(u8::from(number_of_errors > 0))
+            }
+            Self::PrintConfig { path, config } => {
+                let output =
+                    config.unwrap_or_else(|| format!("{path:?} is ignored by the configuration."));
+                println!("{output}");
                 ExitCode::from(0)
             }
+            Self::InvalidConfig => ExitCode::from(1),
         }
     }
 }
@@ -4,11 +4,11 @@
 mod graphic_reporter;
 mod graphical_theme;
 
-use std::path::PathBuf;
+use std::{fmt, path::PathBuf};
 
 pub use graphic_reporter::GraphicalReportHandler;
 pub use miette;
-use miette::Diagnostic;
+use miette::{Diagnostic, LabeledSpan, SourceCode};
 use oxc_ast::{Atom, Span};
 pub use thiserror;
 use thiserror::Error;
@@ -32,3 +32,63 @@ pub struct Redeclaration(
 #[error("File is too long to fit on the screen")]
 #[diagnostic(help("{0:?} seems like a minified file"))]
 pub struct MinifiedFileError(pub PathBuf);
+
+/// A diagnostic reported with a different severity, e.g. the severity configured for a lint rule
+#[derive(Debug)]
+pub struct WithSeverity {
+    error: Error,
+    severity: Severity,
+}
+
+impl WithSeverity {
+    #[must_use]
+    pub fn new(error: Error, severity: Severity) -> Self {
+        Self { error, severity }
+    }
+}
+
+impl fmt::Display for WithSeverity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.error, f)
+    }
+}
+
+impl std::error::Error for WithSeverity {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.error.source()
+    }
+}
+
+impl Diagnostic for WithSeverity {
+    fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.error.code()
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        Some(self.severity)
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.error.help()
+    }
+
+    fn url<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.error.url()
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        self.error.source_code()
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        self.error.labels()
+    }
+
+    fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+        self.error.related()
+    }
+
+    fn diagnostic_source(&self) -> Option<&dyn Diagnostic> {
+        self.error.diagnostic_source()
+    }
+}
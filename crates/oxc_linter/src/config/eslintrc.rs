@@ -0,0 +1,444 @@
+//! `.eslintrc.json` and `.eslintrc` files, JSON with comments, see
+//! [Configuration Files](https://eslint.org/docs/latest/use/configure/configuration-files)
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use globset::{GlobSet, GlobSetBuilder};
+use json_comments::StripComments;
+use oxc_ast::ModuleKind;
+use oxc_diagnostics::miette;
+use serde_json::{Map, Value};
+
+use super::{
+    parser::{ConfigParser, FailedToParseConfig, RuleConfig},
+    LintConfig,
+};
+use crate::{AllowWarnDeny, GlobalValue};
+
+/// Configuration file names in order of precedence, only the first one found in a directory is used
+pub const ESLINTRC_FILE_NAMES: [&str; 2] = [".eslintrc.json", ".eslintrc"];
+
+/// A parsed `.eslintrc` file
+#[derive(Debug)]
+pub struct ESLintConfig {
+    path: PathBuf,
+
+    /// Stop looking for configuration files in parent directories
+    root: bool,
+
+    /// `ignorePatterns`, in gitignore syntax
+    ignore_patterns: Vec<IgnorePattern>,
+
+    options: ConfigOptions,
+}
+
+/// The options shared by the configuration file and its `overrides`
+#[derive(Debug, Default)]
+struct ConfigOptions {
+    /// Categories of the built-in presets, e.g. `correctness` for `eslint:recommended`
+    extends: Vec<&'static str>,
+    envs: Vec<(String, bool)>,
+    globals: Vec<(String, GlobalValue)>,
+    rules: Vec<RuleConfig>,
+    module_kind: Option<ModuleKind>,
+    settings: Map<String, Value>,
+    overrides: Vec<Override>,
+}
+
+#[derive(Debug)]
+struct Override {
+    files: GlobSet,
+    excluded_files: GlobSet,
+    options: ConfigOptions,
+}
+
+#[derive(Debug)]
+struct IgnorePattern {
+    glob: GlobSet,
+    negated: bool,
+}
+
+impl ESLintConfig {
+    /// # Errors
+    /// The file cannot be read, is not valid JSON or contains unknown rules or invalid options.
+    pub fn from_path(path: &Path) -> Result<Self, Vec<miette::Error>> {
+        let text = fs::read_to_string(path)
+            .map_err(|err| vec![FailedToParseConfig(path.to_path_buf(), err.to_string()).into()])?;
+        Self::from_json_str(path, &text)
+    }
+
+    /// Parse the content of the configuration file at `path`,
+    /// globs are matched relative to the directory of `path`.
+    ///
+    /// # Errors
+    /// `text` is not valid JSON or contains unknown rules or invalid options.
+    pub fn from_json_str(path: &Path, text: &str) -> Result<Self, Vec<miette::Error>> {
+        let value: Value = serde_json::from_reader(StripComments::new(text.as_bytes()))
+            .map_err(|err| vec![FailedToParseConfig(path.to_path_buf(), err.to_string()).into()])?;
+        let mut parser = ConfigParser::new(path);
+        let config = parser.eslintrc(&value);
+        if parser.errors.is_empty() { Ok(config) } else { Err(parser.errors) }
+    }
+
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    #[must_use]
+    pub fn is_root(&self) -> bool {
+        self.root
+    }
+
+    fn dir(&self) -> &Path {
+        self.path.parent().unwrap_or_else(|| Path::new(""))
+    }
+
+    /// Is `path` excluded by `ignorePatterns`, the last matching pattern wins
+    #[must_use]
+    pub fn is_ignored(&self, path: &Path) -> bool {
+        let Ok(path) = path.strip_prefix(self.dir()) else { return false };
+        self.ignore_patterns
+            .iter()
+            .rev()
+            .find(|pattern| pattern.glob.is_match(path))
+            .map_or(false, |pattern| !pattern.negated)
+    }
+
+    /// Apply the presets, options and matching `overrides` for the file at `path`
+    pub fn apply(&self, path: &Path, config: &mut LintConfig) {
+        let path = path.strip_prefix(self.dir()).unwrap_or(path);
+        self.options.apply(path, config);
+    }
+}
+
+impl ConfigOptions {
+    fn apply(&self, path: &Path, config: &mut LintConfig) {
+        for category in &self.extends {
+            config.set_rules(AllowWarnDeny::Deny, category);
+        }
+        for (name, enabled) in &self.envs {
+            config.set_env(name, *enabled);
+        }
+        for (name, value) in &self.globals {
+            config.set_global(name, *value);
+        }
+        for (name, allow_warn_deny, options) in &self.rules {
+            config.set_rule_or_category(name, *allow_warn_deny, options.clone());
+        }
+        if let Some(module_kind) = self.module_kind {
+            config.set_module_kind(module_kind);
+        }
+        config.merge_settings(&self.settings);
+        for r#override in &self.overrides {
+            if r#override.files.is_match(path) && !r#override.excluded_files.is_match(path) {
+                r#override.options.apply(path, config);
+            }
+        }
+    }
+}
+
+/// The `.eslintrc` specific options
+impl<'p> ConfigParser<'p> {
+    fn eslintrc(&mut self, value: &Value) -> ESLintConfig {
+        let Some(object) = value.as_object() else {
+            self.error("config", "The configuration must be an object");
+            return ESLintConfig {
+                path: self.path.to_path_buf(),
+                root: false,
+                ignore_patterns: vec![],
+                options: ConfigOptions::default(),
+            };
+        };
+        let root = match object.get("root") {
+            None => false,
+            Some(Value::Bool(root)) => *root,
+            Some(_) => {
+                self.error("root", "Expected a boolean");
+                false
+            }
+        };
+        let ignore_patterns = object
+            .get("ignorePatterns")
+            .map(|value| self.strings("ignorePatterns", value))
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|pattern| self.ignore_pattern(pattern))
+            .collect();
+        let options = self.options(object, false);
+        ESLintConfig { path: self.path.to_path_buf(), root, ignore_patterns, options }
+    }
+
+    fn options(&mut self, object: &Map<String, Value>, is_override: bool) -> ConfigOptions {
+        let mut options = ConfigOptions::default();
+        for (key, value) in object {
+            match key.as_str() {
+                "extends" => options.extends = self.extends(value),
+                "env" => options.envs = self.envs(value),
+                "globals" => options.globals = self.globals(value),
+                "rules" => options.rules = self.rules(value, false),
+                "parserOptions" => options.module_kind = self.parser_options(value),
+                "settings" => options.settings = self.settings(value),
+                "overrides" => options.overrides = self.overrides(value),
+                "root" | "ignorePatterns" if is_override => {
+                    self.error("overrides", format!("`{key}` is not allowed in overrides"));
+                }
+                "files" | "excludedFiles" if is_override => {}
+                "root"
+                | "ignorePatterns"
+                | "$schema"
+                | "parser"
+                | "plugins"
+                | "noInlineConfig"
+                | "reportUnusedDisableDirectives" => {}
+                _ => self.error("config", format!("Unexpected top-level property `{key}`")),
+            }
+        }
+        options
+    }
+
+    fn extends(&mut self, value: &Value) -> Vec<&'static str> {
+        self.strings("extends", value)
+            .iter()
+            .filter_map(|preset| match preset.as_str() {
+                "eslint:recommended" => Some("correctness"),
+                "eslint:all" => Some("all"),
+                _ => {
+                    self.error(
+                        "extends",
+                        format!(
+                            "Unknown preset `{preset}`, only `eslint:recommended` and `eslint:all` are supported"
+                        ),
+                    );
+                    None
+                }
+            })
+            .collect()
+    }
+
+    fn parser_options(&mut self, value: &Value) -> Option<ModuleKind> {
+        let source_type = self.object("parserOptions", value)?.get("sourceType")?;
+        match source_type.as_str() {
+            Some("module") => Some(ModuleKind::Module),
+            Some("script" | "commonjs") => Some(ModuleKind::Script),
+            _ => {
+                self.error(
+                    "parserOptions",
+                    format!(
+                        "Expected `module`, `script` or `commonjs` as the `sourceType`, found {source_type}"
+                    ),
+                );
+                None
+            }
+        }
+    }
+
+    fn overrides(&mut self, value: &Value) -> Vec<Override> {
+        let Some(values) = value.as_array() else {
+            self.error("overrides", "Expected an array");
+            return vec![];
+        };
+        let mut overrides = vec![];
+        for value in values {
+            let Some(object) = self.object("overrides", value) else { continue };
+            let Some(files) = object.get("files") else {
+                self.error("overrides", "`files` is required in overrides");
+                continue;
+            };
+            let files = self.strings("overrides", files);
+            let excluded_files = object
+                .get("excludedFiles")
+                .map(|value| self.strings("overrides", value))
+                .unwrap_or_default();
+            let options = self.options(object, true);
+            overrides.push(Override {
+                files: self.glob_set(&files),
+                excluded_files: self.glob_set(&excluded_files),
+                options,
+            });
+        }
+        overrides
+    }
+
+    /// Globs of `overrides`, relative to the directory of the configuration file.
+    /// A pattern without a slash matches the file name in any directory.
+    fn glob_set(&mut self, patterns: &[String]) -> GlobSet {
+        let mut builder = GlobSetBuilder::new();
+        for pattern in patterns {
+            let pattern = pattern.trim_start_matches("./");
+            let pattern =
+                if pattern.contains('/') { pattern.to_string() } else { format!("**/{pattern}") };
+            if let Some(glob) = self.glob("overrides", &pattern) {
+                builder.add(glob);
+            }
+        }
+        builder.build().unwrap_or_else(|_| GlobSet::empty())
+    }
+
+    /// A gitignore pattern also matches the files inside of the matched directories
+    fn ignore_pattern(&mut self, pattern: &str) -> Option<IgnorePattern> {
+        let (negated, pattern) =
+            pattern.strip_prefix('!').map_or((false, pattern), |pattern| (true, pattern));
+        let pattern = pattern.trim_end_matches('/');
+        let pattern = match pattern.strip_prefix('/') {
+            Some(pattern) => pattern.to_string(),
+            None if pattern.contains('/') => pattern.to_string(),
+            None => format!("**/{pattern}"),
+        };
+        let mut builder = GlobSetBuilder::new();
+        builder.add(self.glob("ignorePatterns", &pattern)?);
+        builder.add(self.glob("ignorePatterns", &format!("{pattern}/**"))?);
+        let glob = builder.build().ok()?;
+        Some(IgnorePattern { glob, negated })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use oxc_ast::SourceType;
+
+    use super::ESLintConfig;
+    use crate::config::LintConfig;
+
+    fn parse(text: &str) -> ESLintConfig {
+        ESLintConfig::from_json_str(Path::new("/project/.eslintrc.json"), text).unwrap()
+    }
+
+    /// Sorted, the order of the keys depends on the `preserve_order` feature of `serde_json`
+    fn errors(text: &str) -> Vec<String> {
+        let mut errors = ESLintConfig::from_json_str(Path::new("/project/.eslintrc.json"), text)
+            .unwrap_err()
+            .into_iter()
+            .map(|error| error.to_string())
+            .collect::<Vec<_>>();
+        errors.sort_unstable();
+        errors
+    }
+
+    fn resolve(config: &ESLintConfig, path: &str) -> LintConfig {
+        let mut lint_config = LintConfig::default();
+        config.apply(Path::new(path), &mut lint_config);
+        lint_config
+    }
+
+    #[test]
+    fn rules() {
+        let config = parse(
+            r#"{
+                // comments are allowed
+                "extends": "eslint:recommended",
+                "rules": {
+                    "no-debugger": "off",
+                    "no-bitwise": ["warn", { "allow": ["~"] }], /* block comment */
+                    "no-empty": 2
+                }
+            }"#,
+        );
+        let lint_config = resolve(&config, "/project/src/a.js");
+        let linter = lint_config.linter();
+        assert!(lint_config.rules.get("no-debugger").is_some_and(|(a, _)| a.is_allow()));
+        assert_eq!(
+            linter.number_of_rules(),
+            lint_config.rules.values().filter(|(a, _)| !a.is_allow()).count()
+        );
+        assert!(lint_config.rules["no-bitwise"].1.is_some());
+    }
+
+    #[test]
+    fn overrides() {
+        let config = parse(
+            r#"{
+                "root": true,
+                "env": { "browser": true },
+                "parserOptions": { "sourceType": "script" },
+                "rules": { "no-bitwise": ["error", { "allow": ["~"] }] },
+                "overrides": [
+                    {
+                        "files": ["*.test.js", "scripts/**"],
+                        "excludedFiles": "scripts/vendor/**",
+                        "env": { "browser": false, "jest": true },
+                        "parserOptions": { "sourceType": "module" },
+                        "rules": { "no-bitwise": "warn" }
+                    }
+                ]
+            }"#,
+        );
+        assert!(config.is_root());
+
+        let lint_config = resolve(&config, "/project/src/a.js");
+        assert!(lint_config.source_type(SourceType::from_path("a.js").unwrap()).is_script());
+        assert!(lint_config.globals().is_defined("window"));
+        assert!(!lint_config.globals().is_defined("describe"));
+
+        for path in ["/project/src/a.test.js", "/project/scripts/build.js"] {
+            let lint_config = resolve(&config, path);
+            assert!(lint_config.source_type(SourceType::default()).is_module(), "{path}");
+            assert!(!lint_config.globals().is_defined("window"), "{path}");
+            assert!(lint_config.globals().is_defined("describe"), "{path}");
+            // The options of `no-bitwise` are kept when only the severity is overridden
+            let (allow_warn_deny, options) = &lint_config.rules["no-bitwise"];
+            assert_eq!(*allow_warn_deny, crate::AllowWarnDeny::Warn, "{path}");
+            assert!(options.is_some(), "{path}");
+        }
+
+        let lint_config = resolve(&config, "/project/scripts/vendor/a.js");
+        assert!(lint_config.globals().is_defined("window"));
+    }
+
+    #[test]
+    fn ignore_patterns() {
+        let config =
+            parse(r#"{ "ignorePatterns": ["dist/", "*.min.js", "/build", "!build/keep.js"] }"#);
+        for (path, ignored) in [
+            ("/project/dist/a.js", true),
+            ("/project/src/dist/a.js", true),
+            ("/project/src/a.min.js", true),
+            ("/project/build/a.js", true),
+            ("/project/build/keep.js", false),
+            ("/project/src/build/a.js", false),
+            ("/project/src/a.js", false),
+            ("/other/dist/a.js", false),
+        ] {
+            assert_eq!(config.is_ignored(Path::new(path)), ignored, "{path}");
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            errors(
+                r#"{
+                    "extends": ["eslint:recommended", "airbnb"],
+                    "env": { "deno": true },
+                    "globals": { "foo": "yes" },
+                    "rules": {
+                        "no-such-rule": "error",
+                        "no-debugger": "on",
+                        "no-restricted-syntax": ["error", "Identifier["]
+                    },
+                    "parserOptions": { "sourceType": "esm" },
+                    "overrides": [{ "rules": {} }, { "files": "*.ts", "root": true }],
+                    "foo": 1
+                }"#
+            ),
+            vec![
+                "Invalid `config` in config \"/project/.eslintrc.json\"",
+                "Invalid `env` in config \"/project/.eslintrc.json\"",
+                "Invalid `extends` in config \"/project/.eslintrc.json\"",
+                "Invalid `globals` in config \"/project/.eslintrc.json\"",
+                "Invalid `overrides` in config \"/project/.eslintrc.json\"",
+                "Invalid `overrides` in config \"/project/.eslintrc.json\"",
+                "Invalid `parserOptions` in config \"/project/.eslintrc.json\"",
+                "Invalid `rules` in config \"/project/.eslintrc.json\"",
+                "Invalid `rules` in config \"/project/.eslintrc.json\"",
+                "Unknown rule `no-such-rule` in config \"/project/.eslintrc.json\"",
+            ]
+        );
+        assert_eq!(errors("{ invalid }").len(), 1);
+    }
+}
@@ -0,0 +1,349 @@
+//! Flat configuration files, `oxc.config.json` (JSON with comments) and `oxc.config.toml`:
+//! an ordered array of configuration objects, merged in order for the files they match, see
+//! [eslint's flat config](https://eslint.org/docs/latest/use/configure/configuration-files-new)
+//!
+//! ```json
+//! [
+//!   { "rules": { "correctness": "error" } },
+//!   { "files": ["**/*.test.js"], "globals": { "describe": "readonly" } },
+//!   { "ignores": ["dist/"] }
+//! ]
+//! ```
+//!
+//! TOML has no top-level arrays, the configuration objects are its `[[config]]` tables.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use globset::{GlobSet, GlobSetBuilder};
+use json_comments::StripComments;
+use oxc_diagnostics::miette;
+use serde_json::{Map, Value};
+
+use super::{
+    parser::{ConfigParser, FailedToParseConfig, RuleConfig},
+    LintConfig,
+};
+use crate::GlobalValue;
+
+/// Configuration file names in order of precedence
+pub const FLAT_CONFIG_FILE_NAMES: [&str; 2] = ["oxc.config.json", "oxc.config.toml"];
+
+/// A parsed flat configuration file
+#[derive(Debug)]
+pub struct FlatConfig {
+    path: PathBuf,
+
+    /// `ignores` of the objects without any other property, the files are not linted
+    ignores: GlobSet,
+
+    objects: Vec<ConfigObject>,
+}
+
+#[derive(Debug)]
+struct ConfigObject {
+    /// Matches all files when missing
+    files: Option<GlobSet>,
+    ignores: GlobSet,
+    globals: Vec<(String, GlobalValue)>,
+    /// Rules, categories or `all`
+    rules: Vec<RuleConfig>,
+    settings: Map<String, Value>,
+}
+
+impl FlatConfig {
+    /// Parse the file at `path` as TOML for a `.toml` extension, as JSON with comments otherwise.
+    ///
+    /// # Errors
+    /// The file cannot be read, cannot be parsed or contains unknown rules or invalid options.
+    pub fn from_path(path: &Path) -> Result<Self, Vec<miette::Error>> {
+        let text = fs::read_to_string(path)
+            .map_err(|err| vec![FailedToParseConfig(path.to_path_buf(), err.to_string()).into()])?;
+        if path.extension().is_some_and(|extension| extension == "toml") {
+            Self::from_toml_str(path, &text)
+        } else {
+            Self::from_json_str(path, &text)
+        }
+    }
+
+    /// Globs are matched relative to the directory of `path`.
+    ///
+    /// # Errors
+    /// `text` is not valid JSON or contains unknown rules or invalid options.
+    pub fn from_json_str(path: &Path, text: &str) -> Result<Self, Vec<miette::Error>> {
+        let value: Value = serde_json::from_reader(StripComments::new(text.as_bytes()))
+            .map_err(|err| vec![FailedToParseConfig(path.to_path_buf(), err.to_string()).into()])?;
+        let mut parser = ConfigParser::new(path);
+        let config = parser.flat_config(&value);
+        if parser.errors.is_empty() { Ok(config) } else { Err(parser.errors) }
+    }
+
+    /// Globs are matched relative to the directory of `path`.
+    ///
+    /// # Errors
+    /// `text` is not valid TOML or contains unknown rules or invalid options.
+    pub fn from_toml_str(path: &Path, text: &str) -> Result<Self, Vec<miette::Error>> {
+        let value: Value = toml::from_str(text)
+            .map_err(|err| vec![FailedToParseConfig(path.to_path_buf(), err.to_string()).into()])?;
+        let mut parser = ConfigParser::new(path);
+        let config = parser.toml_flat_config(&value);
+        if parser.errors.is_empty() { Ok(config) } else { Err(parser.errors) }
+    }
+
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn relative_path<'a>(&self, path: &'a Path) -> &'a Path {
+        let dir = self.path.parent().unwrap_or_else(|| Path::new(""));
+        path.strip_prefix(dir).unwrap_or(path)
+    }
+
+    /// Is `path` excluded by the global `ignores`
+    #[must_use]
+    pub fn is_ignored(&self, path: &Path) -> bool {
+        self.ignores.is_match(self.relative_path(path))
+    }
+
+    /// Apply the configuration objects matching the file at `path` in order
+    pub fn apply(&self, path: &Path, config: &mut LintConfig) {
+        let path = self.relative_path(path);
+        for object in &self.objects {
+            if object.files.as_ref().map_or(true, |files| files.is_match(path))
+                && !object.ignores.is_match(path)
+            {
+                object.apply(config);
+            }
+        }
+    }
+}
+
+impl ConfigObject {
+    fn apply(&self, config: &mut LintConfig) {
+        for (name, value) in &self.globals {
+            config.set_global(name, *value);
+        }
+        for (name, allow_warn_deny, options) in &self.rules {
+            config.set_rule_or_category(name, *allow_warn_deny, options.clone());
+        }
+        config.merge_settings(&self.settings);
+    }
+}
+
+/// The flat configuration specific options
+impl<'p> ConfigParser<'p> {
+    fn flat_config(&mut self, value: &Value) -> FlatConfig {
+        let mut ignores = GlobSetBuilder::new();
+        let mut objects = vec![];
+        if let Some(values) = value.as_array() {
+            for value in values {
+                let Some(object) = self.object("config", value) else { continue };
+                if object.len() == 1 && object.contains_key("ignores") {
+                    let patterns = self.strings("ignores", &object["ignores"]);
+                    self.add_ignores(&mut ignores, &patterns);
+                } else {
+                    objects.push(self.config_object(object));
+                }
+            }
+        } else {
+            self.error("config", "The configuration must be an array of objects");
+        }
+        FlatConfig {
+            path: self.path.to_path_buf(),
+            ignores: ignores.build().unwrap_or_else(|_| GlobSet::empty()),
+            objects,
+        }
+    }
+
+    fn toml_flat_config(&mut self, value: &Value) -> FlatConfig {
+        let mut config = Value::Array(vec![]);
+        for (key, value) in value.as_object().into_iter().flatten() {
+            if key == "config" {
+                config = value.clone();
+            } else {
+                self.error(
+                    "config",
+                    format!("Unexpected top-level property `{key}`, expected `[[config]]` tables"),
+                );
+            }
+        }
+        self.flat_config(&config)
+    }
+
+    fn config_object(&mut self, object: &Map<String, Value>) -> ConfigObject {
+        let mut config_object = ConfigObject {
+            files: None,
+            ignores: GlobSet::empty(),
+            globals: vec![],
+            rules: vec![],
+            settings: Map::new(),
+        };
+        for (key, value) in object {
+            match key.as_str() {
+                "files" => {
+                    let patterns = self.strings("files", value);
+                    let mut builder = GlobSetBuilder::new();
+                    for pattern in &patterns {
+                        if let Some(glob) = self.glob("files", pattern.trim_start_matches("./")) {
+                            builder.add(glob);
+                        }
+                    }
+                    config_object.files = builder.build().ok();
+                }
+                "ignores" => {
+                    let patterns = self.strings("ignores", value);
+                    let mut builder = GlobSetBuilder::new();
+                    self.add_ignores(&mut builder, &patterns);
+                    config_object.ignores = builder.build().unwrap_or_else(|_| GlobSet::empty());
+                }
+                "globals" => config_object.globals = self.globals(value),
+                "rules" => config_object.rules = self.rules(value, true),
+                "settings" => config_object.settings = self.settings(value),
+                _ => self.error("config", format!("Unexpected property `{key}`")),
+            }
+        }
+        config_object
+    }
+
+    /// An ignored directory also ignores the files inside of it, e.g. `dist/`
+    fn add_ignores(&mut self, builder: &mut GlobSetBuilder, patterns: &[String]) {
+        for pattern in patterns {
+            let pattern = pattern.trim_start_matches("./").trim_end_matches('/');
+            for pattern in [pattern.to_string(), format!("{pattern}/**")] {
+                if let Some(glob) = self.glob("ignores", &pattern) {
+                    builder.add(glob);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use serde_json::json;
+
+    use super::FlatConfig;
+    use crate::{config::LintConfig, AllowWarnDeny};
+
+    fn resolve(config: &FlatConfig, path: &str) -> LintConfig {
+        let mut lint_config = LintConfig::default();
+        config.apply(Path::new(path), &mut lint_config);
+        lint_config
+    }
+
+    fn assert_config(config: &FlatConfig) {
+        let lint_config = resolve(config, "/project/src/a.js");
+        assert_eq!(lint_config.rule_severity("no-debugger"), Some(AllowWarnDeny::Deny));
+        assert_eq!(lint_config.rule_severity("no-bitwise"), Some(AllowWarnDeny::Warn));
+        assert!(!lint_config.globals().is_defined("describe"));
+        let json = lint_config.to_json();
+        assert_eq!(json["rules"]["no-bitwise"], json!(["warn", { "allow": ["~"] }]));
+        assert_eq!(json["rules"]["no-debugger"], json!("error"));
+        assert_eq!(json["settings"], json!({ "a": { "b": 1 } }));
+
+        let lint_config = resolve(config, "/project/src/a.test.js");
+        assert_eq!(lint_config.rule_severity("no-debugger"), Some(AllowWarnDeny::Allow));
+        assert!(lint_config.globals().is_defined("describe"));
+        assert_eq!(lint_config.to_json()["settings"], json!({ "a": { "b": 1, "c": 2 } }));
+
+        // `ignores` of a configuration object
+        let lint_config = resolve(config, "/project/src/fixtures/a.test.js");
+        assert_eq!(lint_config.rule_severity("no-debugger"), Some(AllowWarnDeny::Deny));
+
+        for (path, ignored) in [
+            ("/project/dist/a.js", true),
+            ("/project/dist", true),
+            ("/project/src/dist/a.js", false),
+            ("/project/src/a.js", false),
+        ] {
+            assert_eq!(config.is_ignored(Path::new(path)), ignored, "{path}");
+        }
+    }
+
+    #[test]
+    fn json() {
+        let config = FlatConfig::from_json_str(
+            Path::new("/project/oxc.config.json"),
+            r#"[
+                // categories are allowed
+                { "rules": { "correctness": "error", "no-bitwise": ["warn", { "allow": ["~"] }] } },
+                { "settings": { "a": { "b": 1 } } },
+                {
+                    "files": ["**/*.test.js"],
+                    "ignores": ["src/fixtures/"],
+                    "globals": { "describe": "readonly" },
+                    "rules": { "no-debugger": "off" },
+                    "settings": { "a": { "c": 2 } }
+                },
+                { "ignores": ["./dist/"] }
+            ]"#,
+        )
+        .unwrap();
+        assert_config(&config);
+    }
+
+    #[test]
+    fn toml() {
+        let config = FlatConfig::from_toml_str(
+            Path::new("/project/oxc.config.toml"),
+            r#"
+            # categories are allowed
+            [[config]]
+            rules = { correctness = "error", no-bitwise = ["warn", { allow = ["~"] }] }
+
+            [[config]]
+            settings = { a = { b = 1 } }
+
+            [[config]]
+            files = ["**/*.test.js"]
+            ignores = ["src/fixtures/"]
+            globals = { describe = "readonly" }
+            rules = { no-debugger = "off" }
+            settings = { a = { c = 2 } }
+
+            [[config]]
+            ignores = ["./dist/"]
+            "#,
+        )
+        .unwrap();
+        assert_config(&config);
+    }
+
+    #[test]
+    fn invalid() {
+        let errors = |path: &str, text: &str| {
+            let mut errors = FlatConfig::from_json_str(Path::new(path), text)
+                .unwrap_err()
+                .into_iter()
+                .map(|error| error.to_string())
+                .collect::<Vec<_>>();
+            errors.sort_unstable();
+            errors
+        };
+        assert_eq!(
+            errors(
+                "/project/oxc.config.json",
+                r#"[
+                    { "rules": { "no-such-rule": 2, "correctness": ["error", {}] } },
+                    { "files": "*.js", "env": { "browser": true } },
+                    "eslint:recommended"
+                ]"#
+            ),
+            vec![
+                "Invalid `config` in config \"/project/oxc.config.json\"",
+                "Invalid `config` in config \"/project/oxc.config.json\"",
+                "Invalid `rules` in config \"/project/oxc.config.json\"",
+                "Unknown rule `no-such-rule` in config \"/project/oxc.config.json\"",
+            ]
+        );
+        assert_eq!(errors("/project/oxc.config.json", r#"{ "rules": {} }"#).len(), 1);
+        assert!(
+            FlatConfig::from_toml_str(Path::new("/project/oxc.config.toml"), "rules = {}").is_err()
+        );
+    }
+}
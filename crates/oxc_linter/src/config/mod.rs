@@ -0,0 +1,187 @@
+//! Configuration of the rules, globals and source type of the linted files,
+//! read from `.eslintrc` files or from a flat configuration file
+
+mod eslintrc;
+mod flat;
+mod parser;
+
+use std::collections::BTreeMap;
+
+use oxc_ast::{ModuleKind, SourceType};
+use serde_json::{json, Map, Value};
+
+pub use self::{
+    eslintrc::{ESLintConfig, ESLINTRC_FILE_NAMES},
+    flat::{FlatConfig, FLAT_CONFIG_FILE_NAMES},
+};
+use crate::{AllowWarnDeny, GlobalEnvironment, GlobalValue, Linter, RuleCategory, RULES};
+
+/// The configuration of a linted file,
+/// built up by applying presets, configuration files and command line options in order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LintConfig {
+    /// Enabled and disabled environments, e.g. `browser`
+    envs: BTreeMap<String, bool>,
+    globals: BTreeMap<String, GlobalValue>,
+    /// Severity and options of the configured rules
+    rules: BTreeMap<&'static str, (AllowWarnDeny, Option<Value>)>,
+    /// `parserOptions.sourceType`
+    module_kind: Option<ModuleKind>,
+    /// Shared settings, not used by the rules yet
+    settings: Map<String, Value>,
+}
+
+impl LintConfig {
+    /// Set the severity of a rule, a category, or `all` rules, e.g. `-D correctness`.
+    /// Returns `false` when there is no such rule or category.
+    pub fn set_rules(&mut self, allow_warn_deny: AllowWarnDeny, name_or_category: &str) -> bool {
+        let maybe_category = RuleCategory::from(name_or_category);
+        let mut matched = false;
+        for rule in RULES.iter().filter(|rule| {
+            maybe_category.map_or_else(
+                || name_or_category == "all" || rule.name() == name_or_category,
+                |category| rule.category() == category,
+            )
+        }) {
+            self.set_rule(rule.name(), allow_warn_deny, None);
+            matched = true;
+        }
+        matched
+    }
+
+    /// Set the severity of a rule, the options of a previous configuration are kept
+    /// when `options` is `None`.
+    pub fn set_rule(
+        &mut self,
+        name: &'static str,
+        allow_warn_deny: AllowWarnDeny,
+        options: Option<Value>,
+    ) {
+        let entry = self.rules.entry(name).or_insert((allow_warn_deny, None));
+        entry.0 = allow_warn_deny;
+        if options.is_some() {
+            entry.1 = options;
+        }
+    }
+
+    /// Set the severity and options of a rule, or the severity of a category
+    pub fn set_rule_or_category(
+        &mut self,
+        name_or_category: &str,
+        allow_warn_deny: AllowWarnDeny,
+        options: Option<Value>,
+    ) {
+        if let Some(rule) = RULES.iter().find(|rule| rule.name() == name_or_category) {
+            self.set_rule(rule.name(), allow_warn_deny, options);
+        } else {
+            self.set_rules(allow_warn_deny, name_or_category);
+        }
+    }
+
+    /// The severity of a configured rule
+    #[must_use]
+    pub fn rule_severity(&self, name: &str) -> Option<AllowWarnDeny> {
+        self.rules.get(name).map(|(allow_warn_deny, _)| *allow_warn_deny)
+    }
+
+    pub fn set_env(&mut self, name: &str, enabled: bool) {
+        self.envs.insert(name.to_string(), enabled);
+    }
+
+    pub fn set_global(&mut self, name: &str, value: GlobalValue) {
+        self.globals.insert(name.to_string(), value);
+    }
+
+    pub fn set_module_kind(&mut self, module_kind: ModuleKind) {
+        self.module_kind = Some(module_kind);
+    }
+
+    /// Merge `settings` into the previous ones, objects are merged recursively
+    pub fn merge_settings(&mut self, settings: &Map<String, Value>) {
+        fn merge(target: &mut Map<String, Value>, source: &Map<String, Value>) {
+            for (key, value) in source {
+                match (target.get_mut(key), value) {
+                    (Some(Value::Object(target)), Value::Object(source)) => merge(target, source),
+                    _ => {
+                        target.insert(key.clone(), value.clone());
+                    }
+                }
+            }
+        }
+        merge(&mut self.settings, settings);
+    }
+
+    /// Apply `parserOptions.sourceType` to the source type derived from the file extension
+    #[must_use]
+    pub fn source_type(&self, mut source_type: SourceType) -> SourceType {
+        match self.module_kind {
+            Some(ModuleKind::Module) => *source_type.with_module(true),
+            Some(ModuleKind::Script) => *source_type.with_script(true),
+            None => source_type,
+        }
+    }
+
+    #[must_use]
+    pub fn globals(&self) -> GlobalEnvironment {
+        let mut globals = self
+            .envs
+            .iter()
+            .filter(|(_, enabled)| **enabled)
+            .fold(GlobalEnvironment::default(), |globals, (env, _)| globals.with_env(env));
+        for (name, value) in &self.globals {
+            globals = globals.with_global(name, *value);
+        }
+        globals
+    }
+
+    /// A linter running the enabled rules with their configured severity and options
+    #[must_use]
+    pub fn linter(&self) -> Linter {
+        let rules = RULES
+            .iter()
+            .filter_map(|rule| {
+                let (allow_warn_deny, options) = self.rules.get(rule.name())?;
+                Some((rule.read_json(options.clone()), *allow_warn_deny))
+            })
+            .collect();
+        Linter::from_configured_rules(rules).with_globals(self.globals())
+    }
+
+    /// The configuration in the `.eslintrc` format, for `--print-config`
+    #[must_use]
+    pub fn to_json(&self) -> Value {
+        let rules = self
+            .rules
+            .iter()
+            .map(|(name, (allow_warn_deny, options))| {
+                let severity = Value::from(allow_warn_deny.as_str());
+                let value = match options {
+                    Some(Value::Array(options)) => {
+                        Value::Array(std::iter::once(severity).chain(options.clone()).collect())
+                    }
+                    _ => severity,
+                };
+                ((*name).to_string(), value)
+            })
+            .collect::<Map<_, _>>();
+        let globals = self
+            .globals
+            .iter()
+            .map(|(name, value)| (name.clone(), Value::from(value.as_str())))
+            .collect::<Map<_, _>>();
+        let mut config = json!({
+            "env": self.envs,
+            "globals": globals,
+            "rules": rules,
+            "settings": self.settings,
+        });
+        if let Some(module_kind) = self.module_kind {
+            let source_type = match module_kind {
+                ModuleKind::Module => "module",
+                ModuleKind::Script => "script",
+            };
+            config["parserOptions"] = json!({ "sourceType": source_type });
+        }
+        config
+    }
+}
@@ -0,0 +1,160 @@
+//! Validation of the options shared by the configuration file formats
+
+use std::path::{Path, PathBuf};
+
+use globset::{Glob, GlobBuilder};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use serde_json::{Map, Value};
+
+use crate::{
+    options::parse_rule_value, AllowWarnDeny, GlobalEnvironment, GlobalValue, RuleCategory, RULES,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Failed to parse config {0:?}")]
+#[diagnostic(help("{1}"))]
+pub struct FailedToParseConfig(pub PathBuf, pub String);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Unknown rule `{1}` in config {0:?}")]
+#[diagnostic()]
+struct UnknownRule(PathBuf, String);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Invalid `{1}` in config {0:?}")]
+#[diagnostic(help("{2}"))]
+struct InvalidConfig(PathBuf, &'static str, String);
+
+/// A configured rule, or a category of rules when allowed by the format
+pub type RuleConfig = (String, AllowWarnDeny, Option<Value>);
+
+/// Collects all the errors of a configuration file instead of stopping at the first one
+pub struct ConfigParser<'p> {
+    pub path: &'p Path,
+    pub errors: Vec<miette::Error>,
+}
+
+impl<'p> ConfigParser<'p> {
+    pub fn new(path: &'p Path) -> Self {
+        Self { path, errors: vec![] }
+    }
+
+    pub fn error(&mut self, key: &'static str, message: impl Into<String>) {
+        self.errors.push(InvalidConfig(self.path.to_path_buf(), key, message.into()).into());
+    }
+
+    /// A string or an array of strings
+    pub fn strings(&mut self, key: &'static str, value: &Value) -> Vec<String> {
+        match value {
+            Value::String(s) => vec![s.clone()],
+            Value::Array(values) if values.iter().all(Value::is_string) => {
+                values.iter().filter_map(Value::as_str).map(String::from).collect()
+            }
+            _ => {
+                self.error(key, "Expected a string or an array of strings");
+                vec![]
+            }
+        }
+    }
+
+    pub fn object<'v>(
+        &mut self,
+        key: &'static str,
+        value: &'v Value,
+    ) -> Option<&'v Map<String, Value>> {
+        let object = value.as_object();
+        if object.is_none() {
+            self.error(key, "Expected an object");
+        }
+        object
+    }
+
+    pub fn envs(&mut self, value: &Value) -> Vec<(String, bool)> {
+        let Some(object) = self.object("env", value) else { return vec![] };
+        let mut envs = vec![];
+        for (name, enabled) in object {
+            if !GlobalEnvironment::ENVS.contains(&name.as_str()) {
+                self.error("env", format!("Unknown environment `{name}`"));
+            } else if let Some(enabled) = enabled.as_bool() {
+                envs.push((name.clone(), enabled));
+            } else {
+                self.error("env", format!("Expected a boolean for `{name}`"));
+            }
+        }
+        envs
+    }
+
+    pub fn globals(&mut self, value: &Value) -> Vec<(String, GlobalValue)> {
+        let Some(object) = self.object("globals", value) else { return vec![] };
+        let mut globals = vec![];
+        for (name, value) in object {
+            let Some(global) = GlobalValue::from_json(value) else {
+                self.error(
+                    "globals",
+                    format!("Expected `readonly`, `writable` or `off` for `{name}`, found {value}"),
+                );
+                continue;
+            };
+            globals.push((name.clone(), global));
+        }
+        globals
+    }
+
+    /// The rules by name, and the categories and `all` when `allow_categories` is set,
+    /// e.g. `"correctness": "error"`. Options cannot be given to a category.
+    pub fn rules(&mut self, value: &Value, allow_categories: bool) -> Vec<RuleConfig> {
+        let Some(object) = self.object("rules", value) else { return vec![] };
+        let mut rules = vec![];
+        for (name, value) in object {
+            let rule = RULES.iter().find(|rule| rule.name() == name);
+            let is_rule = rule.is_some();
+            let is_category =
+                allow_categories && (name == "all" || RuleCategory::from(name).is_some());
+            if !is_rule && !is_category {
+                self.errors.push(UnknownRule(self.path.to_path_buf(), name.clone()).into());
+                continue;
+            }
+            match parse_rule_value(value) {
+                Some((_, Some(_))) if is_category => {
+                    self.error("rules", format!("Options cannot be given to the category `{name}`"));
+                }
+                Some((allow_warn_deny, options)) => {
+                    let validated = match (rule, &options) {
+                        (Some(rule), Some(options)) => rule.validate_json(options),
+                        _ => Ok(()),
+                    };
+                    match validated {
+                        Ok(()) => rules.push((name.clone(), allow_warn_deny, options)),
+                        Err(message) => {
+                            self.error("rules", format!("Invalid options of `{name}`: {message}"));
+                        }
+                    }
+                }
+                None => self.error(
+                    "rules",
+                    format!(
+                        "Expected `off`, `warn`, `error`, 0, 1 or 2 as the severity of `{name}`, found {value}"
+                    ),
+                ),
+            }
+        }
+        rules
+    }
+
+    pub fn settings(&mut self, value: &Value) -> Map<String, Value> {
+        self.object("settings", value).cloned().unwrap_or_default()
+    }
+
+    pub fn glob(&mut self, key: &'static str, pattern: &str) -> Option<Glob> {
+        match GlobBuilder::new(pattern).literal_separator(true).build() {
+            Ok(glob) => Some(glob),
+            Err(err) => {
+                self.error(key, err.to_string());
+                None
+            }
+        }
+    }
+}
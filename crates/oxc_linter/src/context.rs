@@ -1,14 +1,18 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, rc::Rc, sync::Arc};
 
 use indextree::{Ancestors, NodeId};
 use oxc_ast::{ast::IdentifierReference, AstKind, SourceType};
-use oxc_diagnostics::Error;
+use oxc_diagnostics::{Error, Severity, WithSeverity};
 use oxc_printer::{Printer, PrinterOptions};
-use oxc_semantic::{AstNodes, Scope, ScopeTree, Semantic, SemanticNode, SymbolTable};
+use oxc_semantic::{
+    control_flow::ControlFlowGraph, AstNodes, ClassTable, Scope, ScopeTree, Semantic, SemanticNode,
+    SymbolTable, ThisBindings,
+};
 
 use crate::{
     disable_directives::{DisableDirectives, DisableDirectivesBuilder},
     fixer::{Fix, Message},
+    globals::GlobalEnvironment,
     AstNode,
 };
 
@@ -22,7 +26,12 @@ pub struct LintContext<'a> {
     /// Whether or not to apply code fixes during linting.
     fix: bool,
 
+    globals: Arc<GlobalEnvironment>,
+
     current_rule_name: &'static str,
+
+    /// Configured severity of the current rule
+    current_severity: Option<Severity>,
 }
 
 impl<'a> LintContext<'a> {
@@ -34,15 +43,50 @@ impl<'a> LintContext<'a> {
             diagnostics: RefCell::new(vec![]),
             disable_directives,
             fix,
+            globals: Arc::default(),
             current_rule_name: "",
+            current_severity: None,
         }
     }
 
+    #[must_use]
+    pub fn with_globals(mut self, globals: &Arc<GlobalEnvironment>) -> Self {
+        self.globals = Arc::clone(globals);
+        self
+    }
+
     #[must_use]
     pub fn semantic(&self) -> &Semantic<'a> {
         &self.semantic
     }
 
+    /// The control flow graph, [`crate::Linter::run`] requires the semantic built with it.
+    ///
+    /// # Panics
+    /// When the linter is run without the control flow graph, which is a logic error.
+    #[must_use]
+    pub fn cfg(&self) -> &ControlFlowGraph {
+        self.semantic().cfg().expect("checked by `Linter::run`")
+    }
+
+    /// The class table, [`crate::Linter::run`] requires the semantic built with it.
+    ///
+    /// # Panics
+    /// When the linter is run without the class table, which is a logic error.
+    #[must_use]
+    pub fn classes(&self) -> &ClassTable {
+        self.semantic().classes().expect("checked by `Linter::run`")
+    }
+
+    /// The `this` bindings, [`crate::Linter::run`] requires the semantic built with them.
+    ///
+    /// # Panics
+    /// When the linter is run without the `this` bindings, which is a logic error.
+    #[must_use]
+    pub fn this_bindings(&self) -> &ThisBindings {
+        self.semantic().this_bindings().expect("checked by `Linter::run`")
+    }
+
     #[must_use]
     pub fn source_text(&self) -> &'a str {
         self.semantic().source_text()
@@ -53,8 +97,9 @@ impl<'a> LintContext<'a> {
         self.semantic().source_type()
     }
 
-    pub fn with_rule_name(&mut self, name: &'static str) {
+    pub fn with_rule(&mut self, name: &'static str, severity: Option<Severity>) {
         self.current_rule_name = name;
+        self.current_severity = severity;
     }
 
     /* Diagnostics */
@@ -69,8 +114,17 @@ impl<'a> LintContext<'a> {
         }
     }
 
+    /// Apply the configured severity of the current rule
+    fn error<T: Into<Error>>(&self, diagnostic: T) -> Error {
+        let error = diagnostic.into();
+        match self.current_severity {
+            Some(severity) => WithSeverity::new(error, severity).into(),
+            None => error,
+        }
+    }
+
     pub fn diagnostic<T: Into<Error>>(&self, diagnostic: T) {
-        self.add_diagnostic(Message::new(diagnostic.into(), None));
+        self.add_diagnostic(Message::new(self.error(diagnostic), None));
     }
 
     pub fn diagnostic_with_fix<T, F>(&self, diagnostic: T, fix: F)
@@ -79,7 +133,7 @@ impl<'a> LintContext<'a> {
         F: FnOnce() -> Fix<'a>,
     {
         if self.fix {
-            self.add_diagnostic(Message::new(diagnostic.into(), Some(fix())));
+            self.add_diagnostic(Message::new(self.error(diagnostic), Some(fix())));
         } else {
             self.diagnostic(diagnostic);
         }
@@ -143,9 +197,16 @@ impl<'a> LintContext<'a> {
 
     /* Symbols */
 
-    #[allow(clippy::unused_self)]
-    pub fn is_reference_to_global_variable(&self, _ident: &IdentifierReference) -> bool {
-        true
+    /// The global variables available to the linted code
+    #[must_use]
+    pub fn globals(&self) -> &GlobalEnvironment {
+        &self.globals
+    }
+
+    /// Is `ident` an unresolved reference, i.e. not shadowed by any declaration
+    #[must_use]
+    pub fn is_reference_to_global_variable(&self, ident: &IdentifierReference) -> bool {
+        self.semantic().is_reference_to_global_variable(ident)
     }
 
     #[allow(clippy::unused_self)]
@@ -4,18 +4,13 @@
 //! A value of false indicates that the variable should be considered read-only.
 
 use phf::{phf_map, Map};
+use rustc_hash::FxHashMap;
 
-pub const BUILTINS: Map<&'static str, bool> = phf_map! {
-    "AggregateError" => false,
+/// ECMAScript 5 globals
+pub static ES5: Map<&'static str, bool> = phf_map! {
     "Array" => false,
-    "ArrayBuffer" => false,
-    "Atomics" => false,
-    "BigInt" => false,
-    "BigInt64Array" => false,
-    "BigUint64Array" => false,
     "Boolean" => false,
     "constructor" => false,
-    "DataView" => false,
     "Date" => false,
     "decodeURI" => false,
     "decodeURIComponent" => false,
@@ -25,51 +20,1191 @@ pub const BUILTINS: Map<&'static str, bool> = phf_map! {
     "escape" => false,
     "eval" => false,
     "EvalError" => false,
-    "FinalizationRegistry" => false,
-    "Float32Array" => false,
-    "Float64Array" => false,
     "Function" => false,
-    "globalThis" => false,
     "hasOwnProperty" => false,
     "Infinity" => false,
-    "Int16Array" => false,
-    "Int32Array" => false,
-    "Int8Array" => false,
+    "Intl" => false,
     "isFinite" => false,
     "isNaN" => false,
     "isPrototypeOf" => false,
     "JSON" => false,
-    "Map" => false,
     "Math" => false,
     "NaN" => false,
     "Number" => false,
     "Object" => false,
     "parseFloat" => false,
     "parseInt" => false,
-    "Promise" => false,
     "propertyIsEnumerable" => false,
-    "Proxy" => false,
     "RangeError" => false,
     "ReferenceError" => false,
-    "Reflect" => false,
     "RegExp" => false,
-    "Set" => false,
-    "SharedArrayBuffer" => false,
     "String" => false,
-    "Symbol" => false,
-    "Diagnostic" => false,
+    "SyntaxError" => false,
     "toLocaleString" => false,
     "toString" => false,
     "TypeError" => false,
+    "undefined" => false,
+    "unescape" => false,
+    "URIError" => false,
+    "valueOf" => false
+};
+
+/// Globals added by ECMAScript 2015, `env: { es6: true }`
+pub static ES2015: Map<&'static str, bool> = phf_map! {
+    "ArrayBuffer" => false,
+    "DataView" => false,
+    "Float32Array" => false,
+    "Float64Array" => false,
+    "Int16Array" => false,
+    "Int32Array" => false,
+    "Int8Array" => false,
+    "Map" => false,
+    "Promise" => false,
+    "Proxy" => false,
+    "Reflect" => false,
+    "Set" => false,
+    "Symbol" => false,
     "Uint16Array" => false,
     "Uint32Array" => false,
     "Uint8Array" => false,
     "Uint8ClampedArray" => false,
-    "undefined" => false,
-    "unescape" => false,
-    "URIError" => false,
-    "valueOf" => false,
     "WeakMap" => false,
-    "WeakRef" => false,
     "WeakSet" => false
 };
+
+/// Globals added by ECMAScript 2017
+pub static ES2017: Map<&'static str, bool> = phf_map! {
+    "Atomics" => false,
+    "SharedArrayBuffer" => false
+};
+
+/// Globals added by ECMAScript 2020
+pub static ES2020: Map<&'static str, bool> = phf_map! {
+    "BigInt" => false,
+    "BigInt64Array" => false,
+    "BigUint64Array" => false,
+    "globalThis" => false
+};
+
+/// Globals added by ECMAScript 2021, no globals are added by the later versions
+pub static ES2021: Map<&'static str, bool> = phf_map! {
+    "AggregateError" => false,
+    "FinalizationRegistry" => false,
+    "WeakRef" => false
+};
+
+/// The ECMAScript globals by the version adding them
+static ES_GLOBALS: [(u16, &Map<&'static str, bool>); 5] =
+    [(5, &ES5), (2015, &ES2015), (2017, &ES2017), (2020, &ES2020), (2021, &ES2021)];
+
+/// The latest ECMAScript version, `env: { builtin: true }`
+const LATEST_ECMA_VERSION: u16 = 2024;
+
+/// Is `name` a global of the latest ECMAScript version
+#[must_use]
+pub fn is_builtin(name: &str) -> bool {
+    ES_GLOBALS.iter().any(|(_, globals)| globals.contains_key(name))
+}
+
+/// Browser globals, `env: { browser: true }`
+pub static BROWSER: Map<&'static str, bool> = phf_map! {
+    "AbortController" => false,
+    "AbortSignal" => false,
+    "AbstractRange" => false,
+    "addEventListener" => false,
+    "alert" => false,
+    "AnalyserNode" => false,
+    "Animation" => false,
+    "AnimationEffect" => false,
+    "AnimationEvent" => false,
+    "AnimationPlaybackEvent" => false,
+    "AnimationTimeline" => false,
+    "atob" => false,
+    "Attr" => false,
+    "Audio" => false,
+    "AudioBuffer" => false,
+    "AudioBufferSourceNode" => false,
+    "AudioContext" => false,
+    "AudioDestinationNode" => false,
+    "AudioListener" => false,
+    "AudioNode" => false,
+    "AudioParam" => false,
+    "AudioParamMap" => false,
+    "AudioProcessingEvent" => false,
+    "AudioScheduledSourceNode" => false,
+    "AudioWorklet" => false,
+    "AudioWorkletNode" => false,
+    "BarProp" => false,
+    "BaseAudioContext" => false,
+    "BeforeUnloadEvent" => false,
+    "BiquadFilterNode" => false,
+    "Blob" => false,
+    "BlobEvent" => false,
+    "blur" => false,
+    "BroadcastChannel" => false,
+    "btoa" => false,
+    "ByteLengthQueuingStrategy" => false,
+    "Cache" => false,
+    "caches" => false,
+    "CacheStorage" => false,
+    "cancelAnimationFrame" => false,
+    "cancelIdleCallback" => false,
+    "CanvasGradient" => false,
+    "CanvasPattern" => false,
+    "CanvasRenderingContext2D" => false,
+    "CDATASection" => false,
+    "ChannelMergerNode" => false,
+    "ChannelSplitterNode" => false,
+    "CharacterData" => false,
+    "clearInterval" => false,
+    "clearTimeout" => false,
+    "clientInformation" => false,
+    "Clipboard" => false,
+    "ClipboardEvent" => false,
+    "ClipboardItem" => false,
+    "close" => false,
+    "closed" => false,
+    "CloseEvent" => false,
+    "Comment" => false,
+    "CompositionEvent" => false,
+    "CompressionStream" => false,
+    "confirm" => false,
+    "console" => false,
+    "ConstantSourceNode" => false,
+    "ConvolverNode" => false,
+    "CountQueuingStrategy" => false,
+    "createImageBitmap" => false,
+    "Crypto" => false,
+    "crypto" => false,
+    "CryptoKey" => false,
+    "CSS" => false,
+    "CSSAnimation" => false,
+    "CSSConditionRule" => false,
+    "CSSFontFaceRule" => false,
+    "CSSGroupingRule" => false,
+    "CSSImportRule" => false,
+    "CSSKeyframeRule" => false,
+    "CSSKeyframesRule" => false,
+    "CSSMediaRule" => false,
+    "CSSNamespaceRule" => false,
+    "CSSPageRule" => false,
+    "CSSRule" => false,
+    "CSSRuleList" => false,
+    "CSSStyleDeclaration" => false,
+    "CSSStyleRule" => false,
+    "CSSStyleSheet" => false,
+    "CSSSupportsRule" => false,
+    "CSSTransition" => false,
+    "CustomElementRegistry" => false,
+    "customElements" => false,
+    "CustomEvent" => false,
+    "DataTransfer" => false,
+    "DataTransferItem" => false,
+    "DataTransferItemList" => false,
+    "DecompressionStream" => false,
+    "DelayNode" => false,
+    "DeviceMotionEvent" => false,
+    "DeviceOrientationEvent" => false,
+    "devicePixelRatio" => false,
+    "dispatchEvent" => false,
+    "Document" => false,
+    "document" => false,
+    "DocumentFragment" => false,
+    "DocumentType" => false,
+    "DOMException" => false,
+    "DOMImplementation" => false,
+    "DOMMatrix" => false,
+    "DOMMatrixReadOnly" => false,
+    "DOMParser" => false,
+    "DOMPoint" => false,
+    "DOMPointReadOnly" => false,
+    "DOMQuad" => false,
+    "DOMRect" => false,
+    "DOMRectReadOnly" => false,
+    "DOMStringList" => false,
+    "DOMTokenList" => false,
+    "DragEvent" => false,
+    "DynamicsCompressorNode" => false,
+    "Element" => false,
+    "ElementInternals" => false,
+    "ErrorEvent" => false,
+    "Event" => false,
+    "event" => true,
+    "EventSource" => false,
+    "EventTarget" => false,
+    "external" => false,
+    "fetch" => false,
+    "File" => false,
+    "FileList" => false,
+    "FileReader" => false,
+    "FileSystemDirectoryHandle" => false,
+    "FileSystemFileHandle" => false,
+    "FileSystemHandle" => false,
+    "FocusEvent" => false,
+    "focus" => false,
+    "FontFace" => false,
+    "FontFaceSet" => false,
+    "FormData" => false,
+    "FormDataEvent" => false,
+    "frameElement" => false,
+    "frames" => false,
+    "GainNode" => false,
+    "Gamepad" => false,
+    "GamepadButton" => false,
+    "GamepadEvent" => false,
+    "Geolocation" => false,
+    "GeolocationPosition" => false,
+    "GeolocationPositionError" => false,
+    "getComputedStyle" => false,
+    "getSelection" => false,
+    "HashChangeEvent" => false,
+    "Headers" => false,
+    "History" => false,
+    "history" => false,
+    "HTMLAllCollection" => false,
+    "HTMLAnchorElement" => false,
+    "HTMLAreaElement" => false,
+    "HTMLAudioElement" => false,
+    "HTMLBaseElement" => false,
+    "HTMLBodyElement" => false,
+    "HTMLBRElement" => false,
+    "HTMLButtonElement" => false,
+    "HTMLCanvasElement" => false,
+    "HTMLCollection" => false,
+    "HTMLDataElement" => false,
+    "HTMLDataListElement" => false,
+    "HTMLDetailsElement" => false,
+    "HTMLDialogElement" => false,
+    "HTMLDivElement" => false,
+    "HTMLDListElement" => false,
+    "HTMLDocument" => false,
+    "HTMLElement" => false,
+    "HTMLEmbedElement" => false,
+    "HTMLFieldSetElement" => false,
+    "HTMLFormControlsCollection" => false,
+    "HTMLFormElement" => false,
+    "HTMLFrameSetElement" => false,
+    "HTMLHeadElement" => false,
+    "HTMLHeadingElement" => false,
+    "HTMLHRElement" => false,
+    "HTMLHtmlElement" => false,
+    "HTMLIFrameElement" => false,
+    "HTMLImageElement" => false,
+    "HTMLInputElement" => false,
+    "HTMLLabelElement" => false,
+    "HTMLLegendElement" => false,
+    "HTMLLIElement" => false,
+    "HTMLLinkElement" => false,
+    "HTMLMapElement" => false,
+    "HTMLMediaElement" => false,
+    "HTMLMenuElement" => false,
+    "HTMLMetaElement" => false,
+    "HTMLMeterElement" => false,
+    "HTMLModElement" => false,
+    "HTMLObjectElement" => false,
+    "HTMLOListElement" => false,
+    "HTMLOptGroupElement" => false,
+    "HTMLOptionElement" => false,
+    "HTMLOptionsCollection" => false,
+    "HTMLOutputElement" => false,
+    "HTMLParagraphElement" => false,
+    "HTMLPictureElement" => false,
+    "HTMLPreElement" => false,
+    "HTMLProgressElement" => false,
+    "HTMLQuoteElement" => false,
+    "HTMLScriptElement" => false,
+    "HTMLSelectElement" => false,
+    "HTMLSlotElement" => false,
+    "HTMLSourceElement" => false,
+    "HTMLSpanElement" => false,
+    "HTMLStyleElement" => false,
+    "HTMLTableCaptionElement" => false,
+    "HTMLTableCellElement" => false,
+    "HTMLTableColElement" => false,
+    "HTMLTableElement" => false,
+    "HTMLTableRowElement" => false,
+    "HTMLTableSectionElement" => false,
+    "HTMLTemplateElement" => false,
+    "HTMLTextAreaElement" => false,
+    "HTMLTimeElement" => false,
+    "HTMLTitleElement" => false,
+    "HTMLTrackElement" => false,
+    "HTMLUListElement" => false,
+    "HTMLUnknownElement" => false,
+    "HTMLVideoElement" => false,
+    "IDBCursor" => false,
+    "IDBCursorWithValue" => false,
+    "IDBDatabase" => false,
+    "IDBFactory" => false,
+    "IDBIndex" => false,
+    "IDBKeyRange" => false,
+    "IDBObjectStore" => false,
+    "IDBOpenDBRequest" => false,
+    "IDBRequest" => false,
+    "IDBTransaction" => false,
+    "IDBVersionChangeEvent" => false,
+    "IdleDeadline" => false,
+    "IIRFilterNode" => false,
+    "Image" => false,
+    "ImageBitmap" => false,
+    "ImageBitmapRenderingContext" => false,
+    "ImageData" => false,
+    "indexedDB" => false,
+    "innerHeight" => false,
+    "innerWidth" => false,
+    "InputEvent" => false,
+    "IntersectionObserver" => false,
+    "IntersectionObserverEntry" => false,
+    "isSecureContext" => false,
+    "KeyboardEvent" => false,
+    "KeyframeEffect" => false,
+    "length" => false,
+    "localStorage" => false,
+    "Location" => false,
+    "locationbar" => false,
+    "location" => false,
+    "matchMedia" => false,
+    "MediaDeviceInfo" => false,
+    "MediaDevices" => false,
+    "MediaElementAudioSourceNode" => false,
+    "MediaEncryptedEvent" => false,
+    "MediaError" => false,
+    "MediaKeyMessageEvent" => false,
+    "MediaKeys" => false,
+    "MediaKeySession" => false,
+    "MediaKeyStatusMap" => false,
+    "MediaKeySystemAccess" => false,
+    "MediaList" => false,
+    "MediaQueryList" => false,
+    "MediaQueryListEvent" => false,
+    "MediaRecorder" => false,
+    "MediaSource" => false,
+    "MediaStream" => false,
+    "MediaStreamAudioDestinationNode" => false,
+    "MediaStreamAudioSourceNode" => false,
+    "MediaStreamTrack" => false,
+    "MediaStreamTrackEvent" => false,
+    "menubar" => false,
+    "MessageChannel" => false,
+    "MessageEvent" => false,
+    "MessagePort" => false,
+    "MimeType" => false,
+    "MimeTypeArray" => false,
+    "MouseEvent" => false,
+    "moveBy" => false,
+    "moveTo" => false,
+    "MutationObserver" => false,
+    "MutationRecord" => false,
+    "NamedNodeMap" => false,
+    "name" => true,
+    "Navigator" => false,
+    "navigator" => false,
+    "Node" => false,
+    "NodeFilter" => false,
+    "NodeIterator" => false,
+    "NodeList" => false,
+    "Notification" => false,
+    "OfflineAudioCompletionEvent" => false,
+    "OfflineAudioContext" => false,
+    "OffscreenCanvas" => false,
+    "OffscreenCanvasRenderingContext2D" => false,
+    "onabort" => true,
+    "onafterprint" => true,
+    "onanimationend" => true,
+    "onanimationiteration" => true,
+    "onanimationstart" => true,
+    "onbeforeprint" => true,
+    "onbeforeunload" => true,
+    "onblur" => true,
+    "oncanplay" => true,
+    "oncanplaythrough" => true,
+    "onchange" => true,
+    "onclick" => true,
+    "onclose" => true,
+    "oncontextmenu" => true,
+    "oncuechange" => true,
+    "ondblclick" => true,
+    "ondragend" => true,
+    "ondragenter" => true,
+    "ondragleave" => true,
+    "ondrag" => true,
+    "ondragover" => true,
+    "ondragstart" => true,
+    "ondrop" => true,
+    "ondurationchange" => true,
+    "onemptied" => true,
+    "onended" => true,
+    "onerror" => true,
+    "onfocus" => true,
+    "onhashchange" => true,
+    "oninput" => true,
+    "oninvalid" => true,
+    "onkeydown" => true,
+    "onkeypress" => true,
+    "onkeyup" => true,
+    "onlanguagechange" => true,
+    "onloadeddata" => true,
+    "onloadedmetadata" => true,
+    "onload" => true,
+    "onloadstart" => true,
+    "onmessageerror" => true,
+    "onmessage" => true,
+    "onmousedown" => true,
+    "onmouseenter" => true,
+    "onmouseleave" => true,
+    "onmousemove" => true,
+    "onmouseout" => true,
+    "onmouseover" => true,
+    "onmouseup" => true,
+    "onmousewheel" => true,
+    "onoffline" => true,
+    "ononline" => true,
+    "onpagehide" => true,
+    "onpageshow" => true,
+    "onpause" => true,
+    "onplaying" => true,
+    "onplay" => true,
+    "onpointercancel" => true,
+    "onpointerdown" => true,
+    "onpointerenter" => true,
+    "onpointerleave" => true,
+    "onpointermove" => true,
+    "onpointerout" => true,
+    "onpointerover" => true,
+    "onpointerup" => true,
+    "onpopstate" => true,
+    "onprogress" => true,
+    "onratechange" => true,
+    "onrejectionhandled" => true,
+    "onreset" => true,
+    "onresize" => true,
+    "onscroll" => true,
+    "onsearch" => true,
+    "onseeked" => true,
+    "onseeking" => true,
+    "onselect" => true,
+    "onstalled" => true,
+    "onstorage" => true,
+    "onsubmit" => true,
+    "onsuspend" => true,
+    "ontimeupdate" => true,
+    "ontoggle" => true,
+    "ontransitionend" => true,
+    "onunhandledrejection" => true,
+    "onunload" => true,
+    "onvolumechange" => true,
+    "onwaiting" => true,
+    "onwheel" => true,
+    "opener" => false,
+    "open" => false,
+    "Option" => false,
+    "origin" => false,
+    "OscillatorNode" => false,
+    "outerHeight" => false,
+    "outerWidth" => false,
+    "PageTransitionEvent" => false,
+    "pageXOffset" => false,
+    "pageYOffset" => false,
+    "PannerNode" => false,
+    "parent" => false,
+    "Path2D" => false,
+    "PerformanceEntry" => false,
+    "PerformanceMark" => false,
+    "PerformanceMeasure" => false,
+    "PerformanceObserver" => false,
+    "PerformanceObserverEntryList" => false,
+    "performance" => false,
+    "PerformanceResourceTiming" => false,
+    "PeriodicWave" => false,
+    "Permissions" => false,
+    "PermissionStatus" => false,
+    "personalbar" => false,
+    "Plugin" => false,
+    "PluginArray" => false,
+    "PointerEvent" => false,
+    "PopStateEvent" => false,
+    "postMessage" => false,
+    "print" => false,
+    "ProcessingInstruction" => false,
+    "ProgressEvent" => false,
+    "PromiseRejectionEvent" => false,
+    "prompt" => false,
+    "queryLocalFonts" => false,
+    "queueMicrotask" => false,
+    "RadioNodeList" => false,
+    "Range" => false,
+    "ReadableByteStreamController" => false,
+    "ReadableStream" => false,
+    "ReadableStreamBYOBReader" => false,
+    "ReadableStreamBYOBRequest" => false,
+    "ReadableStreamDefaultController" => false,
+    "ReadableStreamDefaultReader" => false,
+    "releaseEvents" => false,
+    "removeEventListener" => false,
+    "reportError" => false,
+    "Request" => false,
+    "requestAnimationFrame" => false,
+    "requestIdleCallback" => false,
+    "resizeBy" => false,
+    "ResizeObserver" => false,
+    "ResizeObserverEntry" => false,
+    "resizeTo" => false,
+    "Response" => false,
+    "RTCCertificate" => false,
+    "RTCDataChannel" => false,
+    "RTCDataChannelEvent" => false,
+    "RTCDtlsTransport" => false,
+    "RTCDTMFSender" => false,
+    "RTCDTMFToneChangeEvent" => false,
+    "RTCIceCandidate" => false,
+    "RTCIceTransport" => false,
+    "RTCPeerConnection" => false,
+    "RTCPeerConnectionIceEvent" => false,
+    "RTCRtpReceiver" => false,
+    "RTCRtpSender" => false,
+    "RTCRtpTransceiver" => false,
+    "RTCSctpTransport" => false,
+    "RTCSessionDescription" => false,
+    "RTCStatsReport" => false,
+    "RTCTrackEvent" => false,
+    "Screen" => false,
+    "screenLeft" => false,
+    "ScreenOrientation" => false,
+    "screen" => false,
+    "screenTop" => false,
+    "screenX" => false,
+    "screenY" => false,
+    "ScriptProcessorNode" => false,
+    "scrollbars" => false,
+    "scrollBy" => false,
+    "scroll" => false,
+    "ScrollTimeline" => false,
+    "scrollTo" => false,
+    "scrollX" => false,
+    "scrollY" => false,
+    "SecurityPolicyViolationEvent" => false,
+    "Selection" => false,
+    "self" => true,
+    "ServiceWorker" => false,
+    "ServiceWorkerContainer" => false,
+    "ServiceWorkerRegistration" => false,
+    "sessionStorage" => false,
+    "setInterval" => false,
+    "setTimeout" => false,
+    "ShadowRoot" => false,
+    "SharedWorker" => false,
+    "SourceBuffer" => false,
+    "SourceBufferList" => false,
+    "SpeechSynthesisEvent" => false,
+    "speechSynthesis" => false,
+    "SpeechSynthesisUtterance" => false,
+    "StaticRange" => false,
+    "statusbar" => false,
+    "status" => false,
+    "StereoPannerNode" => false,
+    "stop" => false,
+    "Storage" => false,
+    "StorageEvent" => false,
+    "StorageManager" => false,
+    "structuredClone" => false,
+    "styleMedia" => false,
+    "StyleSheet" => false,
+    "StyleSheetList" => false,
+    "SubmitEvent" => false,
+    "SubtleCrypto" => false,
+    "SVGAElement" => false,
+    "SVGAngle" => false,
+    "SVGAnimatedAngle" => false,
+    "SVGAnimatedBoolean" => false,
+    "SVGAnimatedEnumeration" => false,
+    "SVGAnimatedInteger" => false,
+    "SVGAnimatedLength" => false,
+    "SVGAnimatedLengthList" => false,
+    "SVGAnimatedNumber" => false,
+    "SVGAnimatedNumberList" => false,
+    "SVGAnimatedPreserveAspectRatio" => false,
+    "SVGAnimatedRect" => false,
+    "SVGAnimatedString" => false,
+    "SVGAnimatedTransformList" => false,
+    "SVGAnimateElement" => false,
+    "SVGAnimateMotionElement" => false,
+    "SVGAnimateTransformElement" => false,
+    "SVGAnimationElement" => false,
+    "SVGCircleElement" => false,
+    "SVGClipPathElement" => false,
+    "SVGComponentTransferFunctionElement" => false,
+    "SVGDefsElement" => false,
+    "SVGDescElement" => false,
+    "SVGElement" => false,
+    "SVGEllipseElement" => false,
+    "SVGFEBlendElement" => false,
+    "SVGFEColorMatrixElement" => false,
+    "SVGFEComponentTransferElement" => false,
+    "SVGFECompositeElement" => false,
+    "SVGFEConvolveMatrixElement" => false,
+    "SVGFEDiffuseLightingElement" => false,
+    "SVGFEDisplacementMapElement" => false,
+    "SVGFEDistantLightElement" => false,
+    "SVGFEDropShadowElement" => false,
+    "SVGFEFloodElement" => false,
+    "SVGFEFuncAElement" => false,
+    "SVGFEFuncBElement" => false,
+    "SVGFEFuncGElement" => false,
+    "SVGFEFuncRElement" => false,
+    "SVGFEGaussianBlurElement" => false,
+    "SVGFEImageElement" => false,
+    "SVGFEMergeElement" => false,
+    "SVGFEMergeNodeElement" => false,
+    "SVGFEMorphologyElement" => false,
+    "SVGFEOffsetElement" => false,
+    "SVGFEPointLightElement" => false,
+    "SVGFESpecularLightingElement" => false,
+    "SVGFESpotLightElement" => false,
+    "SVGFETileElement" => false,
+    "SVGFETurbulenceElement" => false,
+    "SVGFilterElement" => false,
+    "SVGForeignObjectElement" => false,
+    "SVGGElement" => false,
+    "SVGGeometryElement" => false,
+    "SVGGradientElement" => false,
+    "SVGGraphicsElement" => false,
+    "SVGImageElement" => false,
+    "SVGLength" => false,
+    "SVGLengthList" => false,
+    "SVGLinearGradientElement" => false,
+    "SVGLineElement" => false,
+    "SVGMarkerElement" => false,
+    "SVGMaskElement" => false,
+    "SVGMatrix" => false,
+    "SVGMetadataElement" => false,
+    "SVGMPathElement" => false,
+    "SVGNumber" => false,
+    "SVGNumberList" => false,
+    "SVGPathElement" => false,
+    "SVGPatternElement" => false,
+    "SVGPoint" => false,
+    "SVGPointList" => false,
+    "SVGPolygonElement" => false,
+    "SVGPolylineElement" => false,
+    "SVGPreserveAspectRatio" => false,
+    "SVGRadialGradientElement" => false,
+    "SVGRect" => false,
+    "SVGRectElement" => false,
+    "SVGScriptElement" => false,
+    "SVGSetElement" => false,
+    "SVGStopElement" => false,
+    "SVGStringList" => false,
+    "SVGStyleElement" => false,
+    "SVGSVGElement" => false,
+    "SVGSwitchElement" => false,
+    "SVGSymbolElement" => false,
+    "SVGTextContentElement" => false,
+    "SVGTextElement" => false,
+    "SVGTextPathElement" => false,
+    "SVGTextPositioningElement" => false,
+    "SVGTitleElement" => false,
+    "SVGTransform" => false,
+    "SVGTransformList" => false,
+    "SVGTSpanElement" => false,
+    "SVGUnitTypes" => false,
+    "SVGUseElement" => false,
+    "SVGViewElement" => false,
+    "Text" => false,
+    "TextDecoder" => false,
+    "TextDecoderStream" => false,
+    "TextEncoder" => false,
+    "TextEncoderStream" => false,
+    "TextMetrics" => false,
+    "TextTrack" => false,
+    "TextTrackCue" => false,
+    "TextTrackCueList" => false,
+    "TextTrackList" => false,
+    "TimeRanges" => false,
+    "toolbar" => false,
+    "top" => false,
+    "Touch" => false,
+    "TouchEvent" => false,
+    "TouchList" => false,
+    "TrackEvent" => false,
+    "TransformStream" => false,
+    "TransformStreamDefaultController" => false,
+    "TransitionEvent" => false,
+    "TreeWalker" => false,
+    "UIEvent" => false,
+    "URL" => false,
+    "URLSearchParams" => false,
+    "ValidityState" => false,
+    "VisualViewport" => false,
+    "visualViewport" => false,
+    "VTTCue" => false,
+    "WaveShaperNode" => false,
+    "WebAssembly" => false,
+    "WebGL2RenderingContext" => false,
+    "WebGLActiveInfo" => false,
+    "WebGLBuffer" => false,
+    "WebGLContextEvent" => false,
+    "WebGLFramebuffer" => false,
+    "WebGLProgram" => false,
+    "WebGLQuery" => false,
+    "WebGLRenderbuffer" => false,
+    "WebGLRenderingContext" => false,
+    "WebGLSampler" => false,
+    "WebGLShader" => false,
+    "WebGLShaderPrecisionFormat" => false,
+    "WebGLSync" => false,
+    "WebGLTexture" => false,
+    "WebGLTransformFeedback" => false,
+    "WebGLUniformLocation" => false,
+    "WebGLVertexArrayObject" => false,
+    "WebSocket" => false,
+    "WheelEvent" => false,
+    "Window" => false,
+    "window" => false,
+    "Worker" => false,
+    "WritableStream" => false,
+    "WritableStreamDefaultController" => false,
+    "WritableStreamDefaultWriter" => false,
+    "XMLDocument" => false,
+    "XMLHttpRequest" => false,
+    "XMLHttpRequestEventTarget" => false,
+    "XMLHttpRequestUpload" => false,
+    "XMLSerializer" => false,
+    "XPathEvaluator" => false,
+    "XPathExpression" => false,
+    "XPathResult" => false,
+    "XSLTProcessor" => false
+};
+
+/// Node.js globals, `env: { node: true }`
+pub static NODE: Map<&'static str, bool> = phf_map! {
+    "__dirname" => false,
+    "__filename" => false,
+    "AbortController" => false,
+    "AbortSignal" => false,
+    "atob" => false,
+    "Blob" => false,
+    "BroadcastChannel" => false,
+    "btoa" => false,
+    "Buffer" => false,
+    "ByteLengthQueuingStrategy" => false,
+    "clearImmediate" => false,
+    "clearInterval" => false,
+    "clearTimeout" => false,
+    "CompressionStream" => false,
+    "console" => false,
+    "CountQueuingStrategy" => false,
+    "Crypto" => false,
+    "crypto" => false,
+    "CryptoKey" => false,
+    "CustomEvent" => false,
+    "DecompressionStream" => false,
+    "DOMException" => false,
+    "Event" => false,
+    "EventTarget" => false,
+    "exports" => true,
+    "fetch" => false,
+    "File" => false,
+    "FormData" => false,
+    "global" => false,
+    "Headers" => false,
+    "Intl" => false,
+    "MessageChannel" => false,
+    "MessageEvent" => false,
+    "MessagePort" => false,
+    "module" => false,
+    "PerformanceEntry" => false,
+    "PerformanceMark" => false,
+    "PerformanceMeasure" => false,
+    "PerformanceObserver" => false,
+    "PerformanceObserverEntryList" => false,
+    "performance" => false,
+    "PerformanceResourceTiming" => false,
+    "process" => false,
+    "queueMicrotask" => false,
+    "ReadableByteStreamController" => false,
+    "ReadableStream" => false,
+    "ReadableStreamBYOBReader" => false,
+    "ReadableStreamBYOBRequest" => false,
+    "ReadableStreamDefaultController" => false,
+    "ReadableStreamDefaultReader" => false,
+    "Request" => false,
+    "require" => false,
+    "Response" => false,
+    "setImmediate" => false,
+    "setInterval" => false,
+    "setTimeout" => false,
+    "structuredClone" => false,
+    "SubtleCrypto" => false,
+    "TextDecoder" => false,
+    "TextDecoderStream" => false,
+    "TextEncoder" => false,
+    "TextEncoderStream" => false,
+    "TransformStream" => false,
+    "TransformStreamDefaultController" => false,
+    "URL" => false,
+    "URLSearchParams" => false,
+    "WebAssembly" => false,
+    "WritableStream" => false,
+    "WritableStreamDefaultController" => false,
+    "WritableStreamDefaultWriter" => false
+};
+
+/// Web Worker globals, `env: { worker: true }`
+pub static WORKER: Map<&'static str, bool> = phf_map! {
+    "AbortController" => false,
+    "AbortSignal" => false,
+    "atob" => false,
+    "Blob" => false,
+    "BroadcastChannel" => false,
+    "btoa" => false,
+    "ByteLengthQueuingStrategy" => false,
+    "Cache" => false,
+    "caches" => false,
+    "CacheStorage" => false,
+    "clearInterval" => false,
+    "clearTimeout" => false,
+    "close" => false,
+    "CompressionStream" => false,
+    "console" => false,
+    "CountQueuingStrategy" => false,
+    "createImageBitmap" => false,
+    "Crypto" => false,
+    "crypto" => false,
+    "CryptoKey" => false,
+    "CustomEvent" => false,
+    "DecompressionStream" => false,
+    "DedicatedWorkerGlobalScope" => false,
+    "DOMException" => false,
+    "DOMMatrix" => false,
+    "DOMMatrixReadOnly" => false,
+    "DOMPoint" => false,
+    "DOMPointReadOnly" => false,
+    "DOMQuad" => false,
+    "DOMRect" => false,
+    "DOMRectReadOnly" => false,
+    "DOMStringList" => false,
+    "ErrorEvent" => false,
+    "Event" => false,
+    "EventSource" => false,
+    "EventTarget" => false,
+    "fetch" => false,
+    "File" => false,
+    "FileList" => false,
+    "FileReader" => false,
+    "FileReaderSync" => false,
+    "FontFace" => false,
+    "FontFaceSet" => false,
+    "FormData" => false,
+    "Headers" => false,
+    "IDBCursor" => false,
+    "IDBCursorWithValue" => false,
+    "IDBDatabase" => false,
+    "IDBFactory" => false,
+    "IDBIndex" => false,
+    "IDBKeyRange" => false,
+    "IDBObjectStore" => false,
+    "IDBOpenDBRequest" => false,
+    "IDBRequest" => false,
+    "IDBTransaction" => false,
+    "IDBVersionChangeEvent" => false,
+    "ImageBitmap" => false,
+    "ImageBitmapRenderingContext" => false,
+    "ImageData" => false,
+    "importScripts" => false,
+    "indexedDB" => false,
+    "isSecureContext" => false,
+    "location" => false,
+    "MessageChannel" => false,
+    "MessageEvent" => false,
+    "MessagePort" => false,
+    "navigator" => false,
+    "Notification" => false,
+    "OffscreenCanvas" => false,
+    "OffscreenCanvasRenderingContext2D" => false,
+    "onerror" => true,
+    "onlanguagechange" => true,
+    "onmessageerror" => true,
+    "onmessage" => true,
+    "onoffline" => true,
+    "ononline" => true,
+    "onrejectionhandled" => true,
+    "onunhandledrejection" => true,
+    "origin" => false,
+    "Path2D" => false,
+    "PerformanceEntry" => false,
+    "PerformanceMark" => false,
+    "PerformanceMeasure" => false,
+    "PerformanceObserver" => false,
+    "PerformanceObserverEntryList" => false,
+    "performance" => false,
+    "PerformanceResourceTiming" => false,
+    "Permissions" => false,
+    "PermissionStatus" => false,
+    "postMessage" => false,
+    "ProgressEvent" => false,
+    "PromiseRejectionEvent" => false,
+    "queueMicrotask" => false,
+    "ReadableByteStreamController" => false,
+    "ReadableStream" => false,
+    "ReadableStreamBYOBReader" => false,
+    "ReadableStreamBYOBRequest" => false,
+    "ReadableStreamDefaultController" => false,
+    "ReadableStreamDefaultReader" => false,
+    "reportError" => false,
+    "Request" => false,
+    "Response" => false,
+    "self" => true,
+    "setInterval" => false,
+    "setTimeout" => false,
+    "structuredClone" => false,
+    "SubtleCrypto" => false,
+    "TextDecoder" => false,
+    "TextDecoderStream" => false,
+    "TextEncoder" => false,
+    "TextEncoderStream" => false,
+    "TransformStream" => false,
+    "TransformStreamDefaultController" => false,
+    "URL" => false,
+    "URLSearchParams" => false,
+    "WebAssembly" => false,
+    "WebGL2RenderingContext" => false,
+    "WebGLActiveInfo" => false,
+    "WebGLBuffer" => false,
+    "WebGLContextEvent" => false,
+    "WebGLFramebuffer" => false,
+    "WebGLProgram" => false,
+    "WebGLQuery" => false,
+    "WebGLRenderbuffer" => false,
+    "WebGLRenderingContext" => false,
+    "WebGLSampler" => false,
+    "WebGLShader" => false,
+    "WebGLShaderPrecisionFormat" => false,
+    "WebGLSync" => false,
+    "WebGLTexture" => false,
+    "WebGLTransformFeedback" => false,
+    "WebGLUniformLocation" => false,
+    "WebGLVertexArrayObject" => false,
+    "WebSocket" => false,
+    "Worker" => false,
+    "WorkerGlobalScope" => false,
+    "WorkerLocation" => false,
+    "WorkerNavigator" => false,
+    "WritableStream" => false,
+    "WritableStreamDefaultController" => false,
+    "WritableStreamDefaultWriter" => false,
+    "XMLHttpRequest" => false,
+    "XMLHttpRequestEventTarget" => false,
+    "XMLHttpRequestUpload" => false
+};
+
+/// Jest globals, `env: { jest: true }`
+pub static JEST: Map<&'static str, bool> = phf_map! {
+    "afterAll" => false,
+    "afterEach" => false,
+    "beforeAll" => false,
+    "beforeEach" => false,
+    "describe" => false,
+    "expect" => false,
+    "fdescribe" => false,
+    "fit" => false,
+    "it" => false,
+    "jest" => false,
+    "pit" => false,
+    "require" => false,
+    "test" => false,
+    "xdescribe" => false,
+    "xit" => false,
+    "xtest" => false
+};
+
+/// The value of a configured global, see
+/// [Specifying Globals](https://eslint.org/docs/latest/use/configure/language-options#specifying-globals)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GlobalValue {
+    Readonly,
+    Writable,
+    /// Disables a global, including the ones from environments
+    Off,
+}
+
+impl GlobalValue {
+    /// Parse `"readonly"`, `"writable"`, `"off"`, and the legacy `"readable"`, `"writeable"`,
+    /// `true` and `false`.
+    #[must_use]
+    pub fn from_json(value: &serde_json::Value) -> Option<Self> {
+        match value {
+            serde_json::Value::Bool(writable) => Some(Self::from(*writable)),
+            serde_json::Value::String(value) => match value.as_str() {
+                "readonly" | "readable" | "false" => Some(Self::Readonly),
+                "writable" | "writeable" | "true" => Some(Self::Writable),
+                "off" => Some(Self::Off),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Readonly => "readonly",
+            Self::Writable => "writable",
+            Self::Off => "off",
+        }
+    }
+}
+
+impl From<bool> for GlobalValue {
+    fn from(writable: bool) -> Self {
+        if writable { Self::Writable } else { Self::Readonly }
+    }
+}
+
+/// The global variables available to the linted code,
+/// made of the ECMAScript globals, the enabled environments and custom globals.
+///
+/// The globals of the latest ECMAScript version are available unless an ECMAScript environment
+/// such as `es2017` is enabled, which limits them to the ones of the highest enabled version.
+#[derive(Debug, Clone, Default)]
+pub struct GlobalEnvironment {
+    /// The highest enabled ECMAScript environment
+    ecma_version: Option<u16>,
+    envs: Vec<&'static Map<&'static str, bool>>,
+    globals: FxHashMap<String, GlobalValue>,
+}
+
+impl GlobalEnvironment {
+    /// Names of the supported environments
+    pub const ENVS: [&'static str; 16] = [
+        "builtin", "es6", "es2015", "es2016", "es2017", "es2018", "es2019", "es2020", "es2021",
+        "es2022", "es2023", "es2024", "browser", "node", "worker", "jest",
+    ];
+
+    fn env(name: &str) -> Option<&'static Map<&'static str, bool>> {
+        match name {
+            "browser" => Some(&BROWSER),
+            "node" => Some(&NODE),
+            "worker" => Some(&WORKER),
+            "jest" => Some(&JEST),
+            _ => None,
+        }
+    }
+
+    /// The ECMAScript version of `builtin`, `es6` and `es2015` to `es2024`
+    fn ecma_version(name: &str) -> Option<u16> {
+        match name {
+            "builtin" => Some(LATEST_ECMA_VERSION),
+            "es6" => Some(2015),
+            _ => name
+                .strip_prefix("es")
+                .and_then(|year| year.parse().ok())
+                .filter(|year| (2015..=LATEST_ECMA_VERSION).contains(year)),
+        }
+    }
+
+    /// Enable an environment such as `browser`, `node` or `es2020`, unknown names are ignored.
+    #[must_use]
+    pub fn with_env(mut self, name: &str) -> Self {
+        if let Some(version) = Self::ecma_version(name) {
+            self.ecma_version = Some(self.ecma_version.map_or(version, |v| v.max(version)));
+        } else if let Some(env) = Self::env(name) {
+            self.envs.push(env);
+        }
+        self
+    }
+
+    #[must_use]
+    pub fn with_global(mut self, name: &str, value: GlobalValue) -> Self {
+        self.globals.insert(name.to_string(), value);
+        self
+    }
+
+    /// Read the `env` and `globals` of an eslintrc configuration
+    #[must_use]
+    pub fn from_json(config: &serde_json::Value) -> Self {
+        let mut env = Self::default();
+        if let Some(envs) = config.get("env").and_then(serde_json::Value::as_object) {
+            for (name, enabled) in envs {
+                if enabled.as_bool() == Some(true) {
+                    env = env.with_env(name);
+                }
+            }
+        }
+        if let Some(globals) = config.get("globals").and_then(serde_json::Value::as_object) {
+            for (name, value) in globals {
+                if let Some(value) = GlobalValue::from_json(value) {
+                    env = env.with_global(name, value);
+                }
+            }
+        }
+        env
+    }
+
+    /// Returns `None` when `name` is not a global variable.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<GlobalValue> {
+        if let Some(value) = self.globals.get(name) {
+            return (*value != GlobalValue::Off).then_some(*value);
+        }
+        let ecma_version = self.ecma_version.unwrap_or(LATEST_ECMA_VERSION);
+        ES_GLOBALS
+            .iter()
+            .filter(|(version, _)| *version <= ecma_version)
+            .find_map(|(_, globals)| globals.get(name))
+            .or_else(|| self.envs.iter().find_map(|env| env.get(name)))
+            .map(|writable| GlobalValue::from(*writable))
+    }
+
+    #[must_use]
+    pub fn is_defined(&self, name: &str) -> bool {
+        self.get(name).is_some()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{GlobalEnvironment, GlobalValue};
+
+    #[test]
+    fn environment() {
+        let env = GlobalEnvironment::default();
+        assert_eq!(env.get("Object"), Some(GlobalValue::Readonly));
+        assert!(!env.is_defined("window"));
+
+        let env = GlobalEnvironment::default().with_env("browser").with_env("unknown");
+        assert!(env.is_defined("window"));
+        assert_eq!(env.get("onload"), Some(GlobalValue::Writable));
+        assert!(!env.is_defined("require"));
+
+        let config = serde_json::json!({
+            "env": { "node": true, "jest": false },
+            "globals": { "foo": "writable", "bar": "readonly", "process": "off" }
+        });
+        let env = GlobalEnvironment::from_json(&config);
+        assert!(env.is_defined("require"));
+        assert!(!env.is_defined("describe"));
+        assert!(!env.is_defined("process"));
+        assert_eq!(env.get("foo"), Some(GlobalValue::Writable));
+        assert_eq!(env.get("bar"), Some(GlobalValue::Readonly));
+    }
+
+    #[test]
+    fn ecma_versions() {
+        let env = GlobalEnvironment::default();
+        assert!(env.is_defined("WeakRef"));
+
+        let env = GlobalEnvironment::default().with_env("es6");
+        assert!(env.is_defined("Promise"));
+        assert!(!env.is_defined("SharedArrayBuffer"));
+        assert!(!env.is_defined("globalThis"));
+
+        // The highest version is used
+        let env = GlobalEnvironment::default().with_env("es2020").with_env("es2017");
+        assert!(env.is_defined("globalThis"));
+        assert!(!env.is_defined("WeakRef"));
+
+        let env = GlobalEnvironment::default().with_env("es2017").with_env("builtin");
+        assert!(env.is_defined("AggregateError"));
+        assert!(!GlobalEnvironment::default().with_env("es2016").is_defined("Atomics"));
+    }
+}
@@ -5,32 +5,49 @@
 mod tester;
 
 mod ast_util;
+mod config;
 mod context;
 mod disable_directives;
 mod fixer;
 mod globals;
+mod options;
 pub mod rule;
 mod rules;
+pub mod selector;
 
-use std::{fs, rc::Rc};
+use std::{rc::Rc, sync::Arc};
 
 pub use fixer::{Fixer, Message};
+use oxc_ast::{SourceType, Trivias};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+    Severity,
+};
 pub(crate) use oxc_semantic::AstNode;
-use oxc_semantic::Semantic;
+use oxc_semantic::{Semantic, SemanticBuilder};
 
-use crate::{
-    context::LintContext, rule::Rule, rules::early_error::javascript::EarlyErrorJavaScript,
-};
+use crate::context::LintContext;
 pub use crate::{
+    config::{ESLintConfig, FlatConfig, LintConfig, ESLINTRC_FILE_NAMES, FLAT_CONFIG_FILE_NAMES},
+    globals::{GlobalEnvironment, GlobalValue},
+    options::AllowWarnDeny,
     rule::RuleCategory,
     rules::{RuleEnum, RULES},
 };
 
+#[derive(Debug, Error, Diagnostic)]
+#[error("The linter requires the semantic model built with `SemanticBuilder::{0}`")]
+#[diagnostic()]
+pub struct MissingSemanticAnalysis(pub &'static str);
+
 #[derive(Debug)]
 pub struct Linter {
-    rules: Vec<RuleEnum>,
+    /// Enabled rules with their configured severity,
+    /// `None` keeps the severity of the reported diagnostics
+    rules: Vec<(RuleEnum, Option<Severity>)>,
 
-    early_error_javascript: EarlyErrorJavaScript,
+    globals: Arc<GlobalEnvironment>,
 
     fix: bool,
 }
@@ -49,7 +66,21 @@ impl Linter {
 
     #[must_use]
     pub fn from_rules(rules: Vec<RuleEnum>) -> Self {
-        Self { rules, early_error_javascript: EarlyErrorJavaScript, fix: false }
+        let rules = rules.into_iter().map(|rule| (rule, None)).collect();
+        Self { rules, globals: Arc::default(), fix: false }
+    }
+
+    /// Rules with a configured severity, the severity overrides the one of the reported diagnostics.
+    /// `Allow`ed rules are disabled.
+    #[must_use]
+    pub fn from_configured_rules(rules: Vec<(RuleEnum, AllowWarnDeny)>) -> Self {
+        let rules = rules
+            .into_iter()
+            .filter_map(|(rule, allow_warn_deny)| {
+                allow_warn_deny.severity().map(|severity| (rule, Some(severity)))
+            })
+            .collect();
+        Self { rules, globals: Arc::default(), fix: false }
     }
 
     #[must_use]
@@ -68,67 +99,120 @@ impl Linter {
         self
     }
 
+    /// The global variables available to the linted code
+    #[must_use]
+    pub fn with_globals(mut self, globals: GlobalEnvironment) -> Self {
+        self.globals = Arc::new(globals);
+        self
+    }
+
+    /// Configure the rules from an eslint configuration, e.g.
+    /// `{ "rules": { "no-debugger": "off", "eqeqeq": ["warn", "always"] } }`.
+    /// The severity is `"off"`, `"warn"`, `"error"` or `0`, `1`, `2`.
+    ///
+    /// Rules which are not configured are enabled with the severity of their diagnostics,
+    /// rules with an invalid severity are turned off.
     #[must_use]
     pub fn from_json_str(s: &str) -> Self {
-        let rules = serde_json::from_str(s)
-            .ok()
-            .and_then(|v: serde_json::Value| v.get("rules").cloned())
+        let config: Option<serde_json::Value> = serde_json::from_str(s).ok();
+        let globals = config.as_ref().map(GlobalEnvironment::from_json).unwrap_or_default();
+        let rules_config = config
+            .and_then(|v| v.get("rules").cloned())
             .and_then(|v| v.as_object().cloned())
-            .map_or_else(
-                || RULES.to_vec(),
-                |rules_config| {
-                    RULES
-                        .iter()
-                        .map(|rule| {
-                            let value = rules_config.get(rule.name());
-                            rule.read_json(value.cloned())
-                        })
-                        .collect()
-                },
-            );
-
-        Self::from_rules(rules)
+            .unwrap_or_default();
+        let rules = RULES
+            .iter()
+            .filter_map(|rule| {
+                let Some(value) = rules_config.get(rule.name()) else {
+                    return Some((rule.clone(), None));
+                };
+                let (allow_warn_deny, options) = options::parse_rule_value(value)?;
+                allow_warn_deny.severity().map(|severity| (rule.read_json(options), Some(severity)))
+            })
+            .collect();
+
+        Self { rules, globals: Arc::new(globals), fix: false }
     }
 
+    /// A [`SemanticBuilder`] with the analyses the rules depend on,
+    /// i.e. the control flow graph, the class table and the `this` bindings.
     #[must_use]
-    pub fn run<'a>(&self, semantic: &Rc<Semantic<'a>>) -> Vec<Message<'a>> {
-        let mut ctx = LintContext::new(semantic, self.fix);
-        let is_check_early_error = !semantic.source_type().is_typescript_definition();
+    pub fn semantic_builder<'a>(
+        source_text: &'a str,
+        source_type: SourceType,
+        trivias: &Rc<Trivias>,
+    ) -> SemanticBuilder<'a> {
+        SemanticBuilder::new(source_text, source_type, trivias)
+            .with_cfg(true)
+            .with_classes(true)
+            .with_this_bindings(true)
+    }
+
+    /// # Errors
+    ///
+    /// When `semantic` is not built by [`Linter::semantic_builder`],
+    /// the rules depend on its analyses, e.g. `array-callback-return` analyzes the control flow.
+    pub fn run<'a>(
+        &self,
+        semantic: &Rc<Semantic<'a>>,
+    ) -> Result<Vec<Message<'a>>, MissingSemanticAnalysis> {
+        if semantic.cfg().is_none() {
+            return Err(MissingSemanticAnalysis("with_cfg"));
+        }
+        if semantic.classes().is_none() {
+            return Err(MissingSemanticAnalysis("with_classes"));
+        }
+        if semantic.this_bindings().is_none() {
+            return Err(MissingSemanticAnalysis("with_this_bindings"));
+        }
+
+        let mut ctx = LintContext::new(semantic, self.fix).with_globals(&self.globals);
 
         for node in semantic.nodes().iter() {
-            if is_check_early_error {
-                self.early_error_javascript.run(node, &ctx);
-            }
-            for rule in &self.rules {
-                ctx.with_rule_name(rule.name());
+            for (rule, severity) in &self.rules {
+                ctx.with_rule(rule.name(), *severity);
                 rule.run(node, &ctx);
             }
         }
 
         for symbol in semantic.symbols().iter() {
-            for rule in &self.rules {
+            for (rule, severity) in &self.rules {
+                ctx.with_rule(rule.name(), *severity);
                 rule.run_on_symbol(symbol, &ctx);
             }
         }
 
-        ctx.into_message()
-    }
-
-    #[must_use]
-    pub fn run_early_error<'a>(&self, semantic: &Rc<Semantic<'a>>, fix: bool) -> Vec<Message<'a>> {
-        let ctx = LintContext::new(semantic, fix);
-        for node in semantic.nodes().iter() {
-            self.early_error_javascript.run(node, &ctx);
-        }
-        ctx.into_message()
+        Ok(ctx.into_message())
     }
+}
 
-    #[allow(unused)]
-    fn read_rules_configuration() -> Option<serde_json::Map<String, serde_json::Value>> {
-        fs::read_to_string(".eslintrc.json")
-            .ok()
-            .and_then(|s| serde_json::from_str(&s).ok())
-            .and_then(|v: serde_json::Value| v.get("rules").cloned())
-            .and_then(|v| v.as_object().cloned())
+#[cfg(test)]
+mod test {
+    use std::rc::Rc;
+
+    use oxc_allocator::Allocator;
+    use oxc_ast::SourceType;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+
+    use crate::Linter;
+
+    #[test]
+    fn requires_analyses() {
+        let allocator = Allocator::default();
+        let source_text = "[].map(() => {})";
+        let source_type = SourceType::default();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let program = allocator.alloc(ret.program);
+
+        let semantic = SemanticBuilder::new(source_text, source_type, &ret.trivias).build(program);
+        assert_eq!(
+            Linter::new().run(&Rc::new(semantic.semantic)).unwrap_err().to_string(),
+            "The linter requires the semantic model built with `SemanticBuilder::with_cfg`"
+        );
+
+        let semantic =
+            Linter::semantic_builder(source_text, source_type, &ret.trivias).build(program);
+        assert!(Linter::new().run(&Rc::new(semantic.semantic)).is_ok());
     }
 }
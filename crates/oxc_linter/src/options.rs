@@ -0,0 +1,159 @@
+use oxc_diagnostics::Severity;
+use serde_json::Value;
+
+/// Severity of a configured rule, `off` / `warn` / `error` in eslint configurations
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum AllowWarnDeny {
+    Allow,
+    Warn,
+    Deny,
+}
+
+impl AllowWarnDeny {
+    #[must_use]
+    pub fn is_allow(self) -> bool {
+        self == Self::Allow
+    }
+
+    /// `off`, `warn` or `error`, as written in eslint configurations
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Allow => "off",
+            Self::Warn => "warn",
+            Self::Deny => "error",
+        }
+    }
+
+    /// The severity of the reported diagnostics, `None` for a disabled rule
+    #[must_use]
+    pub fn severity(self) -> Option<Severity> {
+        match self {
+            Self::Allow => None,
+            Self::Warn => Some(Severity::Warning),
+            Self::Deny => Some(Severity::Error),
+        }
+    }
+}
+
+impl TryFrom<&str> for AllowWarnDeny {
+    type Error = ();
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        match s {
+            "allow" | "off" => Ok(Self::Allow),
+            "warn" => Ok(Self::Warn),
+            "deny" | "error" => Ok(Self::Deny),
+            _ => Err(()),
+        }
+    }
+}
+
+impl TryFrom<&Value> for AllowWarnDeny {
+    type Error = ();
+
+    /// `"off"`, `"warn"`, `"error"` or `0`, `1`, `2`
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::String(s) => Self::try_from(s.as_str()),
+            Value::Number(n) => match n.as_u64() {
+                Some(0) => Ok(Self::Allow),
+                Some(1) => Ok(Self::Warn),
+                Some(2) => Ok(Self::Deny),
+                _ => Err(()),
+            },
+            _ => Err(()),
+        }
+    }
+}
+
+/// Split the eslint configuration of a rule into its severity and its options,
+/// e.g. `["warn", { "allow": ["~"] }]` is `Warn` with the options `[{ "allow": ["~"] }]`.
+///
+/// Returns `None` for an invalid severity.
+pub fn parse_rule_value(value: &Value) -> Option<(AllowWarnDeny, Option<Value>)> {
+    match value {
+        Value::Array(values) => {
+            let (severity, options) = values.split_first()?;
+            let options = (!options.is_empty()).then(|| Value::Array(options.to_vec()));
+            Some((AllowWarnDeny::try_from(severity).ok()?, options))
+        }
+        _ => Some((AllowWarnDeny::try_from(value).ok()?, None)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::rc::Rc;
+
+    use oxc_allocator::Allocator;
+    use oxc_ast::SourceType;
+    use oxc_diagnostics::Severity;
+    use oxc_parser::Parser;
+    use serde_json::json;
+
+    use super::{parse_rule_value, AllowWarnDeny};
+    use crate::Linter;
+
+    #[test]
+    fn severity() {
+        for (value, expected) in [
+            (json!("off"), Some(AllowWarnDeny::Allow)),
+            (json!("warn"), Some(AllowWarnDeny::Warn)),
+            (json!("error"), Some(AllowWarnDeny::Deny)),
+            (json!(0), Some(AllowWarnDeny::Allow)),
+            (json!(1), Some(AllowWarnDeny::Warn)),
+            (json!(2), Some(AllowWarnDeny::Deny)),
+            (json!(3), None),
+            (json!("on"), None),
+            (json!(true), None),
+        ] {
+            assert_eq!(AllowWarnDeny::try_from(&value).ok(), expected, "{value}");
+        }
+    }
+
+    #[test]
+    fn rule_value() {
+        assert_eq!(parse_rule_value(&json!("warn")), Some((AllowWarnDeny::Warn, None)));
+        assert_eq!(parse_rule_value(&json!([2])), Some((AllowWarnDeny::Deny, None)));
+        assert_eq!(
+            parse_rule_value(&json!(["error", { "allow": ["~"] }])),
+            Some((AllowWarnDeny::Deny, Some(json!([{ "allow": ["~"] }]))))
+        );
+        assert_eq!(parse_rule_value(&json!([])), None);
+        assert_eq!(parse_rule_value(&json!({ "allow": ["~"] })), None);
+    }
+
+    #[test]
+    fn configured_severity() {
+        let source_text = "debugger;";
+        let severities = |config: &str| -> Vec<Option<Severity>> {
+            let allocator = Allocator::default();
+            let source_type = SourceType::default();
+            let ret = Parser::new(&allocator, source_text, source_type).parse();
+            let program = allocator.alloc(ret.program);
+            let semantic = Linter::semantic_builder(source_text, source_type, &ret.trivias)
+                .build(program)
+                .semantic;
+            Linter::from_json_str(config)
+                .run(&Rc::new(semantic))
+                .unwrap()
+                .into_iter()
+                .filter(|message| message.error.to_string().contains("debugger"))
+                .map(|message| message.error.severity())
+                .collect()
+        };
+        assert_eq!(severities(r#"{ "rules": {} }"#), vec![Some(Severity::Warning)]);
+        assert_eq!(
+            severities(r#"{ "rules": { "no-debugger": "error" } }"#),
+            vec![Some(Severity::Error)]
+        );
+        assert_eq!(
+            severities(r#"{ "rules": { "no-debugger": [1] } }"#),
+            vec![Some(Severity::Warning)]
+        );
+        assert!(severities(r#"{ "rules": { "no-debugger": 0 } }"#).is_empty());
+        assert!(severities(r#"{ "rules": { "no-debugger": "bogus" } }"#).is_empty());
+        assert!(severities(r#"{ "rules": { "no-debugger": [3, "always"] } }"#).is_empty());
+    }
+}
@@ -11,6 +11,15 @@ pub trait Rule: Sized + Default + Debug {
         Self::default()
     }
 
+    /// Validate the eslint json configuration, invalid options are reported as
+    /// configuration errors instead of being ignored by `from_configuration`
+    ///
+    /// # Errors
+    /// A description of the invalid option
+    fn validate_configuration(_value: &serde_json::Value) -> Result<(), String> {
+        Ok(())
+    }
+
     fn run_on_symbol(&self, _symbol: &Symbol, _ctx: &LintContext<'_>) {}
 
     fn run<'a>(&self, _node: &AstNode<'a>, _ctx: &LintContext<'a>) {}
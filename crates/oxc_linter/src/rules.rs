@@ -1,7 +1,3 @@
-pub mod early_error {
-    pub mod javascript;
-}
-
 oxc_macros::declare_all_lint_rules! {
     array_callback_return,
     constructor_super,
@@ -26,6 +22,9 @@ oxc_macros::declare_all_lint_rules! {
     no_compare_neg_zero,
     no_unsafe_negation,
     no_bitwise,
+    no_restricted_syntax,
+    no_undef,
+    no_global_assign,
     deepscan::uninvoked_array_callback,
     use_isnan,
     valid_typeof,
@@ -1,5 +1,3 @@
-mod return_checker;
-
 use oxc_ast::{
     ast::{ChainElement, Expression},
     AstKind, Atom, GetSpan, Span,
@@ -9,10 +7,10 @@ use oxc_diagnostics::{
     thiserror::{self, Error},
 };
 use oxc_macros::declare_oxc_lint;
+use oxc_semantic::AstNodeId;
 use phf::phf_set;
 use serde_json::Value;
 
-use self::return_checker::{check_function_body, StatementReturnStatus};
 use crate::{
     ast_util::{get_enclosing_function, is_nth_argument, outermost_paren},
     context::LintContext,
@@ -100,15 +98,17 @@ impl Rule for ArrayCallbackReturn {
         // Filter on target methods on Arrays
         if let Some(array_method) = get_array_method_name(node, ctx) {
             let return_status = if always_explicit_return {
-                StatementReturnStatus::AlwaysExplicit
+                ReturnStatus::ALWAYS_EXPLICIT
+            } else if let Some(status) = ReturnStatus::of_function(node, ctx) {
+                status
             } else {
-                check_function_body(function_body)
+                return;
             };
 
             match (array_method, self.check_for_each, self.allow_implicit_return) {
                 ("forEach", false, _) => (),
                 ("forEach", true, _) => {
-                    if return_status.may_return_explicit() {
+                    if return_status.may_return_explicit {
                         ctx.diagnostic(ArrayCallbackReturnDiagnostic::ExpectNoReturn(
                             full_array_method_name(array_method),
                             function_body.span,
@@ -116,7 +116,7 @@ impl Rule for ArrayCallbackReturn {
                     }
                 }
                 (_, _, true) => {
-                    if !return_status.must_return() {
+                    if !return_status.must_return {
                         ctx.diagnostic(ArrayCallbackReturnDiagnostic::ExpectReturn(
                             full_array_method_name(array_method),
                             function_body.span,
@@ -124,7 +124,7 @@ impl Rule for ArrayCallbackReturn {
                     }
                 }
                 (_, _, false) => {
-                    if !return_status.must_return() || return_status.may_return_implicit() {
+                    if !return_status.must_return || return_status.may_return_implicit {
                         ctx.diagnostic(ArrayCallbackReturnDiagnostic::ExpectReturn(
                             full_array_method_name(array_method),
                             function_body.span,
@@ -136,6 +136,49 @@ impl Rule for ArrayCallbackReturn {
     }
 }
 
+/// How a callback returns, from the control flow graph of its function
+#[derive(Debug, Clone, Copy)]
+struct ReturnStatus {
+    /// The end of the function cannot be reached
+    must_return: bool,
+    /// `return value`
+    may_return_explicit: bool,
+    /// `return;` or reaching the end of the function
+    may_return_implicit: bool,
+}
+
+impl ReturnStatus {
+    const ALWAYS_EXPLICIT: Self =
+        Self { must_return: true, may_return_explicit: true, may_return_implicit: false };
+
+    /// Like eslint, unreachable `return` statements are also checked, e.g. `return 1; return;`
+    fn of_function<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> Option<Self> {
+        let cfg = ctx.cfg();
+        let nodes = ctx.nodes();
+        let node_id = nodes.get_node_id(node)?;
+        let owner = AstNodeId::from(node_id);
+        let falls_through = cfg.falls_through(owner);
+        let mut status = Self {
+            must_return: !falls_through,
+            may_return_explicit: false,
+            may_return_implicit: falls_through,
+        };
+        for id in node_id.descendants(nodes) {
+            let AstKind::ReturnStatement(stmt) = nodes.kind(id) else { continue };
+            // the returns of nested functions
+            if cfg.block_of(id.into()).map(|block| cfg[block].owner()) != Some(owner) {
+                continue;
+            }
+            if stmt.argument.is_some() {
+                status.may_return_explicit = true;
+            } else {
+                status.may_return_implicit = true;
+            }
+        }
+        Some(status)
+    }
+}
+
 /// Code ported from [eslint](https://github.com/eslint/eslint/blob/main/lib/rules/array-callback-return.js)
 /// We're currently on a `Function` or `ArrowExpression`, findout if it is an argument
 /// to the target array methods we're interested in.
@@ -152,6 +195,7 @@ pub fn get_array_method_name<'a>(
             AstKind::LogicalExpression(_)
             | AstKind::ConditionalExpression(_)
             | AstKind::Argument(_)
+            | AstKind::ChainExpression(_)
             | AstKind::ParenthesizedExpression(_) => {
                 current_node = parent;
             }
@@ -418,6 +462,14 @@ fn test() {
         ("var every = function() {}", None),
         ("foo[`${every}`](function() {})", None),
         ("foo.every(() => true)", None),
+        (
+            "foo.every(function() { switch (a) { case 0: switch (b) { case 1: bar(); default: return 1; } default: return 2; } })",
+            None,
+        ),
+        ("foo.every(function() { while (true) { if (a) return 1; } })", None),
+        ("foo.every(function() { try { return 1; } finally { bar(); } })", None),
+        ("foo.every(function() { { if (a) { return 1; } } return 2; })", None),
+        ("foo.every(function() { throw new Error(); })", None),
     ];
 
     let fail = vec![
@@ -587,6 +639,11 @@ fn test() {
         ("Array?.from([], () => { console.log('hello') })", None),
         ("(Array?.from)([], () => { console.log('hello') })", None),
         ("foo?.filter((function() { return () => { console.log('hello') } })?.())", None),
+        ("foo.every(function() { switch (a) { case 0: return 1; } })", None),
+        ("foo.every(function() { if (a) { return 1; } else { bar(); } })", None),
+        ("foo.every(function() { while (true) { return; } })", None),
+        ("foo.every(function() { for (;;) { if (a) break; return 1; } })", None),
+        ("foo.every(function() { return 1; return; })", None),
     ];
 
     Tester::new(ArrayCallbackReturn::NAME, pass, fail).test_and_snapshot();
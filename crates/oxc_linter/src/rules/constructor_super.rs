@@ -1,5 +1,5 @@
 use oxc_ast::{
-    ast::{ClassElement, Expression, MethodDefinitionKind, Statement},
+    ast::{Expression, MethodDefinitionKind},
     AstKind, Span,
 };
 use oxc_diagnostics::{
@@ -7,6 +7,10 @@ use oxc_diagnostics::{
     thiserror::Error,
 };
 use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{
+    control_flow::{BasicBlockId, EdgeKind},
+    AstNodeId,
+};
 
 use crate::{context::LintContext, rule::Rule, AstNode};
 
@@ -45,42 +49,87 @@ declare_oxc_lint!(
 
 impl Rule for ConstructorSuper {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
-        let AstKind::Class(class) = node.get().kind() else { return };
-        let Some(ctor) = class.body.body.iter().find_map(|el| match el {
-            ClassElement::MethodDefinition(method_definition)
-                if method_definition.kind == MethodDefinitionKind::Constructor =>
-            {
-                Some(method_definition)
-            }
-            _ => None,
-        }) else { return };
+        let AstKind::Function(function) = node.get().kind() else { return };
+        let Some(method_node) = ctx.parent_node(node) else { return };
+        let AstKind::MethodDefinition(ctor) = method_node.get().kind() else { return };
+        if ctor.kind != MethodDefinitionKind::Constructor {
+            return;
+        }
+        let AstKind::Class(class) = ctx.parent_kind(method_node) else { return };
 
         // In cases where there's no super-class, calling 'super()' inside the constructor
         // is handled by the parser.
-        if let Some(super_class) = &class.super_class {
-            ctor.value.body.as_ref().map_or_else(|| {
-                ctx.diagnostic(ConstructorSuperDiagnostic(ctor.span));
-            }, |function_body| {
-                let super_call_expr = function_body.statements.iter().find_map(|stmt| {
-                    let Statement::ExpressionStatement(expr) = stmt else { return None };
-                    let Expression::CallExpression(call_expr) = &expr.expression else { return None };
-                    if matches!(call_expr.callee, Expression::Super(_)) {
-                        Some(call_expr.span)
-                    } else {
-                        None
-                    }
-                });
-
-                super_call_expr.map_or_else(|| {
-                    ctx.diagnostic(ConstructorSuperDiagnostic(ctor.span));
-                }, |span| {
-                    if let Some(super_class_span) = super_class.span() {
-                        ctx.diagnostic(SuperNotConstructorDiagnostic(span, super_class_span));
-                    }
-                });
-            });
+        let Some(super_class) = &class.super_class else { return };
+        if function.body.is_none() {
+            return ctx.diagnostic(ConstructorSuperDiagnostic(ctor.span));
+        }
+
+        // `super` of arrow functions inside of the constructor is resolved to the constructor as well
+        let node_id = AstNodeId::new(ctx.nodes().get_node_id(node).unwrap());
+        let super_calls: Vec<(AstNodeId, Span)> = ctx
+            .this_bindings()
+            .references(node_id)
+            .iter()
+            .filter_map(|super_id| {
+                let node = &ctx.nodes()[super_id.indextree_id()];
+                if !matches!(node.get().kind(), AstKind::Super(_)) {
+                    return None;
+                }
+                match ctx.parent_kind(node) {
+                    AstKind::CallExpression(call_expr) => Some((*super_id, call_expr.span)),
+                    _ => None,
+                }
+            })
+            .collect();
+
+        if let Some((_, span)) = super_calls.first() {
+            if let Some(super_class_span) = super_class.span() {
+                return ctx.diagnostic(SuperNotConstructorDiagnostic(*span, super_class_span));
+            }
+        }
+        if !calls_super_on_every_path(node_id, &super_calls, ctx) {
+            ctx.diagnostic(ConstructorSuperDiagnostic(ctor.span));
+        }
+    }
+}
+
+/// Whether every path from the start of the constructor to a `return` or its end calls `super()`.
+/// Calls inside of callbacks are not counted, they may never be called.
+/// A block calling `super()` may throw before the call, so its exceptions are followed into `catch`.
+fn calls_super_on_every_path(
+    ctor_id: AstNodeId,
+    super_calls: &[(AstNodeId, Span)],
+    ctx: &LintContext,
+) -> bool {
+    let cfg = ctx.cfg();
+    let Some(flow) = cfg.function(ctor_id) else { return true };
+    let super_blocks: Vec<BasicBlockId> = super_calls
+        .iter()
+        .filter_map(|(super_id, _)| cfg.block_of(*super_id))
+        .filter(|block| cfg[*block].owner() == ctor_id)
+        .collect();
+    let mut visited = vec![flow.entry];
+    let mut stack = vec![flow.entry];
+    while let Some(block) = stack.pop() {
+        let calls_super = super_blocks.contains(&block);
+        for &(successor, kind) in cfg[block].successors() {
+            if calls_super && kind != EdgeKind::Throw {
+                continue;
+            }
+            // throwing out of the constructor does not need to construct `this`
+            if kind == EdgeKind::Throw && successor == flow.exit {
+                continue;
+            }
+            if successor == flow.exit {
+                return false;
+            }
+            if !visited.contains(&successor) {
+                visited.push(successor);
+                stack.push(successor);
+            }
         }
     }
+    true
 }
 
 trait NonConstructor {
@@ -118,6 +167,13 @@ fn test() {
         ("class A extends (B ??= 5) { constructor() { super(); } }", None),
         ("class A extends (B || C) { constructor() { super(); } }", None),
         ("class A extends (5 && B) { constructor() { super(); } }", None),
+        ("class A extends B { constructor() { const a = super(); } }", None),
+        ("class A extends B { constructor() { if (a) { super(); } else { super(); } } }", None),
+        ("class A extends B { constructor() { a ? super() : super(); } }", None),
+        ("class A extends B { constructor() { if (a) throw new Error(); super(); } }", None),
+        ("class A extends B { constructor() { try { super(); } catch { super(); } } }", None),
+        ("class A extends B { constructor() { while (!super()); } }", None),
+        ("class A extends B { constructor() { try { f(); } catch { throw e; } super(); } }", None),
     ];
 
     let fail = vec![
@@ -126,6 +182,16 @@ fn test() {
         ("class A extends null { constructor() { } }", None),
         ("class A extends 100 { constructor() { super(); } }", None),
         ("class A extends 'test' { constructor() { super(); } }", None),
+        ("class A extends B { constructor() { class C extends D { constructor() { super(); } } } }", None),
+        ("class A extends null { constructor() { foo(() => () => super()); } }", None),
+        ("class A extends B { constructor() { foo(() => super()); } }", None),
+        ("class A extends B { constructor() { foo(() => { bar(() => super()); }); } }", None),
+        ("class A extends B { constructor() { if (a) super(); } }", None),
+        ("class A extends B { constructor() { a && super(); } }", None),
+        ("class A extends B { constructor() { if (a) return; super(); } }", None),
+        ("class A extends B { constructor() { for (const a of b) super(); } }", None),
+        ("class A extends B { constructor() { try { f(); super(); } catch {} } }", None),
+        ("class A extends B { constructor() { try { f(); } catch { return; } super(); } }", None),
     ];
 
     Tester::new(ConstructorSuper::NAME, pass, fail).test_and_snapshot();
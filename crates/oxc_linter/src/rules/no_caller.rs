@@ -50,17 +50,40 @@ declare_oxc_lint!(
 
 impl Rule for NoCaller {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
-        let AstKind::MemberExpression(member_expr) = node.get().kind() else {return};
-        if let MemberExpression::StaticMemberExpression(expr) = member_expr
-        && let Some(reference) = expr.object.get_identifier_reference()
-         {
-            if reference.name != "arguments" {
-                return;
-            }
+        let AstKind::IdentifierReference(ident) = node.get().kind() else { return };
+        if ident.name != "arguments" {
+            return;
+        }
+
+        // `arguments` is not shadowed by a declaration, e.g. `function f(arguments) {}`
+        let node_id = ctx.nodes().get_node_id(node).unwrap();
+        if ctx.this_bindings().environment(node_id.into()).is_none() {
+            return;
+        }
 
-            if expr.property.name == "callee" || expr.property.name == "caller" {
-                ctx.diagnostic(NoCallerDiagnostic(expr.property.span));
-            }
+        let Some(member_id) = ctx.ancestors(node).skip(1).find(|id| {
+            !matches!(
+                ctx.kind(*id),
+                AstKind::ParenthesizedExpression(_)
+                    | AstKind::TSAsExpression(_)
+                    | AstKind::TSNonNullExpression(_)
+                    | AstKind::TSTypeAssertion(_)
+            )
+        }) else {
+            return;
+        };
+        let AstKind::MemberExpression(MemberExpression::StaticMemberExpression(expr)) =
+            ctx.kind(member_id)
+        else {
+            return;
+        };
+        // `arguments` is the object, not in a computed key of it, e.g. `a[arguments].callee`
+        if expr.object.get_identifier_reference().map(|object| object.span) != Some(ident.span) {
+            return;
+        }
+
+        if expr.property.name == "callee" || expr.property.name == "caller" {
+            ctx.diagnostic(NoCallerDiagnostic(expr.property.span));
         }
     }
 }
@@ -74,9 +97,17 @@ fn test() {
         ("var x = arguments", None),
         ("var x = arguments[0]", None),
         ("var x = arguments[caller]", None),
+        ("function f(arguments) { arguments.callee }", None),
+        ("function f() { let arguments; arguments.callee }", None),
+        ("var x = a[arguments].callee", None),
     ];
 
-    let fail = vec![("var x = arguments.callee", None), ("var x = arguments.caller", None)];
+    let fail = vec![
+        ("var x = arguments.callee", None),
+        ("var x = arguments.caller", None),
+        ("function f() { return () => arguments.callee }", None),
+        ("function f() { return (arguments).caller }", None),
+    ];
 
     Tester::new(NoCaller::NAME, pass, fail).test_and_snapshot();
 }
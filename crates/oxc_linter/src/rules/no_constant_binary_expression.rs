@@ -9,7 +9,7 @@ use oxc_macros::declare_oxc_lint;
 use crate::{
     ast_util::{self, IsConstant},
     context::LintContext,
-    globals::BUILTINS,
+    globals::is_builtin,
     rule::Rule,
     AstNode,
 };
@@ -356,7 +356,7 @@ impl NoConstantBinaryExpression {
             | Expression::RegExpLiteral(_) => true,
             Expression::NewExpression(call_expr) => {
                 if let Expression::Identifier(ident) = &call_expr.callee {
-                    return BUILTINS.contains_key(ident.name.as_str())
+                    return is_builtin(ident.name.as_str())
                         && ctx.is_reference_to_global_variable(ident);
                 }
                 false
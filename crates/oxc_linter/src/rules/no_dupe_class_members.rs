@@ -1,14 +1,12 @@
 use std::hash::BuildHasherDefault;
 
-use oxc_ast::{
-    ast::{ClassElement, MethodDefinitionKind},
-    AstKind, Atom, GetSpan, Span,
-};
+use oxc_ast::{AstKind, Atom, Span};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
 };
 use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{AstNodeId, ClassMember, ClassMemberKind};
 use rustc_hash::FxHashMap;
 
 use crate::{context::LintContext, rule::Rule, AstNode};
@@ -54,56 +52,59 @@ declare_oxc_lint!(
 impl Rule for NoDupeClassMembers {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::Class(class) = node.get().kind() else { return; };
-
-        let num_element = class.body.body.len();
-        let mut property_table = PropertyTable::with_capacity(num_element);
-        for element in &class.body.body {
-            if ctx.source_type().is_typescript() && element.is_ts_empty_body_function() {
+        let classes = ctx.classes();
+        let node_id = AstNodeId::new(ctx.nodes().get_node_id(node).unwrap());
+        let Some(class_id) = classes.get_class_id(node_id) else { return };
+
+        let members = &classes[class_id].members;
+        let mut property_table = PropertyTable::with_capacity(members.len());
+        for member in members {
+            if ctx.source_type().is_typescript()
+                && class.body.body[member.element_index].is_ts_empty_body_function()
+            {
                 // Skip functions with no function bodies, which are Typescript's overload signatures
                 continue;
             }
 
-            if let Some(dup_span) = property_table.insert(element) {
-                let property_name = element.property_key().unwrap().static_name().unwrap();
-                let cur_span = element.property_key().unwrap().span();
-                ctx.diagnostic(NoDupeClassMembersDiagnostic(property_name, dup_span, cur_span));
+            if let Some(dup_span) = property_table.insert(member) {
+                ctx.diagnostic(NoDupeClassMembersDiagnostic(
+                    member.name.clone(),
+                    dup_span,
+                    member.span,
+                ));
             }
         }
     }
 }
 
 /// (static, name)
-type PropertyTableKey = (bool, Atom);
-/// (Definition kind, span of last declaration)
-type PropertyTableEntry = (Option<MethodDefinitionKind>, Span);
+type PropertyTableKey<'b> = (bool, &'b Atom);
+/// (member kind, span of last declaration)
+type PropertyTableEntry = (ClassMemberKind, Span);
 /// Table to track whether a name is defined in static/non-static context as a getter/setter/normal class members
 /// Maps (static, name) -> (kind -> span of last declaration)
 #[derive(Debug, Clone, Default)]
-struct PropertyTable(FxHashMap<PropertyTableKey, Vec<PropertyTableEntry>>);
-
-impl PropertyTable {
-    /// Return the last duplicate span if element's property key is duplicate,
-    /// otherwise return None and insert the property key into the table.
-    pub fn insert(&mut self, element: &ClassElement) -> Option<Span> {
-        // It is valid to have a normal method named 'constructor'
-        if element.method_definition_kind() == Some(MethodDefinitionKind::Constructor) {
+struct PropertyTable<'b>(FxHashMap<PropertyTableKey<'b>, Vec<PropertyTableEntry>>);
+
+impl<'b> PropertyTable<'b> {
+    /// Return the last duplicate span if the member's name is duplicate,
+    /// otherwise return None and insert the name into the table.
+    pub fn insert(&mut self, member: &'b ClassMember) -> Option<Span> {
+        // It is valid to have a normal method named 'constructor',
+        // and private names are never duplicates of other names
+        if member.kind == ClassMemberKind::Constructor || member.is_private_name() {
             return None;
         }
 
-        let Some((property_name, property_span)) = element
-          .property_key()
-          .and_then(|key| key.static_name().map(|name| (name, key.span()))) else { return None; };
-        let property_kind = element.method_definition_kind();
-
-        let key = (element.r#static(), property_name);
+        let key = (member.r#static, &member.name);
         let entry = self.0.entry(key).or_default();
         for (kind, span) in entry.iter() {
-            if Self::conflict(*kind, property_kind) {
+            if Self::conflict(*kind, member.kind) {
                 return Some(*span);
             }
         }
 
-        entry.push((property_kind, property_span));
+        entry.push((member.kind, member.span));
         None
     }
 
@@ -111,12 +112,12 @@ impl PropertyTable {
         Self(FxHashMap::with_capacity_and_hasher(capacity, BuildHasherDefault::default()))
     }
 
-    fn conflict(kind: Option<MethodDefinitionKind>, other: Option<MethodDefinitionKind>) -> bool {
+    fn conflict(kind: ClassMemberKind, other: ClassMemberKind) -> bool {
         // getter and setter can share the same name
         !matches!(
             (kind, other),
-            (Some(MethodDefinitionKind::Get), Some(MethodDefinitionKind::Set))
-                | (Some(MethodDefinitionKind::Set), Some(MethodDefinitionKind::Get))
+            (ClassMemberKind::Getter, ClassMemberKind::Setter)
+                | (ClassMemberKind::Setter, ClassMemberKind::Getter)
         )
     }
 }
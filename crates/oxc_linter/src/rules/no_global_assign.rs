@@ -0,0 +1,101 @@
+use oxc_ast::{AstKind, Atom, Span};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+
+use crate::{context::LintContext, globals::GlobalValue, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-global-assign): Read-only global '{0}' should not be modified.")]
+#[diagnostic(severity(warning))]
+struct NoGlobalAssignDiagnostic(Atom, #[label("Read-only global '{0}' should not be modified.")] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoGlobalAssign {
+    exceptions: Vec<Atom>,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallow assignments to native objects or read-only global variables
+    ///
+    /// ### Why is this bad?
+    /// Overwriting a builtin or environment global such as `Object` or `window`
+    /// changes it for all the other code running in the same environment.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// Object = null;
+    /// undefined = 1;
+    /// ```
+    NoGlobalAssign,
+    nursery
+);
+
+impl Rule for NoGlobalAssign {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let exceptions = value
+            .get(0)
+            .and_then(|v| v.get("exceptions"))
+            .and_then(serde_json::Value::as_array)
+            .map(|exceptions| {
+                exceptions.iter().filter_map(serde_json::Value::as_str).map(Atom::from).collect()
+            })
+            .unwrap_or_default();
+        Self { exceptions }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        if !matches!(node.get().kind(), AstKind::Program(_)) {
+            return;
+        }
+        let mut assignments = vec![];
+        for (name, references) in ctx.semantic().unresolved_references() {
+            if ctx.globals().get(name) != Some(GlobalValue::Readonly)
+                || self.exceptions.contains(name)
+            {
+                continue;
+            }
+            for reference in references.iter().filter(|reference| reference.is_write()) {
+                assignments.push(NoGlobalAssignDiagnostic(name.clone(), reference.span));
+            }
+        }
+        assignments.sort_unstable_by_key(|diagnostic| diagnostic.1.start);
+        for diagnostic in assignments {
+            ctx.diagnostic(diagnostic);
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("string = 'hello world';", None),
+        ("var string;", None),
+        ("Object = 0;", Some(json!([{ "exceptions": ["Object"] }]))),
+        ("top = 0;", None),
+        ("onload = 0;", None),
+        ("require = 0;", None),
+        ("function f() { var Object; Object = 0; }", None),
+        ("let undefined = 1; undefined = 2;", None),
+        ("Object.x = 0;", None),
+        ("a = Object;", None),
+    ];
+
+    let fail = vec![
+        ("String = 'hello world';", None),
+        ("String++;", None),
+        ("({Object = 0, String = 0} = {});", None),
+        ("undefined = 1", None),
+        ("function f() { NaN = 0; }", None),
+        ("Array = 1;", Some(json!([{ "exceptions": ["Object"] }]))),
+    ];
+
+    Tester::new(NoGlobalAssign::NAME, pass, fail).test_and_snapshot();
+}
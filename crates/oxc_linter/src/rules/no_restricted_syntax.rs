@@ -0,0 +1,185 @@
+use indextree::NodeId;
+use oxc_ast::{AstKind, GetSpan, Span};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+
+use crate::{context::LintContext, rule::Rule, selector::Selector, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-restricted-syntax): {0}")]
+#[diagnostic(severity(warning))]
+struct NoRestrictedSyntaxDiagnostic(String, #[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedSyntax {
+    restrictions: Vec<Restriction>,
+}
+
+#[derive(Debug, Clone)]
+struct Restriction {
+    selector: Selector,
+    message: String,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow specified syntax, configured with [esquery](https://github.com/estools/esquery) selectors.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Some language features are not allowed by a codebase's style or are too easy to misuse.
+    ///
+    /// ### Example
+    ///
+    /// With `["WithStatement", { "selector": "CallExpression[callee.property.name='then']", "message": "Use await" }]`:
+    ///
+    /// ```javascript
+    /// with (me) { dontMess(); }
+    /// promise.then(handle);
+    /// ```
+    NoRestrictedSyntax,
+    restriction
+);
+
+impl NoRestrictedSyntax {
+    fn restrictions(value: &serde_json::Value) -> Result<Vec<Restriction>, String> {
+        let Some(values) = value.as_array() else { return Ok(vec![]) };
+        values
+            .iter()
+            .map(|restriction| {
+                let (source, message) = match restriction {
+                    serde_json::Value::String(source) => (source.as_str(), None),
+                    serde_json::Value::Object(obj) => (
+                        obj.get("selector").and_then(serde_json::Value::as_str).ok_or_else(
+                            || format!("Expected a `selector` string, found {restriction}"),
+                        )?,
+                        obj.get("message").and_then(serde_json::Value::as_str),
+                    ),
+                    _ => {
+                        return Err(format!(
+                            "Expected a selector or a {{ selector, message }} object, found {restriction}"
+                        ));
+                    }
+                };
+                let selector = Selector::parse(source).map_err(|err| err.to_string())?;
+                let message = message.map_or_else(
+                    || format!("Using '{source}' is not allowed."),
+                    ToString::to_string,
+                );
+                Ok(Restriction { selector, message })
+            })
+            .collect()
+    }
+}
+
+impl Rule for NoRestrictedSyntax {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        // Invalid configurations are reported by `validate_configuration`
+        Self { restrictions: Self::restrictions(&value).unwrap_or_default() }
+    }
+
+    fn validate_configuration(value: &serde_json::Value) -> Result<(), String> {
+        Self::restrictions(value).map(|_| ())
+    }
+
+    /// All nodes are selected at once from the `Program`,
+    /// matching each node by itself would look at the siblings of its parent again.
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::Program(_) = node.get().kind() else { return };
+        let mut matches: Vec<(NodeId, usize)> = self
+            .restrictions
+            .iter()
+            .enumerate()
+            .flat_map(|(index, restriction)| {
+                restriction.selector.select(ctx.nodes()).into_iter().map(move |id| (id, index))
+            })
+            .collect();
+        // Node ids are in source order
+        matches.sort_unstable();
+        for (id, index) in matches {
+            ctx.diagnostic(NoRestrictedSyntaxDiagnostic(
+                self.restrictions[index].message.clone(),
+                ctx.nodes().kind(id).span(),
+            ));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("doSomething();", None),
+        ("var foo = 42;", Some(json!(["ConditionalExpression"]))),
+        ("foo += 42;", Some(json!(["VariableDeclaration", "FunctionExpression"]))),
+        ("foo;", Some(json!(["Identifier[name=\"bar\"]"]))),
+        ("() => 5", Some(json!(["ArrowFunctionExpression > BlockStatement"]))),
+        ("({ foo: 1, bar: 2 })", Some(json!(["Property > Literal.key"]))),
+        ("A: for (;;) break;", Some(json!(["BreakStatement[label]"]))),
+        ("function foo(bar, baz) {}", Some(json!(["FunctionDeclaration[params.length>2]"]))),
+        ("foo", Some(json!([{ "selector": "Identifier[name=\"bar\"]" }]))),
+        ("foo", Some(json!([{ "selector": "Identifier[name=\"bar\"]", "message": "x" }]))),
+        ("p.catch(f)", Some(json!(["CallExpression[callee.property.name='then']"]))),
+        ("if (a) { b() }", Some(json!(["IfStatement:not(:has(BlockStatement))"]))),
+        ("a; b;", Some(json!(["ExpressionStatement + DebuggerStatement"]))),
+        ("a?.b", Some(json!(["MemberExpression[optional=false]"]))),
+        ("foo(a)", Some(json!(["CallExpression > Identifier.arguments:nth-child(2)"]))),
+        ("var x = [1]", Some(json!(["[elements.length > 1]"]))),
+        ("x = 'one'", Some(json!(["Literal[value=/^t/]"]))),
+        ("foo(bar)", Some(json!(["CallExpression:has(> Literal)"]))),
+        ("a; b; debugger;", Some(json!(["DebuggerStatement:first-child"]))),
+    ];
+
+    let fail = vec![
+        ("var foo = 41;", Some(json!(["VariableDeclaration"]))),
+        (";function lol(a) { return 42; }", Some(json!(["EmptyStatement"]))),
+        ("try { voila(); } catch (e) { oops(); }", Some(json!(["TryStatement", "CallExpression", "CatchClause"]))),
+        ("bar;", Some(json!(["Identifier[name=\"bar\"]"]))),
+        ("bar;", Some(json!(["Identifier", "Identifier[name=\"bar\"]"]))),
+        ("() => {}", Some(json!(["ArrowFunctionExpression > BlockStatement"]))),
+        ("({ foo: 1, 'bar': 2 })", Some(json!(["Property > Literal.key"]))),
+        ("A: for (;;) break A;", Some(json!(["BreakStatement[label]"]))),
+        ("function foo(bar, baz, qux) {}", Some(json!(["FunctionDeclaration[params.length>2]"]))),
+        ("var foo = 41;", Some(json!([{ "selector": "VariableDeclaration" }]))),
+        ("function foo(bar, baz, qux) {}", Some(json!([{ "selector": "FunctionDeclaration[params.length>2]", "message": "custom error message." }]))),
+        ("p.then(f)", Some(json!([{ "selector": "CallExpression[callee.property.name='then']", "message": "Use await instead of then" }]))),
+        ("p?.then(f)", Some(json!(["CallExpression[callee.property.name='then']"]))),
+        ("foo(a)", Some(json!(["CallExpression[callee.type='Identifier']"]))),
+        ("if (a) b()", Some(json!(["IfStatement:not(:has(BlockStatement))"]))),
+        ("if (a) { b() }", Some(json!(["IfStatement:has(> BlockStatement)"]))),
+        ("a; debugger;", Some(json!(["ExpressionStatement + DebuggerStatement"]))),
+        ("debugger; a; b;", Some(json!(["DebuggerStatement ~ ExpressionStatement"]))),
+        ("a?.b", Some(json!(["MemberExpression[optional=true]"]))),
+        ("foo(a, b)", Some(json!(["CallExpression > Identifier.arguments:last-child"]))),
+        ("function f() { return 1 }", Some(json!(["FunctionDeclaration ReturnStatement > :expression"]))),
+        ("var x = [1, 2]", Some(json!(["[elements.length > 1]"]))),
+        ("x = 'two'", Some(json!(["Literal[value=/^t/]"]))),
+        ("x = '2'", Some(json!(["Literal[value=type(string)]"]))),
+        ("foo('bar')", Some(json!(["CallExpression:has(> Literal)"]))),
+        ("foo(function () {})", Some(json!([":function:matches(FunctionExpression, ArrowFunctionExpression)"]))),
+        ("export default class {}", Some(json!(["ExportDefaultDeclaration > ClassDeclaration"]))),
+        ("x => x", Some(json!(["ArrowFunctionExpression > Identifier.params"]))),
+        ("a; debugger; b; debugger;", Some(json!(["DebuggerStatement:nth-last-child(3)", "ExpressionStatement ~ DebuggerStatement"]))),
+    ];
+
+    Tester::new(NoRestrictedSyntax::NAME, pass, fail).test_and_snapshot();
+}
+
+#[test]
+fn invalid_configuration() {
+    use serde_json::json;
+
+    assert!(NoRestrictedSyntax::validate_configuration(&json!(["Identifier"])).is_ok());
+    let error = NoRestrictedSyntax::validate_configuration(&json!(["Identifier", "Identifier["]))
+        .unwrap_err();
+    assert!(error.starts_with("Invalid selector \"Identifier[\""), "{error}");
+    assert!(NoRestrictedSyntax::validate_configuration(&json!([{ "message": "x" }])).is_err());
+    assert!(NoRestrictedSyntax::validate_configuration(&json!([1])).is_err());
+}
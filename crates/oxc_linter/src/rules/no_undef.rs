@@ -0,0 +1,127 @@
+use oxc_ast::{AstKind, Atom, Span};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-undef): Disallow the use of undeclared variables")]
+#[diagnostic(severity(warning))]
+struct NoUndefDiagnostic(Atom, #[label("'{0}' is not defined.")] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUndef {
+    type_of: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallow the use of undeclared variables,
+    /// unless they are builtin or configured globals.
+    ///
+    /// ### Why is this bad?
+    /// It is most likely a typo, or a missing import or declaration.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// var foo = someFunction();
+    /// var bar = a + 1;
+    /// ```
+    NoUndef,
+    nursery
+);
+
+impl Rule for NoUndef {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let type_of = value
+            .get(0)
+            .and_then(|v| v.get("typeof"))
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or_default();
+        Self { type_of }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        if !matches!(node.get().kind(), AstKind::Program(_)) {
+            return;
+        }
+        let nodes = ctx.nodes();
+        let mut undefined = vec![];
+        for (name, references) in ctx.semantic().unresolved_references() {
+            if ctx.globals().is_defined(name) {
+                continue;
+            }
+            for reference in references {
+                // Type references and JSX element names are not checked
+                let id = reference.ast_node_id;
+                if reference.is_type() || !matches!(nodes[id].kind(), AstKind::IdentifierReference(_)) {
+                    continue;
+                }
+                // `typeof a` and `typeof (a)`
+                if !self.type_of && reference.is_typeof() {
+                    continue;
+                }
+                undefined.push(NoUndefDiagnostic(name.clone(), reference.span));
+            }
+        }
+        undefined.sort_unstable_by_key(|diagnostic| diagnostic.1.start);
+        for diagnostic in undefined {
+            ctx.diagnostic(diagnostic);
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("var a = 1, b = 2; a;", None),
+        ("function a(){}  a();", None),
+        ("function f(b) { b; }", None),
+        ("var a; a = 1; a++;", None),
+        ("function f() { g(); } function g() {}", None),
+        ("if (true) { var a = 1; } a;", None),
+        ("Object; NaN; undefined; Intl; Symbol('a')", None),
+        ("typeof a", None),
+        ("typeof (a)", None),
+        ("var b = typeof a", None),
+        ("typeof a === 'undefined'", None),
+        ("if (typeof a === 'undefined') {}", None),
+        ("function foo() { var [a, b=4] = [1, 2]; return {a, b}; }", None),
+        ("var toString = 1;", None),
+        ("function myFunc(...foo) {  return foo; }", None),
+        ("var React, App, a=1; React.render(<App attr={a} />);", None),
+        ("var console; [1,2,3].forEach(obj => { console.log(obj); });", None),
+        ("class A { constructor() { new.target; } }", None),
+        ("class C { static { a; var a; } }", None),
+        ("export default function foo() {}; foo()", None),
+        ("import * as Foo from 'foo'; Foo.bar();", None),
+        ("let a: A; interface A {}", None),
+        ("let a: Array<string> = [];", None),
+        ("try {} catch (e) { e; }", None),
+        ("label: { break label; }", None),
+    ];
+
+    let fail = vec![
+        ("a = 1;", None),
+        ("if (typeof anUndefinedVar === 'string') {}", Some(json!([{ "typeof": true }]))),
+        ("var a = b;", None),
+        ("function f() { b; }", None),
+        ("window;", None),
+        ("require('a');", None),
+        ("function f() { let a; } a;", None),
+        ("{ let a = 1; } a;", None),
+        ("[a] = [0];", None),
+        ("({a} = {});", None),
+        ("a++;", None),
+        ("var React; React.render(<img attr={a} />);", None),
+        ("class A { m() { this.x = b } }", None),
+    ];
+
+    Tester::new(NoUndef::NAME, pass, fail).test_and_snapshot();
+}
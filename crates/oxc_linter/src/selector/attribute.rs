@@ -0,0 +1,559 @@
+//! Attribute lookup for selectors such as `[callee.property.name="then"]`.
+//!
+//! The AST is serialized in its `ESTree` shape with serde, but only the fields along the
+//! attribute path are visited, all other fields are skipped without being serialized.
+
+use std::fmt;
+
+use oxc_ast::AstKind;
+use serde::{
+    ser::{
+        self, SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant, SerializeTuple,
+        SerializeTupleStruct, SerializeTupleVariant,
+    },
+    Serialize, Serializer,
+};
+
+/// Value found at the end of an attribute path.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AttributeValue {
+    String(String),
+    Number(f64),
+    Bool(bool),
+    Null,
+    /// An AST node or any other object, with its `type`, `start` and `end` fields if present.
+    Object {
+        r#type: Option<String>,
+        start: Option<f64>,
+        end: Option<f64>,
+    },
+    /// An array, with its elements identified the same way as objects.
+    Array(Vec<AttributeValue>),
+}
+
+impl AttributeValue {
+    /// String representation used for `=` and `!=`, same as JavaScript's `String(value)`.
+    #[must_use]
+    pub fn to_js_string(&self) -> Option<String> {
+        match self {
+            Self::String(s) => Some(s.clone()),
+            Self::Number(n) => Some(number_to_string(*n)),
+            Self::Bool(b) => Some(b.to_string()),
+            Self::Null => Some("null".to_string()),
+            Self::Object { .. } | Self::Array(_) => None,
+        }
+    }
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn number_to_string(n: f64) -> String {
+    if n.fract() == 0.0 && n.abs() < 1e21 { format!("{}", n as i64) } else { n.to_string() }
+}
+
+/// Get the value at `path` of the `ESTree` representation of `kind`.
+/// Returns `None` if the path does not exist, i.e. the value is `undefined`.
+#[must_use]
+#[allow(clippy::too_many_lines)]
+pub fn get_attribute(kind: AstKind, path: &[String]) -> Option<AttributeValue> {
+    macro_rules! resolve {
+        ($($variant:ident),* $(,)?) => {
+            match kind {
+                $(AstKind::$variant(node) => node.serialize(PathSerializer { path }).ok().flatten(),)*
+                _ => None,
+            }
+        };
+    }
+    resolve!(
+        Program,
+        Directive,
+        BlockStatement,
+        BreakStatement,
+        ContinueStatement,
+        DebuggerStatement,
+        DoWhileStatement,
+        EmptyStatement,
+        ExpressionStatement,
+        ForInStatement,
+        ForOfStatement,
+        ForStatement,
+        ForStatementInit,
+        IfStatement,
+        LabeledStatement,
+        ReturnStatement,
+        SwitchStatement,
+        ThrowStatement,
+        TryStatement,
+        WhileStatement,
+        WithStatement,
+        SwitchCase,
+        CatchClause,
+        FinallyClause,
+        VariableDeclaration,
+        VariableDeclarator,
+        IdentifierName,
+        IdentifierReference,
+        BindingIdentifier,
+        LabelIdentifier,
+        PrivateIdentifier,
+        NumberLiteral,
+        StringLiteral,
+        BooleanLiteral,
+        NullLiteral,
+        BigintLiteral,
+        RegExpLiteral,
+        TemplateLiteral,
+        MetaProperty,
+        Super,
+        ArrayExpression,
+        ArrowExpression,
+        AssignmentExpression,
+        AwaitExpression,
+        BinaryExpression,
+        CallExpression,
+        ChainExpression,
+        ConditionalExpression,
+        ImportExpression,
+        LogicalExpression,
+        MemberExpression,
+        NewExpression,
+        ObjectExpression,
+        ParenthesizedExpression,
+        PrivateInExpression,
+        SequenceExpression,
+        TaggedTemplateExpression,
+        ThisExpression,
+        UnaryExpression,
+        UpdateExpression,
+        YieldExpression,
+        Property,
+        PropertyKey,
+        PropertyValue,
+        Argument,
+        AssignmentTarget,
+        SimpleAssignmentTarget,
+        AssignmentTargetWithDefault,
+        SpreadElement,
+        RestElement,
+        Function,
+        FunctionBody,
+        FormalParameters,
+        FormalParameter,
+        Class,
+        ClassHeritage,
+        StaticBlock,
+        PropertyDefinition,
+        MethodDefinition,
+        AccessorProperty,
+        ArrayPattern,
+        ObjectPattern,
+        AssignmentPattern,
+        Decorator,
+        ModuleDeclaration,
+        ExportSpecifier,
+        JSXOpeningElement,
+        JSXElementName,
+        TSModuleBlock,
+        TSAnyKeyword,
+        TSIntersectionType,
+        TSLiteralType,
+        TSMethodSignature,
+        TSNullKeyword,
+        TSTypeLiteral,
+        TSTypeReference,
+        TSTypeQuery,
+        TSImportType,
+        TSInferType,
+        TSTemplateLiteralType,
+        TSUnionType,
+        TSVoidKeyword,
+        TSIndexedAccessType,
+        TSAsExpression,
+        TSNonNullExpression,
+        TSEnumDeclaration,
+        TSEnumMember,
+        TSImportEqualsDeclaration,
+        TSInterfaceDeclaration,
+        TSInterfaceHeritage,
+        TSClassImplements,
+        TSModuleDeclaration,
+        TSTypeAliasDeclaration,
+        TSTypeAnnotation,
+        TSTypeAssertion,
+        TSTypeParameter,
+        TSTypeParameterDeclaration,
+        TSTypeParameterInstantiation,
+        TSTypeName,
+        TSQualifiedName,
+        TSPropertySignature,
+    )
+}
+
+#[derive(Debug)]
+pub struct PathError(String);
+
+impl fmt::Display for PathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for PathError {}
+
+impl ser::Error for PathError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self(msg.to_string())
+    }
+}
+
+type PathResult = Result<Option<AttributeValue>, PathError>;
+
+/// Serializer which only follows `path`, and returns the value at the end of it.
+#[derive(Clone, Copy)]
+struct PathSerializer<'p> {
+    path: &'p [String],
+}
+
+impl<'p> PathSerializer<'p> {
+    #[allow(clippy::unnecessary_wraps)]
+    fn scalar(self, value: AttributeValue) -> PathResult {
+        // Scalars have no fields
+        Ok(self.path.is_empty().then_some(value))
+    }
+
+    fn compound(self) -> Compound<'p> {
+        Compound {
+            path: self.path,
+            index: 0,
+            elements: vec![],
+            found: None,
+            key: None,
+            r#type: None,
+            start: None,
+            end: None,
+        }
+    }
+}
+
+macro_rules! serialize_number {
+    ($($method:ident: $ty:ty),*) => {
+        $(
+            #[allow(clippy::cast_lossless, clippy::cast_precision_loss)]
+            fn $method(self, v: $ty) -> PathResult {
+                self.scalar(AttributeValue::Number(v as f64))
+            }
+        )*
+    };
+}
+
+impl<'p> Serializer for PathSerializer<'p> {
+    type Error = PathError;
+    type Ok = Option<AttributeValue>;
+    type SerializeMap = Compound<'p>;
+    type SerializeSeq = Compound<'p>;
+    type SerializeStruct = Compound<'p>;
+    type SerializeStructVariant = Compound<'p>;
+    type SerializeTuple = Compound<'p>;
+    type SerializeTupleStruct = Compound<'p>;
+    type SerializeTupleVariant = Compound<'p>;
+
+    serialize_number!(
+        serialize_i8: i8,
+        serialize_i16: i16,
+        serialize_i32: i32,
+        serialize_i64: i64,
+        serialize_u8: u8,
+        serialize_u16: u16,
+        serialize_u32: u32,
+        serialize_u64: u64,
+        serialize_f32: f32,
+        serialize_f64: f64
+    );
+
+    fn serialize_bool(self, v: bool) -> PathResult {
+        self.scalar(AttributeValue::Bool(v))
+    }
+
+    fn serialize_char(self, v: char) -> PathResult {
+        self.scalar(AttributeValue::String(v.to_string()))
+    }
+
+    fn serialize_str(self, v: &str) -> PathResult {
+        self.scalar(AttributeValue::String(v.to_string()))
+    }
+
+    fn serialize_bytes(self, _v: &[u8]) -> PathResult {
+        Ok(None)
+    }
+
+    fn serialize_none(self) -> PathResult {
+        self.scalar(AttributeValue::Null)
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> PathResult {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> PathResult {
+        self.scalar(AttributeValue::Null)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> PathResult {
+        self.scalar(AttributeValue::Null)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> PathResult {
+        self.scalar(AttributeValue::String(variant.to_string()))
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> PathResult {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        value: &T,
+    ) -> PathResult {
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Compound<'p>, PathError> {
+        Ok(self.compound())
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Compound<'p>, PathError> {
+        Ok(self.compound())
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Compound<'p>, PathError> {
+        Ok(self.compound())
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Compound<'p>, PathError> {
+        Ok(self.compound())
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Compound<'p>, PathError> {
+        Ok(self.compound())
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Compound<'p>, PathError> {
+        Ok(self.compound())
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Compound<'p>, PathError> {
+        Ok(self.compound())
+    }
+}
+
+/// State for arrays and objects.
+struct Compound<'p> {
+    path: &'p [String],
+    /// Number of elements seen in an array
+    index: usize,
+    elements: Vec<AttributeValue>,
+    found: Option<AttributeValue>,
+    /// Pending key of a map entry
+    key: Option<String>,
+    r#type: Option<String>,
+    start: Option<f64>,
+    end: Option<f64>,
+}
+
+impl<'p> Compound<'p> {
+    fn element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), PathError> {
+        match self.path.first() {
+            None => {
+                let element = value.serialize(PathSerializer { path: &[] })?;
+                self.elements.push(element.unwrap_or(AttributeValue::Null));
+            }
+            Some(segment) if segment.parse::<usize>().ok() == Some(self.index) => {
+                self.found = value.serialize(PathSerializer { path: &self.path[1..] })?;
+            }
+            Some(_) => {}
+        }
+        self.index += 1;
+        Ok(())
+    }
+
+    fn field<T: ?Sized + Serialize>(&mut self, key: &str, value: &T) -> Result<(), PathError> {
+        let Some(segment) = self.path.first() else {
+            // Only the identifying fields of the object are needed at the end of the path
+            if !matches!(key, "type" | "start" | "end") {
+                return Ok(());
+            }
+            match (key, value.serialize(PathSerializer { path: &[] })?) {
+                ("type", Some(AttributeValue::String(s))) => self.r#type = Some(s),
+                ("start", Some(AttributeValue::Number(n))) => self.start = Some(n),
+                ("end", Some(AttributeValue::Number(n))) => self.end = Some(n),
+                _ => {}
+            }
+            return Ok(());
+        };
+        if self.found.is_none() && segment == key {
+            self.found = value.serialize(PathSerializer { path: &self.path[1..] })?;
+        }
+        Ok(())
+    }
+
+    #[allow(clippy::unnecessary_wraps)]
+    fn end_array(self) -> PathResult {
+        match self.path.first() {
+            None => Ok(Some(AttributeValue::Array(self.elements))),
+            #[allow(clippy::cast_precision_loss)]
+            Some(segment) if segment == "length" => {
+                Ok((self.path.len() == 1).then_some(AttributeValue::Number(self.index as f64)))
+            }
+            Some(_) => Ok(self.found),
+        }
+    }
+
+    #[allow(clippy::unnecessary_wraps)]
+    fn end_object(self) -> PathResult {
+        if self.path.is_empty() {
+            return Ok(Some(AttributeValue::Object {
+                r#type: self.r#type,
+                start: self.start,
+                end: self.end,
+            }));
+        }
+        Ok(self.found)
+    }
+}
+
+impl<'p> SerializeSeq for Compound<'p> {
+    type Error = PathError;
+    type Ok = Option<AttributeValue>;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), PathError> {
+        self.element(value)
+    }
+
+    fn end(self) -> PathResult {
+        self.end_array()
+    }
+}
+
+impl<'p> SerializeTuple for Compound<'p> {
+    type Error = PathError;
+    type Ok = Option<AttributeValue>;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), PathError> {
+        self.element(value)
+    }
+
+    fn end(self) -> PathResult {
+        self.end_array()
+    }
+}
+
+impl<'p> SerializeTupleStruct for Compound<'p> {
+    type Error = PathError;
+    type Ok = Option<AttributeValue>;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), PathError> {
+        self.element(value)
+    }
+
+    fn end(self) -> PathResult {
+        self.end_array()
+    }
+}
+
+impl<'p> SerializeTupleVariant for Compound<'p> {
+    type Error = PathError;
+    type Ok = Option<AttributeValue>;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), PathError> {
+        self.element(value)
+    }
+
+    fn end(self) -> PathResult {
+        self.end_array()
+    }
+}
+
+impl<'p> SerializeMap for Compound<'p> {
+    type Error = PathError;
+    type Ok = Option<AttributeValue>;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), PathError> {
+        self.key = match key.serialize(PathSerializer { path: &[] })? {
+            Some(AttributeValue::String(key)) => Some(key),
+            _ => None,
+        };
+        Ok(())
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), PathError> {
+        if let Some(key) = self.key.take() {
+            self.field(&key, value)?;
+        }
+        Ok(())
+    }
+
+    fn end(self) -> PathResult {
+        self.end_object()
+    }
+}
+
+impl<'p> SerializeStruct for Compound<'p> {
+    type Error = PathError;
+    type Ok = Option<AttributeValue>;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), PathError> {
+        self.field(key, value)
+    }
+
+    fn end(self) -> PathResult {
+        self.end_object()
+    }
+}
+
+impl<'p> SerializeStructVariant for Compound<'p> {
+    type Error = PathError;
+    type Ok = Option<AttributeValue>;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), PathError> {
+        self.field(key, value)
+    }
+
+    fn end(self) -> PathResult {
+        self.end_object()
+    }
+}
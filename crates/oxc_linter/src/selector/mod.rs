@@ -0,0 +1,499 @@
+//! [esquery](https://github.com/estools/esquery) selectors over [`AstNodes`]
+//!
+//! ```ignore
+//! let selector = Selector::parse("CallExpression[callee.property.name='then']").unwrap();
+//! if selector.matches(node, ctx.nodes()) { ... }
+//! ```
+//!
+//! Selectors see the `ESTree` shape of the AST:
+//! * types are matched by their `ESTree` name (`Identifier`, `Literal`, `FunctionDeclaration`) or
+//!   by their [`AstKind`] name (`IdentifierReference`, `StringLiteral`, `Function`)
+//! * attributes are read from the `ESTree` serialization, e.g. `[callee.property.name]`
+//! * nodes that only exist in our AST such as [`AstKind::Argument`] never match and are skipped
+//!   when looking at parents, children and siblings
+
+mod attribute;
+mod parser;
+
+use std::{cell::RefCell, rc::Rc};
+
+use indextree::NodeId;
+use oxc_ast::{
+    ast::{ClassType, FunctionType, ModuleDeclarationKind},
+    AstKind, GetSpan,
+};
+use oxc_semantic::AstNodes;
+use regex::Regex;
+use rustc_hash::FxHashMap;
+use thiserror::Error;
+
+pub use self::attribute::{get_attribute, AttributeValue};
+use self::parser::SelectorParser;
+use crate::AstNode;
+
+#[derive(Debug, Error)]
+#[error("Invalid selector {selector:?}: {message} at position {position}")]
+pub struct SelectorError {
+    pub selector: String,
+    pub message: &'static str,
+    pub position: usize,
+}
+
+#[derive(Debug, Clone)]
+pub enum Selector {
+    /// `*`
+    Wildcard,
+    /// `CallExpression`
+    Type(String),
+    /// `[callee.name="foo"]`
+    Attribute(AttributeSelector),
+    /// `.callee`, the node is at this path of its parent
+    Field(Vec<String>),
+    /// `CallExpression[optional=true]`, all of them match
+    Compound(Vec<Selector>),
+    /// `:matches(A, B)`, `:is(A, B)` and `A, B`
+    Matches(Vec<Selector>),
+    /// `:not(A, B)`
+    Not(Vec<Selector>),
+    /// `:has(A)`, `:has(> A)`
+    Has(Vec<(Combinator, Selector)>),
+    /// `:statement`, `:expression`, `:declaration`, `:function`, `:pattern`
+    Class(NodeClass),
+    /// `:nth-child(n)` and `:first-child`, starting from 1
+    NthChild(usize),
+    /// `:nth-last-child(n)` and `:last-child`, starting from 1
+    NthLastChild(usize),
+    /// `A B`, `A > B`, `A ~ B`, `A + B`, the right hand side is the subject
+    Combinator(Combinator, Box<Selector>, Box<Selector>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Combinator {
+    /// `A B`
+    Descendant,
+    /// `A > B`
+    Child,
+    /// `A ~ B`
+    Sibling,
+    /// `A + B`
+    Adjacent,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeClass {
+    Statement,
+    Expression,
+    Declaration,
+    Function,
+    Pattern,
+}
+
+#[derive(Debug, Clone)]
+pub struct AttributeSelector {
+    pub path: Vec<String>,
+    /// `None` for `[attr]`, which checks the attribute is not `null` or `undefined`
+    pub operator: Option<(AttributeOperator, AttributeTarget)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttributeOperator {
+    Equal,
+    NotEqual,
+    LessThan,
+    LessThanEqual,
+    GreaterThan,
+    GreaterThanEqual,
+}
+
+impl AttributeOperator {
+    fn is_equality(self) -> bool {
+        matches!(self, Self::Equal | Self::NotEqual)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum AttributeTarget {
+    /// `"foo"`, `foo` or `true`, compared as strings
+    Literal(String),
+    Number(f64),
+    /// `/foo/i`
+    Regex(Regex),
+    /// `type(string)`, compared with the JavaScript `typeof` the value
+    Type(String),
+}
+
+impl Selector {
+    /// # Errors
+    /// When the selector is not a valid esquery selector.
+    pub fn parse(source: &str) -> Result<Self, SelectorError> {
+        SelectorParser::new(source).parse()
+    }
+
+    /// Whether `node` is matched by this selector.
+    /// Use [`Selector::select`] for matching all nodes, it shares the work between the nodes.
+    #[must_use]
+    pub fn matches<'a>(&self, node: &AstNode<'a>, nodes: &AstNodes<'a>) -> bool {
+        let cx = MatchContext::new(nodes);
+        nodes.get_node_id(node).is_some_and(|id| self.matches_id(id, &cx))
+    }
+
+    /// All nodes matched by this selector, in source order.
+    #[must_use]
+    pub fn select(&self, nodes: &AstNodes) -> Vec<NodeId> {
+        let cx = MatchContext::new(nodes);
+        nodes
+            .iter()
+            .filter_map(|node| nodes.get_node_id(node))
+            .filter(|id| self.matches_id(*id, &cx))
+            .collect()
+    }
+
+    fn matches_id(&self, id: NodeId, cx: &MatchContext) -> bool {
+        let nodes = cx.nodes;
+        if is_transparent(id, nodes) {
+            return false;
+        }
+        let kind = nodes.kind(id);
+        match self {
+            Self::Wildcard => true,
+            Self::Type(name) => is_type(kind, name),
+            Self::Attribute(attribute) => attribute.matches(kind),
+            Self::Field(path) => parent(id, nodes).is_some_and(|parent_id| {
+                match get_attribute(nodes.kind(parent_id), path) {
+                    Some(AttributeValue::Array(elements)) => {
+                        elements.iter().any(|element| is_same_node(kind, element))
+                    }
+                    Some(value) => is_same_node(kind, &value),
+                    None => false,
+                }
+            }),
+            Self::Compound(selectors) => selectors.iter().all(|s| s.matches_id(id, cx)),
+            Self::Matches(selectors) => selectors.iter().any(|s| s.matches_id(id, cx)),
+            Self::Not(selectors) => !selectors.iter().any(|s| s.matches_id(id, cx)),
+            Self::Has(selectors) => selectors.iter().any(|(combinator, selector)| {
+                if *combinator == Combinator::Child {
+                    children(id, nodes).into_iter().any(|child| selector.matches_id(child, cx))
+                } else {
+                    id.descendants(nodes).skip(1).any(|d| selector.matches_id(d, cx))
+                }
+            }),
+            Self::Class(class) => class.matches(kind),
+            Self::NthChild(n) => cx.siblings(id, |_, index| index + 1 == *n),
+            Self::NthLastChild(n) => {
+                cx.siblings(id, |siblings, index| siblings.len() - index == *n)
+            }
+            Self::Combinator(combinator, left, right) => {
+                if !right.matches_id(id, cx) {
+                    return false;
+                }
+                match combinator {
+                    Combinator::Child => {
+                        parent(id, nodes).is_some_and(|parent_id| left.matches_id(parent_id, cx))
+                    }
+                    Combinator::Descendant => {
+                        let mut current = id;
+                        while let Some(parent_id) = parent(current, nodes) {
+                            if left.matches_id(parent_id, cx) {
+                                return true;
+                            }
+                            current = parent_id;
+                        }
+                        false
+                    }
+                    Combinator::Sibling => cx.siblings(id, |siblings, index| {
+                        siblings[..index].iter().any(|sibling| left.matches_id(*sibling, cx))
+                    }),
+                    Combinator::Adjacent => cx.siblings(id, |siblings, index| {
+                        index > 0 && left.matches_id(siblings[index - 1], cx)
+                    }),
+                }
+            }
+        }
+    }
+}
+
+impl AttributeSelector {
+    fn matches(&self, kind: AstKind) -> bool {
+        let value = get_attribute(kind, &self.path);
+        let Some((operator, target)) = &self.operator else {
+            return !matches!(value, None | Some(AttributeValue::Null));
+        };
+        match operator {
+            AttributeOperator::Equal => self.equals(value.as_ref(), target),
+            AttributeOperator::NotEqual => !self.equals(value.as_ref(), target),
+            _ => {
+                let (Some(AttributeValue::Number(value)), AttributeTarget::Number(target)) =
+                    (value, target) else { return false };
+                match operator {
+                    AttributeOperator::LessThan => value < *target,
+                    AttributeOperator::LessThanEqual => value <= *target,
+                    AttributeOperator::GreaterThan => value > *target,
+                    AttributeOperator::GreaterThanEqual => value >= *target,
+                    AttributeOperator::Equal | AttributeOperator::NotEqual => unreachable!(),
+                }
+            }
+        }
+    }
+
+    fn equals(&self, value: Option<&AttributeValue>, target: &AttributeTarget) -> bool {
+        match target {
+            AttributeTarget::Type(ty) => js_typeof(value) == ty,
+            AttributeTarget::Regex(regex) => match value {
+                Some(AttributeValue::String(s)) => regex.is_match(s),
+                _ => false,
+            },
+            AttributeTarget::Literal(_) | AttributeTarget::Number(_) => {
+                let Some(value) = value.and_then(AttributeValue::to_js_string) else {
+                    return false;
+                };
+                let target = match target {
+                    AttributeTarget::Literal(s) => s.clone(),
+                    AttributeTarget::Number(n) => {
+                        AttributeValue::Number(*n).to_js_string().unwrap()
+                    }
+                    _ => unreachable!(),
+                };
+                // `[callee.type="Identifier"]` should also match `IdentifierReference`
+                value == target
+                    || (self.path.last().is_some_and(|s| s == "type")
+                        && estree_type_name(&value) == target)
+            }
+        }
+    }
+}
+
+impl NodeClass {
+    fn matches(self, kind: AstKind) -> bool {
+        let ty = estree_type(kind);
+        match self {
+            Self::Statement => ty.ends_with("Statement") || ty.ends_with("Declaration"),
+            Self::Declaration => ty.ends_with("Declaration"),
+            Self::Function => kind.is_function_like(),
+            Self::Expression => is_expression(kind),
+            Self::Pattern => {
+                ty.ends_with("Pattern")
+                    || matches!(kind, AstKind::BindingIdentifier(_) | AstKind::RestElement(_))
+                    || is_expression(kind)
+            }
+        }
+    }
+}
+
+fn is_expression(kind: AstKind) -> bool {
+    let ty = estree_type(kind);
+    (ty.ends_with("Expression") && !ty.starts_with("TS"))
+        || ty == "Literal"
+        || matches!(
+            kind,
+            AstKind::IdentifierReference(_)
+                | AstKind::TemplateLiteral(_)
+                | AstKind::MetaProperty(_)
+                | AstKind::Super(_)
+        )
+}
+
+fn js_typeof(value: Option<&AttributeValue>) -> &'static str {
+    match value {
+        None => "undefined",
+        Some(AttributeValue::String(_)) => "string",
+        Some(AttributeValue::Number(_)) => "number",
+        Some(AttributeValue::Bool(_)) => "boolean",
+        Some(AttributeValue::Null | AttributeValue::Object { .. } | AttributeValue::Array(_)) => {
+            "object"
+        }
+    }
+}
+
+fn is_type(kind: AstKind, name: &str) -> bool {
+    kind.debug_name().eq_ignore_ascii_case(name) || estree_type(kind).eq_ignore_ascii_case(name)
+}
+
+/// Whether `kind` is the node described by `value`
+fn is_same_node(kind: AstKind, value: &AttributeValue) -> bool {
+    let AttributeValue::Object { r#type: Some(ty), start, end } = value else { return false };
+    let span = kind.span();
+    is_type(kind, ty) && *start == Some(f64::from(span.start)) && *end == Some(f64::from(span.end))
+}
+
+/// Nodes which do not exist in `ESTree`, they are replaced by their children.
+fn is_transparent(id: NodeId, nodes: &AstNodes) -> bool {
+    let kind = nodes.kind(id);
+    if matches!(kind, AstKind::FunctionBody(_)) {
+        // The body of `() => x` is the expression `x`
+        let parent = id.ancestors(nodes).nth(1).map(|parent| nodes.kind(parent));
+        return matches!(parent, Some(AstKind::ArrowExpression(arrow)) if arrow.expression);
+    }
+    matches!(
+        kind,
+        AstKind::Root
+            | AstKind::Argument(_)
+            | AstKind::PropertyKey(_)
+            | AstKind::PropertyValue(_)
+            | AstKind::AssignmentTarget(_)
+            | AstKind::SimpleAssignmentTarget(_)
+            | AstKind::ForStatementInit(_)
+            | AstKind::ClassHeritage(_)
+            | AstKind::FormalParameters(_)
+            | AstKind::FormalParameter(_)
+            | AstKind::JSXElementName(_)
+    )
+}
+
+/// `ESTree` type of the node
+fn estree_type(kind: AstKind) -> &'static str {
+    match kind {
+        AstKind::IdentifierName(_)
+        | AstKind::IdentifierReference(_)
+        | AstKind::BindingIdentifier(_)
+        | AstKind::LabelIdentifier(_) => "Identifier",
+        AstKind::NumberLiteral(_)
+        | AstKind::StringLiteral(_)
+        | AstKind::BooleanLiteral(_)
+        | AstKind::NullLiteral(_)
+        | AstKind::BigintLiteral(_)
+        | AstKind::RegExpLiteral(_) => "Literal",
+        AstKind::ArrowExpression(_) => "ArrowFunctionExpression",
+        AstKind::FunctionBody(_) | AstKind::FinallyClause(_) => "BlockStatement",
+        AstKind::AssignmentTargetWithDefault(_) => "AssignmentPattern",
+        AstKind::Function(func) => match func.r#type {
+            FunctionType::FunctionDeclaration => "FunctionDeclaration",
+            FunctionType::FunctionExpression => "FunctionExpression",
+            FunctionType::TSDeclareFunction => "TSDeclareFunction",
+        },
+        AstKind::Class(class) => match class.r#type {
+            ClassType::ClassDeclaration => "ClassDeclaration",
+            ClassType::ClassExpression => "ClassExpression",
+        },
+        AstKind::ModuleDeclaration(decl) => match decl.kind {
+            ModuleDeclarationKind::ImportDeclaration(_) => "ImportDeclaration",
+            ModuleDeclarationKind::ExportAllDeclaration(_) => "ExportAllDeclaration",
+            ModuleDeclarationKind::ExportDefaultDeclaration(_) => "ExportDefaultDeclaration",
+            ModuleDeclarationKind::ExportNamedDeclaration(_) => "ExportNamedDeclaration",
+            ModuleDeclarationKind::TSExportAssignment(_) => "TSExportAssignment",
+            ModuleDeclarationKind::TSNamespaceExportDeclaration(_) => {
+                "TSNamespaceExportDeclaration"
+            }
+        },
+        _ => kind.debug_name(),
+    }
+}
+
+/// `ESTree` type for the `type` field of a serialized node
+fn estree_type_name(name: &str) -> &str {
+    match name {
+        "IdentifierName" | "IdentifierReference" | "BindingIdentifier" | "LabelIdentifier" => {
+            "Identifier"
+        }
+        "NumberLiteral" | "StringLiteral" | "BooleanLiteral" | "NullLiteral" | "BigintLiteral"
+        | "RegExpLiteral" => "Literal",
+        "ArrowExpression" => "ArrowFunctionExpression",
+        _ => name,
+    }
+}
+
+fn parent(id: NodeId, nodes: &AstNodes) -> Option<NodeId> {
+    id.ancestors(nodes).skip(1).find(|id| !is_transparent(*id, nodes))
+}
+
+fn children(id: NodeId, nodes: &AstNodes) -> Vec<NodeId> {
+    let mut children = vec![];
+    for child in id.children(nodes) {
+        if is_transparent(child, nodes) {
+            children.extend(self::children(child, nodes));
+        } else {
+            children.push(child);
+        }
+    }
+    children
+}
+
+/// `ESTree` fields which hold a list of nodes
+const LIST_FIELDS: [&str; 16] = [
+    "body",
+    "arguments",
+    "params",
+    "elements",
+    "properties",
+    "expressions",
+    "quasis",
+    "declarations",
+    "consequent",
+    "cases",
+    "specifiers",
+    "decorators",
+    "members",
+    "types",
+    "extends",
+    "implements",
+];
+
+/// Siblings are the nodes in the same list field of the parent, e.g. the `arguments` of a call.
+struct SiblingLists {
+    lists: Vec<Vec<NodeId>>,
+    /// The list and the index in it of each child in a list
+    positions: FxHashMap<NodeId, (usize, usize)>,
+}
+
+impl SiblingLists {
+    fn new(parent: NodeId, nodes: &AstNodes) -> Self {
+        let parent_kind = nodes.kind(parent);
+        let children = children(parent, nodes);
+        let mut lists = vec![];
+        let mut positions = FxHashMap::default();
+        for field in LIST_FIELDS {
+            let Some(AttributeValue::Array(elements)) =
+                get_attribute(parent_kind, &[field.to_string()]) else { continue };
+            let mut elements_by_span: FxHashMap<(u64, u64), Vec<&AttributeValue>> =
+                FxHashMap::default();
+            for element in &elements {
+                if let AttributeValue::Object { start: Some(start), end: Some(end), .. } = element {
+                    elements_by_span
+                        .entry((start.to_bits(), end.to_bits()))
+                        .or_default()
+                        .push(element);
+                }
+            }
+            let mut list = vec![];
+            for child in &children {
+                let kind = nodes.kind(*child);
+                let span = kind.span();
+                let key = (f64::from(span.start).to_bits(), f64::from(span.end).to_bits());
+                if elements_by_span.get(&key).is_some_and(|elements| {
+                    elements.iter().any(|element| is_same_node(kind, element))
+                }) {
+                    positions.entry(*child).or_insert((lists.len(), list.len()));
+                    list.push(*child);
+                }
+            }
+            lists.push(list);
+        }
+        Self { lists, positions }
+    }
+}
+
+/// The state shared by matching a selector against several nodes
+struct MatchContext<'s, 'a> {
+    nodes: &'s AstNodes<'a>,
+
+    /// Keyed by parent, each parent is serialized once for all of its children
+    siblings: RefCell<FxHashMap<NodeId, Rc<SiblingLists>>>,
+}
+
+impl<'s, 'a> MatchContext<'s, 'a> {
+    fn new(nodes: &'s AstNodes<'a>) -> Self {
+        Self { nodes, siblings: RefCell::default() }
+    }
+
+    /// Call `f` with the siblings including the node itself and the index of the node,
+    /// `false` if the node is not in a list.
+    fn siblings(&self, id: NodeId, f: impl FnOnce(&[NodeId], usize) -> bool) -> bool {
+        let Some(parent) = parent(id, self.nodes) else { return false };
+        let lists = Rc::clone(
+            self.siblings
+                .borrow_mut()
+                .entry(parent)
+                .or_insert_with(|| Rc::new(SiblingLists::new(parent, self.nodes))),
+        );
+        lists.positions.get(&id).is_some_and(|(list, index)| f(&lists.lists[*list], *index))
+    }
+}
@@ -0,0 +1,365 @@
+//! Parser for esquery selectors
+//!
+//! Grammar: <https://github.com/estools/esquery/blob/master/grammar.pegjs>
+
+use regex::Regex;
+
+use super::{
+    AttributeOperator, AttributeSelector, AttributeTarget, Combinator, NodeClass, Selector,
+    SelectorError,
+};
+
+pub struct SelectorParser<'s> {
+    source: &'s str,
+    pos: usize,
+}
+
+impl<'s> SelectorParser<'s> {
+    pub fn new(source: &'s str) -> Self {
+        Self { source, pos: 0 }
+    }
+
+    pub fn parse(mut self) -> Result<Selector, SelectorError> {
+        let selector = self.parse_selectors()?;
+        self.skip_whitespace();
+        if self.pos < self.source.len() {
+            return Err(self.error("unexpected character"));
+        }
+        Ok(selector)
+    }
+
+    fn error(&self, message: &'static str) -> SelectorError {
+        SelectorError { selector: self.source.to_string(), message, position: self.pos }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.source[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            return true;
+        }
+        false
+    }
+
+    fn expect(&mut self, c: char, message: &'static str) -> Result<(), SelectorError> {
+        if self.eat(c) { Ok(()) } else { Err(self.error(message)) }
+    }
+
+    fn skip_whitespace(&mut self) -> bool {
+        let start = self.pos;
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.bump();
+        }
+        self.pos > start
+    }
+
+    /// selectors = selector ("," selector)*
+    fn parse_selectors(&mut self) -> Result<Selector, SelectorError> {
+        let mut selectors = self.parse_selector_list()?;
+        if selectors.len() == 1 {
+            return Ok(selectors.remove(0));
+        }
+        Ok(Selector::Matches(selectors))
+    }
+
+    fn parse_selector_list(&mut self) -> Result<Vec<Selector>, SelectorError> {
+        let mut selectors = vec![];
+        loop {
+            self.skip_whitespace();
+            selectors.push(self.parse_selector()?);
+            self.skip_whitespace();
+            if !self.eat(',') {
+                return Ok(selectors);
+            }
+        }
+    }
+
+    /// selector = sequence (combinator sequence)*
+    fn parse_selector(&mut self) -> Result<Selector, SelectorError> {
+        let mut left = self.parse_sequence()?;
+        loop {
+            let Some(combinator) = self.parse_combinator() else { return Ok(left) };
+            let right = self.parse_sequence()?;
+            left = Selector::Combinator(combinator, Box::new(left), Box::new(right));
+        }
+    }
+
+    fn parse_combinator(&mut self) -> Option<Combinator> {
+        let start = self.pos;
+        let has_whitespace = self.skip_whitespace();
+        let combinator = match self.peek() {
+            Some('>') => Combinator::Child,
+            Some('~') => Combinator::Sibling,
+            Some('+') => Combinator::Adjacent,
+            Some(',' | ')') | None => {
+                self.pos = start;
+                return None;
+            }
+            _ if has_whitespace => return Some(Combinator::Descendant),
+            _ => {
+                self.pos = start;
+                return None;
+            }
+        };
+        self.bump();
+        self.skip_whitespace();
+        Some(combinator)
+    }
+
+    /// sequence = atom+
+    fn parse_sequence(&mut self) -> Result<Selector, SelectorError> {
+        let mut atoms = vec![];
+        while let Some(atom) = self.parse_atom()? {
+            atoms.push(atom);
+        }
+        match atoms.len() {
+            0 => Err(self.error("expected a selector")),
+            1 => Ok(atoms.remove(0)),
+            _ => Ok(Selector::Compound(atoms)),
+        }
+    }
+
+    fn parse_atom(&mut self) -> Result<Option<Selector>, SelectorError> {
+        let Some(c) = self.peek() else { return Ok(None) };
+        let atom = match c {
+            '*' => {
+                self.bump();
+                Selector::Wildcard
+            }
+            '[' => {
+                self.bump();
+                Selector::Attribute(self.parse_attribute()?)
+            }
+            '.' => {
+                self.bump();
+                Selector::Field(self.parse_path()?)
+            }
+            ':' => {
+                self.bump();
+                self.parse_pseudo()?
+            }
+            c if is_identifier_char(c) => Selector::Type(self.parse_identifier()?.to_string()),
+            _ => return Ok(None),
+        };
+        Ok(Some(atom))
+    }
+
+    fn parse_identifier(&mut self) -> Result<&'s str, SelectorError> {
+        let start = self.pos;
+        while self.peek().is_some_and(is_identifier_char) {
+            self.bump();
+        }
+        if start == self.pos {
+            return Err(self.error("expected an identifier"));
+        }
+        Ok(&self.source[start..self.pos])
+    }
+
+    /// path = identifier ("." identifier)*
+    fn parse_path(&mut self) -> Result<Vec<String>, SelectorError> {
+        let mut path = vec![self.parse_identifier()?.to_string()];
+        while self.eat('.') {
+            path.push(self.parse_identifier()?.to_string());
+        }
+        Ok(path)
+    }
+
+    /// attribute = "[" path (operator value)? "]"
+    fn parse_attribute(&mut self) -> Result<AttributeSelector, SelectorError> {
+        self.skip_whitespace();
+        let path = self.parse_path()?;
+        self.skip_whitespace();
+        if self.eat(']') {
+            return Ok(AttributeSelector { path, operator: None });
+        }
+        let operator = if self.eat('=') {
+            AttributeOperator::Equal
+        } else if self.eat('!') {
+            self.expect('=', "expected `=` after `!`")?;
+            AttributeOperator::NotEqual
+        } else if self.eat('<') {
+            if self.eat('=') {
+                AttributeOperator::LessThanEqual
+            } else {
+                AttributeOperator::LessThan
+            }
+        } else if self.eat('>') {
+            if self.eat('=') {
+                AttributeOperator::GreaterThanEqual
+            } else {
+                AttributeOperator::GreaterThan
+            }
+        } else {
+            return Err(self.error("expected an attribute operator"));
+        };
+        self.skip_whitespace();
+        let target = self.parse_attribute_target(operator)?;
+        self.skip_whitespace();
+        self.expect(']', "expected `]`")?;
+        Ok(AttributeSelector { path, operator: Some((operator, target)) })
+    }
+
+    fn parse_attribute_target(
+        &mut self,
+        operator: AttributeOperator,
+    ) -> Result<AttributeTarget, SelectorError> {
+        let target = match self.peek() {
+            Some(quote @ ('"' | '\'')) => {
+                self.bump();
+                AttributeTarget::Literal(self.parse_string(quote)?)
+            }
+            Some('/') if operator.is_equality() => {
+                self.bump();
+                AttributeTarget::Regex(self.parse_regex()?)
+            }
+            Some(c) if c.is_ascii_digit() || c == '-' || c == '.' => {
+                let start = self.pos;
+                self.bump();
+                while self.peek().is_some_and(|c| c.is_ascii_digit() || c == '.') {
+                    self.bump();
+                }
+                let number = self.source[start..self.pos]
+                    .parse::<f64>()
+                    .map_err(|_| self.error("invalid number"))?;
+                AttributeTarget::Number(number)
+            }
+            _ => {
+                let name = self.parse_path()?.join(".");
+                if name == "type" && self.eat('(') && operator.is_equality() {
+                    self.skip_whitespace();
+                    let ty = self.parse_identifier()?.to_string();
+                    self.skip_whitespace();
+                    self.expect(')', "expected `)`")?;
+                    AttributeTarget::Type(ty)
+                } else {
+                    AttributeTarget::Literal(name)
+                }
+            }
+        };
+        if !operator.is_equality() && !matches!(target, AttributeTarget::Number(_)) {
+            return Err(self.error("expected a number for a comparison operator"));
+        }
+        Ok(target)
+    }
+
+    fn parse_string(&mut self, quote: char) -> Result<String, SelectorError> {
+        let mut value = String::new();
+        loop {
+            match self.bump() {
+                Some('\\') => match self.bump() {
+                    Some('n') => value.push('\n'),
+                    Some('r') => value.push('\r'),
+                    Some('t') => value.push('\t'),
+                    Some(c) => value.push(c),
+                    None => return Err(self.error("unterminated string")),
+                },
+                Some(c) if c == quote => return Ok(value),
+                Some(c) => value.push(c),
+                None => return Err(self.error("unterminated string")),
+            }
+        }
+    }
+
+    fn parse_regex(&mut self) -> Result<Regex, SelectorError> {
+        let mut pattern = String::new();
+        loop {
+            match self.bump() {
+                Some('\\') => {
+                    pattern.push('\\');
+                    pattern.push(self.bump().ok_or_else(|| self.error("unterminated regex"))?);
+                }
+                Some('/') => break,
+                Some(c) => pattern.push(c),
+                None => return Err(self.error("unterminated regex")),
+            }
+        }
+        let mut flags = String::new();
+        while let Some(c @ ('i' | 'm' | 's' | 'u')) = self.peek() {
+            self.bump();
+            if c != 'u' {
+                flags.push(c);
+            }
+        }
+        let pattern = if flags.is_empty() { pattern } else { format!("(?{flags}){pattern}") };
+        Regex::new(&pattern).map_err(|_| self.error("invalid regex"))
+    }
+
+    fn parse_pseudo(&mut self) -> Result<Selector, SelectorError> {
+        let name = self.parse_identifier()?.to_ascii_lowercase();
+        let selector = match name.as_str() {
+            "not" | "matches" | "is" | "has" => {
+                self.expect('(', "expected `(`")?;
+                let selector = if name == "has" {
+                    Selector::Has(self.parse_relative_selector_list()?)
+                } else {
+                    let selectors = self.parse_selector_list()?;
+                    if name == "not" {
+                        Selector::Not(selectors)
+                    } else {
+                        Selector::Matches(selectors)
+                    }
+                };
+                self.skip_whitespace();
+                self.expect(')', "expected `)`")?;
+                selector
+            }
+            "nth-child" | "nth-last-child" => {
+                self.expect('(', "expected `(`")?;
+                self.skip_whitespace();
+                let start = self.pos;
+                while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+                    self.bump();
+                }
+                let index = self.source[start..self.pos]
+                    .parse::<usize>()
+                    .map_err(|_| self.error("expected a positive integer"))?;
+                self.skip_whitespace();
+                self.expect(')', "expected `)`")?;
+                if name == "nth-child" {
+                    Selector::NthChild(index)
+                } else {
+                    Selector::NthLastChild(index)
+                }
+            }
+            "first-child" => Selector::NthChild(1),
+            "last-child" => Selector::NthLastChild(1),
+            "statement" => Selector::Class(NodeClass::Statement),
+            "expression" => Selector::Class(NodeClass::Expression),
+            "declaration" => Selector::Class(NodeClass::Declaration),
+            "function" => Selector::Class(NodeClass::Function),
+            "pattern" => Selector::Class(NodeClass::Pattern),
+            _ => return Err(self.error("unknown pseudo selector")),
+        };
+        Ok(selector)
+    }
+
+    /// Selectors inside `:has()` may start with a combinator, e.g. `:has(> Identifier)`
+    fn parse_relative_selector_list(
+        &mut self,
+    ) -> Result<Vec<(Combinator, Selector)>, SelectorError> {
+        let mut selectors = vec![];
+        loop {
+            self.skip_whitespace();
+            let combinator = if self.eat('>') { Combinator::Child } else { Combinator::Descendant };
+            self.skip_whitespace();
+            selectors.push((combinator, self.parse_selector()?));
+            self.skip_whitespace();
+            if !self.eat(',') {
+                return Ok(selectors);
+            }
+        }
+    }
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '$' || c == '-'
+}
@@ -4,7 +4,6 @@ use oxc_allocator::Allocator;
 use oxc_ast::SourceType;
 use oxc_diagnostics::miette::{GraphicalReportHandler, GraphicalTheme, NamedSource};
 use oxc_parser::Parser;
-use oxc_semantic::SemanticBuilder;
 use serde_json::Value;
 
 use crate::{rules::RULES, Linter};
@@ -77,15 +76,17 @@ impl Tester {
         assert!(ret.errors.is_empty(), "{:?}", &ret.errors);
         let program = allocator.alloc(ret.program);
         let semantic_ret =
-            SemanticBuilder::new(source_text, source_type, &ret.trivias).build(program);
+            Linter::semantic_builder(source_text, source_type, &ret.trivias).build(program);
         assert!(semantic_ret.errors.is_empty(), "{:?}", &semantic_ret.errors);
         let rule = RULES
             .iter()
             .find(|rule| rule.name() == self.rule_name)
             .unwrap_or_else(|| panic!("Rule not found: {}", &self.rule_name));
         let rule = rule.read_json(config);
-        let result =
-            Linter::from_rules(vec![rule]).with_fix(false).run(&Rc::new(semantic_ret.semantic));
+        let result = Linter::from_rules(vec![rule])
+            .with_fix(false)
+            .run(&Rc::new(semantic_ret.semantic))
+            .unwrap();
         if result.is_empty() {
             return true;
         }
@@ -0,0 +1,69 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Fields, Ident};
+
+pub fn derive_clone_in(input: &DeriveInput) -> TokenStream {
+    let name = &input.ident;
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let (pattern, clone) = clone_fields(&data.fields);
+            quote! {
+                let Self #pattern = self;
+                Self #clone
+            }
+        }
+        Data::Enum(data) => {
+            let arms = data.variants.iter().map(|variant| {
+                let variant_name = &variant.ident;
+                let (pattern, clone) = clone_fields(&variant.fields);
+                quote! { Self::#variant_name #pattern => Self::#variant_name #clone }
+            });
+            quote! {
+                match self {
+                    #(#arms),*
+                }
+            }
+        }
+        Data::Union(_) => panic!("`CloneIn` cannot be derived for unions"),
+    };
+
+    // AST nodes have at most one lifetime, the lifetime of the allocator
+    let (lifetime, type_generics) = input.generics.lifetimes().next().map_or_else(
+        || (quote! { 'alloc }, quote! {}),
+        |def| {
+            let lifetime = &def.lifetime;
+            (quote! { #lifetime }, quote! { <#lifetime> })
+        },
+    );
+
+    quote! {
+        impl<#lifetime> crate::CloneIn<#lifetime> for #name #type_generics {
+            #[allow(unused_variables)]
+            fn clone_in(&self, allocator: &#lifetime oxc_allocator::Allocator) -> Self {
+                #body
+            }
+        }
+    }
+}
+
+/// The pattern binding all the fields, and the expression cloning them
+fn clone_fields(fields: &Fields) -> (TokenStream, TokenStream) {
+    let clone = |binding: &Ident| quote! { crate::CloneIn::clone_in(#binding, allocator) };
+    match fields {
+        Fields::Named(fields) => {
+            let names = fields.named.iter().filter_map(|field| field.ident.as_ref());
+            let clones = names.clone().map(|name| {
+                let value = clone(name);
+                quote! { #name: #value }
+            });
+            (quote! { { #(#names),* } }, quote! { { #(#clones),* } })
+        }
+        Fields::Unnamed(fields) => {
+            let bindings =
+                (0..fields.unnamed.len()).map(|i| format_ident!("field_{i}")).collect::<Vec<_>>();
+            let clones = bindings.iter().map(clone);
+            (quote! { ( #(#bindings),* ) }, quote! { ( #(#clones),* ) })
+        }
+        Fields::Unit => (quote! {}, quote! {}),
+    }
+}
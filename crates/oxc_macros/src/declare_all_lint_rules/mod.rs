@@ -87,6 +87,12 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
                 }
             }
 
+            pub fn validate_json(&self, value: &serde_json::Value) -> Result<(), String> {
+                match self {
+                    #(Self::#struct_names(_) => #struct_names::validate_configuration(value)),*
+                }
+            }
+
             pub fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
                 match self {
                     #(Self::#struct_names(rule) => rule.run(node, ctx)),*
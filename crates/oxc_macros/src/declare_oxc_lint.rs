@@ -58,6 +58,7 @@ pub fn declare_oxc_lint(metadata: LintRuleMeta) -> TokenStream {
     let canonical_name = name.to_string().to_case(Case::Kebab);
     let category = match category.to_string().as_str() {
         "correctness" => quote! { RuleCategory::Correctness },
+        "restriction" => quote! { RuleCategory::Restriction },
         "nursery" => quote! { RuleCategory::Nursery },
         _ => panic!("invalid rule category"),
     };
@@ -1,5 +1,6 @@
-use syn::parse_macro_input;
+use syn::{parse_macro_input, DeriveInput};
 
+mod clone_in;
 mod declare_all_lint_rules;
 mod declare_oxc_lint;
 
@@ -58,3 +59,11 @@ pub fn declare_all_lint_rules(input: proc_macro::TokenStream) -> proc_macro::Tok
 
     declare_all_lint_rules::declare_all_lint_rules(metadata).into()
 }
+
+/// Derive `CloneIn` for the AST nodes of `oxc_ast`, cloning each field into an allocator
+#[proc_macro_derive(CloneIn)]
+pub fn derive_clone_in(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    clone_in::derive_clone_in(&input).into()
+}
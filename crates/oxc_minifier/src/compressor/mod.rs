@@ -53,6 +53,7 @@ impl<'a> Compressor<'a> {
     fn create_void_0(&self) -> Expression<'a> {
         let num = self.ast.literal_number_expression(NumberLiteral::new(
             SPAN,
+            self.ast.node_id(),
             0.0,
             "0",
             NumberBase::Decimal,
@@ -148,6 +149,7 @@ impl<'a> Compressor<'a> {
         && self.options.booleans {
             let num = self.ast.literal_number_expression(NumberLiteral::new(
                 SPAN,
+                self.ast.node_id(),
                 if lit.value { 0.0 } else { 1.0 },
                 if lit.value { "0" } else { "1" },
                 NumberBase::Decimal,
@@ -171,7 +173,7 @@ impl<'a> Compressor<'a> {
             let left = self.ast.identifier_expression((*ident).clone());
             let right = self.create_void_0();
             let operator = BinaryOperator::StrictEquality;
-            *expr = BinaryExpression {span: SPAN, left, operator, right};
+            *expr = BinaryExpression {span: SPAN, node_id: expr.node_id, left, operator, right};
             return true
         }
         false
@@ -0,0 +1,2 @@
+import "./d.js";
+import "./a.js";
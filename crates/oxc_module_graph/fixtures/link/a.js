@@ -0,0 +1,3 @@
+export const a = 1;
+export { b } from "./b.js";
+export { default as c } from "./d.js";
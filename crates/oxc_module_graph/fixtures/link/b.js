@@ -0,0 +1 @@
+export let b = 1;
@@ -0,0 +1,6 @@
+import { a, b, c, missing } from "./a.js";
+import d from "./d.js";
+import * as ns from "./a.js";
+import { x, y, ambiguous } from "./star.js";
+import data from "./data.json";
+import { z } from "./not-found.js";
@@ -0,0 +1,2 @@
+export * from "./x.js";
+export * from "./y.js";
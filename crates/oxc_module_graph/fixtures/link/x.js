@@ -0,0 +1,3 @@
+export const x = 1;
+export const ambiguous = 1;
+export { b as y } from "./b.js";
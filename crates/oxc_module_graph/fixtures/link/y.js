@@ -0,0 +1,3 @@
+export const ambiguous = 2;
+export { b as y } from "./b.js";
+export * from "./star.js";
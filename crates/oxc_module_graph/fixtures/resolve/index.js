@@ -0,0 +1,3 @@
+import { readFile } from "node:fs";
+import path from "path";
+import broken from "broken";
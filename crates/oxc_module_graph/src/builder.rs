@@ -0,0 +1,146 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use oxc_allocator::Allocator;
+use oxc_ast::{module_record::ModuleRecord, Atom, SourceType};
+use oxc_diagnostics::{miette::NamedSource, Error};
+use oxc_parser::Parser;
+use oxc_semantic::module_record::ModuleRecordBuilder;
+use rayon::prelude::*;
+use rustc_hash::FxHashMap;
+
+use crate::{
+    diagnostics::{ReadFile, ResolveModule, UnresolvedModule},
+    Module, ModuleGraph, ModuleId, Resolution, Resolver,
+};
+
+pub struct ModuleGraphBuilder<'r, R: Resolver> {
+    resolver: &'r R,
+}
+
+pub struct ModuleGraphBuilderReturn {
+    pub graph: ModuleGraph,
+    pub errors: Vec<Error>,
+}
+
+/// A parsed file with its module requests resolved to paths
+struct LoadedModule {
+    source_type: Option<SourceType>,
+    source_text: String,
+    module_record: ModuleRecord,
+    dependencies: Vec<(Atom, PathBuf)>,
+    errors: Vec<Error>,
+}
+
+impl<'r, R: Resolver> ModuleGraphBuilder<'r, R> {
+    #[must_use]
+    pub fn new(resolver: &'r R) -> Self {
+        Self { resolver }
+    }
+
+    /// Load the entries and all of their dependencies, then link the imports of every module.
+    /// Files are parsed in parallel, one level of dependencies at a time.
+    #[must_use]
+    pub fn build(self, entries: &[PathBuf]) -> ModuleGraphBuilderReturn {
+        let mut modules = vec![];
+        let mut paths: FxHashMap<PathBuf, ModuleId> = FxHashMap::default();
+        let mut errors = vec![];
+
+        let mut queue = vec![];
+        for entry in entries {
+            if !paths.contains_key(entry) {
+                paths.insert(entry.clone(), ModuleId::new(paths.len()));
+                queue.push(entry.clone());
+            }
+        }
+
+        while !queue.is_empty() {
+            let loaded = queue.par_iter().map(|path| self.load(path)).collect::<Vec<_>>();
+            for (path, loaded) in std::mem::take(&mut queue).into_iter().zip(loaded) {
+                let mut dependencies = FxHashMap::default();
+                for (specifier, dependency) in loaded.dependencies {
+                    let next_id = ModuleId::new(paths.len());
+                    let id = *paths.entry(dependency).or_insert_with_key(|dependency| {
+                        queue.push(dependency.clone());
+                        next_id
+                    });
+                    dependencies.insert(specifier, id);
+                }
+                errors.extend(loaded.errors);
+                modules.push(Module::new(
+                    paths[&path],
+                    path,
+                    loaded.source_type,
+                    loaded.source_text,
+                    loaded.module_record,
+                    dependencies,
+                ));
+            }
+        }
+
+        let graph = ModuleGraph { modules, paths };
+        let link_errors = graph.link();
+        errors.extend(link_errors.into_iter().map(|(module_id, error)| {
+            let module = &graph[module_id];
+            with_source(module.path(), module.source_text(), vec![error]).remove(0)
+        }));
+        ModuleGraphBuilderReturn { graph, errors }
+    }
+
+    fn load(&self, path: &Path) -> LoadedModule {
+        let mut loaded = LoadedModule {
+            source_type: None,
+            source_text: String::new(),
+            module_record: ModuleRecord::default(),
+            dependencies: vec![],
+            errors: vec![],
+        };
+        // Files such as json or css are part of the graph, but their exports are unknown
+        let Ok(source_type) = SourceType::from_path(path) else { return loaded };
+        let Ok(source_text) = fs::read_to_string(path) else {
+            loaded.errors.push(ReadFile(path.to_path_buf()).into());
+            return loaded;
+        };
+
+        let (module_record, mut errors) = {
+            let allocator = Allocator::default();
+            let ret = Parser::new(&allocator, &source_text, source_type).parse();
+            (ModuleRecordBuilder::default().build(&ret.program), ret.errors)
+        };
+        let mut module_requests = module_record.module_requests.iter().collect::<Vec<_>>();
+        module_requests.sort_unstable_by_key(|(_, spans)| spans[0].start);
+        for (specifier, spans) in module_requests {
+            match self.resolver.resolve(path, specifier) {
+                Resolution::File(dependency) => {
+                    loaded.dependencies.push((specifier.clone(), dependency));
+                }
+                Resolution::External => {}
+                Resolution::NotFound => errors.extend(
+                    spans.iter().map(|span| UnresolvedModule(specifier.clone(), *span).into()),
+                ),
+                Resolution::Error(message) => {
+                    errors.extend(spans.iter().map(|span| {
+                        ResolveModule(specifier.clone(), message.clone(), *span).into()
+                    }));
+                }
+            }
+        }
+
+        loaded.errors = with_source(path, &source_text, errors);
+        loaded.source_type = Some(source_type);
+        loaded.source_text = source_text;
+        loaded.module_record = module_record;
+        loaded
+    }
+}
+
+fn with_source(path: &Path, source_text: &str, errors: Vec<Error>) -> Vec<Error> {
+    if errors.is_empty() {
+        return errors;
+    }
+    let source = Arc::new(NamedSource::new(path.to_string_lossy(), source_text.to_owned()));
+    errors.into_iter().map(|error| error.with_source_code(Arc::clone(&source))).collect()
+}
@@ -0,0 +1,106 @@
+//! Import cycle detection with [Tarjan's strongly connected components algorithm](https://en.wikipedia.org/wiki/Tarjan%27s_strongly_connected_components_algorithm)
+
+use crate::{ModuleGraph, ModuleId};
+
+impl ModuleGraph {
+    /// Groups of modules which import each other, directly or indirectly.
+    /// Each cycle is sorted by module id.
+    #[must_use]
+    pub fn cycles(&self) -> Vec<Vec<ModuleId>> {
+        let mut tarjan = Tarjan::new(self);
+        for module in self.modules() {
+            if tarjan.indices[module.id().index()].is_none() {
+                tarjan.strong_connect(module.id());
+            }
+        }
+        tarjan.cycles
+    }
+}
+
+/// A module being visited, and the index of its next dependency to visit
+struct Frame {
+    module: ModuleId,
+    dependencies: Vec<ModuleId>,
+    next: usize,
+}
+
+struct Tarjan<'g> {
+    graph: &'g ModuleGraph,
+    index: usize,
+    indices: Vec<Option<usize>>,
+    low_links: Vec<usize>,
+    on_stack: Vec<bool>,
+    stack: Vec<ModuleId>,
+    cycles: Vec<Vec<ModuleId>>,
+}
+
+impl<'g> Tarjan<'g> {
+    fn new(graph: &'g ModuleGraph) -> Self {
+        let len = graph.modules().len();
+        Self {
+            graph,
+            index: 0,
+            indices: vec![None; len],
+            low_links: vec![0; len],
+            on_stack: vec![false; len],
+            stack: vec![],
+            cycles: vec![],
+        }
+    }
+
+    /// Iterative, with a frame per visited module in place of the recursion
+    /// so that long import chains do not overflow the stack
+    fn strong_connect(&mut self, root: ModuleId) {
+        let mut frames = vec![self.visit(root)];
+        while let Some(frame) = frames.last_mut() {
+            let v = frame.module;
+            if let Some(&w) = frame.dependencies.get(frame.next) {
+                frame.next += 1;
+                let index = self.indices[w.index()];
+                match index {
+                    None => frames.push(self.visit(w)),
+                    Some(index) if self.on_stack[w.index()] => {
+                        self.low_links[v.index()] = self.low_links[v.index()].min(index);
+                    }
+                    Some(_) => {}
+                }
+                continue;
+            }
+
+            let frame = frames.pop().unwrap();
+            if let Some(parent) = frames.last() {
+                let u = parent.module;
+                self.low_links[u.index()] =
+                    self.low_links[u.index()].min(self.low_links[v.index()]);
+            }
+
+            // `v` is the root of a strongly connected component
+            if Some(self.low_links[v.index()]) == self.indices[v.index()] {
+                let mut component = vec![];
+                while let Some(w) = self.stack.pop() {
+                    self.on_stack[w.index()] = false;
+                    component.push(w);
+                    if w == v {
+                        break;
+                    }
+                }
+                if component.len() > 1 || frame.dependencies.contains(&v) {
+                    component.sort_unstable();
+                    self.cycles.push(component);
+                }
+            }
+        }
+    }
+
+    fn visit(&mut self, v: ModuleId) -> Frame {
+        self.indices[v.index()] = Some(self.index);
+        self.low_links[v.index()] = self.index;
+        self.index += 1;
+        self.stack.push(v);
+        self.on_stack[v.index()] = true;
+
+        let mut dependencies = self.graph[v].dependencies().collect::<Vec<_>>();
+        dependencies.sort_unstable();
+        Frame { module: v, dependencies, next: 0 }
+    }
+}
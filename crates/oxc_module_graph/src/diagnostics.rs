@@ -0,0 +1,32 @@
+use std::path::PathBuf;
+
+use oxc_ast::{Atom, Span};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Failed to read {0:?}")]
+#[diagnostic()]
+pub struct ReadFile(pub PathBuf);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Cannot find module `{0}`")]
+#[diagnostic()]
+pub struct UnresolvedModule(pub Atom, #[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Failed to resolve `{0}`: {1}")]
+#[diagnostic()]
+pub struct ResolveModule(pub Atom, pub String, #[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("`{0}` is not exported by `{1}`")]
+#[diagnostic()]
+pub struct MissingExport(pub Atom, pub Atom, #[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("`{0}` is ambiguous in `{1}`")]
+#[diagnostic(help("More than one `export *` declaration provides a different `{0}`"))]
+pub struct AmbiguousExport(pub Atom, pub Atom, #[label] pub Span);
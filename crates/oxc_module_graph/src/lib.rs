@@ -0,0 +1,171 @@
+//! Module Graph
+//! Loads a set of entry files and their dependencies, and links the
+//! import entries of each [`ModuleRecord`](oxc_ast::module_record::ModuleRecord)
+//! to the export entries of the imported modules.
+
+mod builder;
+mod cycles;
+mod diagnostics;
+mod link;
+mod module;
+mod resolver;
+
+use std::{
+    ops::Index,
+    path::{Path, PathBuf},
+};
+
+pub use builder::{ModuleGraphBuilder, ModuleGraphBuilderReturn};
+pub use link::{ExportResolution, ResolvedBinding};
+pub use module::{Module, ModuleId};
+pub use resolver::{Resolution, Resolver};
+use rustc_hash::FxHashMap;
+
+#[derive(Debug)]
+pub struct ModuleGraph {
+    modules: Vec<Module>,
+
+    paths: FxHashMap<PathBuf, ModuleId>,
+}
+
+impl Index<ModuleId> for ModuleGraph {
+    type Output = Module;
+
+    fn index(&self, id: ModuleId) -> &Self::Output {
+        &self.modules[id.index()]
+    }
+}
+
+impl ModuleGraph {
+    /// All modules, indexed by [`ModuleId`], in the order they are found.
+    #[must_use]
+    pub fn modules(&self) -> &[Module] {
+        &self.modules
+    }
+
+    #[must_use]
+    pub fn module_by_path(&self, path: &Path) -> Option<&Module> {
+        self.paths.get(path).map(|id| &self[*id])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::{Path, PathBuf};
+
+    use oxc_ast::{module_record::ModuleRecord, Atom};
+    use oxc_resolver::Resolver;
+    use rustc_hash::FxHashMap;
+
+    use crate::{
+        ExportResolution, Module, ModuleGraph, ModuleGraphBuilder, ModuleId, ResolvedBinding,
+    };
+
+    fn fixture(path: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures").join(path)
+    }
+
+    fn build(entry: &str) -> (ModuleGraph, Vec<String>) {
+        let ret = ModuleGraphBuilder::new(&Resolver::default()).build(&[fixture(entry)]);
+        (ret.graph, ret.errors.iter().map(ToString::to_string).collect())
+    }
+
+    fn id(graph: &ModuleGraph, path: &str) -> ModuleId {
+        graph.module_by_path(&fixture(path)).unwrap().id()
+    }
+
+    #[test]
+    fn link() {
+        let (graph, errors) = build("link/index.js");
+        assert_eq!(graph.modules().len(), 8);
+        assert_eq!(
+            errors,
+            vec![
+                "Cannot find module `./not-found.js`",
+                "`missing` is not exported by `./a.js`",
+                "`ambiguous` is ambiguous in `./star.js`",
+            ]
+        );
+
+        let a = id(&graph, "link/a.js");
+        let b = id(&graph, "link/b.js");
+        let d = id(&graph, "link/d.js");
+        let star = id(&graph, "link/star.js");
+        let local = |module, name: &str| {
+            ExportResolution::Found(ResolvedBinding::Local { module, name: name.into() })
+        };
+        assert_eq!(graph.resolve_export(a, &"a".into()), local(a, "a"));
+        assert_eq!(graph.resolve_export(a, &"b".into()), local(b, "b"));
+        assert_eq!(graph.resolve_export(a, &"c".into()), local(d, "*default*"));
+        assert_eq!(graph.resolve_export(star, &"y".into()), local(b, "b"));
+        assert_eq!(graph.resolve_export(star, &"ambiguous".into()), ExportResolution::Ambiguous);
+        assert_eq!(graph.resolve_export(star, &"default".into()), ExportResolution::NotFound);
+
+        let mut names = graph.exported_names(star);
+        names.sort_unstable();
+        assert_eq!(names, vec!["ambiguous", "x", "y"]);
+
+        // The exports of json files are unknown
+        let index = &graph[id(&graph, "link/index.js")];
+        let data =
+            index.module_record().import_entries.iter().find(|e| e.local_name.name() == &"data");
+        assert_eq!(graph.resolve_import(index.id(), data.unwrap()), ExportResolution::Unknown);
+    }
+
+    #[test]
+    fn resolve() {
+        // Builtin modules are not part of the graph, and their imports are not reported
+        let (graph, errors) = build("resolve/index.js");
+        assert_eq!(graph.modules().len(), 1);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].starts_with("Failed to resolve `broken`: Invalid package config"));
+
+        let index = &graph[id(&graph, "resolve/index.js")];
+        let read_file = &index.module_record().import_entries[0];
+        assert_eq!(graph.resolve_import(index.id(), read_file), ExportResolution::Unknown);
+    }
+
+    #[test]
+    fn cycles() {
+        let (graph, errors) = build("cycle/d.js");
+        assert!(errors.is_empty());
+        let ids = |paths: &[&str]| {
+            let mut ids = paths.iter().map(|path| id(&graph, path)).collect::<Vec<_>>();
+            ids.sort_unstable();
+            ids
+        };
+        let mut cycles = graph.cycles();
+        cycles.sort_unstable_by_key(Vec::len);
+        assert_eq!(
+            cycles,
+            vec![ids(&["cycle/d.js"]), ids(&["cycle/a.js", "cycle/b.js", "cycle/c.js"])]
+        );
+    }
+
+    #[test]
+    fn long_cycle() {
+        // A chain of imports deeper than the stack could hold with a recursive search
+        let len = 100_000;
+        let modules = (0..len)
+            .map(|index| {
+                let dependencies = FxHashMap::from_iter([(
+                    Atom::from(format!("./{}.js", (index + 1) % len)),
+                    ModuleId::new((index + 1) % len),
+                )]);
+                let path = PathBuf::from(format!("{index}.js"));
+                Module::new(
+                    ModuleId::new(index),
+                    path,
+                    None,
+                    String::new(),
+                    ModuleRecord::default(),
+                    dependencies,
+                )
+            })
+            .collect();
+        let graph = ModuleGraph { modules, paths: FxHashMap::default() };
+        let cycles = graph.cycles();
+        assert_eq!(cycles.len(), 1);
+        assert_eq!(cycles[0].len(), len);
+    }
+}
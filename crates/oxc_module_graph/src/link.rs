@@ -0,0 +1,233 @@
+//! Linking of import and export entries between modules
+//! <https://tc39.es/ecma262/#sec-resolveexport>
+
+use oxc_ast::{
+    module_record::{
+        ExportExportName, ExportImportName, ExportLocalName, ImportEntry, ImportImportName,
+        NameSpan,
+    },
+    Atom, SourceType,
+};
+use oxc_diagnostics::Error;
+
+use crate::{
+    diagnostics::{AmbiguousExport, MissingExport},
+    ModuleGraph, ModuleId,
+};
+
+/// The binding an export name of a module refers to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResolvedBinding {
+    /// A binding declared in `module`, `*default*` for anonymous default exports such as `export default 1`
+    Local { module: ModuleId, name: Atom },
+    /// The namespace object of a module, e.g. `export * as ns from "mod"`
+    Namespace(ModuleId),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExportResolution {
+    Found(ResolvedBinding),
+    NotFound,
+    /// The name is provided by more than one `export *` with different bindings
+    Ambiguous,
+    /// The name may be provided by a module whose exports are unknown,
+    /// e.g. a module which could not be resolved or TypeScript types, which are not recorded
+    Unknown,
+}
+
+fn export_name_of(name: &ExportExportName) -> Option<Atom> {
+    match name {
+        ExportExportName::Name(name) => Some(name.name().clone()),
+        ExportExportName::Default(_) => Some("default".into()),
+        ExportExportName::Null => None,
+    }
+}
+
+impl ModuleGraph {
+    /// [ResolveExport](https://tc39.es/ecma262/#sec-resolveexport)
+    #[must_use]
+    pub fn resolve_export(&self, module_id: ModuleId, export_name: &Atom) -> ExportResolution {
+        self.resolve_export_impl(module_id, export_name, &mut vec![])
+    }
+
+    /// The binding an import entry of `module_id` refers to.
+    #[must_use]
+    pub fn resolve_import(&self, module_id: ModuleId, entry: &ImportEntry) -> ExportResolution {
+        let Some(imported) = self[module_id].dependency(entry.module_request.name()) else {
+            return ExportResolution::Unknown;
+        };
+        match &entry.import_name {
+            ImportImportName::NamespaceObject => {
+                ExportResolution::Found(ResolvedBinding::Namespace(imported))
+            }
+            ImportImportName::Name(name) => self.resolve_export(imported, name.name()),
+            ImportImportName::Default(_) => self.resolve_export(imported, &"default".into()),
+        }
+    }
+
+    /// [GetExportedNames](https://tc39.es/ecma262/#sec-getexportednames)
+    #[must_use]
+    pub fn exported_names(&self, module_id: ModuleId) -> Vec<Atom> {
+        self.exported_names_impl(module_id, &mut vec![])
+    }
+
+    fn exported_names_impl(
+        &self,
+        module_id: ModuleId,
+        export_star_set: &mut Vec<ModuleId>,
+    ) -> Vec<Atom> {
+        // We've reached the starting point of an export * circularity.
+        if export_star_set.contains(&module_id) {
+            return vec![];
+        }
+        export_star_set.push(module_id);
+
+        let module = &self[module_id];
+        let record = module.module_record();
+        let mut exported_names: Vec<Atom> = record
+            .local_export_entries
+            .iter()
+            .chain(&record.indirect_export_entries)
+            .filter_map(|e| export_name_of(&e.export_name))
+            .collect();
+        for e in &record.star_export_entries {
+            let Some(requested) = e.module_request.as_ref().and_then(|r| module.dependency(r.name())) else {
+                continue;
+            };
+            for name in self.exported_names_impl(requested, export_star_set) {
+                if name != "default" && !exported_names.contains(&name) {
+                    exported_names.push(name);
+                }
+            }
+        }
+        exported_names
+    }
+
+    fn resolve_export_impl(
+        &self,
+        module_id: ModuleId,
+        export_name: &Atom,
+        resolve_set: &mut Vec<(ModuleId, Atom)>,
+    ) -> ExportResolution {
+        let module = &self[module_id];
+        if module.source_type().is_none() {
+            return ExportResolution::Unknown;
+        }
+
+        // This is a circular import request.
+        if resolve_set.iter().any(|(id, name)| *id == module_id && name == export_name) {
+            return ExportResolution::NotFound;
+        }
+        resolve_set.push((module_id, export_name.clone()));
+
+        let record = module.module_record();
+        for e in &record.local_export_entries {
+            if export_name_of(&e.export_name).as_ref() == Some(export_name) {
+                let name = match &e.local_name {
+                    ExportLocalName::Name(name) => name.name().clone(),
+                    ExportLocalName::Default(_) | ExportLocalName::Null => "*default*".into(),
+                };
+                return ExportResolution::Found(ResolvedBinding::Local { module: module_id, name });
+            }
+        }
+
+        for e in &record.indirect_export_entries {
+            if export_name_of(&e.export_name).as_ref() != Some(export_name) {
+                continue;
+            }
+            let Some(imported) = e.module_request.as_ref().and_then(|r| module.dependency(r.name())) else {
+                return ExportResolution::Unknown;
+            };
+            let import_name = match &e.import_name {
+                ExportImportName::All => {
+                    return ExportResolution::Found(ResolvedBinding::Namespace(imported));
+                }
+                ExportImportName::Name(name) => name.name(),
+                // `export { a } from "mod"` records the imported name as the local name
+                ExportImportName::Null | ExportImportName::AllButDefault => {
+                    let Some(name) = e.local_name.name() else { continue };
+                    name
+                }
+            };
+            return self.resolve_export_impl(imported, import_name, resolve_set);
+        }
+
+        // A default export cannot be provided by export * from "mod".
+        if *export_name == "default" {
+            return Self::not_found(module.source_type());
+        }
+
+        let mut star_resolution = None;
+        let mut unknown = false;
+        for e in &record.star_export_entries {
+            let Some(imported) = e.module_request.as_ref().and_then(|r| module.dependency(r.name())) else {
+                unknown = true;
+                continue;
+            };
+            match self.resolve_export_impl(imported, export_name, resolve_set) {
+                ExportResolution::Found(resolution) => match &star_resolution {
+                    None => star_resolution = Some(resolution),
+                    Some(existing) if *existing != resolution => {
+                        return ExportResolution::Ambiguous;
+                    }
+                    Some(_) => {}
+                },
+                ExportResolution::Ambiguous => return ExportResolution::Ambiguous,
+                ExportResolution::Unknown => unknown = true,
+                ExportResolution::NotFound => {}
+            }
+        }
+        match star_resolution {
+            Some(resolution) => ExportResolution::Found(resolution),
+            None if unknown => ExportResolution::Unknown,
+            None => Self::not_found(module.source_type()),
+        }
+    }
+
+    fn not_found(source_type: Option<SourceType>) -> ExportResolution {
+        // TypeScript types are not recorded as exports
+        if source_type.map_or(false, SourceType::is_typescript) {
+            ExportResolution::Unknown
+        } else {
+            ExportResolution::NotFound
+        }
+    }
+
+    /// Check that every import and re-export of every module resolves to a binding.
+    pub(crate) fn link(&self) -> Vec<(ModuleId, Error)> {
+        let mut errors = vec![];
+        for module in self.modules() {
+            let record = module.module_record();
+            let mut check = |resolution, name: Atom, request: &NameSpan, span| {
+                let specifier = request.name().clone();
+                let error: Error = match resolution {
+                    ExportResolution::NotFound => MissingExport(name, specifier, span).into(),
+                    ExportResolution::Ambiguous => AmbiguousExport(name, specifier, span).into(),
+                    ExportResolution::Found(_) | ExportResolution::Unknown => return,
+                };
+                errors.push((module.id(), error));
+            };
+            for entry in &record.import_entries {
+                let (name, span) = match &entry.import_name {
+                    ImportImportName::Name(name) => (name.name().clone(), name.span()),
+                    ImportImportName::Default(span) => ("default".into(), *span),
+                    ImportImportName::NamespaceObject => continue,
+                };
+                check(self.resolve_import(module.id(), entry), name, &entry.module_request, span);
+            }
+            for entry in &record.indirect_export_entries {
+                let Some(request) = &entry.module_request else { continue };
+                let ((ExportImportName::Name(name), _)
+                | (ExportImportName::Null, ExportLocalName::Name(name))) =
+                    (&entry.import_name, &entry.local_name)
+                else {
+                    continue;
+                };
+                let Some(imported) = module.dependency(request.name()) else { continue };
+                let resolution = self.resolve_export(imported, name.name());
+                check(resolution, name.name().clone(), request, name.span());
+            }
+        }
+        errors
+    }
+}
@@ -0,0 +1,86 @@
+use std::path::{Path, PathBuf};
+
+use oxc_ast::{module_record::ModuleRecord, Atom, SourceType};
+use rustc_hash::FxHashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ModuleId(usize);
+
+impl ModuleId {
+    #[must_use]
+    pub(crate) fn new(index: usize) -> Self {
+        Self(index)
+    }
+
+    #[must_use]
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+/// A file in the [`ModuleGraph`](crate::ModuleGraph)
+#[derive(Debug)]
+pub struct Module {
+    id: ModuleId,
+
+    path: PathBuf,
+
+    /// `None` for files which are not JavaScript or TypeScript, e.g. `import data from "./data.json"`
+    source_type: Option<SourceType>,
+
+    source_text: String,
+
+    module_record: ModuleRecord,
+
+    /// Module requests which are resolved to a module in the graph, keyed by module specifier
+    dependencies: FxHashMap<Atom, ModuleId>,
+}
+
+impl Module {
+    #[must_use]
+    pub(crate) fn new(
+        id: ModuleId,
+        path: PathBuf,
+        source_type: Option<SourceType>,
+        source_text: String,
+        module_record: ModuleRecord,
+        dependencies: FxHashMap<Atom, ModuleId>,
+    ) -> Self {
+        Self { id, path, source_type, source_text, module_record, dependencies }
+    }
+
+    #[must_use]
+    pub fn id(&self) -> ModuleId {
+        self.id
+    }
+
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    #[must_use]
+    pub fn source_type(&self) -> Option<SourceType> {
+        self.source_type
+    }
+
+    #[must_use]
+    pub fn source_text(&self) -> &str {
+        &self.source_text
+    }
+
+    #[must_use]
+    pub fn module_record(&self) -> &ModuleRecord {
+        &self.module_record
+    }
+
+    /// The module which `specifier` resolves to.
+    #[must_use]
+    pub fn dependency(&self, specifier: &Atom) -> Option<ModuleId> {
+        self.dependencies.get(specifier).copied()
+    }
+
+    pub fn dependencies(&self) -> impl Iterator<Item = ModuleId> + '_ {
+        self.dependencies.values().copied()
+    }
+}
@@ -0,0 +1,35 @@
+use std::path::{Path, PathBuf};
+
+use oxc_resolver::ResolveError;
+
+/// Resolves the module specifiers of import and export declarations to files.
+pub trait Resolver: Sync {
+    /// Resolve `specifier` of the file `importer`.
+    fn resolve(&self, importer: &Path, specifier: &str) -> Resolution;
+}
+
+/// The result of resolving a module specifier
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Resolution {
+    /// The path of a file, which is loaded into the graph
+    File(PathBuf),
+    /// A module which is not a file, such as the Node.js builtin module `fs`.
+    /// It is not part of the graph and its exports are unknown.
+    External,
+    /// The module can not be found
+    NotFound,
+    /// The module can not be resolved because of an invalid config, such as an invalid `package.json`
+    Error(String),
+}
+
+impl Resolver for oxc_resolver::Resolver {
+    fn resolve(&self, importer: &Path, specifier: &str) -> Resolution {
+        let Some(directory) = importer.parent() else { return Resolution::NotFound };
+        match self.resolve(directory, specifier) {
+            Ok(path) => Resolution::File(path),
+            Err(ResolveError::Builtin(_)) => Resolution::External,
+            Err(ResolveError::NotFound(_)) => Resolution::NotFound,
+            Err(error) => Resolution::Error(error.to_string()),
+        }
+    }
+}
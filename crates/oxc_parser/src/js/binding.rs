@@ -88,8 +88,11 @@ impl<'a> Parser<'a> {
             //       ^ BindingIdentifier
             if let PropertyKey::Identifier(ident) = &key {
                 shorthand = true;
-                let binding_identifier =
-                    BindingIdentifier { span: ident.span, name: ident.name.clone() };
+                let binding_identifier = BindingIdentifier {
+                    span: ident.span,
+                    node_id: self.ast.node_id(),
+                    name: ident.name.clone(),
+                };
                 let identifier = self.ast.binding_identifier(binding_identifier);
                 let left = self.ast.binding_pattern(identifier, None, false);
                 PropertyValue::Pattern(self.parse_initializer(span, left)?)
@@ -105,6 +108,7 @@ impl<'a> Parser<'a> {
 
         Ok(Property {
             span: self.end_span(span),
+            node_id: self.ast.node_id(),
             key,
             value,
             kind: PropertyKind::Init,
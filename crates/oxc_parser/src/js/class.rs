@@ -170,7 +170,7 @@ impl<'a> Parser<'a> {
 
         let mut key_name = None;
 
-        let modifier = self.parse_class_element_modifiers(false);
+        let (modifier, modifiers) = self.parse_class_element_modifiers(false);
 
         let accessor = matches!(
             self.peek_kind(),
@@ -278,6 +278,7 @@ impl<'a> Parser<'a> {
                 r#abstract,
                 accessibility,
                 optional,
+                modifiers,
             )?;
             if let Some((name, span)) = definition.prop_name() {
                 if r#static && name == "prototype" {
@@ -309,6 +310,7 @@ impl<'a> Parser<'a> {
                 accessibility,
                 optional,
                 definite,
+                modifiers,
             )?;
             if let Some((name, span)) = definition.prop_name() {
                 if name == "constructor" {
@@ -346,6 +348,7 @@ impl<'a> Parser<'a> {
         r#abstract: bool,
         accessibility: Option<TSAccessibility>,
         optional: bool,
+        modifiers: Modifiers<'a>,
     ) -> Result<ClassElement<'a>> {
         let kind = if !r#static
             && !computed
@@ -378,6 +381,7 @@ impl<'a> Parser<'a> {
 
         let method_definition = MethodDefinition {
             span: self.end_span(span),
+            node_id: self.ast.node_id(),
             key,
             value,
             kind,
@@ -387,6 +391,7 @@ impl<'a> Parser<'a> {
             accessibility,
             optional,
             decorators,
+            modifiers,
         };
 
         if r#abstract {
@@ -413,6 +418,7 @@ impl<'a> Parser<'a> {
         accessibility: Option<TSAccessibility>,
         optional: bool,
         definite: bool,
+        modifiers: Modifiers<'a>,
     ) -> Result<ClassElement<'a>> {
         let type_annotation =
             if self.ts_enabled() { self.parse_ts_type_annotation()? } else { None };
@@ -429,6 +435,7 @@ impl<'a> Parser<'a> {
 
         let property_definition = PropertyDefinition {
             span: self.end_span(span),
+            node_id: self.ast.node_id(),
             key,
             value,
             computed,
@@ -441,6 +448,7 @@ impl<'a> Parser<'a> {
             optional,
             definite,
             decorators: self.state.consume_decorators(),
+            modifiers,
         };
 
         if r#abstract {
@@ -51,7 +51,7 @@ impl<'a> Parser<'a> {
         }
         let (span, name) = self.parse_identifier_kind(Kind::Ident);
         self.check_identifier(span, &name);
-        Ok(IdentifierReference { span, name })
+        Ok(IdentifierReference { span, node_id: self.ast.node_id(), name })
     }
 
     /// `BindingIdentifier` : Identifier
@@ -61,7 +61,7 @@ impl<'a> Parser<'a> {
         }
         let (span, name) = self.parse_identifier_kind(Kind::Ident);
         self.check_identifier(span, &name);
-        Ok(BindingIdentifier { span, name })
+        Ok(BindingIdentifier { span, node_id: self.ast.node_id(), name })
     }
 
     pub(crate) fn parse_label_identifier(&mut self) -> Result<LabelIdentifier> {
@@ -70,7 +70,7 @@ impl<'a> Parser<'a> {
         }
         let (span, name) = self.parse_identifier_kind(Kind::Ident);
         self.check_identifier(span, &name);
-        Ok(LabelIdentifier { span, name })
+        Ok(LabelIdentifier { span, node_id: self.ast.node_id(), name })
     }
 
     pub(crate) fn parse_identifier_name(&mut self) -> Result<IdentifierName> {
@@ -78,13 +78,13 @@ impl<'a> Parser<'a> {
             return Err(self.unexpected());
         }
         let (span, name) = self.parse_identifier_kind(Kind::Ident);
-        Ok(IdentifierName { span, name })
+        Ok(IdentifierName { span, node_id: self.ast.node_id(), name })
     }
 
     /// Parse keyword kind as identifier
     pub(crate) fn parse_keyword_identifier(&mut self, kind: Kind) -> IdentifierName {
         let (span, name) = self.parse_identifier_kind(kind);
-        IdentifierName { span, name }
+        IdentifierName { span, node_id: self.ast.node_id(), name }
     }
 
     pub(crate) fn parse_identifier_kind(&mut self, kind: Kind) -> (Span, Atom) {
@@ -116,7 +116,7 @@ impl<'a> Parser<'a> {
         let span = self.start_span();
         let name = Atom::from(self.cur_string().unwrap());
         self.bump_any();
-        PrivateIdentifier { span: self.end_span(span), name }
+        PrivateIdentifier { span: self.end_span(span), node_id: self.ast.node_id(), name }
     }
 
     /// Section [Primary Expression](https://tc39.es/ecma262/#sec-primary-expression)
@@ -255,13 +255,13 @@ impl<'a> Parser<'a> {
             _ => return Err(self.unexpected()),
         };
         self.bump_any();
-        Ok(BooleanLiteral { span: self.end_span(span), value })
+        Ok(BooleanLiteral { span: self.end_span(span), node_id: self.ast.node_id(), value })
     }
 
     pub(crate) fn parse_literal_null(&mut self) -> NullLiteral {
         let span = self.start_span();
         self.bump_any(); // bump `null`
-        NullLiteral { span: self.end_span(span) }
+        NullLiteral { span: self.end_span(span), node_id: self.ast.node_id() }
     }
 
     pub(crate) fn parse_literal_number(&mut self) -> Result<NumberLiteral<'a>> {
@@ -276,7 +276,7 @@ impl<'a> Parser<'a> {
         let value = self.cur_token().value.as_number();
         let raw = self.cur_src();
         self.bump_any();
-        Ok(NumberLiteral::new(self.end_span(span), value, raw, base))
+        Ok(NumberLiteral::new(self.end_span(span), self.ast.node_id(), value, raw, base))
     }
 
     pub(crate) fn parse_literal_bigint(&mut self) -> Result<BigintLiteral> {
@@ -286,7 +286,7 @@ impl<'a> Parser<'a> {
             _ => return Err(self.unexpected()),
         };
         self.bump_any();
-        Ok(BigintLiteral { span: self.end_span(span), value })
+        Ok(BigintLiteral { span: self.end_span(span), node_id: self.ast.node_id(), value })
     }
 
     pub(crate) fn parse_literal_regexp(&mut self) -> Result<RegExpLiteral> {
@@ -300,6 +300,7 @@ impl<'a> Parser<'a> {
         self.bump_any();
         Ok(RegExpLiteral {
             span: self.end_span(span),
+            node_id: self.ast.node_id(),
             value: EmptyObject {},
             regex: RegExp { pattern, flags },
         })
@@ -314,7 +315,11 @@ impl<'a> Parser<'a> {
         };
         let span = self.start_span();
         self.bump_any();
-        Ok(StringLiteral { span: self.end_span(span), value: value.into() })
+        Ok(StringLiteral {
+            span: self.end_span(span),
+            node_id: self.ast.node_id(),
+            value: value.into(),
+        })
     }
 
     /// Section [Array Expression](https://tc39.es/ecma262/#prod-ArrayLiteral)
@@ -366,7 +371,12 @@ impl<'a> Parser<'a> {
             }
             _ => unreachable!("parse_template_literal"),
         }
-        Ok(TemplateLiteral { span: self.end_span(span), quasis, expressions })
+        Ok(TemplateLiteral {
+            span: self.end_span(span),
+            node_id: self.ast.node_id(),
+            quasis,
+            expressions,
+        })
     }
 
     fn parse_template_literal_expression(&mut self, tagged: bool) -> Result<Expression<'a>> {
@@ -424,7 +434,12 @@ impl<'a> Parser<'a> {
         }
 
         let tail = matches!(cur_kind, Kind::TemplateTail | Kind::NoSubstitutionTemplate);
-        TemplateElement { span, tail, value: TemplateElementValue { raw, cooked } }
+        TemplateElement {
+            span,
+            node_id: self.ast.node_id(),
+            tail,
+            value: TemplateElementValue { raw, cooked },
+        }
     }
 
     /// Section 13.3 Meta Property
@@ -545,6 +560,7 @@ impl<'a> Parser<'a> {
                         lhs = Expression::TSInstantiationExpression(self.ast.alloc(
                             TSInstantiationExpression {
                                 span: self.end_span(lhs_span),
+                                node_id: self.ast.node_id(),
                                 expression: lhs,
                                 type_parameters: arguments,
                             },
@@ -839,6 +855,7 @@ impl<'a> Parser<'a> {
             let right = self.parse_unary_expression_base(lhs_span)?;
             Expression::PrivateInExpression(self.ast.alloc(PrivateInExpression {
                 span: self.end_span(lhs_span),
+                node_id: self.ast.node_id(),
                 left,
                 operator: BinaryOperator::In,
                 right,
@@ -892,6 +909,7 @@ impl<'a> Parser<'a> {
                 let type_annotation = self.parse_ts_type()?;
                 lhs = Expression::TSAsExpression(self.ast.alloc(TSAsExpression {
                     span: self.end_span(lhs_span),
+                    node_id: self.ast.node_id(),
                     expression: lhs,
                     type_annotation,
                 }));
@@ -91,6 +91,7 @@ impl<'a> CoverGrammar<'a, ArrayExpression<'a>> for ArrayAssignmentTarget<'a> {
 
         Ok(ArrayAssignmentTarget {
             span: expr.span,
+            node_id: p.ast.node_id(),
             elements,
             rest,
             trailing_comma: expr.trailing_comma,
@@ -114,8 +115,8 @@ impl<'a> CoverGrammar<'a, Expression<'a>> for AssignmentTargetMaybeDefault<'a> {
 }
 
 impl<'a> CoverGrammar<'a, AssignmentExpression<'a>> for AssignmentTargetWithDefault<'a> {
-    fn cover(expr: AssignmentExpression<'a>, _p: &mut Parser<'a>) -> Result<Self> {
-        Ok(Self { span: expr.span, binding: expr.left, init: expr.right })
+    fn cover(expr: AssignmentExpression<'a>, p: &mut Parser<'a>) -> Result<Self> {
+        Ok(Self { span: expr.span, node_id: p.ast.node_id(), binding: expr.left, init: expr.right })
     }
 }
 
@@ -141,7 +142,7 @@ impl<'a> CoverGrammar<'a, ObjectExpression<'a>> for ObjectAssignmentTarget<'a> {
             }
         }
 
-        Ok(Self { span: expr.span, properties, rest })
+        Ok(Self { span: expr.span, node_id: p.ast.node_id(), properties, rest })
     }
 }
 
@@ -149,9 +150,11 @@ impl<'a> CoverGrammar<'a, Property<'a>> for AssignmentTargetProperty<'a> {
     fn cover(property: Property<'a>, p: &mut Parser<'a>) -> Result<Self> {
         if property.shorthand {
             let binding = match property.key {
-                PropertyKey::Identifier(ident) => {
-                    IdentifierReference { span: ident.span, name: ident.unbox().name }
-                }
+                PropertyKey::Identifier(ident) => IdentifierReference {
+                    span: ident.span,
+                    node_id: p.ast.node_id(),
+                    name: ident.unbox().name,
+                },
                 _ => return Err(p.unexpected()),
             };
             let init = match property.value {
@@ -160,7 +163,12 @@ impl<'a> CoverGrammar<'a, Property<'a>> for AssignmentTargetProperty<'a> {
                 }
                 _ => None,
             };
-            let target = AssignmentTargetPropertyIdentifier { span: property.span, binding, init };
+            let target = AssignmentTargetPropertyIdentifier {
+                span: property.span,
+                node_id: p.ast.node_id(),
+                binding,
+                init,
+            };
             Ok(AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(p.ast.alloc(target)))
         } else {
             let binding = match property.value {
@@ -171,6 +179,7 @@ impl<'a> CoverGrammar<'a, Property<'a>> for AssignmentTargetProperty<'a> {
             };
             let target = AssignmentTargetPropertyProperty {
                 span: property.span,
+                node_id: p.ast.node_id(),
                 name: property.key,
                 binding,
             };
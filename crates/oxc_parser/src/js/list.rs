@@ -1,5 +1,7 @@
 use oxc_allocator::Vec;
-use oxc_ast::{ast::*, syntax_directed_operations::PrivateBoundIdentifiers, Atom, GetSpan, Span};
+use oxc_ast::{
+    ast::*, syntax_directed_operations::PrivateBoundIdentifiers, Atom, CloneIn, GetSpan, Span,
+};
 use oxc_diagnostics::{Redeclaration, Result};
 use rustc_hash::FxHashMap;
 
@@ -241,7 +243,7 @@ impl<'a> SeparatedList<'a> for FormalParameterList<'a> {
         let span = p.start_span();
         p.eat_decorators()?;
 
-        let modifiers = p.parse_class_element_modifiers(true);
+        let (modifiers, _) = p.parse_class_element_modifiers(true);
         let accessibility = modifiers.accessibility();
         let readonly = modifiers.readonly();
 
@@ -298,7 +300,8 @@ impl<'a> SeparatedList<'a> for AssertEntries<'a> {
 
         p.expect(Kind::Colon)?;
         let value = p.parse_literal_string()?;
-        let element = ImportAttribute { span: p.end_span(span), key, value };
+        let element =
+            ImportAttribute { span: p.end_span(span), node_id: p.ast.node_id(), key, value };
         self.elements.push(element);
         Ok(())
     }
@@ -350,8 +353,17 @@ impl<'a> SeparatedList<'a> for ExportNamedSpecifiers<'a> {
         }
 
         let local = p.parse_module_export_name()?;
-        let exported = if p.eat(Kind::As) { p.parse_module_export_name()? } else { local.clone() };
-        let element = ExportSpecifier { span: p.end_span(specifier_span), local, exported };
+        let exported = if p.eat(Kind::As) {
+            p.parse_module_export_name()?
+        } else {
+            local.clone_in(p.ast.allocator)
+        };
+        let element = ExportSpecifier {
+            span: p.end_span(specifier_span),
+            node_id: p.ast.node_id(),
+            local,
+            exported,
+        };
         self.elements.push(element);
         Ok(())
     }
@@ -108,6 +108,7 @@ impl<'a> Parser<'a> {
         let local = self.parse_binding_identifier()?;
         Ok(ImportDeclarationSpecifier::ImportDefaultSpecifier(ImportDefaultSpecifier {
             span: self.end_span(span),
+            node_id: self.ast.node_id(),
             local,
         }))
     }
@@ -120,6 +121,7 @@ impl<'a> Parser<'a> {
         let local = self.parse_binding_identifier()?;
         Ok(ImportDeclarationSpecifier::ImportNamespaceSpecifier(ImportNamespaceSpecifier {
             span: self.end_span(span),
+            node_id: self.ast.node_id(),
             local,
         }))
     }
@@ -157,7 +159,11 @@ impl<'a> Parser<'a> {
         let expression = self.parse_assignment_expression_base()?;
         self.asi()?;
 
-        Ok(self.ast.alloc(TSExportAssignment { span: self.end_span(span), expression }))
+        Ok(self.ast.alloc(TSExportAssignment {
+            span: self.end_span(span),
+            node_id: self.ast.node_id(),
+            expression,
+        }))
     }
 
     pub(crate) fn parse_ts_export_namespace(
@@ -170,7 +176,11 @@ impl<'a> Parser<'a> {
         let id = self.parse_identifier_name()?;
         self.asi()?;
 
-        Ok(self.ast.alloc(TSNamespaceExportDeclaration { span: self.end_span(span), id }))
+        Ok(self.ast.alloc(TSNamespaceExportDeclaration {
+            span: self.end_span(span),
+            node_id: self.ast.node_id(),
+            id,
+        }))
     }
 
     /// [Exports](https://tc39.es/ecma262/#sec-exports)
@@ -363,10 +373,19 @@ impl<'a> Parser<'a> {
             (imported, local)
         } else {
             let local = self.parse_binding_identifier()?;
-            let imported = IdentifierName { span: local.span, name: local.name.clone() };
+            let imported = IdentifierName {
+                span: local.span,
+                node_id: self.ast.node_id(),
+                name: local.name.clone(),
+            };
             (ModuleExportName::Identifier(imported), local)
         };
-        Ok(ImportSpecifier { span: self.end_span(specifier_span), imported, local })
+        Ok(ImportSpecifier {
+            span: self.end_span(specifier_span),
+            node_id: self.ast.node_id(),
+            imported,
+            local,
+        })
     }
 
     // ModuleExportName :
@@ -118,9 +118,11 @@ impl<'a> Parser<'a> {
         Ok(self.ast.property(
             self.end_span(span),
             PropertyKind::Init,
-            PropertyKey::Identifier(
-                self.ast.alloc(IdentifierName { span: identifier.span, name: identifier.name }),
-            ),
+            PropertyKey::Identifier(self.ast.alloc(IdentifierName {
+                span: identifier.span,
+                node_id: self.ast.node_id(),
+                name: identifier.name,
+            })),
             PropertyValue::Expression(value),
             /* method */ false,
             /* shorthand */ true,
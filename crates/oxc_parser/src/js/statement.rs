@@ -123,7 +123,11 @@ impl<'a> Parser<'a> {
             // Section 14.13 Labelled Statement
             // Avoids lookahead for a labeled statement, which is on a hot path
             if self.eat(Kind::Colon) {
-                let label = LabelIdentifier { span: ident.span, name: ident.name.clone() };
+                let label = LabelIdentifier {
+                    span: ident.span,
+                    node_id: self.ast.node_id(),
+                    name: ident.name.clone(),
+                };
                 let body = self.parse_statement_list_item(StatementContext::Label)?;
                 return Ok(self.ast.labeled_statement(self.end_span(span), label, body));
             }
@@ -39,6 +39,8 @@ mod ts;
 mod diagnostics;
 mod lexer;
 
+pub mod template;
+
 use std::rc::Rc;
 
 use context::{Context, StatementContext};
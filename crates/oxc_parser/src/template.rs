@@ -0,0 +1,491 @@
+//! Code templates
+//!
+//! Build AST nodes from source code with `%%placeholder%%`s, similar to
+//! [@babel/template](https://babeljs.io/docs/babel-template).
+//!
+//! ```ignore
+//! let template =
+//!     Template::new(&allocator, "function %%name%%() { return %%value%%; }", source_type)?;
+//! let statement = template.build_statement([
+//!     ("name", Replacement::Identifier("foo".into())),
+//!     ("value", ast.void_0().into()),
+//! ])?;
+//! ```
+//!
+//! The template is parsed once, each build clones the parsed statements before substituting
+//! the placeholders.
+//!
+//! Placeholders are replaced by
+//! * [`Replacement::Identifier`] in any identifier position, it can be used more than once
+//! * [`Replacement::Expression`] in expression positions
+//! * [`Replacement::Statement`] and [`Replacement::Statements`] in place of a whole
+//!   `%%placeholder%%;` statement
+
+use oxc_allocator::{Allocator, Vec};
+use oxc_ast::{
+    ast::{
+        BindingIdentifier, Expression, IdentifierName, IdentifierReference, LabelIdentifier,
+        Statement,
+    },
+    visit_mut::VisitMut,
+    AstBuilder, Atom, CloneIn, SourceType,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::Parser;
+
+/// Identifiers in the parsed template are named `__oxc_template_{placeholder}__`
+const PLACEHOLDER_PREFIX: &str = "__oxc_template_";
+const PLACEHOLDER_SUFFIX: &str = "__";
+
+#[derive(Debug, Error, Diagnostic)]
+pub enum TemplateError {
+    #[error("Template placeholder `%%{0}%%` has no replacement")]
+    MissingReplacement(String),
+    #[error("Template replacement `{0}` does not match any placeholder")]
+    UnusedReplacement(String),
+    #[error(
+        "Template placeholder `%%{0}%%` is used more than once, only identifiers can be reused"
+    )]
+    ReusedReplacement(String),
+    #[error("Template placeholder `%%{0}%%` expects {1}")]
+    InvalidReplacement(String, &'static str),
+    #[error("Template is expected to be {0}")]
+    InvalidShape(&'static str),
+}
+
+/// A node to substitute for a placeholder
+#[derive(Debug)]
+pub enum Replacement<'a> {
+    Identifier(Atom),
+    Expression(Expression<'a>),
+    Statement(Statement<'a>),
+    Statements(Vec<'a, Statement<'a>>),
+}
+
+impl<'a> From<Expression<'a>> for Replacement<'a> {
+    fn from(expr: Expression<'a>) -> Self {
+        Self::Expression(expr)
+    }
+}
+
+impl<'a> From<Statement<'a>> for Replacement<'a> {
+    fn from(stmt: Statement<'a>) -> Self {
+        Self::Statement(stmt)
+    }
+}
+
+impl<'a> From<Vec<'a, Statement<'a>>> for Replacement<'a> {
+    fn from(stmts: Vec<'a, Statement<'a>>) -> Self {
+        Self::Statements(stmts)
+    }
+}
+
+/// Source code with placeholders, parsed once and built many times.
+#[derive(Debug)]
+pub struct Template<'a> {
+    allocator: &'a Allocator,
+    /// The parsed statements, placeholders are identifiers named after them
+    body: Vec<'a, Statement<'a>>,
+    placeholders: std::vec::Vec<String>,
+}
+
+impl<'a> Template<'a> {
+    /// # Errors
+    /// Syntax errors of the template source.
+    pub fn new(
+        allocator: &'a Allocator,
+        source_text: &str,
+        source_type: SourceType,
+    ) -> Result<Self, std::vec::Vec<oxc_diagnostics::Error>> {
+        let mut placeholders = vec![];
+        let mut rewritten = String::with_capacity(source_text.len());
+        let mut rest = source_text;
+        while let Some(start) = rest.find("%%") {
+            let after = &rest[start + 2..];
+            let len = after
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '$'))
+                .unwrap_or(after.len());
+            if len == 0 || !after[len..].starts_with("%%") {
+                // Not a placeholder, leave it to the parser
+                rewritten.push_str(&rest[..start + 2]);
+                rest = after;
+                continue;
+            }
+            let name = &after[..len];
+            rewritten.push_str(&rest[..start]);
+            rewritten.push_str(PLACEHOLDER_PREFIX);
+            rewritten.push_str(name);
+            rewritten.push_str(PLACEHOLDER_SUFFIX);
+            if !placeholders.iter().any(|p| p == name) {
+                placeholders.push(name.to_string());
+            }
+            rest = &after[len + 2..];
+        }
+        rewritten.push_str(rest);
+
+        let source_text = AstBuilder::new(allocator).new_str(&rewritten);
+        let parser_ret = Parser::new(allocator, source_text, source_type)
+            .allow_return_outside_function(true)
+            .parse();
+        if !parser_ret.errors.is_empty() {
+            return Err(parser_ret.errors);
+        }
+        Ok(Self { allocator, body: parser_ret.program.body, placeholders })
+    }
+
+    /// Names of the placeholders, in order of appearance.
+    #[must_use]
+    pub fn placeholders(&self) -> &[String] {
+        &self.placeholders
+    }
+
+    /// Build the template as a list of statements.
+    ///
+    /// # Errors
+    /// When a placeholder has no replacement, a replacement is unused, or a replacement does not
+    /// fit its placeholder's position.
+    pub fn build_statements<'r>(
+        &self,
+        replacements: impl IntoIterator<Item = (&'r str, Replacement<'a>)>,
+    ) -> Result<Vec<'a, Statement<'a>>, oxc_diagnostics::Error> {
+        let mut body = self.body.clone_in(self.allocator);
+        let mut substitute = Substitute::new(self.allocator, replacements);
+        substitute.visit_statements(&mut body);
+        substitute.finish()?;
+        Ok(body)
+    }
+
+    /// Build a template containing a single statement.
+    ///
+    /// # Errors
+    /// See [`Template::build_statements`].
+    pub fn build_statement<'r>(
+        &self,
+        replacements: impl IntoIterator<Item = (&'r str, Replacement<'a>)>,
+    ) -> Result<Statement<'a>, oxc_diagnostics::Error> {
+        let mut stmts = self.build_statements(replacements)?;
+        if stmts.len() != 1 {
+            return Err(TemplateError::InvalidShape("a single statement").into());
+        }
+        Ok(stmts.remove(0))
+    }
+
+    /// Build a template containing a single expression.
+    /// Wrap object literals in parentheses, the outermost parentheses are removed.
+    ///
+    /// # Errors
+    /// See [`Template::build_statements`].
+    pub fn build_expression<'r>(
+        &self,
+        replacements: impl IntoIterator<Item = (&'r str, Replacement<'a>)>,
+    ) -> Result<Expression<'a>, oxc_diagnostics::Error> {
+        match self.build_statement(replacements)? {
+            Statement::ExpressionStatement(stmt) => match stmt.unbox().expression {
+                Expression::ParenthesizedExpression(expr) => Ok(expr.unbox().expression),
+                expr => Ok(expr),
+            },
+            _ => Err(TemplateError::InvalidShape("a single expression").into()),
+        }
+    }
+}
+
+/// Replace placeholder identifiers with the replacements
+struct Substitute<'a> {
+    ast: AstBuilder<'a>,
+    /// Keyed by placeholder name
+    replacements: FxHashMap<String, Replacement<'a>>,
+    used: FxHashSet<String>,
+    error: Option<TemplateError>,
+}
+
+impl<'a> Substitute<'a> {
+    fn new<'r>(
+        allocator: &'a Allocator,
+        replacements: impl IntoIterator<Item = (&'r str, Replacement<'a>)>,
+    ) -> Self {
+        Self {
+            ast: AstBuilder::new(allocator),
+            replacements: replacements.into_iter().map(|(k, v)| (k.to_string(), v)).collect(),
+            used: FxHashSet::default(),
+            error: None,
+        }
+    }
+
+    fn finish(self) -> Result<(), TemplateError> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+        let unused = self.replacements.into_keys().find(|name| !self.used.contains(name));
+        unused.map_or(Ok(()), |name| Err(TemplateError::UnusedReplacement(name)))
+    }
+
+    fn error(&mut self, error: TemplateError) {
+        self.error.get_or_insert(error);
+    }
+
+    /// Placeholder name of a template identifier
+    fn placeholder(name: &Atom) -> Option<String> {
+        name.strip_prefix(PLACEHOLDER_PREFIX)
+            .and_then(|name| name.strip_suffix(PLACEHOLDER_SUFFIX))
+            .map(ToString::to_string)
+    }
+
+    /// Take the replacement of `name`, identifiers are copied and others are moved out.
+    fn take(&mut self, name: String) -> Option<Replacement<'a>> {
+        let replacement = match self.replacements.get(&name) {
+            Some(Replacement::Identifier(ident)) => Some(Replacement::Identifier(ident.clone())),
+            Some(_) => self.replacements.remove(&name),
+            None if self.used.contains(&name) => {
+                self.error(TemplateError::ReusedReplacement(name.clone()));
+                None
+            }
+            None => {
+                self.error(TemplateError::MissingReplacement(name.clone()));
+                None
+            }
+        };
+        self.used.insert(name);
+        replacement
+    }
+
+    /// Rename a placeholder in an identifier position
+    fn rename(&mut self, name: &mut Atom) {
+        let Some(placeholder) = Self::placeholder(name) else { return };
+        match self.take(placeholder.clone()) {
+            Some(Replacement::Identifier(ident)) => *name = ident,
+            Some(Replacement::Expression(Expression::Identifier(ident))) => {
+                *name = ident.name.clone();
+            }
+            Some(_) => self.error(TemplateError::InvalidReplacement(placeholder, "an identifier")),
+            None => {}
+        }
+    }
+
+    /// Placeholder name of a `%%placeholder%%;` statement which is replaced by statements
+    fn statement_placeholder(&self, stmt: &Statement<'a>) -> Option<String> {
+        let Statement::ExpressionStatement(stmt) = stmt else { return None };
+        let Expression::Identifier(ident) = &stmt.expression else { return None };
+        Self::placeholder(&ident.name).filter(|name| {
+            matches!(
+                self.replacements.get(name),
+                Some(Replacement::Statement(_) | Replacement::Statements(_))
+            )
+        })
+    }
+}
+
+impl<'a, 'b> VisitMut<'a, 'b> for Substitute<'a> {
+    fn visit_statements(&mut self, stmts: &'b mut Vec<'a, Statement<'a>>) {
+        if stmts.iter().any(|stmt| self.statement_placeholder(stmt).is_some()) {
+            let old = std::mem::replace(stmts, self.ast.new_vec());
+            for stmt in old {
+                match self.statement_placeholder(&stmt).and_then(|name| self.take(name)) {
+                    Some(Replacement::Statement(replacement)) => stmts.push(replacement),
+                    Some(Replacement::Statements(replacements)) => stmts.extend(replacements),
+                    _ => stmts.push(stmt),
+                }
+            }
+        }
+        for stmt in stmts.iter_mut() {
+            self.visit_statement(stmt);
+        }
+    }
+
+    fn visit_expression(&mut self, expr: &'b mut Expression<'a>) {
+        let Expression::Identifier(ident) = expr else {
+            self.visit_expression_match(expr);
+            return;
+        };
+        let Some(placeholder) = Self::placeholder(&ident.name) else { return };
+        match self.take(placeholder.clone()) {
+            Some(Replacement::Identifier(name)) => ident.name = name,
+            Some(Replacement::Expression(replacement)) => *expr = replacement,
+            Some(_) => self.error(TemplateError::InvalidReplacement(placeholder, "an expression")),
+            None => {}
+        }
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &'b mut IdentifierReference) {
+        self.rename(&mut ident.name);
+    }
+
+    fn visit_binding_identifier(&mut self, ident: &'b mut BindingIdentifier) {
+        self.rename(&mut ident.name);
+    }
+
+    fn visit_label_identifier(&mut self, ident: &'b mut LabelIdentifier) {
+        self.rename(&mut ident.name);
+    }
+
+    fn visit_identifier_name(&mut self, ident: &'b mut IdentifierName) {
+        self.rename(&mut ident.name);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::{
+        ast::{Expression, Statement},
+        AstBuilder, SourceType, Span,
+    };
+
+    use super::{Replacement, Template};
+
+    /// Serialize statements without spans for comparison
+    fn to_json(stmts: &[Statement]) -> serde_json::Value {
+        fn strip_spans(value: &mut serde_json::Value) {
+            match value {
+                serde_json::Value::Object(obj) => {
+                    obj.remove("start");
+                    obj.remove("end");
+                    obj.values_mut().for_each(strip_spans);
+                }
+                serde_json::Value::Array(arr) => arr.iter_mut().for_each(strip_spans),
+                _ => {}
+            }
+        }
+        let mut value = serde_json::to_value(stmts).unwrap();
+        strip_spans(&mut value);
+        value
+    }
+
+    fn source(allocator: &Allocator, source_text: &str) -> serde_json::Value {
+        let template = Template::new(allocator, source_text, SourceType::default()).unwrap();
+        to_json(&template.build_statements([]).unwrap())
+    }
+
+    fn number<'a>(ast: &AstBuilder<'a>, value: f64) -> Expression<'a> {
+        use oxc_ast::ast::{NumberBase, NumberLiteral};
+        ast.literal_number_expression(NumberLiteral::new(
+            Span::default(),
+            ast.node_id(),
+            value,
+            ast.new_str(&value.to_string()),
+            NumberBase::Decimal,
+        ))
+    }
+
+    #[test]
+    fn placeholders() {
+        let allocator = Allocator::default();
+        let template = Template::new(
+            &allocator,
+            "function %%name%%() { return %%value%% + %%name%%; }",
+            SourceType::default(),
+        )
+        .unwrap();
+        assert_eq!(template.placeholders(), ["name", "value"]);
+        // `%%` which is not a placeholder is left to the parser
+        assert!(Template::new(&allocator, "'100%%'", SourceType::default()).is_ok());
+        assert!(Template::new(&allocator, "%%a", SourceType::default()).is_err());
+    }
+
+    #[test]
+    fn build() {
+        let allocator = Allocator::default();
+        let ast = AstBuilder::new(&allocator);
+        let template = Template::new(
+            &allocator,
+            "function %%name%%() { return %%value%% + %%name%%.length; }",
+            SourceType::default(),
+        )
+        .unwrap();
+        let stmt = template
+            .build_statement([
+                ("name", Replacement::Identifier("foo".into())),
+                ("value", number(&ast, 1.0).into()),
+            ])
+            .unwrap();
+        assert_eq!(
+            to_json(&[stmt]),
+            source(&allocator, "function foo() { return 1 + foo.length; }")
+        );
+
+        // reuse the same template
+        let stmt = template
+            .build_statement([
+                ("name", Replacement::Identifier("bar".into())),
+                ("value", number(&ast, 2.0).into()),
+            ])
+            .unwrap();
+        assert_eq!(
+            to_json(&[stmt]),
+            source(&allocator, "function bar() { return 2 + bar.length; }")
+        );
+    }
+
+    #[test]
+    fn statements() {
+        let allocator = Allocator::default();
+        let template =
+            Template::new(&allocator, "if (%%test%%) { %%body%%; }", SourceType::default())
+                .unwrap();
+        let body = Template::new(&allocator, "a(); b();", SourceType::default())
+            .unwrap()
+            .build_statements([])
+            .unwrap();
+        let test = Template::new(&allocator, "x.y", SourceType::default())
+            .unwrap()
+            .build_expression([])
+            .unwrap();
+        let stmts =
+            template.build_statements([("test", test.into()), ("body", body.into())]).unwrap();
+        assert_eq!(to_json(&stmts), source(&allocator, "if (x.y) { a(); b(); }"));
+    }
+
+    #[test]
+    fn expression() {
+        let allocator = Allocator::default();
+        let template =
+            Template::new(&allocator, "({ %%key%%: %%value%% })", SourceType::default()).unwrap();
+        let value = Template::new(&allocator, "void 0", SourceType::default())
+            .unwrap()
+            .build_expression([])
+            .unwrap();
+        let expr = template
+            .build_expression([
+                ("key", Replacement::Identifier("a".into())),
+                ("value", value.into()),
+            ])
+            .unwrap();
+        assert!(matches!(expr, Expression::ObjectExpression(_)));
+    }
+
+    #[test]
+    fn errors() {
+        let allocator = Allocator::default();
+        let ast = AstBuilder::new(&allocator);
+        let template = Template::new(&allocator, "%%a%% + %%a%%", SourceType::default()).unwrap();
+        let error = template.build_expression([("a", number(&ast, 1.0).into())]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Template placeholder `%%a%%` is used more than once, only identifiers can be reused"
+        );
+        assert!(template.build_expression([("a", Replacement::Identifier("x".into()))]).is_ok());
+
+        let error = template.build_expression([]).unwrap_err();
+        assert_eq!(error.to_string(), "Template placeholder `%%a%%` has no replacement");
+
+        let error = template
+            .build_expression([
+                ("a", Replacement::Identifier("x".into())),
+                ("b", Replacement::Identifier("y".into())),
+            ])
+            .unwrap_err();
+        assert_eq!(error.to_string(), "Template replacement `b` does not match any placeholder");
+
+        let template = Template::new(&allocator, "let %%a%%", SourceType::default()).unwrap();
+        let error = template.build_statement([("a", number(&ast, 1.0).into())]).unwrap_err();
+        assert_eq!(error.to_string(), "Template placeholder `%%a%%` expects an identifier");
+
+        let template = Template::new(&allocator, "a; b;", SourceType::default()).unwrap();
+        let error = template.build_statement([]).unwrap_err();
+        assert_eq!(error.to_string(), "Template is expected to be a single statement");
+    }
+}
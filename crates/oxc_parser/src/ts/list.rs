@@ -57,7 +57,13 @@ impl<'a> SeparatedList<'a> for TSTupleElementList<'a> {
 
             let element_type = p.parse_ts_type()?;
             self.elements.push(TSTupleElement::TSNamedTupleMember(p.ast.alloc(
-                TSNamedTupleMember { span: p.end_span(span), element_type, label, optional },
+                TSNamedTupleMember {
+                    span: p.end_span(span),
+                    node_id: p.ast.node_id(),
+                    element_type,
+                    label,
+                    optional,
+                },
             )));
 
             return Ok(());
@@ -65,17 +71,21 @@ impl<'a> SeparatedList<'a> for TSTupleElementList<'a> {
 
         if p.eat(Kind::Dot3) {
             let type_annotation = p.parse_ts_type()?;
-            self.elements.push(TSTupleElement::TSRestType(
-                p.ast.alloc(TSRestType { span: p.end_span(span), type_annotation }),
-            ));
+            self.elements.push(TSTupleElement::TSRestType(p.ast.alloc(TSRestType {
+                span: p.end_span(span),
+                node_id: p.ast.node_id(),
+                type_annotation,
+            })));
             return Ok(());
         }
 
         let type_annotation = p.parse_ts_type()?;
         if p.eat(Kind::Question) {
-            self.elements.push(TSTupleElement::TSOptionalType(
-                p.ast.alloc(TSOptionalType { span: p.end_span(span), type_annotation }),
-            ));
+            self.elements.push(TSTupleElement::TSOptionalType(p.ast.alloc(TSOptionalType {
+                span: p.end_span(span),
+                node_id: p.ast.node_id(),
+                type_annotation,
+            })));
         } else {
             self.elements.push(TSTupleElement::TSType(type_annotation));
         }
@@ -41,7 +41,7 @@ impl<'a> Parser<'a> {
         let initializer =
             if self.eat(Kind::Eq) { Some(self.parse_assignment_expression_base()?) } else { None };
 
-        Ok(TSEnumMember { span: self.end_span(span), id, initializer })
+        Ok(TSEnumMember { span: self.end_span(span), node_id: self.ast.node_id(), id, initializer })
     }
 
     fn parse_ts_enum_member_name(&mut self) -> Result<TSEnumMemberName<'a>> {
@@ -407,6 +407,7 @@ impl<'a> Parser<'a> {
             self.expect(Kind::RParen)?;
             TSModuleReference::ExternalModuleReference(TSExternalModuleReference {
                 span: self.end_span(reference_span),
+                node_id: self.ast.node_id(),
                 expression,
             })
         } else {
@@ -454,7 +455,8 @@ impl<'a> Parser<'a> {
             flags.set(modifier_flag, true);
             let kind = self.cur_kind();
             self.bump_any();
-            modifiers.push(Self::modifier(kind, self.end_span(span)));
+            let span = self.end_span(span);
+            modifiers.push(self.modifier(kind, span));
         }
 
         (flags, Modifiers::new(modifiers))
@@ -526,7 +528,7 @@ impl<'a> Parser<'a> {
             || matches!(kind, Kind::LCurly | Kind::LBrack | Kind::Star | Kind::Dot3)
     }
 
-    fn modifier(kind: Kind, span: Span) -> Modifier {
+    pub(crate) fn modifier(&self, kind: Kind, span: Span) -> Modifier {
         let modifier_kind = match kind {
             Kind::Abstract => ModifierKind::Abstract,
             Kind::Declare => ModifierKind::Declare,
@@ -545,6 +547,6 @@ impl<'a> Parser<'a> {
             Kind::Accessor => ModifierKind::Accessor,
             _ => unreachable!(),
         };
-        Modifier { span, kind: modifier_kind }
+        Modifier { span, node_id: self.ast.node_id(), kind: modifier_kind }
     }
 }
@@ -475,6 +475,7 @@ impl<'a> Parser<'a> {
             let right = self.parse_identifier_name()?;
             left = TSTypeName::QualifiedName(self.ast.alloc(TSQualifiedName {
                 span: self.end_span(span),
+                node_id: self.ast.node_id(),
                 left,
                 right,
             }));
@@ -849,7 +850,10 @@ impl<'a> Parser<'a> {
         let parameter_name = if self.at(Kind::This) {
             let span = self.start_span();
             self.bump_any();
-            TSTypePredicateName::This(TSThisKeyword { span: self.end_span(span) })
+            TSTypePredicateName::This(TSThisKeyword {
+                span: self.end_span(span),
+                node_id: self.ast.node_id(),
+            })
         } else {
             TSTypePredicateName::Identifier(self.parse_identifier_name()?)
         };
@@ -1054,6 +1058,7 @@ impl<'a> Parser<'a> {
 
         Ok(self.ast.alloc(TSIndexSignatureName {
             span: self.end_span(span),
+            node_id: self.ast.node_id(),
             name,
             type_annotation: type_annotation.unwrap(),
         }))
@@ -1062,13 +1067,14 @@ impl<'a> Parser<'a> {
     pub(crate) fn parse_class_element_modifiers(
         &mut self,
         is_constructor_parameter: bool,
-    ) -> ModifierFlags {
+    ) -> (ModifierFlags, Modifiers<'a>) {
         let mut flags = ModifierFlags::empty();
 
         if !self.ts_enabled() {
-            return flags;
+            return (flags, Modifiers::empty());
         }
 
+        let mut modifiers = self.ast.new_vec();
         loop {
             if !self.is_nth_at_modifier(0, is_constructor_parameter) {
                 break;
@@ -1080,10 +1086,13 @@ impl<'a> Parser<'a> {
                 break;
             }
 
+            let span = self.start_span();
+            let kind = self.cur_kind();
             self.bump_any();
+            modifiers.push(self.modifier(kind, self.end_span(span)));
         }
 
-        flags
+        (flags, Modifiers::new(modifiers))
     }
 
     fn parse_js_doc_unknown_or_nullable_type(&mut self) -> Result<TSType<'a>> {
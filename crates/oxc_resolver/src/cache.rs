@@ -0,0 +1,111 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::{Arc, RwLock},
+};
+
+use rustc_hash::FxHashMap;
+
+use crate::{PackageJson, ResolveError, TsConfig};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FileKind {
+    File,
+    Directory,
+    Missing,
+}
+
+/// File system lookups shared by all resolutions of a [`Resolver`](crate::Resolver)
+#[derive(Debug, Default)]
+pub struct Cache {
+    file_kinds: RwLock<FxHashMap<PathBuf, FileKind>>,
+
+    /// Keyed by the path of the package.json, `None` if it does not exist
+    package_jsons: RwLock<FxHashMap<PathBuf, Option<Arc<PackageJson>>>>,
+
+    tsconfigs: RwLock<FxHashMap<PathBuf, Arc<TsConfig>>>,
+}
+
+impl Cache {
+    pub fn clear(&self) {
+        self.file_kinds.write().unwrap().clear();
+        self.package_jsons.write().unwrap().clear();
+        self.tsconfigs.write().unwrap().clear();
+    }
+
+    fn file_kind(&self, path: &Path) -> FileKind {
+        if let Some(kind) = self.file_kinds.read().unwrap().get(path) {
+            return *kind;
+        }
+        let kind = match fs::metadata(path) {
+            Ok(metadata) if metadata.is_file() => FileKind::File,
+            Ok(metadata) if metadata.is_dir() => FileKind::Directory,
+            _ => FileKind::Missing,
+        };
+        self.file_kinds.write().unwrap().insert(path.to_path_buf(), kind);
+        kind
+    }
+
+    #[must_use]
+    pub fn is_file(&self, path: &Path) -> bool {
+        self.file_kind(path) == FileKind::File
+    }
+
+    #[must_use]
+    pub fn is_dir(&self, path: &Path) -> bool {
+        self.file_kind(path) == FileKind::Directory
+    }
+
+    /// The package.json at `path`, `None` if the file does not exist.
+    ///
+    /// # Errors
+    /// * [`ResolveError::InvalidPackageConfig`] when the file is not valid JSON
+    pub fn package_json(&self, path: &Path) -> Result<Option<Arc<PackageJson>>, ResolveError> {
+        if let Some(package_json) = self.package_jsons.read().unwrap().get(path) {
+            return Ok(package_json.clone());
+        }
+        let package_json = if self.is_file(path) {
+            let json = fs::read_to_string(path).map_err(|error| {
+                ResolveError::InvalidPackageConfig(path.to_path_buf(), error.to_string())
+            })?;
+            Some(Arc::new(PackageJson::parse(path.to_path_buf(), &json)?))
+        } else {
+            None
+        };
+        self.package_jsons.write().unwrap().insert(path.to_path_buf(), package_json.clone());
+        Ok(package_json)
+    }
+
+    /// The nearest package.json in `directory` or its ancestors.
+    ///
+    /// # Errors
+    /// * [`ResolveError::InvalidPackageConfig`] when the file is not valid JSON
+    pub fn find_package_json(
+        &self,
+        directory: &Path,
+    ) -> Result<Option<Arc<PackageJson>>, ResolveError> {
+        for directory in directory.ancestors() {
+            if let Some(package_json) = self.package_json(&directory.join("package.json"))? {
+                return Ok(Some(package_json));
+            }
+        }
+        Ok(None)
+    }
+
+    /// # Errors
+    /// * [`ResolveError::InvalidTsConfig`] when the file or a file it extends is missing or invalid
+    pub fn tsconfig(&self, path: &Path) -> Result<Arc<TsConfig>, ResolveError> {
+        if let Some(tsconfig) = self.tsconfigs.read().unwrap().get(path) {
+            return Ok(Arc::clone(tsconfig));
+        }
+        let json = fs::read_to_string(path).map_err(|error| {
+            ResolveError::InvalidTsConfig(path.to_path_buf(), error.to_string())
+        })?;
+        let tsconfig = TsConfig::parse(path, &json, |extends| {
+            self.tsconfig(extends).map(|tsconfig| (*tsconfig).clone())
+        })?;
+        let tsconfig = Arc::new(tsconfig);
+        self.tsconfigs.write().unwrap().insert(path.to_path_buf(), Arc::clone(&tsconfig));
+        Ok(tsconfig)
+    }
+}
@@ -0,0 +1,32 @@
+use std::path::PathBuf;
+
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ResolveError {
+    #[error("Cannot find module `{0}`")]
+    NotFound(String),
+
+    /// Node.js builtin modules such as `fs` and `node:path` do not resolve to a file
+    #[error("`{0}` is a builtin module")]
+    Builtin(String),
+
+    #[error("Package subpath `{0}` is not defined by \"exports\" in {1:?}")]
+    PackagePathNotExported(String, PathBuf),
+
+    #[error("Package import specifier `{0}` is not defined in {1:?}")]
+    PackageImportNotDefined(String, PathBuf),
+
+    #[error("Invalid package target `{0}` in {1:?}")]
+    InvalidPackageTarget(String, PathBuf),
+
+    /// The part of a specifier matched by `*` has `.`, `..`, `node_modules` or empty segments
+    #[error("Invalid module specifier `{0}` for the pattern targets in {1:?}")]
+    InvalidModuleSpecifier(String, PathBuf),
+
+    #[error("Invalid package config {0:?}: {1}")]
+    InvalidPackageConfig(PathBuf, String),
+
+    #[error("Invalid tsconfig {0:?}: {1}")]
+    InvalidTsConfig(PathBuf, String),
+}
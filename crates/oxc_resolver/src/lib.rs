@@ -0,0 +1,404 @@
+//! Node.js and TypeScript module resolution
+//!
+//! * [CommonJS](https://nodejs.org/api/modules.html#all-together)
+//! * [ESM](https://nodejs.org/api/esm.html#resolution-algorithm-specification)
+//! * [TypeScript `paths` and `baseUrl`](https://www.typescriptlang.org/tsconfig#paths)
+
+#![feature(is_some_and)]
+#![feature(let_chains)]
+
+mod builtins;
+mod cache;
+mod error;
+mod options;
+mod package_json;
+mod tsconfig;
+
+use std::path::{Component, Path, PathBuf};
+
+use serde_json::{Map, Value};
+
+use crate::cache::Cache;
+pub use crate::{
+    builtins::NODEJS_BUILTINS, error::ResolveError, options::ResolveOptions,
+    package_json::PackageJson, tsconfig::TsConfig,
+};
+
+type ResolveResult = Result<Option<PathBuf>, ResolveError>;
+
+/// Resolves module specifiers to files.
+/// File system lookups, package.json and tsconfig.json files are cached until [`Resolver::clear_cache`].
+#[derive(Debug, Default)]
+pub struct Resolver {
+    options: ResolveOptions,
+
+    cache: Cache,
+}
+
+impl Resolver {
+    #[must_use]
+    pub fn new(options: ResolveOptions) -> Self {
+        Self { options, cache: Cache::default() }
+    }
+
+    #[must_use]
+    pub fn options(&self) -> &ResolveOptions {
+        &self.options
+    }
+
+    /// Clear the cache, e.g. after files are changed.
+    pub fn clear_cache(&self) {
+        self.cache.clear();
+    }
+
+    /// Resolve `specifier` imported by a file in `directory` to the path of a file.
+    ///
+    /// # Errors
+    /// * [`ResolveError::NotFound`] when no file is found
+    /// * [`ResolveError::Builtin`] for Node.js builtin modules
+    /// * other [`ResolveError`]s for invalid package.json and tsconfig.json files
+    pub fn resolve<P: AsRef<Path>>(
+        &self,
+        directory: P,
+        specifier: &str,
+    ) -> Result<PathBuf, ResolveError> {
+        self.resolve_impl(directory.as_ref(), specifier)?
+            .ok_or_else(|| ResolveError::NotFound(specifier.to_string()))
+    }
+
+    fn resolve_impl(&self, directory: &Path, specifier: &str) -> ResolveResult {
+        if is_relative(specifier) || Path::new(specifier).is_absolute() {
+            let path = normalize(&directory.join(specifier));
+            if specifier.ends_with('/') {
+                return self.load_as_directory(&path);
+            }
+            return self.load_as_file_or_directory(&path, self.options.fully_specified);
+        }
+        if specifier.starts_with('#') {
+            return self.package_imports_resolve(directory, specifier);
+        }
+        if let Some(tsconfig) = &self.options.tsconfig {
+            for path in self.cache.tsconfig(tsconfig)?.resolve_paths(specifier) {
+                if let Some(path) = self.load_as_file_or_directory(&path, false)? {
+                    return Ok(Some(path));
+                }
+            }
+        }
+        if specifier.starts_with("node:") || NODEJS_BUILTINS.contains(&specifier) {
+            return Err(ResolveError::Builtin(specifier.to_string()));
+        }
+        if let Some(path) = self.load_package_self(directory, specifier)? {
+            return Ok(Some(path));
+        }
+        self.load_node_modules(directory, specifier)
+    }
+
+    fn load_as_file_or_directory(&self, path: &Path, fully_specified: bool) -> ResolveResult {
+        if let Some(path) = self.load_as_file(path, fully_specified) {
+            return Ok(Some(path));
+        }
+        if fully_specified {
+            return Ok(None);
+        }
+        self.load_as_directory(path)
+    }
+
+    fn load_as_file(&self, path: &Path, fully_specified: bool) -> Option<PathBuf> {
+        if self.cache.is_file(path) {
+            return Some(path.to_path_buf());
+        }
+        if fully_specified {
+            return None;
+        }
+        self.options
+            .extensions
+            .iter()
+            .map(|extension| with_suffix(path, extension))
+            .find(|path| self.cache.is_file(path))
+    }
+
+    fn load_index(&self, path: &Path) -> Option<PathBuf> {
+        self.options
+            .extensions
+            .iter()
+            .map(|extension| path.join(format!("index{extension}")))
+            .find(|path| self.cache.is_file(path))
+    }
+
+    fn load_as_directory(&self, path: &Path) -> ResolveResult {
+        if !self.cache.is_dir(path) {
+            return Ok(None);
+        }
+        if let Some(package_json) = self.cache.package_json(&path.join("package.json"))? {
+            for main in package_json.main_fields(&self.options.main_fields) {
+                let main = normalize(&path.join(main));
+                if let Some(path) =
+                    self.load_as_file(&main, false).or_else(|| self.load_index(&main))
+                {
+                    return Ok(Some(path));
+                }
+            }
+        }
+        Ok(self.load_index(path))
+    }
+
+    /// A package importing itself by its own name
+    fn load_package_self(&self, directory: &Path, specifier: &str) -> ResolveResult {
+        let Some((name, subpath)) = parse_package_name(specifier) else { return Ok(None) };
+        let Some(package_json) = self.cache.find_package_json(directory)? else { return Ok(None) };
+        if package_json.name() != Some(name) {
+            return Ok(None);
+        }
+        let Some(exports) = package_json.exports() else { return Ok(None) };
+        self.package_exports_resolve(&package_json, &subpath, exports).map(Some)
+    }
+
+    fn load_node_modules(&self, directory: &Path, specifier: &str) -> ResolveResult {
+        let Some((name, subpath)) = parse_package_name(specifier) else { return Ok(None) };
+        for directory in directory.ancestors() {
+            if directory.file_name().is_some_and(|name| name == "node_modules") {
+                continue;
+            }
+            let package_directory = directory.join("node_modules").join(name);
+            if !self.cache.is_dir(&package_directory) {
+                continue;
+            }
+            let package_json = self.cache.package_json(&package_directory.join("package.json"))?;
+            if let Some(package_json) = &package_json && let Some(exports) = package_json.exports() {
+                return self.package_exports_resolve(package_json, &subpath, exports).map(Some);
+            }
+            let path = normalize(&package_directory.join(&subpath));
+            if let Some(path) = self.load_as_file_or_directory(&path, false)? {
+                return Ok(Some(path));
+            }
+        }
+        Ok(None)
+    }
+
+    /// [PACKAGE_EXPORTS_RESOLVE](https://nodejs.org/api/esm.html#resolution-algorithm-specification)
+    fn package_exports_resolve(
+        &self,
+        package_json: &PackageJson,
+        subpath: &str,
+        exports: &Value,
+    ) -> Result<PathBuf, ResolveError> {
+        let not_exported = || {
+            ResolveError::PackagePathNotExported(subpath.to_string(), package_json.path().into())
+        };
+        let resolved = match exports {
+            Value::Object(map) if map.keys().any(|key| key.starts_with('.')) => {
+                if !map.keys().all(|key| key.starts_with('.')) {
+                    return Err(ResolveError::InvalidPackageConfig(
+                        package_json.path().into(),
+                        "\"exports\" cannot mix subpaths and conditions".into(),
+                    ));
+                }
+                self.package_imports_exports_resolve(subpath, map, package_json, false)?
+            }
+            // `exports` is the main export, as a string, an array or conditions
+            _ if subpath == "." => {
+                self.package_target_resolve(package_json, exports, None, false)?
+            }
+            _ => None,
+        };
+        let path = resolved.ok_or_else(not_exported)?;
+        if !self.cache.is_file(&path) {
+            return Err(ResolveError::NotFound(path.to_string_lossy().into()));
+        }
+        Ok(path)
+    }
+
+    /// [PACKAGE_IMPORTS_RESOLVE](https://nodejs.org/api/esm.html#resolution-algorithm-specification)
+    fn package_imports_resolve(&self, directory: &Path, specifier: &str) -> ResolveResult {
+        let not_defined = |path: &Path| {
+            ResolveError::PackageImportNotDefined(specifier.to_string(), path.to_path_buf())
+        };
+        if specifier == "#" || specifier.starts_with("#/") {
+            return Err(not_defined(directory));
+        }
+        let Some(package_json) = self.cache.find_package_json(directory)? else {
+            return Err(not_defined(directory));
+        };
+        let Some(imports) = package_json.imports() else {
+            return Err(not_defined(package_json.path()));
+        };
+        let Some(path) = self.package_imports_exports_resolve(specifier, imports, &package_json, true)? else {
+            return Err(not_defined(package_json.path()));
+        };
+        if !self.cache.is_file(&path) {
+            return Err(ResolveError::NotFound(specifier.to_string()));
+        }
+        Ok(Some(path))
+    }
+
+    /// [PACKAGE_IMPORTS_EXPORTS_RESOLVE](https://nodejs.org/api/esm.html#resolution-algorithm-specification)
+    fn package_imports_exports_resolve(
+        &self,
+        match_key: &str,
+        match_obj: &Map<String, Value>,
+        package_json: &PackageJson,
+        is_imports: bool,
+    ) -> ResolveResult {
+        if !match_key.contains('*') && let Some(target) = match_obj.get(match_key) {
+            return self.package_target_resolve(package_json, target, None, is_imports);
+        }
+
+        // The most specific pattern, e.g. `./a/b/*` over `./a/*`
+        let mut best_match: Option<(&str, &Value, &str)> = None;
+        for (key, target) in match_obj {
+            let Some((base, trailer)) = key.split_once('*') else { continue };
+            if trailer.contains('*')
+                || !match_key.starts_with(base)
+                || match_key == base
+                || !(trailer.is_empty()
+                    || (match_key.ends_with(trailer) && match_key.len() >= key.len()))
+            {
+                continue;
+            }
+            if best_match.map_or(true, |(best_key, ..)| pattern_key_compare(key, best_key)) {
+                let pattern_match = &match_key[base.len()..match_key.len() - trailer.len()];
+                best_match = Some((key, target, pattern_match));
+            }
+        }
+        match best_match {
+            Some((_, target, pattern_match)) => {
+                self.package_target_resolve(package_json, target, Some(pattern_match), is_imports)
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// [PACKAGE_TARGET_RESOLVE](https://nodejs.org/api/esm.html#resolution-algorithm-specification)
+    fn package_target_resolve(
+        &self,
+        package_json: &PackageJson,
+        target: &Value,
+        pattern_match: Option<&str>,
+        is_imports: bool,
+    ) -> ResolveResult {
+        let invalid = |target: &str| {
+            ResolveError::InvalidPackageTarget(target.into(), package_json.path().into())
+        };
+        match target {
+            Value::String(target) => {
+                let replaced = pattern_match.map_or_else(
+                    || target.clone(),
+                    |pattern_match| target.replace('*', pattern_match),
+                );
+                if !target.starts_with("./") {
+                    // `imports` may map to another package, e.g. `"#dep": "dep"`
+                    if !is_imports || target.starts_with("../") || target.starts_with('/') {
+                        return Err(invalid(target));
+                    }
+                    return self.resolve_impl(package_json.directory(), &replaced);
+                }
+                if target
+                    .split(['/', '\\'])
+                    .skip(1)
+                    .any(|segment| matches!(segment, "." | ".." | "node_modules"))
+                {
+                    return Err(invalid(target));
+                }
+                if let Some(pattern_match) = pattern_match
+                    && pattern_match.split(['/', '\\']).any(|segment| {
+                        matches!(segment, "" | "." | "..")
+                            || segment.eq_ignore_ascii_case("node_modules")
+                    })
+                {
+                    return Err(ResolveError::InvalidModuleSpecifier(
+                        pattern_match.into(),
+                        package_json.path().into(),
+                    ));
+                }
+                Ok(Some(normalize(&package_json.directory().join(replaced))))
+            }
+            Value::Object(conditions) => {
+                for (condition, target) in conditions {
+                    if condition == "default" || self.options.condition_names.contains(condition) {
+                        let resolved = self.package_target_resolve(
+                            package_json,
+                            target,
+                            pattern_match,
+                            is_imports,
+                        )?;
+                        if resolved.is_some() {
+                            return Ok(resolved);
+                        }
+                    }
+                }
+                Ok(None)
+            }
+            Value::Array(targets) => {
+                let mut last_error = None;
+                for target in targets {
+                    match self.package_target_resolve(
+                        package_json,
+                        target,
+                        pattern_match,
+                        is_imports,
+                    ) {
+                        Ok(None) => {}
+                        Ok(resolved) => return Ok(resolved),
+                        Err(error) => last_error = Some(error),
+                    }
+                }
+                last_error.map_or(Ok(None), Err)
+            }
+            Value::Null => Ok(None),
+            _ => Err(invalid(&target.to_string())),
+        }
+    }
+}
+
+fn is_relative(specifier: &str) -> bool {
+    matches!(specifier, "." | "..") || specifier.starts_with("./") || specifier.starts_with("../")
+}
+
+/// Split a bare specifier into the package name and the subpath, e.g.
+/// `@scope/pkg/sub` into `@scope/pkg` and `./sub`, and `pkg` into `pkg` and `.`
+fn parse_package_name(specifier: &str) -> Option<(&str, String)> {
+    let separator = if specifier.starts_with('@') {
+        let scope_end = specifier.find('/')?;
+        specifier[scope_end + 1..].find('/').map(|index| scope_end + 1 + index)
+    } else {
+        specifier.find('/')
+    };
+    let (name, rest) = separator.map_or((specifier, ""), |index| specifier.split_at(index));
+    if name.is_empty() || name.starts_with('.') || name.contains('\\') || name.contains('%') {
+        return None;
+    }
+    Some((name, format!(".{rest}")))
+}
+
+/// [PATTERN_KEY_COMPARE](https://nodejs.org/api/esm.html#resolution-algorithm-specification),
+/// whether `a` is more specific than `b`
+fn pattern_key_compare(a: &str, b: &str) -> bool {
+    let base_length_a = a.find('*').unwrap_or(a.len());
+    let base_length_b = b.find('*').unwrap_or(b.len());
+    base_length_a > base_length_b || (base_length_a == base_length_b && a.len() > b.len())
+}
+
+pub(crate) fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut path = path.as_os_str().to_os_string();
+    path.push(suffix);
+    PathBuf::from(path)
+}
+
+/// Remove `.` and `..` components without accessing the file system.
+pub(crate) fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => match normalized.components().next_back() {
+                Some(Component::Normal(_)) => {
+                    normalized.pop();
+                }
+                Some(Component::RootDir | Component::Prefix(_)) => {}
+                _ => normalized.push(".."),
+            },
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
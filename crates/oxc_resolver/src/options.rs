@@ -0,0 +1,37 @@
+use std::path::PathBuf;
+
+#[derive(Debug, Clone)]
+pub struct ResolveOptions {
+    /// Extensions to try when a path does not point to a file, in order.
+    pub extensions: Vec<String>,
+
+    /// Fields of package.json pointing to the entry of a package, in order.
+    pub main_fields: Vec<String>,
+
+    /// Conditions to match in package.json `exports` and `imports`, `default` always matches.
+    /// Use `require` instead of `import` for CommonJS resolution.
+    pub condition_names: Vec<String>,
+
+    /// Require relative specifiers to include the extension and not point to a directory,
+    /// as in Node's ESM resolution.
+    pub fully_specified: bool,
+
+    /// Path to a tsconfig.json whose `compilerOptions.paths` and `compilerOptions.baseUrl` are
+    /// tried before `node_modules`.
+    pub tsconfig: Option<PathBuf>,
+}
+
+impl Default for ResolveOptions {
+    fn default() -> Self {
+        Self {
+            extensions: [".js", ".jsx", ".mjs", ".cjs", ".ts", ".tsx", ".mts", ".cts", ".json"]
+                .into_iter()
+                .map(String::from)
+                .collect(),
+            main_fields: vec!["main".into()],
+            condition_names: vec!["node".into(), "import".into()],
+            fully_specified: false,
+            tsconfig: None,
+        }
+    }
+}
@@ -0,0 +1,61 @@
+//! package.json fields used for resolution
+//! <https://nodejs.org/api/packages.html#nodejs-packagejson-field-definitions>
+
+use std::path::{Path, PathBuf};
+
+use serde_json::{Map, Value};
+
+use crate::ResolveError;
+
+#[derive(Debug)]
+pub struct PackageJson {
+    path: PathBuf,
+
+    fields: Map<String, Value>,
+}
+
+impl PackageJson {
+    /// # Errors
+    /// * [`ResolveError::InvalidPackageConfig`] when `json` is not a JSON object
+    pub fn parse(path: PathBuf, json: &str) -> Result<Self, ResolveError> {
+        match serde_json::from_str(json) {
+            Ok(Value::Object(fields)) => Ok(Self { path, fields }),
+            Ok(_) => Err(ResolveError::InvalidPackageConfig(path, "expected an object".into())),
+            Err(error) => Err(ResolveError::InvalidPackageConfig(path, error.to_string())),
+        }
+    }
+
+    /// Path to the package.json file
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The directory containing the package.json file
+    /// # Panics
+    /// * When the path has no parent, which never happens for a path from the resolver
+    #[must_use]
+    pub fn directory(&self) -> &Path {
+        self.path.parent().unwrap()
+    }
+
+    #[must_use]
+    pub fn name(&self) -> Option<&str> {
+        self.fields.get("name").and_then(Value::as_str)
+    }
+
+    /// The values of the `main_fields` which are strings, in order.
+    pub fn main_fields<'a>(&'a self, main_fields: &'a [String]) -> impl Iterator<Item = &'a str> {
+        main_fields.iter().filter_map(|field| self.fields.get(field).and_then(Value::as_str))
+    }
+
+    #[must_use]
+    pub fn exports(&self) -> Option<&Value> {
+        self.fields.get("exports").filter(|exports| !exports.is_null())
+    }
+
+    #[must_use]
+    pub fn imports(&self) -> Option<&Map<String, Value>> {
+        self.fields.get("imports").and_then(Value::as_object)
+    }
+}
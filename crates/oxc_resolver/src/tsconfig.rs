@@ -0,0 +1,171 @@
+//! `compilerOptions.baseUrl` and `compilerOptions.paths` of tsconfig.json
+//! <https://www.typescriptlang.org/tsconfig#paths>
+
+use std::{
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use json_comments::StripComments;
+use serde_json::Value;
+
+use crate::{normalize, with_suffix, ResolveError};
+
+#[derive(Debug, Default, Clone)]
+pub struct TsConfig {
+    /// Absolute `compilerOptions.baseUrl`
+    base_url: Option<PathBuf>,
+
+    /// `compilerOptions.paths`, in order
+    paths: Vec<(String, Vec<String>)>,
+
+    /// The directory `paths` are relative to,
+    /// which is `baseUrl` if it is set or the directory of the tsconfig declaring `paths`
+    paths_base: PathBuf,
+}
+
+impl TsConfig {
+    /// Parse a tsconfig.json, which may contain comments and trailing commas.
+    /// `extends` is followed with `load` when it is a relative path.
+    ///
+    /// # Errors
+    /// * [`ResolveError::InvalidTsConfig`] when the file is not valid JSON
+    pub fn parse(
+        path: &Path,
+        json: &str,
+        load: impl Fn(&Path) -> Result<Self, ResolveError>,
+    ) -> Result<Self, ResolveError> {
+        let invalid = |message: String| ResolveError::InvalidTsConfig(path.to_path_buf(), message);
+        let json = strip_json_comments(json).map_err(|e| invalid(e.to_string()))?;
+        let value: Value = serde_json::from_str(&json).map_err(|e| invalid(e.to_string()))?;
+        let directory = path.parent().unwrap_or_else(|| Path::new(""));
+
+        let mut tsconfig = match value.get("extends").and_then(Value::as_str) {
+            Some(extends) if extends.starts_with('.') => {
+                let mut extends = normalize(&directory.join(extends));
+                if !extends.to_string_lossy().ends_with(".json") {
+                    extends = with_suffix(&extends, ".json");
+                }
+                load(&extends)?
+            }
+            _ => Self { paths_base: directory.to_path_buf(), ..Self::default() },
+        };
+
+        let Some(compiler_options) = value.get("compilerOptions") else { return Ok(tsconfig) };
+        if let Some(base_url) = compiler_options.get("baseUrl").and_then(Value::as_str) {
+            let base_url = normalize(&directory.join(base_url));
+            tsconfig.paths_base = base_url.clone();
+            tsconfig.base_url = Some(base_url);
+        }
+        if let Some(paths) = compiler_options.get("paths").and_then(Value::as_object) {
+            if tsconfig.base_url.is_none() {
+                tsconfig.paths_base = directory.to_path_buf();
+            }
+            tsconfig.paths = paths
+                .iter()
+                .map(|(pattern, substitutions)| {
+                    let substitutions = substitutions
+                        .as_array()
+                        .map(|substitutions| {
+                            substitutions
+                                .iter()
+                                .filter_map(Value::as_str)
+                                .map(String::from)
+                                .collect()
+                        })
+                        .unwrap_or_default();
+                    (pattern.clone(), substitutions)
+                })
+                .collect();
+        }
+        Ok(tsconfig)
+    }
+
+    /// Candidate paths for a non-relative `specifier`, in the order they should be tried.
+    /// The pattern with the longest prefix before `*` is used,
+    /// followed by `specifier` relative to `baseUrl`.
+    #[must_use]
+    pub fn resolve_paths(&self, specifier: &str) -> Vec<PathBuf> {
+        let mut best_match: Option<(&[String], &str, usize)> = None;
+        for (pattern, substitutions) in &self.paths {
+            match pattern.split_once('*') {
+                None if pattern == specifier => {
+                    best_match = Some((substitutions, "", usize::MAX));
+                    break;
+                }
+                Some((prefix, suffix))
+                    if specifier.len() >= prefix.len() + suffix.len()
+                        && specifier.starts_with(prefix)
+                        && specifier.ends_with(suffix)
+                        && best_match.map_or(true, |(.., len)| prefix.len() > len) =>
+                {
+                    let matched = &specifier[prefix.len()..specifier.len() - suffix.len()];
+                    best_match = Some((substitutions, matched, prefix.len()));
+                }
+                _ => {}
+            }
+        }
+
+        let mut candidates = best_match
+            .map(|(substitutions, matched, _)| {
+                substitutions
+                    .iter()
+                    .map(|substitution| {
+                        normalize(&self.paths_base.join(substitution.replace('*', matched)))
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        if let Some(base_url) = &self.base_url {
+            candidates.push(normalize(&base_url.join(specifier)));
+        }
+        candidates
+    }
+}
+
+/// Remove `//` and `/* */` comments and trailing commas, which tsconfig.json allows.
+fn strip_json_comments(json: &str) -> io::Result<String> {
+    let mut stripped = String::with_capacity(json.len());
+    StripComments::new(json.as_bytes()).read_to_string(&mut stripped)?;
+    Ok(strip_trailing_commas(&stripped))
+}
+
+/// `json_comments` only removes the comments, the commas before a closing bracket are dropped here.
+fn strip_trailing_commas(json: &str) -> String {
+    let mut result = String::with_capacity(json.len());
+    let mut in_string = false;
+    let mut escaped = false;
+    for c in json.chars() {
+        if in_string {
+            in_string = escaped || c != '"';
+            escaped = !escaped && c == '\\';
+        } else if c == '"' {
+            in_string = true;
+        } else if matches!(c, ']' | '}') {
+            let trimmed = result.trim_end().len();
+            if result[..trimmed].ends_with(',') {
+                result.truncate(trimmed - 1);
+            }
+        }
+        result.push(c);
+    }
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::strip_json_comments;
+
+    #[test]
+    fn strip_comments() {
+        let json = r#"{
+            // comment
+            "a": "// not a comment", /* comment */
+            "b": ["\"/*", ],
+            "c": "\\",
+        }"#;
+        let value: serde_json::Value =
+            serde_json::from_str(&strip_json_comments(json).unwrap()).unwrap();
+        assert_eq!(value, serde_json::json!({ "a": "// not a comment", "b": ["\"/*"], "c": "\\" }));
+    }
+}
@@ -0,0 +1,163 @@
+use std::path::{Path, PathBuf};
+
+use oxc_resolver::{ResolveError, ResolveOptions, Resolver};
+
+fn fixture(path: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures").join(path)
+}
+
+#[test]
+fn relative() {
+    let resolver = Resolver::default();
+    let project = fixture("project");
+    let pass = [
+        ("./a", "a.js"),
+        ("./a.js", "a.js"),
+        ("./b", "b.ts"),
+        ("./c.json", "c.json"),
+        ("./dir", "dir/index.js"),
+        ("./dir/", "dir/index.js"),
+        ("./main-dir", "main-dir/lib/entry.js"),
+        ("./src/../a", "a.js"),
+    ];
+    for (specifier, expected) in pass {
+        assert_eq!(
+            resolver.resolve(&project, specifier),
+            Ok(project.join(expected)),
+            "{specifier}"
+        );
+    }
+    assert_eq!(resolver.resolve(project.join("src"), "../a"), Ok(project.join("a.js")));
+    assert_eq!(
+        resolver.resolve(&project, "./missing"),
+        Err(ResolveError::NotFound("./missing".into()))
+    );
+}
+
+#[test]
+fn fully_specified() {
+    let resolver =
+        Resolver::new(ResolveOptions { fully_specified: true, ..ResolveOptions::default() });
+    let project = fixture("project");
+    assert_eq!(resolver.resolve(&project, "./a.js"), Ok(project.join("a.js")));
+    assert!(resolver.resolve(&project, "./a").is_err());
+    assert!(resolver.resolve(&project, "./dir").is_err());
+    // Packages are not affected
+    assert_eq!(
+        resolver.resolve(&project, "main-field"),
+        Ok(project.join("node_modules/main-field/lib/index.js"))
+    );
+}
+
+#[test]
+fn node_modules() {
+    let resolver = Resolver::default();
+    let project = fixture("project");
+    let node_modules = project.join("node_modules");
+    let pass = [
+        ("main-field", "main-field/lib/index.js"),
+        ("no-package-json", "no-package-json/index.js"),
+        ("subpath/feature", "subpath/feature.js"),
+        ("@scope/pkg", "@scope/pkg/index.js"),
+        ("@scope/pkg/sub", "@scope/pkg/sub.js"),
+    ];
+    for (specifier, expected) in pass {
+        assert_eq!(resolver.resolve(&project, specifier), Ok(node_modules.join(expected)));
+        // Looked up from ancestor directories
+        let nested = project.join("src");
+        assert_eq!(resolver.resolve(nested, specifier), Ok(node_modules.join(expected)));
+    }
+    assert_eq!(
+        resolver.resolve(&project, "missing"),
+        Err(ResolveError::NotFound("missing".into()))
+    );
+    assert_eq!(resolver.resolve(&project, "fs"), Err(ResolveError::Builtin("fs".into())));
+    assert_eq!(resolver.resolve(&project, "node:fs"), Err(ResolveError::Builtin("node:fs".into())));
+}
+
+#[test]
+fn exports() {
+    let resolver = Resolver::default();
+    let project = fixture("project");
+    let exports = project.join("node_modules/exports");
+    let pass = [
+        ("exports", "esm.mjs"),
+        ("exports/feature", "feature-node.js"),
+        ("exports/lib/a", "lib/a.js"),
+        ("exports/fallback", "fallback.js"),
+    ];
+    for (specifier, expected) in pass {
+        assert_eq!(
+            resolver.resolve(&project, specifier),
+            Ok(exports.join(expected)),
+            "{specifier}"
+        );
+    }
+
+    let package_json = exports.join("package.json");
+    for subpath in ["./lib/private/b", "./esm.mjs", "./missing"] {
+        let specifier = format!("exports{}", &subpath[1..]);
+        assert_eq!(
+            resolver.resolve(&project, &specifier),
+            Err(ResolveError::PackagePathNotExported(subpath.into(), package_json.clone()))
+        );
+    }
+
+    // `*` cannot match segments leaving the directory of the pattern target
+    for pattern_match in ["../../../x", "./a", "b//a", "node_modules/x"] {
+        let specifier = format!("exports/lib/{pattern_match}");
+        assert_eq!(
+            resolver.resolve(&project, &specifier),
+            Err(ResolveError::InvalidModuleSpecifier(pattern_match.into(), package_json.clone())),
+            "{specifier}"
+        );
+    }
+
+    assert_eq!(
+        resolver.resolve(&project, "exports-string"),
+        Ok(project.join("node_modules/exports-string/main.js"))
+    );
+
+    let condition_names = vec!["require".into()];
+    let resolver = Resolver::new(ResolveOptions { condition_names, ..ResolveOptions::default() });
+    assert_eq!(resolver.resolve(&project, "exports"), Ok(exports.join("cjs.cjs")));
+    assert_eq!(resolver.resolve(&project, "exports/feature"), Ok(exports.join("feature.js")));
+}
+
+#[test]
+fn imports_and_self_reference() {
+    let resolver = Resolver::default();
+    let project = fixture("project");
+    assert_eq!(resolver.resolve(&project, "#utils/format"), Ok(project.join("utils/format.js")));
+    assert_eq!(
+        resolver.resolve(project.join("src"), "#dep"),
+        Ok(project.join("node_modules/main-field/lib/index.js"))
+    );
+    let package_json = project.join("package.json");
+    for specifier in ["#private", "#missing"] {
+        assert_eq!(
+            resolver.resolve(&project, specifier),
+            Err(ResolveError::PackageImportNotDefined(specifier.into(), package_json.clone()))
+        );
+    }
+    assert_eq!(resolver.resolve(project.join("src"), "project/self"), Ok(project.join("a.js")));
+}
+
+#[test]
+fn tsconfig_paths() {
+    let root = fixture("tsconfig");
+    let tsconfig = Some(root.join("tsconfig.json"));
+    let resolver = Resolver::new(ResolveOptions { tsconfig, ..ResolveOptions::default() });
+    let src = root.join("src");
+    let pass = [
+        ("@app/a", "app/a.ts"),
+        ("@app/b", "fallback/b.ts"),
+        ("exact", "exact.ts"),
+        // `baseUrl` from the extended tsconfig
+        ("src/base", "base.ts"),
+    ];
+    for (specifier, expected) in pass {
+        assert_eq!(resolver.resolve(&src, specifier), Ok(src.join(expected)), "{specifier}");
+    }
+    assert!(resolver.resolve(&src, "@app/missing").is_err());
+}
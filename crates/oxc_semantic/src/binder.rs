@@ -2,11 +2,11 @@
 
 #[allow(clippy::wildcard_imports)]
 use oxc_ast::ast::*;
-use oxc_ast::{syntax_directed_operations::BoundNames, AstKind, SourceType};
+use oxc_ast::{syntax_directed_operations::BoundNames, AstKind, Atom, SourceType};
 
 use crate::{
     scope::{Scope, ScopeFlags, ScopeId},
-    symbol::SymbolFlags,
+    symbol::{SymbolFlags, SymbolId},
     SemanticBuilder,
 };
 
@@ -67,10 +67,16 @@ impl<'a> Binder for VariableDeclarator<'a> {
 impl<'a> Binder for Class<'a> {
     fn bind(&self, builder: &mut SemanticBuilder) {
         if let Some(ident) = &self.id && !self.modifiers.contains(ModifierKind::Declare) {
+            let mut scope_id = builder.scope.current_scope_id;
+            // A class declaration with type parameters has its own scope for them,
+            // but the class name is bound to the enclosing scope.
+            if self.is_declaration() && self.type_parameters.is_some() {
+                scope_id = builder.scope.scopes[*scope_id].parent().unwrap().into();
+            }
             builder.declare_symbol(
                 &ident.name,
                 ident.span,
-                builder.scope.current_scope_id,
+                scope_id,
                 SymbolFlags::Class,
                 SymbolFlags::ClassExcludes,
             );
@@ -107,23 +113,73 @@ impl<'a> Binder for Function<'a> {
                     if (parent_scope.strict_mode || self.r#async || self.generator)
                         && !function_as_var(parent_scope, builder.source_type)
                     {
-                        (SymbolFlags::BlockScopedVariable, SymbolFlags::BlockScopedVariableExcludes)
+                        (
+                            SymbolFlags::BlockScopedVariable | SymbolFlags::Function,
+                            SymbolFlags::BlockScopedVariableExcludes,
+                        )
                     } else {
                         (
-                            SymbolFlags::FunctionScopedVariable,
+                            SymbolFlags::FunctionScopedVariable | SymbolFlags::Function,
                             SymbolFlags::FunctionScopedVariableExcludes,
                         )
                     };
 
-                builder.declare_symbol(
+                let symbol_id = builder.declare_symbol(
                     &ident.name,
                     ident.span,
                     parent_scope_id,
                     includes,
                     excludes,
                 );
+
+                let parent_scope: &Scope = &builder.scope.scopes[parent_scope_id];
+                if !parent_scope.strict_mode
+                    && !self.r#async
+                    && !self.generator
+                    && !function_as_var(parent_scope, builder.source_type)
+                {
+                    hoist_block_level_function(builder, &ident.name, parent_scope_id, symbol_id);
+                }
+            }
+        }
+    }
+}
+
+/// Block-level functions in sloppy mode are also bound to the enclosing var scope,
+/// unless this conflicts with a lexical declaration in between or a parameter of the same name.
+/// See [Changes to FunctionDeclarationInstantiation](https://tc39.es/ecma262/#sec-web-compat-functiondeclarationinstantiation)
+fn hoist_block_level_function(
+    builder: &mut SemanticBuilder,
+    name: &Atom,
+    block_scope_id: ScopeId,
+    symbol_id: SymbolId,
+) {
+    let mut scope_ids = vec![];
+    for scope_id in block_scope_id.ancestors(&builder.scope.scopes).skip(1) {
+        let scope = builder.scope.scopes[scope_id].get();
+        match scope.get_variable_symbol_id(name) {
+            // Parameters are not replaced, e.g. `function g(f) { { function f() {} } f }`
+            Some(id)
+                if matches!(
+                    builder.nodes.kind(builder.symbols[id].declaration()),
+                    AstKind::FormalParameters(_)
+                ) =>
+            {
+                return;
             }
+            // `var` declarations are shared with the function
+            Some(id)
+                if builder.symbols[id].flags().intersects(SymbolFlags::FunctionScopedVariable) => {}
+            Some(_) => return,
+            None => scope_ids.push(ScopeId::from(scope_id)),
         }
+        if scope.flags.intersects(ScopeFlags::VAR) {
+            break;
+        }
+    }
+    for scope_id in scope_ids {
+        builder.scope.scopes[scope_id].variables.insert(name.clone(), symbol_id);
+        builder.hoisted_functions.insert((scope_id, name.clone()));
     }
 }
 
@@ -179,8 +235,299 @@ impl<'a> Binder for ModuleDeclaration<'a> {
                 ident.span,
                 builder.scope.current_scope_id,
                 SymbolFlags::empty(),
-                SymbolFlags::empty(),
+                SymbolFlags::ImportExcludes,
             );
         }
     }
 }
+
+/// Binds the name to the scope enclosing the declaration's own scope,
+/// e.g. `A` in `interface A<T> {}`
+fn declare_in_parent_scope(
+    builder: &mut SemanticBuilder,
+    ident: &BindingIdentifier,
+    includes: SymbolFlags,
+    excludes: SymbolFlags,
+) {
+    let current_scope_id = builder.scope.current_scope_id;
+    let parent_scope_id = builder.scope.scopes[*current_scope_id].parent().unwrap().into();
+    builder.declare_symbol(&ident.name, ident.span, parent_scope_id, includes, excludes);
+}
+
+impl<'a> Binder for TSInterfaceDeclaration<'a> {
+    fn bind(&self, builder: &mut SemanticBuilder) {
+        declare_in_parent_scope(
+            builder,
+            &self.id,
+            SymbolFlags::Interface,
+            SymbolFlags::InterfaceExcludes,
+        );
+    }
+}
+
+impl<'a> Binder for TSTypeAliasDeclaration<'a> {
+    fn bind(&self, builder: &mut SemanticBuilder) {
+        declare_in_parent_scope(
+            builder,
+            &self.id,
+            SymbolFlags::TypeAlias,
+            SymbolFlags::TypeAliasExcludes,
+        );
+    }
+}
+
+impl<'a> Binder for TSTypeParameter<'a> {
+    fn bind(&self, builder: &mut SemanticBuilder) {
+        builder.declare_symbol(
+            &self.name.name,
+            self.name.span,
+            builder.scope.current_scope_id,
+            SymbolFlags::TypeParameter,
+            SymbolFlags::TypeParameterExcludes,
+        );
+    }
+}
+
+impl<'a> Binder for TSEnumDeclaration<'a> {
+    fn bind(&self, builder: &mut SemanticBuilder) {
+        let (includes, excludes) = if self.modifiers.contains(ModifierKind::Const) {
+            (SymbolFlags::ConstEnum, SymbolFlags::ConstEnumExcludes)
+        } else {
+            (SymbolFlags::RegularEnum, SymbolFlags::RegularEnumExcludes)
+        };
+        builder.declare_symbol(
+            &self.id.name,
+            self.id.span,
+            builder.scope.current_scope_id,
+            includes,
+            excludes,
+        );
+    }
+}
+
+impl<'a> Binder for TSModuleDeclaration<'a> {
+    fn bind(&self, builder: &mut SemanticBuilder) {
+        // `declare module "foo" {}` does not declare a name,
+        // and `B` in `namespace A.B {}` is a member of `A`.
+        let TSModuleDeclarationName::Identifier(ident) = &self.id else { return };
+        if matches!(builder.parent_kind(), AstKind::TSModuleDeclaration(_)) {
+            return;
+        }
+        let current_scope_id = builder.scope.current_scope_id;
+        let (includes, mut excludes) = if is_instantiated(&self.body) {
+            (SymbolFlags::ValueModule, SymbolFlags::ValueModuleExcludes)
+        } else {
+            (SymbolFlags::NameSpaceModule, SymbolFlags::NameSpaceModuleExcludes)
+        };
+        // Namespaces merge with function declarations
+        if let Some(symbol_id) = builder.scope.current_scope().get_variable_symbol_id(&ident.name)
+            && builder.symbols[symbol_id].flags().contains(SymbolFlags::Function)
+        {
+            excludes -= SymbolFlags::Variable;
+        }
+        builder.declare_symbol(&ident.name, ident.span, current_scope_id, includes, excludes);
+    }
+}
+
+/// A namespace is instantiated if it contains values,
+/// see <https://www.typescriptlang.org/docs/handbook/declaration-merging.html#merging-namespaces>
+fn is_instantiated(body: &TSModuleDeclarationBody) -> bool {
+    let is_declaration_instantiated = |decl: &Declaration| match decl {
+        Declaration::TSInterfaceDeclaration(_) | Declaration::TSTypeAliasDeclaration(_) => false,
+        Declaration::TSModuleDeclaration(module) => is_instantiated(&module.body),
+        Declaration::TSEnumDeclaration(decl) => !decl.modifiers.contains(ModifierKind::Const),
+        _ => true,
+    };
+    match body {
+        TSModuleDeclarationBody::TSModuleDeclaration(module) => is_instantiated(&module.body),
+        TSModuleDeclarationBody::TSModuleBlock(block) => block.body.iter().any(|stmt| match stmt {
+            Statement::Declaration(decl) => is_declaration_instantiated(decl),
+            Statement::ModuleDeclaration(decl) => match &decl.kind {
+                ModuleDeclarationKind::ExportNamedDeclaration(decl) => {
+                    decl.declaration.as_ref().map_or(true, is_declaration_instantiated)
+                }
+                ModuleDeclarationKind::ImportDeclaration(_) => false,
+                _ => true,
+            },
+            _ => true,
+        }),
+    }
+}
+
+impl<'a> Binder for TSImportEqualsDeclaration<'a> {
+    fn bind(&self, builder: &mut SemanticBuilder) {
+        builder.declare_symbol(
+            &self.id.name,
+            self.id.span,
+            builder.scope.current_scope_id,
+            SymbolFlags::Import,
+            SymbolFlags::ImportExcludes,
+        );
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::rc::Rc;
+
+    use oxc_allocator::Allocator;
+    use oxc_ast::SourceType;
+
+    use crate::{symbol::SymbolFlags, tester, SemanticBuilder};
+
+    /// Returns the symbols as `(name, flags, reference count)` and the number of errors
+    fn build(source_text: &str) -> (Vec<(String, SymbolFlags, usize)>, usize) {
+        build_with(source_text, SourceType::from_path("test.ts").unwrap())
+    }
+
+    fn build_with(
+        source_text: &str,
+        source_type: SourceType,
+    ) -> (Vec<(String, SymbolFlags, usize)>, usize) {
+        let allocator = Allocator::default();
+        let program = tester::parse(&allocator, source_text, source_type);
+        let ret = SemanticBuilder::new(source_text, source_type, &Rc::default()).build(program);
+        let symbols = ret
+            .semantic
+            .symbols()
+            .iter()
+            .map(|s| (s.name().to_string(), s.flags(), s.references().len()))
+            .collect();
+        (symbols, ret.errors.len())
+    }
+
+    #[test]
+    fn type_space() {
+        let (symbols, errors) = build("interface A {} type B = A; let a: B;");
+        assert_eq!(errors, 0);
+        assert_eq!(symbols[0], ("A".into(), SymbolFlags::Interface, 1));
+        assert_eq!(symbols[1], ("B".into(), SymbolFlags::TypeAlias, 1));
+
+        // Types and values with the same name do not conflict
+        let (symbols, errors) = build("type A = number; const A = 1; let a: A = A;");
+        assert_eq!(errors, 0);
+        assert_eq!(symbols[0].2, 2);
+
+        // Type references skip symbols in the value space
+        let (symbols, errors) = build("type T = 1; function f() { let T; let a: T; }");
+        assert_eq!(errors, 0);
+        assert_eq!(symbols[0], ("T".into(), SymbolFlags::TypeAlias, 1));
+        assert!(symbols.iter().any(|s| s.0 == "T" && s.2 == 0));
+
+        // `typeof` references a value, only the leftmost name of a qualified name is referenced
+        let (symbols, _) =
+            build("import a from 'a'; import * as b from 'b'; let x: typeof a | b.C;");
+        assert_eq!(symbols[0].2, 1);
+        assert_eq!(symbols[1].2, 1);
+        assert!(!symbols.iter().any(|s| s.0 == "C"));
+    }
+
+    #[test]
+    fn type_parameters() {
+        let (symbols, errors) =
+            build("type T = 1; interface A<T> { a: T } class B<T> { b: T } function c<T>(c: T) {}");
+        assert_eq!(errors, 0);
+        let params: Vec<_> =
+            symbols.iter().filter(|s| s.1 == SymbolFlags::TypeParameter).map(|s| s.2).collect();
+        assert_eq!(params, vec![1, 1, 1]);
+        assert_eq!(symbols[0].2, 0);
+    }
+
+    #[test]
+    fn declaration_merging() {
+        let (symbols, errors) = build("interface A {} class A {} interface A {}");
+        assert_eq!(errors, 0);
+        assert_eq!(symbols.len(), 1);
+        assert_eq!(symbols[0].1, SymbolFlags::Interface | SymbolFlags::Class);
+
+        // The flags of the export declaration are merged
+        let (symbols, errors) = build("interface A {} export class A {}");
+        assert_eq!(errors, 0);
+        assert_eq!(symbols[0].1, SymbolFlags::Interface | SymbolFlags::Class | SymbolFlags::Export);
+
+        let (symbols, errors) = build("function f() {} namespace f { export const a = 1 }");
+        assert_eq!(errors, 0);
+        assert!(symbols[0].1.contains(SymbolFlags::Function | SymbolFlags::ValueModule));
+
+        let (symbols, errors) = build("enum E { A } enum E { B } namespace E {}");
+        assert_eq!(errors, 0);
+        assert_eq!(symbols[0].1, SymbolFlags::RegularEnum | SymbolFlags::NameSpaceModule);
+
+        let (symbols, errors) = build("namespace N { type T = 1; }\nnamespace M.O { let a }");
+        assert_eq!(errors, 0);
+        assert_eq!(symbols[0].1, SymbolFlags::NameSpaceModule);
+        assert!(symbols.iter().any(|s| s.0 == "M" && s.1 == SymbolFlags::ValueModule));
+        assert!(!symbols.iter().any(|s| s.0 == "O"));
+
+        for source_text in [
+            "type A = 1; interface A {}",
+            "class A {} class A {}",
+            "const enum E {} enum E {}",
+            "let a; namespace a { let b }",
+            "import a = require('a'); let a;",
+            "let a; import a = require('a');",
+            "import a from 'a'; import a = require('a');",
+            "import a from 'a'; class a {}",
+            "import { a } from 'a'; var a;",
+        ] {
+            assert_eq!(build(source_text).1, 1, "{source_text}");
+        }
+    }
+
+    #[test]
+    fn annex_b_functions() {
+        let script = SourceType::default();
+
+        // Block-level functions are also bound to the enclosing function in sloppy mode
+        let (symbols, errors) = build_with("{ function f() {} } f();", script);
+        assert_eq!(errors, 0);
+        assert_eq!(symbols[0].0, "f");
+        assert_eq!(symbols[0].2, 1);
+
+        let (symbols, errors) =
+            build_with("function g() { if (a) { function f() {} } return f; }", script);
+        assert_eq!(errors, 0);
+        assert!(symbols.iter().any(|s| s.0 == "f" && s.2 == 1));
+
+        // A lexical declaration of the same name prevents the hoisting
+        for source_text in [
+            "{ function f() {} } let f; f();",
+            "let f; { function f() {} } f();",
+            "{ let f; { function f() {} } } f();",
+            "{ { function f() {} } let f; } f();",
+        ] {
+            let (symbols, errors) = build_with(source_text, script);
+            assert_eq!(errors, 0, "{source_text}");
+            let function = symbols.iter().find(|s| s.1.contains(SymbolFlags::Function)).unwrap();
+            assert_eq!(function.2, 0, "{source_text}");
+        }
+
+        // Nor a parameter of the same name
+        for source_text in [
+            "function g(f) { { function f() {} } f }",
+            "function g(f) { if (a) { { function f() {} } f } }",
+        ] {
+            let (symbols, errors) = build_with(source_text, script);
+            assert_eq!(errors, 0, "{source_text}");
+            let function =
+                symbols.iter().find(|s| s.1.contains(SymbolFlags::Function) && s.0 == "f");
+            assert_eq!(function.unwrap().2, 0, "{source_text}");
+            let parameter =
+                symbols.iter().find(|s| s.0 == "f" && s.1 == SymbolFlags::FunctionScopedVariable);
+            assert_eq!(parameter.unwrap().2, 1, "{source_text}");
+        }
+
+        // Not in strict mode, nor for async and generator functions
+        for source_text in [
+            "'use strict'; { function f() {} } f();",
+            "{ async function f() {} } f();",
+            "{ function* f() {} } f();",
+        ] {
+            let (symbols, _) = build_with(source_text, script);
+            assert_eq!(symbols[0].2, 0, "{source_text}");
+        }
+        let (symbols, _) =
+            build_with("{ function f() {} } f();", *SourceType::default().with_module(true));
+        assert_eq!(symbols[0].2, 0);
+    }
+}
@@ -6,20 +6,25 @@ use std::rc::Rc;
 
 #[allow(clippy::wildcard_imports)]
 use oxc_ast::{
-    ast::*, module_record::ModuleRecord, visit::Visit, AstKind, Atom, GetSpan, SourceType, Span,
-    Trivias,
+    ast::*, module_record::ModuleRecord, visit::Visit, AstKind, Atom, SourceType, Span, Trivias,
 };
 use oxc_diagnostics::{Error, Redeclaration};
+use rustc_hash::FxHashSet;
 
 use crate::{
     binder::Binder,
+    checker,
+    class::ClassTable,
+    control_flow::ControlFlowGraphBuilder,
     module_record::ModuleRecordBuilder,
     node::{AstNodeId, AstNodes, NodeFlags, SemanticNode},
-    scope::{ScopeBuilder, ScopeId},
+    scope::{ScopeBuilder, ScopeId, ScopeTree},
     symbol::{Reference, ReferenceFlag, SymbolFlags, SymbolId, SymbolTable},
+    this_binding::ThisBindings,
     Semantic,
 };
 
+#[allow(clippy::struct_excessive_bools)]
 pub struct SemanticBuilder<'a> {
     pub source_text: &'a str,
 
@@ -34,6 +39,9 @@ pub struct SemanticBuilder<'a> {
     pub current_node_id: AstNodeId,
     pub current_node_flags: NodeFlags,
     pub current_symbol_flags: SymbolFlags,
+    /// Block-level functions bound to enclosing scopes by Annex B hoisting,
+    /// a later lexical declaration of the same name replaces them.
+    pub(crate) hoisted_functions: FxHashSet<(ScopeId, Atom)>,
 
     // builders
     pub nodes: AstNodes<'a>,
@@ -42,6 +50,18 @@ pub struct SemanticBuilder<'a> {
 
     with_module_record_builder: bool,
     module_record_builder: ModuleRecordBuilder,
+
+    with_cfg: bool,
+
+    with_classes: bool,
+
+    with_this_bindings: bool,
+
+    with_free_variables: bool,
+
+    with_initialization: bool,
+
+    with_check_syntax_error: bool,
 }
 
 pub struct SemanticBuilderReturn<'a> {
@@ -52,11 +72,33 @@ pub struct SemanticBuilderReturn<'a> {
 impl<'a> SemanticBuilder<'a> {
     #[must_use]
     pub fn new(source_text: &'a str, source_type: SourceType, trivias: &Rc<Trivias>) -> Self {
-        let scope = ScopeBuilder::new(source_type);
+        Self::with_storage(
+            source_text,
+            source_type,
+            trivias,
+            ScopeTree::new(false),
+            SymbolTable::default(),
+            0,
+        )
+    }
+
+    /// Build into the cleared scope tree and symbol table of a detached semantic,
+    /// see [`crate::DetachedSemantic::rebuild`].
+    pub(crate) fn with_storage(
+        source_text: &'a str,
+        source_type: SourceType,
+        trivias: &Rc<Trivias>,
+        scopes: ScopeTree,
+        mut symbols: SymbolTable,
+        nodes_capacity: usize,
+    ) -> Self {
+        let scope = ScopeBuilder::from_tree(source_type, scopes);
+        symbols.clear();
         let mut nodes = AstNodes::default();
+        nodes.reserve(nodes_capacity);
         let semantic_node =
             SemanticNode::new(AstKind::Root, scope.current_scope_id, NodeFlags::empty());
-        let current_node_id = nodes.new_node(semantic_node).into();
+        let current_node_id = nodes.add_node(semantic_node, None);
         Self {
             source_text,
             source_type,
@@ -65,11 +107,18 @@ impl<'a> SemanticBuilder<'a> {
             current_node_id,
             current_node_flags: NodeFlags::empty(),
             current_symbol_flags: SymbolFlags::empty(),
+            hoisted_functions: FxHashSet::default(),
             nodes,
             scope,
-            symbols: SymbolTable::default(),
+            symbols,
             with_module_record_builder: false,
             module_record_builder: ModuleRecordBuilder::default(),
+            with_cfg: false,
+            with_classes: false,
+            with_this_bindings: false,
+            with_free_variables: false,
+            with_initialization: false,
+            with_check_syntax_error: false,
         }
     }
 
@@ -79,18 +128,79 @@ impl<'a> SemanticBuilder<'a> {
         self
     }
 
+    /// Build the control flow graph, see [`crate::control_flow`].
+    #[must_use]
+    pub fn with_cfg(mut self, yes: bool) -> Self {
+        self.with_cfg = yes;
+        self
+    }
+
+    /// Build the class table, see [`Semantic::classes`].
+    #[must_use]
+    pub fn with_classes(mut self, yes: bool) -> Self {
+        self.with_classes = yes;
+        self
+    }
+
+    /// Resolve `this`, `super` and `arguments`, see [`Semantic::this_bindings`].
+    #[must_use]
+    pub fn with_this_bindings(mut self, yes: bool) -> Self {
+        self.with_this_bindings = yes;
+        self
+    }
+
+    /// Compute the bindings captured by each function, see [`ScopeTree::free_variables`].
+    #[must_use]
+    pub fn with_free_variables(mut self, yes: bool) -> Self {
+        self.with_free_variables = yes;
+        self
+    }
+
+    /// Find the references evaluated before their binding is initialized,
+    /// see [`crate::ResolvedReference::initialization`].
+    #[must_use]
+    pub fn with_initialization(mut self, yes: bool) -> Self {
+        self.with_initialization = yes;
+        self
+    }
+
+    /// Report all early errors, see [`crate::checker`].
+    /// This also builds the module record, the class table and the `this` bindings,
+    /// which are needed for checking exports, private names and `super`.
+    #[must_use]
+    pub fn with_check_syntax_error(mut self, yes: bool) -> Self {
+        self.with_check_syntax_error = yes;
+        self
+    }
+
     #[must_use]
     pub fn build(mut self, program: &'a Program<'a>) -> SemanticBuilderReturn<'a> {
         // First AST pass
         self.visit_program(program);
+        if self.with_free_variables {
+            self.scope.scopes.compute_free_variables(&self.nodes, &self.symbols);
+        }
+        if self.with_initialization {
+            self.symbols.compute_initialization(&self.nodes, &self.scope.scopes);
+        }
+        let classes = (self.with_classes || self.with_check_syntax_error)
+            .then(|| ClassTable::build(&self.nodes));
+        let this_bindings = (self.with_this_bindings || self.with_check_syntax_error)
+            .then(|| ThisBindings::build(&self.nodes, &self.symbols));
 
         // Second partial AST pass on top level import / export statements
-        let module_record = if self.with_module_record_builder {
+        let module_record = if self.with_module_record_builder || self.with_check_syntax_error {
             self.module_record_builder.build(program)
         } else {
             ModuleRecord::default()
         };
 
+        // Third AST pass for the control flow graph
+        let root_id = self.current_node_id;
+        let cfg = self
+            .with_cfg
+            .then(|| ControlFlowGraphBuilder::new(&self.nodes, root_id).build(program));
+
         let semantic = Semantic {
             source_text: self.source_text,
             source_type: self.source_type,
@@ -98,9 +208,21 @@ impl<'a> SemanticBuilder<'a> {
             nodes: self.nodes,
             scopes: self.scope.scopes,
             symbols: self.symbols,
+            classes,
+            this_bindings,
             module_record,
+            cfg,
+            with_module_record_builder: self.with_module_record_builder,
+            with_free_variables: self.with_free_variables,
+            with_initialization: self.with_initialization,
+            with_check_syntax_error: self.with_check_syntax_error,
         };
-        SemanticBuilderReturn { semantic, errors: self.errors }
+
+        let mut errors = self.errors;
+        if self.with_check_syntax_error {
+            errors.extend(checker::check(&semantic));
+        }
+        SemanticBuilderReturn { semantic, errors }
     }
 
     /// Push a Syntax Error
@@ -121,9 +243,7 @@ impl<'a> SemanticBuilder<'a> {
     fn create_ast_node(&mut self, kind: AstKind<'a>) {
         let ast_node =
             SemanticNode::new(kind, self.scope.current_scope_id, self.current_node_flags);
-        let node_id = self.current_node_id.append_value(ast_node, &mut self.nodes);
-
-        self.current_node_id = node_id.into();
+        self.current_node_id = self.nodes.add_node(ast_node, Some(self.current_node_id));
     }
 
     fn pop_ast_node(&mut self) {
@@ -158,15 +278,39 @@ impl<'a> SemanticBuilder<'a> {
         // The flags which node cannot be declared alongside in a symbol table. Used to report forbidden declarations.
         excludes: SymbolFlags,
     ) -> SymbolId {
+        if !includes.intersects(SymbolFlags::FunctionScopedVariable)
+            && self.hoisted_functions.contains(&(scope_id, name.clone()))
+        {
+            self.unhoist_function(scope_id, name);
+        }
+        let includes = includes | self.current_symbol_flags;
         if let Some(symbol_id) = self.check_redeclaration(scope_id, name, span, excludes) {
+            if !self.symbols[symbol_id].flags().intersects(excludes) {
+                self.symbols[symbol_id].merge_flags(includes);
+            }
             return symbol_id;
         }
-        let includes = includes | self.current_symbol_flags;
         let symbol_id = self.symbols.create(self.current_node_id, name.clone(), span, includes);
         self.scope.scopes[scope_id].variables.insert(name.clone(), symbol_id);
         symbol_id
     }
 
+    /// Removes the Annex B binding of a block-level function from `scope_id` and the enclosing scopes,
+    /// the function is not hoisted when a lexical declaration of the same name is in between.
+    fn unhoist_function(&mut self, scope_id: ScopeId, name: &Atom) {
+        let symbol_id = self.scope.scopes[scope_id].get_variable_symbol_id(name);
+        let scope_ids: Vec<ScopeId> =
+            self.scope.scopes.ancestors(scope_id).map(ScopeId::from).collect();
+        for scope_id in scope_ids {
+            let scope = &mut self.scope.scopes[scope_id];
+            if scope.get_variable_symbol_id(name) == symbol_id
+                && self.hoisted_functions.remove(&(scope_id, name.clone()))
+            {
+                scope.variables.remove(name);
+            }
+        }
+    }
+
     /// Declares a `Symbol` for the node, shadowing previous declarations in the same scope.
     pub fn declare_shadow_symbol(
         &mut self,
@@ -241,9 +385,33 @@ impl<'a> SemanticBuilder<'a> {
             AstKind::CatchClause(clause) => {
                 clause.bind(self);
             }
+            AstKind::TSInterfaceDeclaration(decl) => {
+                decl.bind(self);
+            }
+            AstKind::TSTypeAliasDeclaration(decl) => {
+                decl.bind(self);
+            }
+            AstKind::TSTypeParameter(param) => {
+                param.bind(self);
+            }
+            AstKind::TSEnumDeclaration(decl) => {
+                decl.bind(self);
+            }
+            AstKind::TSModuleDeclaration(decl) => {
+                decl.bind(self);
+            }
+            AstKind::TSImportEqualsDeclaration(decl) => {
+                decl.bind(self);
+            }
             AstKind::IdentifierReference(ident) => {
                 self.reference_identifier(ident);
             }
+            AstKind::ExportSpecifier(specifier) => {
+                self.reference_export_specifier(specifier);
+            }
+            AstKind::TSTypeName(name) => {
+                self.reference_ts_type_name(name);
+            }
             AstKind::JSXElementName(elem) => {
                 self.reference_jsx_element_name(elem);
             }
@@ -271,14 +439,77 @@ impl<'a> SemanticBuilder<'a> {
     }
 
     fn reference_identifier(&mut self, ident: &IdentifierReference) {
-        let flag = if matches!(
-            self.parent_kind(),
-            AstKind::SimpleAssignmentTarget(_) | AstKind::AssignmentTarget(_)
-        ) {
-            ReferenceFlag::Write
-        } else {
-            ReferenceFlag::Read
+        let flag = self.reference_flag_of_identifier(ident);
+        let reference = Reference::new(self.current_node_id, ident.span, flag);
+        self.scope.reference_identifier(&ident.name, reference);
+    }
+
+    /// Whether the identifier is read, written or both, by looking at its ancestors.
+    fn reference_flag_of_identifier(&self, ident: &IdentifierReference) -> ReferenceFlag {
+        let mut kinds = self
+            .current_node_id
+            .ancestors(&self.nodes)
+            .skip(1)
+            .map(|id| self.nodes[id].get().kind())
+            .filter(|kind| !matches!(kind, AstKind::ParenthesizedExpression(_)));
+        match kinds.next() {
+            Some(AstKind::SimpleAssignmentTarget(_)) => match kinds.next() {
+                // `a++`
+                Some(AstKind::UpdateExpression(_)) => ReferenceFlag::ReadWrite,
+                Some(AstKind::AssignmentTarget(_)) => match kinds.next() {
+                    // `a += 1`, `a ||= 1`
+                    Some(AstKind::AssignmentExpression(expr))
+                        if expr.operator != AssignmentOperator::Assign =>
+                    {
+                        ReferenceFlag::ReadWrite
+                    }
+                    // `a = 1`, `[a] = b`, `for (a of b)`
+                    _ => ReferenceFlag::Write,
+                },
+                _ => ReferenceFlag::Write,
+            },
+            // `a` in `({ a } = b)`, but not `b` in `({ a = b } = c)`
+            Some(AstKind::AssignmentTarget(AssignmentTarget::AssignmentTargetPattern(
+                AssignmentTargetPattern::ObjectAssignmentTarget(target),
+            ))) if target.properties.iter().any(|property| {
+                matches!(property, AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(property)
+                    if property.binding.span == ident.span)
+            }) =>
+            {
+                ReferenceFlag::Write
+            }
+            Some(AstKind::UnaryExpression(expr)) if expr.operator == UnaryOperator::Typeof => {
+                ReferenceFlag::Read | ReferenceFlag::Typeof
+            }
+            // `delete a` never changes a declared binding
+            _ => ReferenceFlag::Read,
+        }
+    }
+
+    /// `a` in `export { a }` is a reference to the local `a`,
+    /// `export { a } from "mod"` does not reference anything.
+    fn reference_export_specifier(&mut self, specifier: &ExportSpecifier) {
+        let AstKind::ModuleDeclaration(decl) = self.parent_kind() else { return };
+        let ModuleDeclarationKind::ExportNamedDeclaration(decl) = &decl.kind else { return };
+        if decl.source.is_some() {
+            return;
+        }
+        if let ModuleExportName::Identifier(ident) = &specifier.local {
+            let reference = Reference::new(self.current_node_id, ident.span, ReferenceFlag::Export);
+            self.scope.reference_identifier(&ident.name, reference);
+        }
+    }
+
+    fn reference_ts_type_name(&mut self, name: &TSTypeName) {
+        let flag = match self.parent_kind() {
+            // Only the leftmost name of `A.B.C` is a reference,
+            // the qualifier of `import("a").B` is not.
+            AstKind::TSQualifiedName(_) | AstKind::TSImportType(_) => return,
+            // `typeof a` references a value
+            AstKind::TSTypeQuery(_) => ReferenceFlag::Read,
+            _ => ReferenceFlag::Type,
         };
+        let ident = TSTypeName::get_first_name(name);
         let reference = Reference::new(self.current_node_id, ident.span, flag);
         self.scope.reference_identifier(&ident.name, reference);
     }
@@ -295,7 +526,7 @@ impl<'a> SemanticBuilder<'a> {
                 _ => None,
             } {
                 let reference =
-                    Reference::new(self.current_node_id, elem.span(), ReferenceFlag::Read);
+                    Reference::new(self.current_node_id, ident.span, ReferenceFlag::Read);
                 self.scope.reference_identifier(&ident.name, reference);
             }
         }
@@ -0,0 +1,479 @@
+//! Call graph of a single module, built from the resolved references and the class table
+
+use oxc_ast::{
+    ast::{Argument, ClassElement, Expression, MemberExpression, ThisExpression},
+    AstKind, Span,
+};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{
+    class::{ClassId, ClassMemberKind},
+    node::{AstNode, AstNodeId},
+    symbol::SymbolId,
+    Semantic,
+};
+
+/// Array methods which call their first argument
+const ARRAY_CALLBACK_METHODS: [&str; 14] = [
+    "every",
+    "filter",
+    "find",
+    "findIndex",
+    "findLast",
+    "findLastIndex",
+    "flatMap",
+    "forEach",
+    "map",
+    "reduce",
+    "reduceRight",
+    "some",
+    "sort",
+    "toSorted",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CallKind {
+    /// `f()` or `new F()`, where `f` is a function declaration or a binding initialized with a function
+    Direct,
+    /// `this.#m()`, `C.m()`, `c.m()` where `c` is initialized with `new C()`, and `new C()`
+    Method,
+    /// `this.m()`, resolved to the method of the enclosing class,
+    /// which is not called when `this` is an instance of a subclass overriding `m`
+    Virtual,
+    /// `f` in `arr.map(f)`
+    Callback,
+}
+
+/// A call from the body of `caller` to `callee`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CallSite {
+    /// The `Function` or `ArrowExpression` node containing the call, or the `Program` node
+    pub caller: AstNodeId,
+    /// The `Function` or `ArrowExpression` node being called
+    pub callee: AstNodeId,
+    /// Span of the `CallExpression` or `NewExpression`
+    pub span: Span,
+    pub kind: CallKind,
+}
+
+/// Calls which can be resolved statically, calls through values of unknown origin are not included.
+/// Functions bound to reassigned variables are never resolved.
+/// Method calls are resolved when the semantic is built with
+/// [`crate::SemanticBuilder::with_classes`] and [`crate::SemanticBuilder::with_this_bindings`].
+#[derive(Debug, Default)]
+pub struct CallGraph {
+    /// Outer calls come before the calls inside of their callee and arguments
+    call_sites: Vec<CallSite>,
+
+    /// Indices of `call_sites` keyed by caller
+    calls_from: FxHashMap<AstNodeId, Vec<usize>>,
+
+    /// Indices of `call_sites` keyed by callee
+    calls_to: FxHashMap<AstNodeId, Vec<usize>>,
+}
+
+impl CallGraph {
+    #[must_use]
+    pub fn new(semantic: &Semantic) -> Self {
+        let mut graph = Self::default();
+        let resolver = CallResolver { semantic };
+
+        for node in semantic.nodes().iter() {
+            let (span, callee, arguments) = match node.get().kind() {
+                AstKind::CallExpression(expr) => (expr.span, &expr.callee, &expr.arguments),
+                AstKind::NewExpression(expr) => (expr.span, &expr.callee, &expr.arguments),
+                _ => continue,
+            };
+            let caller_id = resolver.caller(node);
+            let is_new = matches!(node.get().kind(), AstKind::NewExpression(_));
+            if let Some((callee, kind)) = resolver.resolve_callee(callee, is_new) {
+                graph.add(CallSite { caller: caller_id, callee, span, kind });
+            }
+            if let Some(Argument::Expression(callback)) = arguments.first()
+                && let Expression::MemberExpression(member) = callee.get_inner_expression()
+                && member
+                    .static_property_name()
+                    .is_some_and(|name| ARRAY_CALLBACK_METHODS.contains(&name))
+                && let Some(callee) = resolver.resolve_function(callback)
+            {
+                graph.add(CallSite { caller: caller_id, callee, span, kind: CallKind::Callback });
+            }
+        }
+
+        graph
+    }
+
+    /// All resolved calls, outer calls before the calls nested inside of them
+    #[must_use]
+    pub fn call_sites(&self) -> &[CallSite] {
+        &self.call_sites
+    }
+
+    /// The calls made by the body of a function, or by the top level of the `Program` node.
+    pub fn calls_from(&self, caller: AstNodeId) -> impl Iterator<Item = &CallSite> + '_ {
+        self.calls_from.get(&caller).into_iter().flatten().map(|index| &self.call_sites[*index])
+    }
+
+    /// The calls of a function.
+    pub fn calls_to(&self, callee: AstNodeId) -> impl Iterator<Item = &CallSite> + '_ {
+        self.calls_to.get(&callee).into_iter().flatten().map(|index| &self.call_sites[*index])
+    }
+
+    /// Whether the function calls itself, directly or through other functions.
+    #[must_use]
+    pub fn is_recursive(&self, function: AstNodeId) -> bool {
+        let mut visited = FxHashSet::default();
+        let mut stack = vec![function];
+        while let Some(caller) = stack.pop() {
+            for call_site in self.calls_from(caller) {
+                if call_site.callee == function {
+                    return true;
+                }
+                if visited.insert(call_site.callee) {
+                    stack.push(call_site.callee);
+                }
+            }
+        }
+        false
+    }
+
+    fn add(&mut self, call_site: CallSite) {
+        let index = self.call_sites.len();
+        self.calls_from.entry(call_site.caller).or_default().push(index);
+        self.calls_to.entry(call_site.callee).or_default().push(index);
+        self.call_sites.push(call_site);
+    }
+}
+
+struct CallResolver<'s, 'a> {
+    semantic: &'s Semantic<'a>,
+}
+
+impl<'s, 'a> CallResolver<'s, 'a> {
+    fn node_id(&self, kind: AstKind<'a>) -> Option<AstNodeId> {
+        self.semantic.nodes().ast_node_id(kind)
+    }
+
+    /// The innermost function containing `node`, or the `Program` node.
+    fn caller(&self, node: &AstNode<'a>) -> AstNodeId {
+        let nodes = self.semantic.nodes();
+        let node_id = nodes.get_node_id(node).unwrap();
+        node_id
+            .ancestors(nodes)
+            .find(|node_id| {
+                matches!(
+                    nodes.kind(*node_id),
+                    AstKind::Function(_) | AstKind::ArrowExpression(_) | AstKind::Program(_)
+                )
+            })
+            .unwrap()
+            .into()
+    }
+
+    fn resolve_callee(
+        &self,
+        callee: &'a Expression<'a>,
+        is_new: bool,
+    ) -> Option<(AstNodeId, CallKind)> {
+        if is_new {
+            if let Some(class_id) = self.resolve_class(callee) {
+                let constructor = self.find_method(class_id, false, |member_kind, _| {
+                    member_kind == ClassMemberKind::Constructor
+                })?;
+                return Some((constructor, CallKind::Method));
+            }
+        }
+        match callee.get_inner_expression() {
+            Expression::MemberExpression(member) => self.resolve_method(member),
+            expr => self.resolve_function(expr).map(|callee| (callee, CallKind::Direct)),
+        }
+    }
+
+    /// A function expression, or a reference to a function.
+    fn resolve_function(&self, expr: &'a Expression<'a>) -> Option<AstNodeId> {
+        match expr.get_inner_expression() {
+            Expression::FunctionExpression(func) => self.node_id(AstKind::Function(func)),
+            Expression::ArrowFunctionExpression(arrow) => {
+                self.node_id(AstKind::ArrowExpression(arrow))
+            }
+            Expression::Identifier(_) => {
+                let symbol_id = self.resolve_symbol(expr)?;
+                let declaration = self.semantic.symbols()[symbol_id].declaration();
+                match self.semantic.nodes().kind(declaration) {
+                    AstKind::Function(_) => Some(declaration),
+                    AstKind::VariableDeclarator(decl) => {
+                        let init = decl.init.as_ref()?;
+                        if !init.get_inner_expression().is_function() {
+                            return None;
+                        }
+                        self.resolve_function(init)
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    fn resolve_method(&self, member: &'a MemberExpression<'a>) -> Option<(AstNodeId, CallKind)> {
+        let MemberExpression::StaticMemberExpression(expr) = member else {
+            // Private names are resolved by the class table regardless of the object
+            let MemberExpression::PrivateFieldExpression(expr) = member else { return None };
+            let node_id = self.node_id(AstKind::PrivateIdentifier(&expr.field))?;
+            let member_id = self.semantic.classes()?.get_private_reference(node_id)?;
+            let callee = self.method_function(member_id.class_id, member_id.index)?;
+            return Some((callee, CallKind::Method));
+        };
+        let name = &expr.property.name;
+        let (class_id, r#static, kind) = match expr.object.get_inner_expression() {
+            Expression::ThisExpression(this) => {
+                let (class_id, r#static) = self.resolve_this(this)?;
+                (class_id, r#static, CallKind::Virtual)
+            }
+            Expression::Identifier(_) => {
+                if let Some(class_id) = self.resolve_class(&expr.object) {
+                    (class_id, true, CallKind::Method)
+                } else {
+                    // `const c = new C()`
+                    let symbol_id = self.resolve_symbol(&expr.object)?;
+                    let declaration = self.semantic.symbols()[symbol_id].declaration();
+                    let AstKind::VariableDeclarator(decl) = self.semantic.nodes().kind(declaration) else {
+                        return None;
+                    };
+                    let Some(Expression::NewExpression(new)) =
+                        decl.init.as_ref().map(Expression::get_inner_expression)
+                    else {
+                        return None;
+                    };
+                    (self.resolve_class(&new.callee)?, false, CallKind::Method)
+                }
+            }
+            _ => return None,
+        };
+        let callee = self.find_method(class_id, r#static, |member_kind, member_name| {
+            member_kind == ClassMemberKind::Method && name.as_str() == member_name
+        })?;
+        Some((callee, kind))
+    }
+
+    /// The class of `this` and whether it refers to the class itself,
+    /// inside of class methods, field initializers and static blocks.
+    fn resolve_this(&self, this: &'a ThisExpression) -> Option<(ClassId, bool)> {
+        let nodes = self.semantic.nodes();
+        let node_id = self.node_id(AstKind::ThisExpression(this))?;
+        let environment_id = self.semantic.this_bindings()?.environment(node_id)?;
+        let environment = &nodes[*environment_id];
+        let r#static = match environment.get().kind() {
+            AstKind::Function(_) => match nodes.parent_kind(environment) {
+                AstKind::MethodDefinition(def) => def.r#static,
+                _ => return None,
+            },
+            AstKind::PropertyDefinition(def) => def.r#static,
+            AstKind::AccessorProperty(def) => def.r#static,
+            AstKind::StaticBlock(_) => true,
+            _ => return None,
+        };
+        let class_node_id = environment_id
+            .ancestors(nodes)
+            .find(|node_id| matches!(nodes.kind(*node_id), AstKind::Class(_)))?;
+        let class_id = self.semantic.classes()?.get_class_id(class_node_id.into())?;
+        Some((class_id, r#static))
+    }
+
+    /// A reference to a class declaration, or to a binding initialized with a class expression.
+    fn resolve_class(&self, expr: &'a Expression<'a>) -> Option<ClassId> {
+        let symbol_id = self.resolve_symbol(expr)?;
+        let declaration = self.semantic.symbols()[symbol_id].declaration();
+        let class_node_id = match self.semantic.nodes().kind(declaration) {
+            AstKind::Class(_) => declaration,
+            AstKind::VariableDeclarator(decl) => match decl.init.as_ref()?.get_inner_expression() {
+                Expression::ClassExpression(class) => self.node_id(AstKind::Class(class))?,
+                _ => return None,
+            },
+            _ => return None,
+        };
+        self.semantic.classes()?.get_class_id(class_node_id)
+    }
+
+    /// The symbol an identifier resolves to, if it is never reassigned.
+    fn resolve_symbol(&self, expr: &'a Expression<'a>) -> Option<SymbolId> {
+        let Expression::Identifier(ident) = expr.get_inner_expression() else { return None };
+        let node_id = self.node_id(AstKind::IdentifierReference(ident))?;
+        let symbols = self.semantic.symbols();
+        let symbol_id = symbols.get_resolved_reference(node_id)?.resolved_symbol_id;
+        (!symbols.is_reassigned(symbol_id)).then_some(symbol_id)
+    }
+
+    fn find_method<F>(&self, class_id: ClassId, r#static: bool, predicate: F) -> Option<AstNodeId>
+    where
+        F: Fn(ClassMemberKind, &str) -> bool,
+    {
+        let class = &self.semantic.classes()?[class_id];
+        let index = class.members.iter().position(|member| {
+            member.r#static == r#static
+                && !member.is_private_name()
+                && predicate(member.kind, member.name.as_str())
+        })?;
+        self.method_function(class_id, index)
+    }
+
+    /// The `Function` node of a method
+    fn method_function(&self, class_id: ClassId, index: usize) -> Option<AstNodeId> {
+        let classes = self.semantic.classes()?;
+        let member = &classes[class_id].members[index];
+        if !matches!(member.kind, ClassMemberKind::Method | ClassMemberKind::Constructor) {
+            return None;
+        }
+        let AstKind::Class(class) = self.semantic.nodes().kind(classes[class_id].node_id) else {
+            return None;
+        };
+        match &class.body.body[member.element_index] {
+            ClassElement::MethodDefinition(def) => self.node_id(AstKind::Function(&def.value)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::{ast::PropertyKey, AstKind, GetSpan, SourceType};
+
+    use super::{CallGraph, CallKind};
+    use crate::{tester, AstNodeId, Semantic};
+
+    /// Describe a function by its name, or by its start for anonymous functions
+    fn describe(semantic: &Semantic, node_id: AstNodeId) -> String {
+        match semantic.nodes().kind(node_id) {
+            AstKind::Function(func) => func.id.as_ref().map_or_else(
+                || {
+                    // Methods are named by their key
+                    let parent = semantic.nodes()[*node_id].parent().unwrap();
+                    match semantic.nodes().kind(parent) {
+                        AstKind::MethodDefinition(def) => match &def.key {
+                            PropertyKey::PrivateIdentifier(ident) => format!("#{}", ident.name),
+                            key => key.static_name().unwrap().to_string(),
+                        },
+                        kind => format!("@{}", kind.span().start),
+                    }
+                },
+                |id| id.name.to_string(),
+            ),
+            AstKind::Program(_) => "program".to_string(),
+            kind => format!("@{}", kind.span().start),
+        }
+    }
+
+    fn build<'a>(allocator: &'a Allocator, source_text: &'a str) -> Semantic<'a> {
+        tester::semantic(allocator, source_text, SourceType::default(), |builder| {
+            builder.with_classes(true).with_this_bindings(true)
+        })
+    }
+
+    /// The call sites as `caller -> callee (kind)`
+    fn call_sites(source_text: &str) -> Vec<String> {
+        let allocator = Allocator::default();
+        let semantic = build(&allocator, source_text);
+        let graph = CallGraph::new(&semantic);
+        graph
+            .call_sites()
+            .iter()
+            .map(|call_site| {
+                let kind = match call_site.kind {
+                    CallKind::Direct => "direct",
+                    CallKind::Method => "method",
+                    CallKind::Virtual => "virtual",
+                    CallKind::Callback => "callback",
+                };
+                format!(
+                    "{} -> {} ({kind})",
+                    describe(&semantic, call_site.caller),
+                    describe(&semantic, call_site.callee)
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn direct() {
+        assert_eq!(
+            call_sites(
+                "function f() { g(); }
+                 const g = () => h();
+                 var h = function i() {};
+                 let j = function() {}; j = k;
+                 f(); j(); unknown(); new f();"
+            ),
+            vec![
+                "f -> @49 (direct)",
+                "@49 -> i (direct)",
+                "program -> f (direct)",
+                "program -> f (direct)",
+            ]
+        );
+    }
+
+    #[test]
+    fn methods() {
+        assert_eq!(
+            call_sites(
+                "class A {
+                    constructor() { this.m(); this.#p(); A.s(); }
+                    m() { this.constructor(); }
+                    #p() {}
+                    static s() { this.s(); this.m(); }
+                }
+                const a = new A();
+                a.m(); a.s(); a.n();"
+            ),
+            vec![
+                "constructor -> m (virtual)",
+                "constructor -> #p (method)",
+                "constructor -> s (method)",
+                "s -> s (virtual)",
+                "program -> constructor (method)",
+                "program -> m (method)",
+            ]
+        );
+        // `B` overrides `m`, so `this.m()` in `A` may not call `A.prototype.m`
+        assert_eq!(
+            call_sites(
+                "class A { n() { this.m(); } m() {} }
+                 class B extends A { m() {} }
+                 const b = new B(); b.m();"
+            ),
+            vec!["n -> m (virtual)", "program -> m (method)"]
+        );
+    }
+
+    #[test]
+    fn callbacks() {
+        assert_eq!(
+            call_sites(
+                "function f(x) { return x; }
+                 [1].map(f).forEach((x) => f(x));
+                 [1].push(f);"
+            ),
+            vec!["program -> @64 (callback)", "@64 -> f (direct)", "program -> f (callback)"]
+        );
+    }
+
+    #[test]
+    fn recursion() {
+        let source_text = "function f() { g(); } function g() { f(); } function h() { f(); }";
+        let allocator = Allocator::default();
+        let semantic = build(&allocator, source_text);
+        let graph = CallGraph::new(&semantic);
+        let functions: Vec<_> = semantic
+            .nodes()
+            .iter()
+            .filter(|node| matches!(node.get().kind(), AstKind::Function(_)))
+            .map(|node| AstNodeId::new(semantic.nodes().get_node_id(node).unwrap()))
+            .collect();
+        let recursive: Vec<_> =
+            functions.iter().map(|function| graph.is_recursive(*function)).collect();
+        assert_eq!(recursive, vec![true, true, false]);
+        assert_eq!(graph.calls_to(functions[0]).count(), 2);
+        assert_eq!(graph.calls_from(functions[2]).count(), 1);
+    }
+}
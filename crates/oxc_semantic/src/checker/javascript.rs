@@ -10,73 +10,68 @@ use oxc_diagnostics::{
     thiserror::{self, Error},
     Redeclaration,
 };
-use oxc_semantic::ScopeFlags;
 
-use crate::{ast_util::STRICT_MODE_NAMES, context::LintContext, rule::Rule, AstNode};
+use super::SemanticContext;
+use crate::{AstNode, AstNodeId, ScopeFlags};
 
-#[derive(Debug, Default, Clone)]
-pub struct EarlyErrorJavaScript;
+pub fn check<'a>(node: &AstNode<'a>, ctx: &SemanticContext<'_, 'a>) {
+    let kind = node.get().kind();
+    check_function_declaration(kind, node, ctx);
 
-impl Rule for EarlyErrorJavaScript {
-    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
-        let kind = node.get().kind();
-        check_function_declaration(kind, node, ctx);
-
-        match kind {
-            AstKind::Program(_) => check_program(node, ctx),
-            AstKind::BindingIdentifier(ident) => {
-                check_identifier(&ident.name, ident.span, node, ctx);
-                check_binding_identifier(ident, node, ctx);
-            }
-            AstKind::IdentifierReference(ident) => {
-                check_identifier(&ident.name, ident.span, node, ctx);
-                check_identifier_reference(ident, node, ctx);
-            }
-            AstKind::LabelIdentifier(ident) => check_identifier(&ident.name, ident.span, node, ctx),
-            AstKind::PrivateIdentifier(ident) => check_private_identifier(ident, node, ctx),
-
-            AstKind::NumberLiteral(lit) => check_number_literal(lit, node, ctx),
-            AstKind::StringLiteral(lit) => check_string_literal(lit, node, ctx),
-            AstKind::RegExpLiteral(lit) => check_regexp_literal(lit, ctx),
-
-            AstKind::Directive(dir) => check_directive(dir, node, ctx),
-            AstKind::ModuleDeclaration(decl) => {
-                check_module_declaration(decl, node, ctx);
-                if let ModuleDeclarationKind::ImportDeclaration(import_decl) = &decl.kind {
-                    check_import_declaration(import_decl, ctx);
-                }
+    match kind {
+        AstKind::Program(_) => check_program(node, ctx),
+        AstKind::BindingIdentifier(ident) => {
+            check_identifier(&ident.name, ident.span, node, ctx);
+            check_binding_identifier(ident, node, ctx);
+        }
+        AstKind::IdentifierReference(ident) => {
+            check_identifier(&ident.name, ident.span, node, ctx);
+            check_identifier_reference(ident, node, ctx);
+        }
+        AstKind::LabelIdentifier(ident) => check_identifier(&ident.name, ident.span, node, ctx),
+        AstKind::PrivateIdentifier(ident) => check_private_identifier(ident, node, ctx),
+
+        AstKind::NumberLiteral(lit) => check_number_literal(lit, node, ctx),
+        AstKind::StringLiteral(lit) => check_string_literal(lit, node, ctx),
+        AstKind::RegExpLiteral(lit) => check_regexp_literal(lit, ctx),
+
+        AstKind::Directive(dir) => check_directive(dir, node, ctx),
+        AstKind::ModuleDeclaration(decl) => {
+            check_module_declaration(decl, node, ctx);
+            if let ModuleDeclarationKind::ImportDeclaration(import_decl) = &decl.kind {
+                check_import_declaration(import_decl, ctx);
             }
-            AstKind::MetaProperty(prop) => check_meta_property(prop, node, ctx),
-
-            AstKind::WithStatement(stmt) => check_with_statement(stmt, node, ctx),
-            AstKind::SwitchStatement(stmt) => check_switch_statement(stmt, ctx),
-            AstKind::BreakStatement(stmt) => check_break_statement(stmt, node, ctx),
-            AstKind::ContinueStatement(stmt) => check_continue_statement(stmt, node, ctx),
-            AstKind::LabeledStatement(stmt) => check_labeled_statement(stmt, node, ctx),
-            AstKind::ForInStatement(stmt) => check_for_statement_left(&stmt.left, true, node, ctx),
-            AstKind::ForOfStatement(stmt) => check_for_statement_left(&stmt.left, false, node, ctx),
-
-            AstKind::Class(class) => check_class(class, ctx),
-            AstKind::Super(sup) => check_super(sup, node, ctx),
-            AstKind::Property(prop) => check_property(prop, ctx),
-
-            AstKind::FormalParameters(params) => check_formal_parameters(params, node, ctx),
-            AstKind::FormalParameter(param) => check_formal_parameter(param, ctx),
-            AstKind::ArrayPattern(pat) => check_array_pattern(pat, ctx),
-
-            AstKind::ObjectExpression(expr) => check_object_expression(expr, ctx),
-            AstKind::BinaryExpression(expr) => check_binary_expression(expr, ctx),
-            AstKind::LogicalExpression(expr) => check_logical_expression(expr, ctx),
-            AstKind::MemberExpression(expr) => check_member_expression(expr, ctx),
-            AstKind::UnaryExpression(expr) => check_unary_expression(expr, node, ctx),
-            AstKind::AwaitExpression(expr) => check_await_expression(expr, node, ctx),
-            AstKind::YieldExpression(expr) => check_yield_expression(expr, node, ctx),
-            _ => {}
         }
+        AstKind::MetaProperty(prop) => check_meta_property(prop, node, ctx),
+
+        AstKind::WithStatement(stmt) => check_with_statement(stmt, node, ctx),
+        AstKind::SwitchStatement(stmt) => check_switch_statement(stmt, ctx),
+        AstKind::BreakStatement(stmt) => check_break_statement(stmt, node, ctx),
+        AstKind::ContinueStatement(stmt) => check_continue_statement(stmt, node, ctx),
+        AstKind::LabeledStatement(stmt) => check_labeled_statement(stmt, node, ctx),
+        AstKind::ForInStatement(stmt) => check_for_statement_left(&stmt.left, true, node, ctx),
+        AstKind::ForOfStatement(stmt) => check_for_statement_left(&stmt.left, false, node, ctx),
+
+        AstKind::Class(class) => check_class(class, ctx),
+        AstKind::Super(sup) => check_super(sup, node, ctx),
+        AstKind::Property(prop) => check_property(prop, ctx),
+
+        AstKind::FormalParameters(params) => check_formal_parameters(params, node, ctx),
+        AstKind::FormalParameter(param) => check_formal_parameter(param, ctx),
+        AstKind::ArrayPattern(pat) => check_array_pattern(pat, ctx),
+
+        AstKind::ObjectExpression(expr) => check_object_expression(expr, ctx),
+        AstKind::BinaryExpression(expr) => check_binary_expression(expr, ctx),
+        AstKind::LogicalExpression(expr) => check_logical_expression(expr, ctx),
+        AstKind::MemberExpression(expr) => check_member_expression(expr, ctx),
+        AstKind::UnaryExpression(expr) => check_unary_expression(expr, node, ctx),
+        AstKind::AwaitExpression(expr) => check_await_expression(expr, node, ctx),
+        AstKind::YieldExpression(expr) => check_yield_expression(expr, node, ctx),
+        _ => {}
     }
 }
 
-fn check_program(node: &AstNode, ctx: &LintContext) {
+fn check_program(node: &AstNode, ctx: &SemanticContext) {
     #[derive(Debug, Error, Diagnostic)]
     #[error("Export '{0}' is not defined")]
     #[diagnostic()]
@@ -132,7 +127,7 @@ fn check_program(node: &AstNode, ctx: &LintContext) {
     }
 }
 
-fn check_duplicate_bound_names<T: BoundNames>(bound_names: &T, ctx: &LintContext) {
+fn check_duplicate_bound_names<T: BoundNames>(bound_names: &T, ctx: &SemanticContext) {
     // bound_names are usually small, a simple loop should be more performant checking with a hashmap
     let mut idents = bound_names.bound_names();
     idents.sort_unstable_by_key(|ident| ident.name.as_str());
@@ -154,7 +149,24 @@ struct ClassStatickBlockAwait(#[label] Span);
 #[diagnostic()]
 struct ReservedKeyword(Atom, #[label] Span);
 
-fn check_identifier<'a>(name: &Atom, span: Span, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+const STRICT_MODE_NAMES: &[&str] = &[
+    "implements",
+    "interface",
+    "let",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "static",
+    "yield",
+];
+
+fn check_identifier<'a>(
+    name: &Atom,
+    span: Span,
+    node: &AstNode<'a>,
+    ctx: &SemanticContext<'_, 'a>,
+) {
     if *name == "await" {
         // It is a Syntax Error if the goal symbol of the syntactic grammar is Module and the StringValue of IdentifierName is "await".
         if ctx.source_type().is_module() {
@@ -167,7 +179,7 @@ fn check_identifier<'a>(name: &Atom, span: Span, node: &AstNode<'a>, ctx: &LintC
     }
 
     // It is a Syntax Error if this phrase is contained in strict mode code and the StringValue of IdentifierName is: "implements", "interface", "let", "package", "private", "protected", "public", "static", or "yield".
-    if ctx.strict_mode(node) && STRICT_MODE_NAMES.contains(name.as_str()) {
+    if ctx.strict_mode(node) && STRICT_MODE_NAMES.contains(&name.as_str()) {
         ctx.diagnostic(ReservedKeyword(name.clone(), span));
     }
 }
@@ -180,7 +192,7 @@ struct UnexpectedIdentifierAssign(Atom, #[label] Span);
 fn check_binding_identifier<'a>(
     ident: &BindingIdentifier,
     node: &AstNode<'a>,
-    ctx: &LintContext<'a>,
+    ctx: &SemanticContext<'_, 'a>,
 ) {
     let strict_mode = ctx.strict_mode(node);
     // It is a Diagnostic if the StringValue of a BindingIdentifier is "eval" or "arguments" within strict mode code.
@@ -215,7 +227,7 @@ fn check_binding_identifier<'a>(
 fn check_identifier_reference<'a>(
     ident: &IdentifierReference,
     node: &AstNode<'a>,
-    ctx: &LintContext<'a>,
+    ctx: &SemanticContext<'_, 'a>,
 ) {
     #[derive(Debug, Error, Diagnostic)]
     #[error("'arguments' is not allowed in {0}")]
@@ -265,7 +277,7 @@ fn check_identifier_reference<'a>(
 fn check_private_identifier<'a>(
     ident: &PrivateIdentifier,
     node: &AstNode<'a>,
-    ctx: &LintContext<'a>,
+    ctx: &SemanticContext<'_, 'a>,
 ) {
     // Ignore private identifier declaration inside class
     if matches!(ctx.parent_kind(node), AstKind::PropertyKey(_)) {
@@ -273,11 +285,11 @@ fn check_private_identifier<'a>(
     }
 
     // Find enclosing classes
-    let mut classes = vec![];
+    let mut in_class = false;
     for node_id in ctx.ancestors(node).skip(1) {
         let kind = ctx.kind(node_id);
-        if let AstKind::Class(class) = kind {
-            classes.push(class);
+        if matches!(kind, AstKind::Class(_)) {
+            in_class = true;
         }
         // stop lookup when the class is a heritage, e.g.
         // `class C extends class extends class { x = this.#foo; } {} { #foo }`
@@ -287,7 +299,7 @@ fn check_private_identifier<'a>(
         }
     }
 
-    if classes.is_empty() {
+    if !in_class {
         #[derive(Debug, Error, Diagnostic)]
         #[error("Private identifier '#{0}' is not allowed outside class bodies")]
         #[diagnostic()]
@@ -295,24 +307,8 @@ fn check_private_identifier<'a>(
         return ctx.diagnostic(PrivateNotInClass(ident.name.clone(), ident.span));
     };
 
-    // Check private identifier declarations in class.
-    // This implementations does a simple lookup for private identifier declarations inside a class.
-    // Performance can be improved by storing private identifiers for each class inside a lookup table,
-    // but there are not many private identifiers in the wild so we should be good fow now.
-    let found_private_ident = classes.iter().any(|class| {
-        class.body.body.iter().any(|def| {
-            // let key = match def {
-            // ClassElement::PropertyDefinition(def) => &def.key,
-            // ClassElement::MethodDefinition(def) => &def.key,
-            // _ => return false,
-            // };
-            if let Some(key) = def.property_key()
-                && let PropertyKey::PrivateIdentifier(prop_ident) = key {
-                return prop_ident.name == ident.name;
-            }
-            false
-        })
-    });
+    let node_id = AstNodeId::new(ctx.nodes().get_node_id(node).unwrap());
+    let found_private_ident = ctx.classes().get_private_reference(node_id).is_some();
 
     if !found_private_ident {
         #[derive(Debug, Error, Diagnostic)]
@@ -328,7 +324,7 @@ fn check_private_identifier<'a>(
 #[diagnostic(help("for octal literals use the '0o' prefix instead"))]
 struct LegacyOctal(#[label] Span);
 
-fn check_number_literal(lit: &NumberLiteral, node: &AstNode, ctx: &LintContext) {
+fn check_number_literal(lit: &NumberLiteral, node: &AstNode, ctx: &SemanticContext) {
     // NumericLiteral :: LegacyOctalIntegerLiteral
     // DecimalIntegerLiteral :: NonOctalDecimalIntegerLiteral
     // * It is a Syntax Error if the source text matched by this production is strict mode code.
@@ -359,7 +355,11 @@ fn check_number_literal(lit: &NumberLiteral, node: &AstNode, ctx: &LintContext)
     }
 }
 
-fn check_string_literal<'a>(lit: &StringLiteral, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+fn check_string_literal<'a>(
+    lit: &StringLiteral,
+    node: &AstNode<'a>,
+    ctx: &SemanticContext<'_, 'a>,
+) {
     // 12.9.4.1 Static Semantics: Early Errors
     // EscapeSequence ::
     //   LegacyOctalEscapeSequence
@@ -395,7 +395,7 @@ fn check_string_literal<'a>(lit: &StringLiteral, node: &AstNode<'a>, ctx: &LintC
 
 // It is a Syntax Error if FunctionBodyContainsUseStrict of AsyncFunctionBody is true and IsSimpleParameterList of FormalParameters is false.
 // background: https://humanwhocodes.com/blog/2016/10/the-ecmascript-2016-change-you-probably-dont-know/
-fn check_directive<'a>(directive: &Directive, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+fn check_directive<'a>(directive: &Directive, node: &AstNode<'a>, ctx: &SemanticContext<'_, 'a>) {
     #[derive(Debug, Error, Diagnostic)]
     #[error("Illegal 'use strict' directive in function with non-simple parameter list")]
     #[diagnostic()]
@@ -426,7 +426,7 @@ fn check_directive<'a>(directive: &Directive, node: &AstNode<'a>, ctx: &LintCont
 fn check_module_declaration<'a>(
     decl: &ModuleDeclaration,
     node: &AstNode<'a>,
-    ctx: &LintContext<'a>,
+    ctx: &SemanticContext<'_, 'a>,
 ) {
     #[derive(Debug, Error, Diagnostic)]
     #[error("'{0}' declaration can only be used at the top level of a module")]
@@ -467,14 +467,14 @@ fn check_module_declaration<'a>(
     }
 }
 
-fn check_import_declaration(decl: &ImportDeclaration, ctx: &LintContext) {
+fn check_import_declaration(decl: &ImportDeclaration, ctx: &SemanticContext) {
     // ModuleItem : ImportDeclaration
     // It is a Syntax Error if the BoundNames of ImportDeclaration contains any duplicate entries.
     // bound_names are usually small, a simple loop should be more performant checking with a hashmap
     check_duplicate_bound_names(decl, ctx);
 }
 
-fn check_meta_property<'a>(prop: &MetaProperty, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+fn check_meta_property<'a>(prop: &MetaProperty, node: &AstNode<'a>, ctx: &SemanticContext<'_, 'a>) {
     #[derive(Debug, Error, Diagnostic)]
     #[error("Unexpected new.target expression")]
     #[diagnostic(help(
@@ -532,7 +532,11 @@ fn check_meta_property<'a>(prop: &MetaProperty, node: &AstNode<'a>, ctx: &LintCo
     }
 }
 
-fn check_function_declaration<'a>(kind: AstKind<'a>, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+fn check_function_declaration<'a>(
+    kind: AstKind<'a>,
+    node: &AstNode<'a>,
+    ctx: &SemanticContext<'_, 'a>,
+) {
     #[derive(Debug, Error, Diagnostic)]
     #[error("Invalid function declaration")]
     #[diagnostic(help(
@@ -578,7 +582,7 @@ fn check_function_declaration<'a>(kind: AstKind<'a>, node: &AstNode<'a>, ctx: &L
     }
 }
 
-fn check_regexp_literal(lit: &RegExpLiteral, ctx: &LintContext) {
+fn check_regexp_literal(lit: &RegExpLiteral, ctx: &SemanticContext) {
     #[derive(Debug, Error, Diagnostic)]
     #[error("The 'u' and 'v' regular expression flags cannot be enabled at the same time")]
     #[diagnostic()]
@@ -590,7 +594,11 @@ fn check_regexp_literal(lit: &RegExpLiteral, ctx: &LintContext) {
     }
 }
 
-fn check_with_statement<'a>(stmt: &WithStatement, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+fn check_with_statement<'a>(
+    stmt: &WithStatement,
+    node: &AstNode<'a>,
+    ctx: &SemanticContext<'_, 'a>,
+) {
     #[derive(Debug, Error, Diagnostic)]
     #[error("'with' statements are not allowed")]
     #[diagnostic()]
@@ -601,7 +609,7 @@ fn check_with_statement<'a>(stmt: &WithStatement, node: &AstNode<'a>, ctx: &Lint
     }
 }
 
-fn check_switch_statement<'a>(stmt: &SwitchStatement<'a>, ctx: &LintContext<'a>) {
+fn check_switch_statement<'a>(stmt: &SwitchStatement<'a>, ctx: &SemanticContext<'_, 'a>) {
     let mut previous_default: Option<Span> = None;
     for case in &stmt.cases {
         if case.test.is_none() {
@@ -624,7 +632,11 @@ struct InvalidLabelJumpTarget(#[label] Span);
 #[diagnostic()]
 struct InvalidLabelTarget(#[label("This label is used, but not defined")] Span);
 
-fn check_break_statement<'a>(stmt: &BreakStatement, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+fn check_break_statement<'a>(
+    stmt: &BreakStatement,
+    node: &AstNode<'a>,
+    ctx: &SemanticContext<'_, 'a>,
+) {
     #[derive(Debug, Error, Diagnostic)]
     #[error("Illegal break statement")]
     #[diagnostic(help(
@@ -667,7 +679,7 @@ fn check_break_statement<'a>(stmt: &BreakStatement, node: &AstNode<'a>, ctx: &Li
 fn check_continue_statement<'a>(
     stmt: &ContinueStatement,
     node: &AstNode<'a>,
-    ctx: &LintContext<'a>,
+    ctx: &SemanticContext<'_, 'a>,
 ) {
     #[derive(Debug, Error, Diagnostic)]
     #[error("Illegal continue statement: no surrounding iteration statement")]
@@ -729,7 +741,11 @@ fn check_continue_statement<'a>(
     }
 }
 
-fn check_labeled_statement<'a>(stmt: &LabeledStatement, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+fn check_labeled_statement<'a>(
+    stmt: &LabeledStatement,
+    node: &AstNode<'a>,
+    ctx: &SemanticContext<'_, 'a>,
+) {
     for node_id in ctx.ancestors(node).skip(1) {
         match ctx.kind(node_id) {
             // label cannot cross boundary on function or static block
@@ -751,7 +767,7 @@ fn check_for_statement_left<'a>(
     left: &ForStatementLeft,
     is_for_in: bool,
     node: &AstNode<'a>,
-    ctx: &LintContext<'a>,
+    ctx: &SemanticContext<'_, 'a>,
 ) {
     #[derive(Debug, Error, Diagnostic)]
     #[error("Only a single declaration is allowed in a `for...{0}` statement")]
@@ -789,7 +805,7 @@ fn check_for_statement_left<'a>(
     }
 }
 
-fn check_class(class: &Class, ctx: &LintContext) {
+fn check_class(class: &Class, ctx: &SemanticContext) {
     #[derive(Debug, Error, Diagnostic)]
     #[error("Multiple constructor implementations are not allowed.")]
     #[diagnostic()]
@@ -819,7 +835,7 @@ fn check_class(class: &Class, ctx: &LintContext) {
     }
 }
 
-fn check_super<'a>(sup: &Super, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+fn check_super<'a>(sup: &Super, node: &AstNode<'a>, ctx: &SemanticContext<'_, 'a>) {
     #[derive(Debug, Error, Diagnostic)]
     #[error("'super' can only be referenced in a derived class.")]
     #[diagnostic(help("either remove this super, or extend the class"))]
@@ -843,79 +859,74 @@ fn check_super<'a>(sup: &Super, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         _ => None,
     };
 
-    // skip(1) is the self `Super`
-    // skip(2) is the parent `CallExpression` or `NewExpression`
-    for node_id in ctx.ancestors(node).skip(2) {
-        match ctx.kind(node_id) {
-            AstKind::Class(class) => {
+    let node_id = AstNodeId::new(ctx.nodes().get_node_id(node).unwrap());
+    let Some(environment_id) = ctx.this_bindings().environment(node_id) else { return };
+    let environment = &ctx.nodes()[*environment_id];
+    match environment.get().kind() {
+        AstKind::Function(_) => match ctx.parent_kind(environment) {
+            // ClassElement : MethodDefinition
+            // It is a Syntax Error if PropName of MethodDefinition is not "constructor" and HasDirectSuper of MethodDefinition is true.
+            AstKind::MethodDefinition(def) => {
+                // super references are allowed in methods
+                let Some(super_call_span) = super_call_span else { return };
+                if def.kind != MethodDefinitionKind::Constructor {
+                    return ctx.diagnostic(UnexpectedSuperCall(super_call_span));
+                }
                 // ClassTail : ClassHeritageopt { ClassBody }
                 // It is a Syntax Error if ClassHeritage is not present and the following algorithm returns true:
                 // 1. Let constructor be ConstructorMethod of ClassBody.
                 // 2. If constructor is empty, return false.
                 // 3. Return HasDirectSuper of constructor.
-                if class.super_class.is_none() {
-                    return ctx.diagnostic(SuperWithoutDerivedClass(sup.span, class.span));
+                let class =
+                    ctx.ancestors(environment).find_map(|node_id| match ctx.kind(node_id) {
+                        AstKind::Class(class) => Some(class),
+                        _ => None,
+                    });
+                if let Some(class) = class && class.super_class.is_none() {
+                    ctx.diagnostic(SuperWithoutDerivedClass(sup.span, class.span));
                 }
-                break;
             }
-            AstKind::MethodDefinition(def) => {
-                // ClassElement : MethodDefinition
-                // It is a Syntax Error if PropName of MethodDefinition is not "constructor" and HasDirectSuper of MethodDefinition is true.
-                if let Some(super_call_span) = super_call_span {
-                    if def.kind == MethodDefinitionKind::Constructor {
-                        // pass through and let AstKind::Class check ClassHeritage
-                    } else {
-                        return ctx.diagnostic(UnexpectedSuperCall(super_call_span));
-                    }
-                } else {
-                    // super references are allowed in method
-                    break;
-                }
-            }
-            // FieldDefinition : ClassElementName Initializer opt
-            // * It is a Syntax Error if Initializer is present and Initializer Contains SuperCall is true.
             // PropertyDefinition : MethodDefinition
             // * It is a Syntax Error if HasDirectSuper of MethodDefinition is true.
-            AstKind::PropertyDefinition(_) => {
-                if let Some(super_call_span) = super_call_span {
-                    return ctx.diagnostic(UnexpectedSuperCall(super_call_span));
-                }
-                break;
-            }
-            AstKind::PropertyValue(value) => {
-                if let PropertyValue::Expression(
-                    Expression::FunctionExpression(_) | Expression::ArrowFunctionExpression(_),
-                ) = value
-                {
-                    if let Some(super_call_span) = super_call_span {
-                        return ctx.diagnostic(UnexpectedSuperCall(super_call_span));
-                    }
-                    break;
-                }
-            }
-            // ClassStaticBlockBody : ClassStaticBlockStatementList
-            // * It is a Syntax Error if ClassStaticBlockStatementList Contains SuperCall is true.
-            AstKind::StaticBlock(_) => {
+            AstKind::PropertyValue(_)
+                if matches!(
+                    ctx.parent_node(environment).map(|value| ctx.parent_kind(value)),
+                    Some(AstKind::Property(prop)) if prop.method || prop.kind != PropertyKind::Init
+                ) =>
+            {
                 if let Some(super_call_span) = super_call_span {
-                    return ctx.diagnostic(UnexpectedSuperCall(super_call_span));
+                    ctx.diagnostic(UnexpectedSuperCall(super_call_span));
                 }
             }
-            // ModuleBody : ModuleItemList
-            // * It is a Syntax Error if ModuleItemList Contains super.
-            // ScriptBody : StatementList
-            // * It is a Syntax Error if StatementList Contains super
-            AstKind::Program(_) => {
-                return super_call_span.map_or_else(
-                    || ctx.diagnostic(UnexpectedSuperReference(sup.span)),
-                    |super_call_span| ctx.diagnostic(UnexpectedSuperCall(super_call_span)),
-                );
+            // FunctionBody : FunctionStatementList
+            // * It is a Syntax Error if FunctionStatementList Contains SuperProperty is true.
+            // * It is a Syntax Error if FunctionStatementList Contains SuperCall is true.
+            _ => super_call_span.map_or_else(
+                || ctx.diagnostic(UnexpectedSuperReference(sup.span)),
+                |super_call_span| ctx.diagnostic(UnexpectedSuperCall(super_call_span)),
+            ),
+        },
+        // FieldDefinition : ClassElementName Initializer opt
+        // * It is a Syntax Error if Initializer is present and Initializer Contains SuperCall is true.
+        // ClassStaticBlockBody : ClassStaticBlockStatementList
+        // * It is a Syntax Error if ClassStaticBlockStatementList Contains SuperCall is true.
+        AstKind::PropertyDefinition(_) | AstKind::AccessorProperty(_) | AstKind::StaticBlock(_) => {
+            if let Some(super_call_span) = super_call_span {
+                ctx.diagnostic(UnexpectedSuperCall(super_call_span));
             }
-            _ => {}
         }
+        // ModuleBody : ModuleItemList
+        // * It is a Syntax Error if ModuleItemList Contains super.
+        // ScriptBody : StatementList
+        // * It is a Syntax Error if StatementList Contains super
+        _ => super_call_span.map_or_else(
+            || ctx.diagnostic(UnexpectedSuperReference(sup.span)),
+            |super_call_span| ctx.diagnostic(UnexpectedSuperCall(super_call_span)),
+        ),
     }
 }
 
-fn check_property(prop: &Property, ctx: &LintContext) {
+fn check_property(prop: &Property, ctx: &SemanticContext) {
     #[derive(Debug, Error, Diagnostic)]
     #[error("Invalid assignment in object literal")]
     #[diagnostic(help(
@@ -935,7 +946,7 @@ fn check_property(prop: &Property, ctx: &LintContext) {
 fn check_formal_parameters<'a>(
     params: &FormalParameters,
     node: &AstNode<'a>,
-    ctx: &LintContext<'a>,
+    ctx: &SemanticContext<'_, 'a>,
 ) {
     if params.is_empty() {
         return;
@@ -952,7 +963,7 @@ fn check_formal_parameters<'a>(
     check_duplicate_bound_names(params, ctx);
 }
 
-fn check_formal_parameter(param: &FormalParameter, ctx: &LintContext) {
+fn check_formal_parameter(param: &FormalParameter, ctx: &SemanticContext) {
     #[derive(Debug, Error, Diagnostic)]
     #[error("A rest parameter cannot have an initializer")]
     #[diagnostic()]
@@ -968,7 +979,7 @@ fn check_formal_parameter(param: &FormalParameter, ctx: &LintContext) {
     }
 }
 
-fn check_array_pattern(pattern: &ArrayPattern, ctx: &LintContext) {
+fn check_array_pattern(pattern: &ArrayPattern, ctx: &SemanticContext) {
     #[derive(Debug, Error, Diagnostic)]
     #[error("A rest parameter cannot have an initializer")]
     #[diagnostic()]
@@ -988,7 +999,7 @@ fn check_array_pattern(pattern: &ArrayPattern, ctx: &LintContext) {
     }
 }
 
-fn check_object_expression(obj_expr: &ObjectExpression, ctx: &LintContext) {
+fn check_object_expression(obj_expr: &ObjectExpression, ctx: &SemanticContext) {
     // ObjectLiteral : { PropertyDefinitionList }
     // It is a Syntax Error if PropertyNameList of PropertyDefinitionList contains any duplicate entries for "__proto__"
     // and at least two of those entries were obtained from productions of the form PropertyDefinition : PropertyName : AssignmentExpression
@@ -1004,7 +1015,7 @@ fn check_object_expression(obj_expr: &ObjectExpression, ctx: &LintContext) {
     }
 }
 
-fn check_binary_expression(binary_expr: &BinaryExpression, ctx: &LintContext) {
+fn check_binary_expression(binary_expr: &BinaryExpression, ctx: &SemanticContext) {
     #[derive(Debug, Error, Diagnostic)]
     #[error("Unexpected exponentiation expression")]
     #[diagnostic(help("Wrap {0} expression in parentheses to enforce operator precedence"))]
@@ -1026,7 +1037,7 @@ fn check_binary_expression(binary_expr: &BinaryExpression, ctx: &LintContext) {
     }
 }
 
-fn check_logical_expression(logical_expr: &LogicalExpression, ctx: &LintContext) {
+fn check_logical_expression(logical_expr: &LogicalExpression, ctx: &SemanticContext) {
     #[derive(Debug, Error, Diagnostic)]
     #[error("Logical expressions and coalesce expressions cannot be mixed")]
     #[diagnostic(help("Wrap either expression by parentheses"))]
@@ -1052,7 +1063,7 @@ fn check_logical_expression(logical_expr: &LogicalExpression, ctx: &LintContext)
     }
 }
 
-fn check_member_expression(member_expr: &MemberExpression, ctx: &LintContext) {
+fn check_member_expression(member_expr: &MemberExpression, ctx: &SemanticContext) {
     #[derive(Debug, Error, Diagnostic)]
     #[error("Private fields cannot be accessed on super")]
     #[diagnostic()]
@@ -1069,7 +1080,7 @@ fn check_member_expression(member_expr: &MemberExpression, ctx: &LintContext) {
 fn check_unary_expression<'a>(
     unary_expr: &'a UnaryExpression,
     node: &AstNode<'a>,
-    ctx: &LintContext<'a>,
+    ctx: &SemanticContext<'_, 'a>,
 ) {
     #[derive(Debug, Error, Diagnostic)]
     #[error("Delete of an unqualified identifier in strict mode.")]
@@ -1097,7 +1108,7 @@ fn check_unary_expression<'a>(
     }
 }
 
-fn is_in_formal_parameters<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> bool {
+fn is_in_formal_parameters<'a>(node: &AstNode<'a>, ctx: &SemanticContext<'_, 'a>) -> bool {
     for node_id in ctx.ancestors(node).skip(1) {
         match ctx.kind(node_id) {
             AstKind::FormalParameters(_) => return true,
@@ -1116,7 +1127,11 @@ struct AwaitOrYieldInParameter(
     #[label("{0} expression not allowed in formal parameter")] Span,
 );
 
-fn check_await_expression<'a>(expr: &AwaitExpression, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+fn check_await_expression<'a>(
+    expr: &AwaitExpression,
+    node: &AstNode<'a>,
+    ctx: &SemanticContext<'_, 'a>,
+) {
     if is_in_formal_parameters(node, ctx) {
         ctx.diagnostic(AwaitOrYieldInParameter("await", expr.span));
     }
@@ -1127,7 +1142,11 @@ fn check_await_expression<'a>(expr: &AwaitExpression, node: &AstNode<'a>, ctx: &
     }
 }
 
-fn check_yield_expression<'a>(expr: &YieldExpression, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+fn check_yield_expression<'a>(
+    expr: &YieldExpression,
+    node: &AstNode<'a>,
+    ctx: &SemanticContext<'_, 'a>,
+) {
     if is_in_formal_parameters(node, ctx) {
         ctx.diagnostic(AwaitOrYieldInParameter("yield", expr.span));
     }
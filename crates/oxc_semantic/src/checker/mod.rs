@@ -0,0 +1,161 @@
+//! Early errors, the syntax errors of the specification which are not reported by the parser,
+//! see [Static Semantics: Early Errors](https://tc39.es/ecma262/#early-error)
+//!
+//! Enabled by [`crate::SemanticBuilder::with_check_syntax_error`].
+
+mod javascript;
+mod typescript;
+
+use std::cell::RefCell;
+
+use indextree::{Ancestors, NodeId};
+use oxc_ast::{AstKind, SourceType};
+use oxc_diagnostics::Error;
+
+use crate::{
+    AstNode, AstNodes, ClassTable, Scope, ScopeTree, Semantic, SemanticNode, ThisBindings,
+};
+
+/// Check all nodes of `semantic` for early errors.
+pub fn check(semantic: &Semantic) -> Vec<Error> {
+    let source_type = semantic.source_type();
+    if source_type.is_typescript_definition() {
+        return vec![];
+    }
+    let ctx = SemanticContext::new(semantic);
+    let is_typescript = source_type.is_typescript();
+    for node in semantic.nodes().iter() {
+        javascript::check(node, &ctx);
+        if is_typescript {
+            typescript::check(node, &ctx);
+        }
+    }
+    ctx.into_errors()
+}
+
+/// The queries on [`Semantic`] used by the checks, and the errors they report.
+pub struct SemanticContext<'s, 'a> {
+    semantic: &'s Semantic<'a>,
+
+    errors: RefCell<Vec<Error>>,
+}
+
+impl<'s, 'a> SemanticContext<'s, 'a> {
+    fn new(semantic: &'s Semantic<'a>) -> Self {
+        Self { semantic, errors: RefCell::new(vec![]) }
+    }
+
+    fn into_errors(self) -> Vec<Error> {
+        self.errors.into_inner()
+    }
+
+    pub fn diagnostic<T: Into<Error>>(&self, diagnostic: T) {
+        self.errors.borrow_mut().push(diagnostic.into());
+    }
+
+    pub fn semantic(&self) -> &Semantic<'a> {
+        self.semantic
+    }
+
+    pub fn source_text(&self) -> &'a str {
+        self.semantic.source_text()
+    }
+
+    pub fn source_type(&self) -> &SourceType {
+        self.semantic.source_type()
+    }
+
+    /* Nodes */
+
+    pub fn nodes(&self) -> &AstNodes<'a> {
+        self.semantic.nodes()
+    }
+
+    pub fn kind(&self, node_id: NodeId) -> AstKind<'a> {
+        self.nodes().kind(node_id)
+    }
+
+    pub fn parent_kind(&self, node: &AstNode<'a>) -> AstKind<'a> {
+        self.nodes().parent_kind(node)
+    }
+
+    pub fn parent_node(&self, node: &AstNode<'a>) -> Option<&AstNode<'a>> {
+        node.parent().and_then(|node_id| self.nodes().get(node_id))
+    }
+
+    pub fn ancestors(&self, node: &AstNode<'a>) -> Ancestors<'_, SemanticNode<'a>> {
+        let node_id = self.nodes().get_node_id(node).unwrap();
+        node_id.ancestors(self.nodes())
+    }
+
+    /* Classes and `this` */
+
+    /// Built for the early errors, see [`crate::SemanticBuilder::with_check_syntax_error`]
+    pub fn classes(&self) -> &ClassTable {
+        self.semantic.classes().expect("built with the early errors")
+    }
+
+    /// Built for the early errors, see [`crate::SemanticBuilder::with_check_syntax_error`]
+    pub fn this_bindings(&self) -> &ThisBindings {
+        self.semantic.this_bindings().expect("built with the early errors")
+    }
+
+    /* Scopes */
+
+    pub fn scopes(&self) -> &ScopeTree {
+        self.semantic.scopes()
+    }
+
+    pub fn scope(&self, node: &AstNode) -> &Scope {
+        self.scopes().node_scope(node)
+    }
+
+    pub fn scope_ancestors(&self, node: &AstNode) -> Ancestors<'_, Scope> {
+        self.scopes().node_scope_ancestors(node)
+    }
+
+    pub fn strict_mode(&self, node: &AstNode) -> bool {
+        self.scopes().strict_mode(node)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::rc::Rc;
+
+    use oxc_allocator::Allocator;
+    use oxc_ast::SourceType;
+
+    use crate::{tester, SemanticBuilder};
+
+    pub fn errors(source_text: &str, source_type: SourceType, check: bool) -> Vec<String> {
+        let allocator = Allocator::default();
+        let program = tester::parse(&allocator, source_text, source_type);
+        SemanticBuilder::new(source_text, source_type, &Rc::default())
+            .with_check_syntax_error(check)
+            .build(program)
+            .errors
+            .into_iter()
+            .map(|error| error.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn check_syntax_error() {
+        let source_type = SourceType::from_path("test.mjs").unwrap();
+        let source_text = "export { a }; label: label: ;";
+        assert!(errors(source_text, source_type, false).is_empty());
+        assert_eq!(
+            errors(source_text, source_type, true),
+            vec!["Export 'a' is not defined", "Identifier `label` has already been declared"]
+        );
+
+        let source_type = SourceType::from_path("test.ts").unwrap();
+        assert_eq!(
+            errors("function f(a?: number, b: number) {}", source_type, true),
+            vec!["TS1016: A required parameter cannot follow an optional parameter."]
+        );
+        let source_type = SourceType::from_path("test.d.ts").unwrap();
+        assert!(errors("function f(a?: number, b: number) {}", source_type, true).is_empty());
+    }
+}
@@ -0,0 +1,644 @@
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, AstKind, Atom, GetSpan, Span};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+
+use super::SemanticContext;
+use crate::AstNode;
+
+/// Grammar errors reported by `tsc` before type checking
+pub fn check<'a>(node: &AstNode<'a>, ctx: &SemanticContext<'_, 'a>) {
+    match node.get().kind() {
+        AstKind::Program(program) => {
+            check_overloads(&program.body, node, ctx);
+            check_export_assignment(program, ctx);
+        }
+        AstKind::BlockStatement(block) => check_overloads(&block.body, node, ctx),
+        AstKind::FunctionBody(body) => check_overloads(&body.statements, node, ctx),
+        AstKind::TSModuleBlock(block) => check_overloads(&block.body, node, ctx),
+
+        AstKind::Class(class) => {
+            check_declare_modifier(&class.modifiers, node, ctx);
+            check_class(class, node, ctx);
+        }
+        AstKind::Function(func) => {
+            check_declare_modifier(&func.modifiers, node, ctx);
+            check_function(func, node, ctx);
+        }
+        AstKind::VariableDeclaration(decl) => {
+            check_declare_modifier(&decl.modifiers, node, ctx);
+            check_variable_declaration(decl, node, ctx);
+        }
+        AstKind::TSEnumDeclaration(decl) => {
+            check_declare_modifier(&decl.modifiers, node, ctx);
+            check_enum_declaration(decl, ctx);
+        }
+        AstKind::TSModuleDeclaration(decl) => {
+            check_declare_modifier(&decl.modifiers, node, ctx);
+        }
+        AstKind::TSInterfaceDeclaration(decl) => {
+            check_declare_modifier(&decl.modifiers, node, ctx);
+        }
+        AstKind::TSTypeAliasDeclaration(decl) => {
+            check_declare_modifier(&decl.modifiers, node, ctx);
+        }
+
+        AstKind::FormalParameters(params) => check_formal_parameters(params, node, ctx),
+        _ => {}
+    }
+}
+
+/// Inside of a `declare namespace` or a `declare class`
+fn is_in_ambient_context<'a>(node: &AstNode<'a>, ctx: &SemanticContext<'_, 'a>) -> bool {
+    ctx.ancestors(node).skip(1).any(|node_id| match ctx.kind(node_id) {
+        AstKind::TSModuleDeclaration(decl) => decl.modifiers.contains(ModifierKind::Declare),
+        AstKind::Class(class) => class.is_declare(),
+        _ => false,
+    })
+}
+
+fn check_declare_modifier<'a>(
+    modifiers: &Modifiers,
+    node: &AstNode<'a>,
+    ctx: &SemanticContext<'_, 'a>,
+) {
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("TS1038: A 'declare' modifier cannot be used in an already ambient context.")]
+    #[diagnostic()]
+    struct DeclareInAmbientContext(#[label] Span);
+
+    if let Some(modifier) = modifiers.find(ModifierKind::Declare)
+        && ctx.ancestors(node).skip(1).any(|node_id| {
+            matches!(ctx.kind(node_id), AstKind::TSModuleDeclaration(decl) if decl.modifiers.contains(ModifierKind::Declare))
+        })
+    {
+        ctx.diagnostic(DeclareInAmbientContext(modifier.span));
+    }
+}
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("TS1039: Initializers are not allowed in ambient contexts.")]
+#[diagnostic()]
+struct InitializerInAmbientContext(#[label] Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("TS1183: An implementation cannot be declared in ambient contexts.")]
+#[diagnostic()]
+struct ImplementationInAmbientContext(#[label] Span);
+
+fn check_variable_declaration<'a>(
+    decl: &VariableDeclaration,
+    node: &AstNode<'a>,
+    ctx: &SemanticContext<'_, 'a>,
+) {
+    // `declare const a = 1;` is allowed for literal types
+    if decl.kind == VariableDeclarationKind::Const
+        || !(decl.modifiers.contains(ModifierKind::Declare) || is_in_ambient_context(node, ctx))
+    {
+        return;
+    }
+    for declarator in &decl.declarations {
+        if let Some(init) = &declarator.init {
+            ctx.diagnostic(InitializerInAmbientContext(init.span()));
+        }
+    }
+}
+
+fn check_function<'a>(func: &Function, node: &AstNode<'a>, ctx: &SemanticContext<'_, 'a>) {
+    let Some(body) = &func.body else { return };
+    if func.modifiers.contains(ModifierKind::Declare) || is_in_ambient_context(node, ctx) {
+        ctx.diagnostic(ImplementationInAmbientContext(body.span));
+    }
+}
+
+fn check_enum_declaration(decl: &TSEnumDeclaration, ctx: &SemanticContext) {
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("TS2300: Duplicate identifier '{0}'.")]
+    #[diagnostic()]
+    struct DuplicateEnumMember(
+        Atom,
+        #[label("'{0}' has already been declared here")] Span,
+        #[label("it cannot be redeclared here")] Span,
+    );
+
+    let mut names: Vec<(&Atom, Span)> = vec![];
+    for member in &decl.members {
+        let (name, span) = match &member.id {
+            TSEnumMemberName::Identifier(ident) => (&ident.name, ident.span),
+            TSEnumMemberName::StringLiteral(lit) => (&lit.value, lit.span),
+            _ => continue,
+        };
+        if let Some((_, prev_span)) = names.iter().find(|(prev, _)| *prev == name) {
+            ctx.diagnostic(DuplicateEnumMember(name.clone(), *prev_span, span));
+        } else {
+            names.push((name, span));
+        }
+    }
+}
+
+fn check_export_assignment(program: &Program, ctx: &SemanticContext) {
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("TS1203: Export assignment cannot be used when targeting ECMAScript modules.")]
+    #[diagnostic(help("Consider using 'export default' or another module format instead."))]
+    struct ExportAssignmentInModule(#[label] Span);
+
+    #[derive(Debug, Error, Diagnostic)]
+    #[error(
+        "TS2309: An export assignment cannot be used in a module with other exported elements."
+    )]
+    #[diagnostic()]
+    struct ExportAssignmentWithOtherExports(#[label] Span);
+
+    let mut export_assignment = None;
+    let mut has_other_exports = false;
+    // `import` and `export` declarations, the file is an ECMAScript module
+    let mut has_esm_syntax = false;
+    for stmt in &program.body {
+        let Statement::ModuleDeclaration(decl) = stmt else { continue };
+        match &decl.kind {
+            ModuleDeclarationKind::TSExportAssignment(assignment) => {
+                export_assignment = Some(assignment.span);
+            }
+            ModuleDeclarationKind::TSNamespaceExportDeclaration(_) => has_other_exports = true,
+            ModuleDeclarationKind::ImportDeclaration(_) => has_esm_syntax = true,
+            // `export type T = 1` and `export interface I {}` are allowed alongside `export =`
+            ModuleDeclarationKind::ExportNamedDeclaration(decl)
+                if decl.export_kind == Some(ImportOrExportKind::Type)
+                    || decl.declaration.as_ref().is_some_and(Declaration::is_typescript_syntax) =>
+            {
+                has_esm_syntax = true;
+            }
+            _ => {
+                has_esm_syntax = true;
+                has_other_exports = true;
+            }
+        }
+    }
+
+    let Some(span) = export_assignment else { return };
+    // `export =` compiles to CommonJS unless the file is written as an ECMAScript module
+    if has_esm_syntax {
+        ctx.diagnostic(ExportAssignmentInModule(span));
+    }
+    if has_other_exports {
+        ctx.diagnostic(ExportAssignmentWithOtherExports(span));
+    }
+}
+
+#[allow(clippy::too_many_lines)]
+fn check_class<'a>(class: &Class<'a>, node: &AstNode<'a>, ctx: &SemanticContext<'_, 'a>) {
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("TS1244: Abstract methods can only appear within an abstract class.")]
+    #[diagnostic()]
+    struct AbstractMethodInNonAbstractClass(#[label] Span);
+
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("TS1253: Abstract properties can only appear within an abstract class.")]
+    #[diagnostic()]
+    struct AbstractPropertyInNonAbstractClass(#[label] Span);
+
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("TS1245: Method '{0}' cannot have an implementation because it is marked abstract.")]
+    #[diagnostic()]
+    struct AbstractMethodWithImplementation(String, #[label] Span);
+
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("TS1267: Property '{0}' cannot have an initializer because it is marked abstract.")]
+    #[diagnostic()]
+    struct AbstractPropertyWithInitializer(String, #[label] Span);
+
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("TS1243: '{0}' modifier cannot be used with 'abstract' modifier.")]
+    #[diagnostic()]
+    struct AbstractModifierConflict(&'static str, #[label] Span);
+
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("TS18019: 'abstract' modifier cannot be used with a private identifier.")]
+    #[diagnostic()]
+    struct AbstractPrivateIdentifier(#[label] Span);
+
+    #[derive(Debug, Error, Diagnostic)]
+    #[error(
+        "TS1242: 'abstract' modifier can only appear on a class, method, or property declaration."
+    )]
+    #[diagnostic()]
+    struct AbstractConstructor(#[label] Span);
+
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("TS18010: An accessibility modifier cannot be used with a private identifier.")]
+    #[diagnostic()]
+    struct AccessibilityPrivateIdentifier(#[label] Span);
+
+    let is_abstract_class = class.modifiers.contains(ModifierKind::Abstract);
+    let is_ambient = class.is_declare() || is_in_ambient_context(node, ctx);
+    let key_text = |key: &PropertyKey| key.span().source_text(ctx.source_text()).to_string();
+
+    for element in &class.body.body {
+        let (key, r#static, accessibility) = match element {
+            ClassElement::MethodDefinition(def) => {
+                check_class_element_modifiers(&def.modifiers, true, ctx);
+                (&def.key, def.r#static, def.accessibility)
+            }
+            ClassElement::PropertyDefinition(def) => {
+                check_class_element_modifiers(&def.modifiers, false, ctx);
+                (&def.key, def.r#static, def.accessibility)
+            }
+            ClassElement::TSAbstractMethodDefinition(def) => {
+                let def = &def.method_definition;
+                check_class_element_modifiers(&def.modifiers, true, ctx);
+                (&def.key, def.r#static, def.accessibility)
+            }
+            ClassElement::TSAbstractPropertyDefinition(def) => {
+                let def = &def.property_definition;
+                check_class_element_modifiers(&def.modifiers, false, ctx);
+                (&def.key, def.r#static, def.accessibility)
+            }
+            _ => continue,
+        };
+        if accessibility.is_some() && key.is_private_identifier() {
+            ctx.diagnostic(AccessibilityPrivateIdentifier(key.span()));
+        }
+
+        match element {
+            ClassElement::TSAbstractMethodDefinition(def) => {
+                let def = &def.method_definition;
+                if def.kind == MethodDefinitionKind::Constructor {
+                    ctx.diagnostic(AbstractConstructor(def.span));
+                    continue;
+                }
+                if !is_abstract_class {
+                    ctx.diagnostic(AbstractMethodInNonAbstractClass(def.span));
+                }
+                if let Some(body) = &def.value.body {
+                    ctx.diagnostic(AbstractMethodWithImplementation(key_text(key), body.span));
+                }
+            }
+            ClassElement::TSAbstractPropertyDefinition(def) => {
+                let def = &def.property_definition;
+                if !is_abstract_class {
+                    ctx.diagnostic(AbstractPropertyInNonAbstractClass(def.span));
+                }
+                if let Some(value) = &def.value {
+                    ctx.diagnostic(AbstractPropertyWithInitializer(key_text(key), value.span()));
+                }
+            }
+            ClassElement::PropertyDefinition(def) => {
+                if let Some(value) = &def.value && (def.declare || is_ambient) {
+                    ctx.diagnostic(InitializerInAmbientContext(value.span()));
+                }
+                continue;
+            }
+            // implementations of methods in ambient classes are reported by `check_function`
+            _ => continue,
+        }
+
+        // abstract members
+        if r#static {
+            ctx.diagnostic(AbstractModifierConflict("static", key.span()));
+        }
+        if accessibility == Some(TSAccessibility::Private) {
+            ctx.diagnostic(AbstractModifierConflict("private", key.span()));
+        }
+        if key.is_private_identifier() {
+            ctx.diagnostic(AbstractPrivateIdentifier(key.span()));
+        }
+    }
+
+    if !is_ambient {
+        check_method_overloads(class, ctx);
+    }
+}
+
+/// Modifiers which the parser accepts on any class element,
+/// but which are not allowed on methods or more than once
+fn check_class_element_modifiers(modifiers: &Modifiers, is_method: bool, ctx: &SemanticContext) {
+    #[derive(Debug, Error, Diagnostic)]
+    #[error(
+        "TS1024: 'readonly' modifier can only appear on a property declaration or index signature."
+    )]
+    #[diagnostic()]
+    struct ReadonlyMethod(#[label] Span);
+
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("TS1028: Accessibility modifier already seen.")]
+    #[diagnostic()]
+    struct AccessibilityModifierAlreadySeen(#[label] Span);
+
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("TS1031: 'declare' modifier cannot appear on class elements of this kind.")]
+    #[diagnostic()]
+    struct DeclareMethod(#[label] Span);
+
+    let mut has_accessibility = false;
+    for modifier in modifiers.iter() {
+        match modifier.kind {
+            ModifierKind::Public | ModifierKind::Private | ModifierKind::Protected => {
+                if has_accessibility {
+                    ctx.diagnostic(AccessibilityModifierAlreadySeen(modifier.span));
+                }
+                has_accessibility = true;
+            }
+            ModifierKind::Readonly if is_method => ctx.diagnostic(ReadonlyMethod(modifier.span)),
+            ModifierKind::Declare if is_method => ctx.diagnostic(DeclareMethod(modifier.span)),
+            _ => {}
+        }
+    }
+}
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("TS2391: Function implementation is missing or not immediately following the declaration.")]
+#[diagnostic()]
+struct MissingImplementation(#[label] Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("TS2389: Function implementation name must be '{0}'.")]
+#[diagnostic()]
+struct ImplementationName(String, #[label] Span);
+
+/// Overload signatures of methods must be followed by their implementation,
+/// and agree with it on `static` and accessibility.
+fn check_method_overloads(class: &Class, ctx: &SemanticContext) {
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("TS2387: Function overload must be static.")]
+    #[diagnostic()]
+    struct OverloadMustBeStatic(#[label] Span);
+
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("TS2388: Function overload must not be static.")]
+    #[diagnostic()]
+    struct OverloadMustNotBeStatic(#[label] Span);
+
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("TS2385: Overload signatures must all be public, private or protected.")]
+    #[diagnostic()]
+    struct OverloadAccessibility(#[label] Span);
+
+    let public = |def: &MethodDefinition| def.accessibility.unwrap_or(TSAccessibility::Public);
+    let mut elements = class.body.body.iter().peekable();
+    while let Some(element) = elements.next() {
+        let ClassElement::MethodDefinition(overload) = element else { continue };
+        if overload.value.body.is_some() {
+            continue;
+        }
+        let Some(name) = overload.key.static_name() else { continue };
+        match elements.peek() {
+            Some(ClassElement::MethodDefinition(next))
+                if next.key.static_name().as_ref() == Some(&name) =>
+            {
+                if overload.r#static && !next.r#static {
+                    ctx.diagnostic(OverloadMustBeStatic(next.key.span()));
+                } else if !overload.r#static && next.r#static {
+                    ctx.diagnostic(OverloadMustNotBeStatic(next.key.span()));
+                }
+                if public(overload) != public(next) {
+                    ctx.diagnostic(OverloadAccessibility(next.key.span()));
+                }
+            }
+            Some(ClassElement::MethodDefinition(next))
+                if next.value.body.is_some() && next.key.static_name().is_some() =>
+            {
+                ctx.diagnostic(ImplementationName(name.to_string(), next.key.span()));
+            }
+            _ => ctx.diagnostic(MissingImplementation(overload.key.span())),
+        }
+    }
+}
+
+/// A function declaration in a statement list, and whether it is exported
+fn function_declaration<'a, 'b>(stmt: &'b Statement<'a>) -> Option<(&'b Function<'a>, bool)> {
+    match stmt {
+        Statement::Declaration(Declaration::FunctionDeclaration(func)) => Some((func, false)),
+        Statement::ModuleDeclaration(decl) => match &decl.kind {
+            ModuleDeclarationKind::ExportNamedDeclaration(decl) => match &decl.declaration {
+                Some(Declaration::FunctionDeclaration(func)) => Some((func, true)),
+                _ => None,
+            },
+            ModuleDeclarationKind::ExportDefaultDeclaration(decl) => match &decl.declaration {
+                ExportDefaultDeclarationKind::FunctionDeclaration(func) => Some((func, true)),
+                _ => None,
+            },
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Overload signatures of functions must be followed by their implementation,
+/// and all be exported or non-exported.
+fn check_overloads<'a>(
+    statements: &[Statement<'a>],
+    node: &AstNode<'a>,
+    ctx: &SemanticContext<'_, 'a>,
+) {
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("TS2383: Overload signatures must all be exported or non-exported.")]
+    #[diagnostic()]
+    struct OverloadExport(#[label] Span);
+
+    if is_in_ambient_context(node, ctx) {
+        return;
+    }
+
+    let mut statements = statements.iter().peekable();
+    while let Some(stmt) = statements.next() {
+        let Some((overload, exported)) = function_declaration(stmt) else { continue };
+        if overload.body.is_some() || overload.modifiers.contains(ModifierKind::Declare) {
+            continue;
+        }
+        let Some(id) = &overload.id else { continue };
+        let next = statements.peek().and_then(|stmt| function_declaration(stmt));
+        match next {
+            Some((next, next_exported))
+                if next.id.as_ref().is_some_and(|next_id| next_id.name == id.name) =>
+            {
+                if exported != next_exported {
+                    ctx.diagnostic(OverloadExport(next.id.as_ref().unwrap().span));
+                }
+            }
+            Some((next, _)) if next.body.is_some() && next.id.is_some() => {
+                ctx.diagnostic(ImplementationName(
+                    id.name.to_string(),
+                    next.id.as_ref().unwrap().span,
+                ));
+            }
+            _ => ctx.diagnostic(MissingImplementation(id.span)),
+        }
+    }
+}
+
+fn check_formal_parameters<'a>(
+    params: &FormalParameters<'a>,
+    node: &AstNode<'a>,
+    ctx: &SemanticContext<'_, 'a>,
+) {
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("TS1047: A rest parameter cannot be optional.")]
+    #[diagnostic()]
+    struct OptionalRestParameter(#[label] Span);
+
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("TS1016: A required parameter cannot follow an optional parameter.")]
+    #[diagnostic()]
+    struct RequiredParameterAfterOptional(#[label] Span);
+
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("TS2369: A parameter property is only allowed in a constructor implementation.")]
+    #[diagnostic()]
+    struct ParameterPropertyOutsideConstructor(#[label] Span);
+
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("TS1187: A parameter property may not be declared using a binding pattern.")]
+    #[diagnostic()]
+    struct ParameterPropertyBindingPattern(#[label] Span);
+
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("TS1317: A parameter property cannot be declared using a rest parameter.")]
+    #[diagnostic()]
+    struct ParameterPropertyRest(#[label] Span);
+
+    let is_constructor_implementation = match ctx.parent_kind(node) {
+        AstKind::Function(func) if func.body.is_some() => {
+            ctx.parent_node(node).is_some_and(|func| {
+                matches!(
+                    ctx.parent_kind(func),
+                    AstKind::MethodDefinition(def) if def.kind == MethodDefinitionKind::Constructor
+                )
+            })
+        }
+        _ => false,
+    };
+
+    let mut seen_optional = false;
+    for param in &params.items {
+        let pattern = &param.pattern;
+        let is_rest = pattern.kind.is_rest_element();
+        // `...rest?: T[]`, the `?` belongs to the argument of the rest element
+        if let BindingPatternKind::RestElement(rest) = &pattern.kind && rest.argument.optional {
+            ctx.diagnostic(OptionalRestParameter(param.span));
+        }
+        if pattern.optional {
+            seen_optional = true;
+        } else if seen_optional
+            && !is_rest
+            && !matches!(pattern.kind, BindingPatternKind::AssignmentPattern(_))
+        {
+            ctx.diagnostic(RequiredParameterAfterOptional(param.span));
+        }
+
+        if param.accessibility.is_none() && !param.readonly {
+            continue;
+        }
+        if !is_constructor_implementation {
+            ctx.diagnostic(ParameterPropertyOutsideConstructor(param.span));
+        } else if is_rest {
+            ctx.diagnostic(ParameterPropertyRest(param.span));
+        } else if pattern.kind.is_destructuring_pattern() {
+            ctx.diagnostic(ParameterPropertyBindingPattern(param.span));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_ast::SourceType;
+
+    use crate::checker::test::errors;
+
+    /// The error codes of `source_text`, e.g. `TS1016`
+    fn codes(source_text: &str, path: &str) -> Vec<String> {
+        let source_type = SourceType::from_path(path).unwrap();
+        errors(source_text, source_type, true)
+            .into_iter()
+            .map(|error| error.split(':').next().unwrap().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn early_errors() {
+        let cases: &[(&str, &str, &[&str])] = &[
+            // TS1038
+            ("declare namespace N { declare const a: number; }", "test.ts", &["TS1038"]),
+            ("declare namespace N { const a: number; }", "test.ts", &[]),
+            // TS1039
+            ("declare let a = 1;", "test.ts", &["TS1039"]),
+            ("declare class C { a = 1 }", "test.ts", &["TS1039"]),
+            ("declare const a = 1; let b = 1; class C { a = 1 }", "test.ts", &[]),
+            // TS1183
+            ("declare function f() {}", "test.ts", &["TS1183"]),
+            ("declare class C { m() {} }", "test.ts", &["TS1183"]),
+            ("declare function f(): void; declare class C { m(): void }", "test.ts", &[]),
+            // TS2300
+            ("enum E { A, A }", "test.ts", &["TS2300"]),
+            ("enum E { A, B }", "test.ts", &[]),
+            // TS1203
+            ("import a from 'a'; export = a;", "test.ts", &["TS1203"]),
+            ("import a from 'a'; export = a;", "test.d.ts", &[]),
+            ("const a = 1; export = a;", "test.ts", &[]),
+            ("import a = require('a'); export = a;", "test.ts", &[]),
+            // TS2309
+            ("const a = 1; export const b = 1; export = a;", "test.ts", &["TS1203", "TS2309"]),
+            ("const a = 1; export type T = 1; export = a;", "test.ts", &["TS1203"]),
+            // TS1244, TS1253
+            ("class C { abstract m(): void }", "test.ts", &["TS1244"]),
+            ("class C { abstract a: number }", "test.ts", &["TS1253"]),
+            ("abstract class C { abstract m(): void; abstract a: number }", "test.ts", &[]),
+            // TS1245, TS1267
+            ("abstract class C { abstract m() {} }", "test.ts", &["TS1245"]),
+            ("abstract class C { abstract a = 1 }", "test.ts", &["TS1267"]),
+            // TS1243
+            ("abstract class C { static abstract m(): void }", "test.ts", &["TS1243"]),
+            ("abstract class C { private abstract m(): void }", "test.ts", &["TS1243"]),
+            ("abstract class C { protected abstract m(): void }", "test.ts", &[]),
+            // TS18019
+            ("abstract class C { abstract #a: number }", "test.ts", &["TS18019"]),
+            // TS1242
+            ("abstract class C { abstract constructor() }", "test.ts", &["TS1242"]),
+            // TS1024
+            ("class C { readonly m() {} }", "test.ts", &["TS1024"]),
+            ("class C { readonly a = 1 }", "test.ts", &[]),
+            // TS1028
+            ("class C { public private a = 1 }", "test.ts", &["TS1028"]),
+            ("class C { public public m() {} }", "test.ts", &["TS1028"]),
+            // TS1031
+            ("class C { declare m() {} }", "test.ts", &["TS1031"]),
+            ("class C { declare a: number }", "test.ts", &[]),
+            // TS18010
+            ("class C { private #a = 1 }", "test.ts", &["TS18010"]),
+            ("class C { #a = 1; private b = 1 }", "test.ts", &[]),
+            // TS2391
+            ("function f(): void;", "test.ts", &["TS2391"]),
+            ("class C { m(): void; }", "test.ts", &["TS2391"]),
+            ("function f(): void; function f() {} class C { m(): void; m() {} }", "test.ts", &[]),
+            ("function f(): void;", "test.d.ts", &[]),
+            // TS2389
+            ("function f(): void; function g() {}", "test.ts", &["TS2389"]),
+            ("class C { m(): void; n() {} }", "test.ts", &["TS2389"]),
+            // TS2387, TS2388
+            ("class C { static m(): void; m() {} }", "test.ts", &["TS2387"]),
+            ("class C { m(): void; static m() {} }", "test.ts", &["TS2388"]),
+            ("class C { static m(): void; static m() {} }", "test.ts", &[]),
+            // TS2385
+            ("class C { private m(): void; m() {} }", "test.ts", &["TS2385"]),
+            ("class C { private m(): void; private m() {} }", "test.ts", &[]),
+            // TS2383
+            ("export function f(): void; function f() {}", "test.ts", &["TS2383"]),
+            ("export function f(): void; export function f() {}", "test.ts", &[]),
+            // TS1047
+            ("function f(...a?: number[]) {}", "test.ts", &["TS1047"]),
+            // TS1016
+            ("function f(a?: number, b: number) {}", "test.ts", &["TS1016"]),
+            ("function f(a?: number, b = 1, ...c: number[]) {}", "test.ts", &[]),
+            // TS2369
+            ("function f(private a) {}", "test.ts", &["TS2369"]),
+            ("class C { constructor(private a) {} }", "test.ts", &[]),
+            // TS1187, TS1317
+            ("class C { constructor(private { a }) {} }", "test.ts", &["TS1187"]),
+            ("class C { constructor(private ...a) {} }", "test.ts", &["TS1317"]),
+        ];
+        for (source_text, path, expected) in cases {
+            assert_eq!(codes(source_text, path), *expected, "{source_text} in {path}");
+        }
+    }
+}
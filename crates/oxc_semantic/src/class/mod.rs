@@ -0,0 +1,138 @@
+//! Class member tables and private name resolution
+//! See [Private Names](https://tc39.es/ecma262/#sec-private-names)
+
+mod table;
+
+use oxc_ast::{
+    ast::{ClassElement, MethodDefinitionKind, PropertyKey, TSAccessibility},
+    Atom, GetSpan, Span,
+};
+
+pub use self::table::ClassTable;
+use crate::node::AstNodeId;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ClassId(usize);
+
+impl ClassId {
+    #[must_use]
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+/// A member of a class, addressed by its class and its index in [`ClassInfo::members`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ClassMemberId {
+    pub class_id: ClassId,
+    pub index: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClassMemberKind {
+    Constructor,
+    Method,
+    Getter,
+    Setter,
+    Field,
+    /// `accessor a`
+    Accessor,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClassMemberVisibility {
+    Public,
+    /// TypeScript `protected`
+    Protected,
+    /// TypeScript `private`
+    Private,
+    /// `#a`, only accessible inside of the class body
+    PrivateName,
+}
+
+/// A named element of a class body, static blocks, index signatures
+/// and computed keys without a static name are not members.
+#[derive(Debug)]
+pub struct ClassMember {
+    /// The static name of the key, without the `#` of private names
+    pub name: Atom,
+    /// Span of the key
+    pub span: Span,
+    pub kind: ClassMemberKind,
+    pub r#static: bool,
+    pub visibility: ClassMemberVisibility,
+    /// Index of the element in the class body
+    pub element_index: usize,
+    /// Uses of a private name, e.g. `this.#a` and `#a in obj`, in source order
+    pub references: Vec<AstNodeId>,
+}
+
+impl ClassMember {
+    pub(crate) fn new(element_index: usize, element: &ClassElement) -> Option<Self> {
+        let kind_of = |kind: MethodDefinitionKind| match kind {
+            MethodDefinitionKind::Constructor => ClassMemberKind::Constructor,
+            MethodDefinitionKind::Method => ClassMemberKind::Method,
+            MethodDefinitionKind::Get => ClassMemberKind::Getter,
+            MethodDefinitionKind::Set => ClassMemberKind::Setter,
+        };
+        let (kind, accessibility) = match element {
+            ClassElement::MethodDefinition(def) => (kind_of(def.kind), def.accessibility),
+            ClassElement::PropertyDefinition(def) => (ClassMemberKind::Field, def.accessibility),
+            ClassElement::AccessorProperty(_) => (ClassMemberKind::Accessor, None),
+            ClassElement::TSAbstractMethodDefinition(def) => {
+                (kind_of(def.method_definition.kind), def.method_definition.accessibility)
+            }
+            ClassElement::TSAbstractPropertyDefinition(def) => {
+                (ClassMemberKind::Field, def.property_definition.accessibility)
+            }
+            ClassElement::StaticBlock(_) | ClassElement::TSIndexSignature(_) => return None,
+        };
+        let key = element.property_key()?;
+        let (name, visibility) = match key {
+            PropertyKey::PrivateIdentifier(ident) => {
+                (ident.name.clone(), ClassMemberVisibility::PrivateName)
+            }
+            key => {
+                let visibility = match accessibility {
+                    Some(TSAccessibility::Private) => ClassMemberVisibility::Private,
+                    Some(TSAccessibility::Protected) => ClassMemberVisibility::Protected,
+                    _ => ClassMemberVisibility::Public,
+                };
+                (key.static_name()?, visibility)
+            }
+        };
+        Some(Self {
+            name,
+            span: key.span(),
+            kind,
+            r#static: element.r#static(),
+            visibility,
+            element_index,
+            references: vec![],
+        })
+    }
+
+    #[must_use]
+    pub fn is_private_name(&self) -> bool {
+        self.visibility == ClassMemberVisibility::PrivateName
+    }
+}
+
+#[derive(Debug)]
+pub struct ClassInfo {
+    pub id: ClassId,
+    /// The `AstKind::Class` node
+    pub node_id: AstNodeId,
+    /// The class enclosing this class,
+    /// which does not include the class whose heritage this class is in, e.g. `A` in `class A extends class B {} {}`
+    pub parent_id: Option<ClassId>,
+    pub members: Vec<ClassMember>,
+}
+
+impl ClassInfo {
+    /// The private member `#name`, the getter when both a getter and a setter are declared.
+    #[must_use]
+    pub fn get_private_member(&self, name: &Atom) -> Option<usize> {
+        self.members.iter().position(|member| member.is_private_name() && member.name == name)
+    }
+}
@@ -0,0 +1,258 @@
+use std::ops::Index;
+
+use oxc_ast::{AstKind, Atom};
+use rustc_hash::FxHashMap;
+
+use super::{ClassId, ClassInfo, ClassMember, ClassMemberId};
+use crate::node::{AstNode, AstNodeId, AstNodes};
+
+/// The classes of a program, with their members and the resolved uses of private names
+#[derive(Debug, Default)]
+pub struct ClassTable {
+    classes: Vec<ClassInfo>,
+
+    node_classes: FxHashMap<AstNodeId, ClassId>,
+
+    /// Keyed by the `PrivateIdentifier` node of the use
+    private_references: FxHashMap<AstNodeId, ClassMemberId>,
+
+    /// Uses of private names not declared by any enclosing class, which are early errors
+    unresolved_private_references: Vec<AstNodeId>,
+}
+
+impl Index<ClassId> for ClassTable {
+    type Output = ClassInfo;
+
+    fn index(&self, id: ClassId) -> &Self::Output {
+        &self.classes[id.0]
+    }
+}
+
+impl Index<ClassMemberId> for ClassTable {
+    type Output = ClassMember;
+
+    fn index(&self, id: ClassMemberId) -> &Self::Output {
+        &self[id.class_id].members[id.index]
+    }
+}
+
+impl ClassTable {
+    /// All classes in source order
+    #[must_use]
+    pub fn classes(&self) -> &[ClassInfo] {
+        &self.classes
+    }
+
+    /// The class of an `AstKind::Class` node
+    #[must_use]
+    pub fn get_class_id(&self, node_id: AstNodeId) -> Option<ClassId> {
+        self.node_classes.get(&node_id).copied()
+    }
+
+    /// The member a use of a private name refers to, e.g. `#a` in `this.#a`
+    #[must_use]
+    pub fn get_private_reference(&self, node_id: AstNodeId) -> Option<ClassMemberId> {
+        self.private_references.get(&node_id).copied()
+    }
+
+    /// `PrivateIdentifier` nodes which do not refer to a private name of an enclosing class
+    #[must_use]
+    pub fn unresolved_private_references(&self) -> &[AstNodeId] {
+        &self.unresolved_private_references
+    }
+
+    pub(crate) fn build(nodes: &AstNodes) -> Self {
+        let mut table = Self::default();
+        let mut private_uses: Vec<(AstNodeId, &Atom, Option<ClassId>)> = vec![];
+
+        for node in nodes.iter() {
+            let node_id = AstNodeId::new(nodes.get_node_id(node).unwrap());
+            match node.get().kind() {
+                AstKind::Class(class) => {
+                    let id = ClassId(table.classes.len());
+                    let members = class
+                        .body
+                        .body
+                        .iter()
+                        .enumerate()
+                        .filter_map(|(index, element)| ClassMember::new(index, element))
+                        .collect();
+                    let parent_id = table.enclosing_class(nodes, node);
+                    table.classes.push(ClassInfo { id, node_id, parent_id, members });
+                    table.node_classes.insert(node_id, id);
+                }
+                // Declarations are property keys
+                AstKind::PrivateIdentifier(ident)
+                    if !matches!(nodes.parent_kind(node), AstKind::PropertyKey(_)) =>
+                {
+                    private_uses.push((node_id, &ident.name, table.enclosing_class(nodes, node)));
+                }
+                _ => {}
+            }
+        }
+
+        for (node_id, name, class_id) in private_uses {
+            let mut class_id = class_id;
+            let member_id = loop {
+                let Some(id) = class_id else { break None };
+                let class = &table[id];
+                if let Some(index) = class.get_private_member(name) {
+                    break Some(ClassMemberId { class_id: id, index });
+                }
+                class_id = class.parent_id;
+            };
+            match member_id {
+                Some(member_id) => {
+                    table.classes[member_id.class_id.0].members[member_id.index]
+                        .references
+                        .push(node_id);
+                    table.private_references.insert(node_id, member_id);
+                }
+                None => table.unresolved_private_references.push(node_id),
+            }
+        }
+
+        table
+    }
+
+    /// The innermost class whose body contains `node`,
+    /// the heritage of a class is evaluated outside of its body.
+    fn enclosing_class(&self, nodes: &AstNodes, node: &AstNode) -> Option<ClassId> {
+        let mut in_heritage = false;
+        let mut parent_id = node.parent();
+        while let Some(node_id) = parent_id {
+            let parent = &nodes[node_id];
+            match parent.get().kind() {
+                AstKind::ClassHeritage(_) => in_heritage = true,
+                AstKind::Class(_) if in_heritage => in_heritage = false,
+                AstKind::Class(_) => return self.get_class_id(AstNodeId::new(node_id)),
+                _ => {}
+            }
+            parent_id = parent.parent();
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::{AstKind, SourceType};
+
+    use crate::{
+        class::{ClassMemberKind, ClassMemberVisibility},
+        tester,
+    };
+
+    #[test]
+    fn members() {
+        let source_text = "
+            class A {
+                constructor() {}
+                static a = 1;
+                get b() {} set b(v) {}
+                #c() {}
+                accessor d;
+                private e = 1;
+                protected f() {}
+                ['g'] = 1;
+                [h] = 1;
+                static {}
+            }
+        ";
+        let allocator = Allocator::default();
+        let source_type = *SourceType::default().with_typescript(true);
+        let semantic = tester::semantic(&allocator, source_text, source_type, |builder| {
+            builder.with_classes(true)
+        });
+
+        let classes = semantic.classes().unwrap().classes();
+        assert_eq!(classes.len(), 1);
+        let members: Vec<_> = classes[0]
+            .members
+            .iter()
+            .map(|member| (member.name.as_str(), member.kind, member.r#static, member.visibility))
+            .collect();
+        assert_eq!(
+            members,
+            vec![
+                ("constructor", ClassMemberKind::Constructor, false, ClassMemberVisibility::Public),
+                ("a", ClassMemberKind::Field, true, ClassMemberVisibility::Public),
+                ("b", ClassMemberKind::Getter, false, ClassMemberVisibility::Public),
+                ("b", ClassMemberKind::Setter, false, ClassMemberVisibility::Public),
+                ("c", ClassMemberKind::Method, false, ClassMemberVisibility::PrivateName),
+                ("d", ClassMemberKind::Accessor, false, ClassMemberVisibility::Public),
+                ("e", ClassMemberKind::Field, false, ClassMemberVisibility::Private),
+                ("f", ClassMemberKind::Method, false, ClassMemberVisibility::Protected),
+                ("g", ClassMemberKind::Field, false, ClassMemberVisibility::Public),
+            ]
+        );
+        assert_eq!(classes[0].members[8].element_index, 8);
+    }
+
+    #[test]
+    fn private_references() {
+        let source_text = "
+            class A {
+                #a = 1; #b; #unused;
+                m(o) {
+                    class B extends (class { x = this.#a; }) {
+                        #b;
+                        n() { return [this.#a, this.#b, #b in o, this.#c]; }
+                    }
+                    return this.#b;
+                }
+            }
+            this.#d;
+        ";
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let semantic = tester::semantic(&allocator, source_text, source_type, |builder| {
+            builder.with_classes(true)
+        });
+        let classes = semantic.classes().unwrap();
+
+        let names = |node_ids: &[_]| -> Vec<String> {
+            node_ids
+                .iter()
+                .map(|id| {
+                    let AstKind::PrivateIdentifier(ident) = semantic.nodes().kind(*id) else {
+                        unreachable!()
+                    };
+                    format!("{}@{}", ident.name, ident.span.start)
+                })
+                .collect()
+        };
+        let a = &classes.classes()[0];
+        let b = &classes.classes()[1];
+        let heritage = &classes.classes()[2];
+        assert_eq!(b.parent_id, Some(a.id));
+        // The class in the heritage of `B` is not inside of the body of `B`
+        assert_eq!(heritage.parent_id, Some(a.id));
+
+        let references = |class: &crate::class::ClassInfo, name: &str| {
+            names(&class.members[class.get_private_member(&name.into()).unwrap()].references)
+        };
+        let offset =
+            |pattern: &str| source_text.find(pattern).unwrap() + pattern.find('#').unwrap();
+        assert_eq!(
+            references(a, "a"),
+            vec![format!("a@{}", offset("this.#a;")), format!("a@{}", offset("[this.#a")),]
+        );
+        assert_eq!(references(a, "b"), vec![format!("b@{}", offset("return this.#b"))]);
+        assert_eq!(references(a, "unused"), Vec::<String>::new());
+        assert_eq!(
+            references(b, "b"),
+            vec![format!("b@{}", offset(", this.#b")), format!("b@{}", offset(", #b"))]
+        );
+        assert_eq!(
+            names(classes.unresolved_private_references()),
+            vec![format!("c@{}", offset("this.#c")), format!("d@{}", offset("this.#d"))]
+        );
+
+        let node_id = a.members[0].references[0];
+        let member_id = classes.get_private_reference(node_id).unwrap();
+        assert_eq!(member_id.class_id, a.id);
+        assert_eq!(classes[member_id].name, "a");
+    }
+}
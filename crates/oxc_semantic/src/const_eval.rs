@@ -0,0 +1,709 @@
+//! Static evaluation of constant expressions, e.g. `1 + 2`, `` `a${1}` `` and `typeof 0`,
+//! following the semantics of the operators on primitive values.
+//! See [ECMAScript Language: Expressions](https://tc39.es/ecma262/#sec-ecmascript-language-expressions)
+
+use std::{cmp::Ordering, str::FromStr};
+
+use num_bigint::BigInt;
+use num_traits::{FromPrimitive, Signed, ToPrimitive, Zero};
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::ast::*;
+use oxc_ast::AstKind;
+
+use crate::Semantic;
+
+/// How many `const` bindings are followed, guards against cycles such as `const a = b, b = a;`
+const MAX_BINDING_DEPTH: usize = 16;
+
+/// `BigInt`s with more bits are not computed, e.g. `2n ** 100000000n`
+const MAX_BIGINT_BITS: u64 = 1 << 16;
+
+/// A JavaScript primitive value
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConstantValue {
+    Number(f64),
+    String(String),
+    Boolean(bool),
+    Null,
+    Undefined,
+    BigInt(BigInt),
+}
+
+impl ConstantValue {
+    /// [ToBoolean](https://tc39.es/ecma262/#sec-toboolean)
+    #[must_use]
+    pub fn to_boolean(&self) -> bool {
+        match self {
+            Self::Number(n) => !(n.is_nan() || *n == 0.0),
+            Self::String(s) => !s.is_empty(),
+            Self::Boolean(b) => *b,
+            Self::Null | Self::Undefined => false,
+            Self::BigInt(n) => !n.is_zero(),
+        }
+    }
+
+    /// [ToNumber](https://tc39.es/ecma262/#sec-tonumber), `None` for `BigInt`s which throw a `TypeError`
+    #[must_use]
+    pub fn to_number(&self) -> Option<f64> {
+        match self {
+            Self::Number(n) => Some(*n),
+            Self::String(s) => Some(string_to_number(s)),
+            Self::Boolean(b) => Some(if *b { 1.0 } else { 0.0 }),
+            Self::Null => Some(0.0),
+            Self::Undefined => Some(f64::NAN),
+            Self::BigInt(_) => None,
+        }
+    }
+
+    /// [ToString](https://tc39.es/ecma262/#sec-tostring)
+    #[must_use]
+    pub fn to_js_string(&self) -> String {
+        match self {
+            Self::Number(n) => ryu_js::Buffer::new().format(*n).to_string(),
+            Self::String(s) => s.clone(),
+            Self::Boolean(b) => b.to_string(),
+            Self::Null => "null".to_string(),
+            Self::Undefined => "undefined".to_string(),
+            Self::BigInt(n) => n.to_string(),
+        }
+    }
+
+    /// The result of `typeof`
+    #[must_use]
+    pub fn type_of(&self) -> &'static str {
+        match self {
+            Self::Number(_) => "number",
+            Self::String(_) => "string",
+            Self::Boolean(_) => "boolean",
+            Self::Null => "object",
+            Self::Undefined => "undefined",
+            Self::BigInt(_) => "bigint",
+        }
+    }
+
+    /// [IsStrictlyEqual](https://tc39.es/ecma262/#sec-isstrictlyequal), `===`
+    #[must_use]
+    #[allow(clippy::float_cmp)]
+    pub fn strict_equals(&self, other: &Self) -> bool {
+        match (self, other) {
+            // `NaN !== NaN` and `0 === -0`
+            (Self::Number(a), Self::Number(b)) => a == b,
+            _ => self == other,
+        }
+    }
+
+    /// [IsLooselyEqual](https://tc39.es/ecma262/#sec-islooselyequal), `==`
+    #[must_use]
+    pub fn loose_equals(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Null | Self::Undefined, Self::Null | Self::Undefined) => true,
+            (Self::Null | Self::Undefined, _) | (_, Self::Null | Self::Undefined) => false,
+            (Self::Number(_), Self::String(s)) => {
+                self.loose_equals(&Self::Number(string_to_number(s)))
+            }
+            (Self::String(s), Self::Number(_)) => {
+                Self::Number(string_to_number(s)).loose_equals(other)
+            }
+            (Self::BigInt(n), Self::String(s)) | (Self::String(s), Self::BigInt(n)) => {
+                string_to_bigint(s).is_some_and(|m| m == *n)
+            }
+            (Self::Boolean(_), _) if !matches!(other, Self::Boolean(_)) => {
+                Self::Number(self.to_number().unwrap_or(f64::NAN)).loose_equals(other)
+            }
+            (_, Self::Boolean(_)) if !matches!(self, Self::Boolean(_)) => {
+                self.loose_equals(&Self::Number(other.to_number().unwrap_or(f64::NAN)))
+            }
+            (Self::BigInt(n), Self::Number(m)) | (Self::Number(m), Self::BigInt(n)) => {
+                m.fract() == 0.0 && BigInt::from_f64(*m).is_some_and(|m| m == *n)
+            }
+            _ => self.strict_equals(other),
+        }
+    }
+}
+
+/// Evaluate expressions to their [`ConstantValue`].
+///
+/// Evaluation gives up with `None` when a value is not known statically,
+/// or when evaluating the expression would throw, e.g. `1n + 1`.
+/// Expressions with side effects are never constant, e.g. `void f()` and `typeof { a: f() }`.
+#[derive(Default)]
+pub struct ConstantEvaluator<'a, 'b> {
+    semantic: Option<&'b Semantic<'a>>,
+}
+
+impl<'a, 'b> ConstantEvaluator<'a, 'b> {
+    /// An evaluator for literals and operators only, identifiers are never evaluated
+    /// because they may be shadowed.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// An evaluator which also follows references to the globals `undefined`, `NaN` and `Infinity`,
+    /// and to `const` bindings with constant initializers, e.g. `a` in `const a = 1; a + 1`.
+    #[must_use]
+    pub fn with_semantic(semantic: &'b Semantic<'a>) -> Self {
+        Self { semantic: Some(semantic) }
+    }
+
+    #[must_use]
+    pub fn eval(&self, expr: &Expression<'a>) -> Option<ConstantValue> {
+        self.eval_expression(expr, 0)
+    }
+
+    fn eval_expression(&self, expr: &Expression<'a>, depth: usize) -> Option<ConstantValue> {
+        match expr.get_inner_expression() {
+            Expression::BooleanLiteral(lit) => Some(ConstantValue::Boolean(lit.value)),
+            Expression::NullLiteral(_) => Some(ConstantValue::Null),
+            Expression::NumberLiteral(lit) => Some(ConstantValue::Number(*lit.value)),
+            Expression::BigintLiteral(lit) => {
+                Some(ConstantValue::BigInt(BigInt::from(lit.value.clone())))
+            }
+            Expression::StringLiteral(lit) => Some(ConstantValue::String(lit.value.to_string())),
+            Expression::TemplateLiteral(template) => {
+                let mut value = String::new();
+                for (i, quasi) in template.quasis.iter().enumerate() {
+                    value.push_str(quasi.value.cooked.as_ref()?);
+                    if let Some(expr) = template.expressions.get(i) {
+                        value.push_str(&self.eval_expression(expr, depth)?.to_js_string());
+                    }
+                }
+                Some(ConstantValue::String(value))
+            }
+            Expression::Identifier(ident) => self.eval_identifier(ident, depth),
+            Expression::UnaryExpression(expr) => self.eval_unary_expression(expr, depth),
+            Expression::BinaryExpression(expr) => {
+                let left = self.eval_expression(&expr.left, depth)?;
+                let right = self.eval_expression(&expr.right, depth)?;
+                binary_operation(expr.operator, &left, &right)
+            }
+            Expression::LogicalExpression(expr) => {
+                let left = self.eval_expression(&expr.left, depth)?;
+                let short_circuit = match expr.operator {
+                    LogicalOperator::And => !left.to_boolean(),
+                    LogicalOperator::Or => left.to_boolean(),
+                    LogicalOperator::Coalesce => {
+                        !matches!(left, ConstantValue::Null | ConstantValue::Undefined)
+                    }
+                };
+                if short_circuit { Some(left) } else { self.eval_expression(&expr.right, depth) }
+            }
+            Expression::ConditionalExpression(expr) => {
+                if self.eval_expression(&expr.test, depth)?.to_boolean() {
+                    self.eval_expression(&expr.consequent, depth)
+                } else {
+                    self.eval_expression(&expr.alternate, depth)
+                }
+            }
+            Expression::SequenceExpression(expr) => expr
+                .expressions
+                .iter()
+                .map(|expr| self.eval_expression(expr, depth))
+                .collect::<Option<Vec<_>>>()?
+                .pop(),
+            _ => None,
+        }
+    }
+
+    fn eval_identifier(&self, ident: &IdentifierReference, depth: usize) -> Option<ConstantValue> {
+        let semantic = self.semantic?;
+        let reference = semantic
+            .nodes()
+            .ast_node_id(AstKind::IdentifierReference(ident))
+            .and_then(|id| semantic.symbols().get_resolved_reference(id));
+        if let Some(reference) = reference {
+            let symbol = &semantic.symbols()[reference.resolved_symbol_id];
+            if !symbol.is_const() || depth >= MAX_BINDING_DEPTH {
+                return None;
+            }
+            let AstKind::VariableDeclarator(declarator) = semantic.nodes().kind(symbol.declaration()) else {
+                return None;
+            };
+            // References before the declaration are in the temporal dead zone
+            if !matches!(declarator.id.kind, BindingPatternKind::BindingIdentifier(_))
+                || reference.span().start < declarator.span.end
+            {
+                return None;
+            }
+            return self.eval_expression(declarator.init.as_ref()?, depth + 1);
+        }
+        if !semantic.is_reference_to_global_variable(ident) {
+            return None;
+        }
+        match ident.name.as_str() {
+            "undefined" => Some(ConstantValue::Undefined),
+            "NaN" => Some(ConstantValue::Number(f64::NAN)),
+            "Infinity" => Some(ConstantValue::Number(f64::INFINITY)),
+            _ => None,
+        }
+    }
+
+    /// Whether evaluating `expr` has no side effects and does not throw:
+    /// constants, functions, regular expressions, and objects and arrays of these.
+    /// Class expressions are not included, their computed keys and static members are evaluated.
+    fn is_side_effect_free(&self, expr: &Expression<'a>, depth: usize) -> bool {
+        match expr.get_inner_expression() {
+            Expression::FunctionExpression(_)
+            | Expression::ArrowFunctionExpression(_)
+            | Expression::RegExpLiteral(_) => true,
+            Expression::ArrayExpression(array) => {
+                array.elements.iter().all(|element| match element {
+                    Some(Argument::Expression(expr)) => self.is_side_effect_free(expr, depth),
+                    Some(Argument::SpreadElement(_)) => false,
+                    None => true,
+                })
+            }
+            Expression::ObjectExpression(object) => {
+                object.properties.iter().all(|property| match property {
+                    ObjectProperty::Property(property) => {
+                        let key_is_free = match &property.key {
+                            PropertyKey::Expression(key) => {
+                                self.eval_expression(key, depth).is_some()
+                            }
+                            _ => true,
+                        };
+                        key_is_free
+                            && match &property.value {
+                                PropertyValue::Expression(value) => {
+                                    self.is_side_effect_free(value, depth)
+                                }
+                                PropertyValue::Pattern(_) => false,
+                            }
+                    }
+                    ObjectProperty::SpreadProperty(_) => false,
+                })
+            }
+            expr => self.eval_expression(expr, depth).is_some(),
+        }
+    }
+
+    fn eval_unary_expression(
+        &self,
+        expr: &UnaryExpression<'a>,
+        depth: usize,
+    ) -> Option<ConstantValue> {
+        match expr.operator {
+            UnaryOperator::Typeof => {
+                let type_of = match expr.argument.get_inner_expression() {
+                    Expression::FunctionExpression(_) | Expression::ArrowFunctionExpression(_) => {
+                        "function"
+                    }
+                    argument @ (Expression::ObjectExpression(_)
+                    | Expression::ArrayExpression(_)
+                    | Expression::RegExpLiteral(_)) => {
+                        if !self.is_side_effect_free(argument, depth) {
+                            return None;
+                        }
+                        "object"
+                    }
+                    argument => self.eval_expression(argument, depth)?.type_of(),
+                };
+                return Some(ConstantValue::String(type_of.to_string()));
+            }
+            UnaryOperator::Void => {
+                return self
+                    .is_side_effect_free(&expr.argument, depth)
+                    .then_some(ConstantValue::Undefined);
+            }
+            UnaryOperator::Delete => return None,
+            _ => {}
+        }
+        let value = self.eval_expression(&expr.argument, depth)?;
+        match expr.operator {
+            UnaryOperator::LogicalNot => Some(ConstantValue::Boolean(!value.to_boolean())),
+            UnaryOperator::UnaryPlus => value.to_number().map(ConstantValue::Number),
+            UnaryOperator::UnaryNegation => match value {
+                ConstantValue::BigInt(n) => Some(ConstantValue::BigInt(-n)),
+                value => value.to_number().map(|n| ConstantValue::Number(-n)),
+            },
+            UnaryOperator::BitwiseNot => match value {
+                ConstantValue::BigInt(n) => Some(ConstantValue::BigInt(-n - 1)),
+                value => value.to_number().map(|n| ConstantValue::Number(f64::from(!to_int32(n)))),
+            },
+            _ => None,
+        }
+    }
+}
+
+/// Apply a binary operator to two primitive values, `None` when the operation throws.
+fn binary_operation(
+    operator: BinaryOperator,
+    left: &ConstantValue,
+    right: &ConstantValue,
+) -> Option<ConstantValue> {
+    let boolean = |b| Some(ConstantValue::Boolean(b));
+    match operator {
+        BinaryOperator::Addition
+            if matches!(left, ConstantValue::String(_))
+                || matches!(right, ConstantValue::String(_)) =>
+        {
+            Some(ConstantValue::String(left.to_js_string() + &right.to_js_string()))
+        }
+        BinaryOperator::Equality => boolean(left.loose_equals(right)),
+        BinaryOperator::Inequality => boolean(!left.loose_equals(right)),
+        BinaryOperator::StrictEquality => boolean(left.strict_equals(right)),
+        BinaryOperator::StrictInequality => boolean(!left.strict_equals(right)),
+        BinaryOperator::LessThan => boolean(compare(left, right)? == Some(Ordering::Less)),
+        BinaryOperator::GreaterThan => boolean(compare(left, right)? == Some(Ordering::Greater)),
+        BinaryOperator::LessEqualThan => {
+            boolean(matches!(compare(left, right)?, Some(Ordering::Less | Ordering::Equal)))
+        }
+        BinaryOperator::GreaterEqualThan => {
+            boolean(matches!(compare(left, right)?, Some(Ordering::Greater | Ordering::Equal)))
+        }
+        BinaryOperator::In | BinaryOperator::Instanceof => None,
+        _ => match (left, right) {
+            (ConstantValue::BigInt(a), ConstantValue::BigInt(b)) => {
+                bigint_operation(operator, a, b).map(ConstantValue::BigInt)
+            }
+            // Mixing BigInts and other types throws a `TypeError`
+            (ConstantValue::BigInt(_), _) | (_, ConstantValue::BigInt(_)) => None,
+            _ => number_operation(operator, left.to_number()?, right.to_number()?)
+                .map(ConstantValue::Number),
+        },
+    }
+}
+
+/// [IsLessThan](https://tc39.es/ecma262/#sec-islessthan),
+/// `Some(None)` when the values are not comparable, i.e. `undefined` in the spec.
+/// Comparisons between `BigInt`s and other types are not evaluated.
+#[allow(clippy::option_option)]
+fn compare(left: &ConstantValue, right: &ConstantValue) -> Option<Option<Ordering>> {
+    match (left, right) {
+        // Strings are compared by UTF-16 code units
+        (ConstantValue::String(a), ConstantValue::String(b)) => {
+            Some(Some(a.encode_utf16().cmp(b.encode_utf16())))
+        }
+        (ConstantValue::BigInt(a), ConstantValue::BigInt(b)) => Some(Some(a.cmp(b))),
+        (ConstantValue::BigInt(_), _) | (_, ConstantValue::BigInt(_)) => None,
+        _ => Some(left.to_number()?.partial_cmp(&right.to_number()?)),
+    }
+}
+
+#[allow(clippy::float_cmp)]
+fn number_operation(operator: BinaryOperator, a: f64, b: f64) -> Option<f64> {
+    let value = match operator {
+        BinaryOperator::Addition => a + b,
+        BinaryOperator::Subtraction => a - b,
+        BinaryOperator::Multiplication => a * b,
+        BinaryOperator::Division => a / b,
+        BinaryOperator::Remainder => a % b,
+        BinaryOperator::Exponential => {
+            // `1 ** NaN` and `1 ** Infinity` are `NaN` unlike `powf`
+            if b.is_nan() || (a.abs() == 1.0 && b.is_infinite()) { f64::NAN } else { a.powf(b) }
+        }
+        BinaryOperator::ShiftLeft => f64::from(to_int32(a).wrapping_shl(to_uint32(b))),
+        BinaryOperator::ShiftRight => f64::from(to_int32(a).wrapping_shr(to_uint32(b))),
+        BinaryOperator::ShiftRightZeroFill => f64::from(to_uint32(a).wrapping_shr(to_uint32(b))),
+        BinaryOperator::BitwiseAnd => f64::from(to_int32(a) & to_int32(b)),
+        BinaryOperator::BitwiseOR => f64::from(to_int32(a) | to_int32(b)),
+        BinaryOperator::BitwiseXOR => f64::from(to_int32(a) ^ to_int32(b)),
+        _ => return None,
+    };
+    Some(value)
+}
+
+/// `None` for the operations which throw a `RangeError` or `TypeError`,
+/// and for results too large to compute.
+fn bigint_operation(operator: BinaryOperator, a: &BigInt, b: &BigInt) -> Option<BigInt> {
+    let shift = |a: &BigInt, b: &BigInt, left: bool| {
+        let bits = b.to_i64().filter(|bits| bits.unsigned_abs() <= MAX_BIGINT_BITS)?;
+        let bits = usize::try_from(bits.unsigned_abs()).ok()?;
+        Some(if left == b.is_positive() || b.is_zero() { a << bits } else { a >> bits })
+    };
+    match operator {
+        BinaryOperator::Addition => Some(a + b),
+        BinaryOperator::Subtraction => Some(a - b),
+        BinaryOperator::Multiplication => Some(a * b),
+        BinaryOperator::Division if !b.is_zero() => Some(a / b),
+        BinaryOperator::Remainder if !b.is_zero() => Some(a % b),
+        BinaryOperator::Exponential if !b.is_negative() => {
+            let exponent = b.to_u32()?;
+            (a.bits() * u64::from(exponent) <= MAX_BIGINT_BITS).then(|| a.pow(exponent))
+        }
+        BinaryOperator::ShiftLeft => shift(a, b, true),
+        BinaryOperator::ShiftRight => shift(a, b, false),
+        BinaryOperator::BitwiseAnd => Some(a & b),
+        BinaryOperator::BitwiseOR => Some(a | b),
+        BinaryOperator::BitwiseXOR => Some(a ^ b),
+        _ => None,
+    }
+}
+
+/// [ToInt32](https://tc39.es/ecma262/#sec-toint32)
+#[allow(clippy::cast_possible_wrap)]
+fn to_int32(n: f64) -> i32 {
+    to_uint32(n) as i32
+}
+
+/// [ToUint32](https://tc39.es/ecma262/#sec-touint32)
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn to_uint32(n: f64) -> u32 {
+    if n.is_finite() { n.trunc().rem_euclid(4_294_967_296.0) as u32 } else { 0 }
+}
+
+/// [WhiteSpace](https://tc39.es/ecma262/#sec-white-space) and
+/// [LineTerminator](https://tc39.es/ecma262/#sec-line-terminators)
+fn is_js_whitespace(c: char) -> bool {
+    c == '\u{feff}' || (c.is_whitespace() && c != '\u{85}')
+}
+
+/// Split the `0x`, `0o` and `0b` prefixes off a string
+fn strip_radix_prefix(s: &str) -> Option<(&str, u32)> {
+    let radix = match s.get(..2)? {
+        "0x" | "0X" => 16,
+        "0o" | "0O" => 8,
+        "0b" | "0B" => 2,
+        _ => return None,
+    };
+    Some((&s[2..], radix))
+}
+
+/// [StringToNumber](https://tc39.es/ecma262/#sec-stringtonumber)
+fn string_to_number(s: &str) -> f64 {
+    let s = s.trim_matches(is_js_whitespace);
+    if s.is_empty() {
+        return 0.0;
+    }
+    if let Some((digits, radix)) = strip_radix_prefix(s) {
+        if digits.is_empty() {
+            return f64::NAN;
+        }
+        return digits
+            .chars()
+            .try_fold(0.0, |value: f64, c| {
+                c.to_digit(radix).map(|digit| value.mul_add(f64::from(radix), f64::from(digit)))
+            })
+            .unwrap_or(f64::NAN);
+    }
+    match s {
+        "Infinity" | "+Infinity" => f64::INFINITY,
+        "-Infinity" => f64::NEG_INFINITY,
+        // `f64::from_str` also accepts `inf` and `NaN`
+        _ if s.chars().all(|c| c.is_ascii_digit() || matches!(c, '.' | 'e' | 'E' | '+' | '-')) => {
+            s.parse().unwrap_or(f64::NAN)
+        }
+        _ => f64::NAN,
+    }
+}
+
+/// [StringToBigInt](https://tc39.es/ecma262/#sec-stringtobigint)
+fn string_to_bigint(s: &str) -> Option<BigInt> {
+    let s = s.trim_matches(is_js_whitespace);
+    if s.is_empty() {
+        return Some(BigInt::zero());
+    }
+    if let Some((digits, radix)) = strip_radix_prefix(s) {
+        return digits
+            .chars()
+            .all(|c| c.is_digit(radix))
+            .then(|| BigInt::parse_bytes(digits.as_bytes(), radix))
+            .flatten();
+    }
+    let digits = s.strip_prefix(['+', '-']).unwrap_or(s);
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    BigInt::from_str(s).ok()
+}
+
+#[cfg(test)]
+#[allow(clippy::unnecessary_wraps)]
+mod test {
+    use num_bigint::BigInt;
+    use oxc_allocator::{Allocator, Box};
+    use oxc_ast::{
+        ast::{Expression, IdentifierReference, Statement},
+        NodeId, SourceType,
+    };
+
+    use super::{ConstantEvaluator, ConstantValue};
+    use crate::tester;
+
+    /// Evaluate the last expression statement of `source_text`
+    fn eval(source_text: &str) -> Option<ConstantValue> {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let semantic = tester::semantic(&allocator, source_text, source_type, |builder| builder);
+        let program = tester::program(&semantic);
+        let Some(Statement::ExpressionStatement(stmt)) = program.body.last() else {
+            unreachable!()
+        };
+        ConstantEvaluator::with_semantic(&semantic).eval(&stmt.expression)
+    }
+
+    fn number(n: f64) -> Option<ConstantValue> {
+        Some(ConstantValue::Number(n))
+    }
+
+    fn string(s: &str) -> Option<ConstantValue> {
+        Some(ConstantValue::String(s.to_string()))
+    }
+
+    fn boolean(b: bool) -> Option<ConstantValue> {
+        Some(ConstantValue::Boolean(b))
+    }
+
+    fn bigint(n: i64) -> Option<ConstantValue> {
+        Some(ConstantValue::BigInt(BigInt::from(n)))
+    }
+
+    #[test]
+    fn literals() {
+        assert_eq!(eval("1.5"), number(1.5));
+        assert_eq!(eval("('a')"), string("a"));
+        assert_eq!(eval("true"), boolean(true));
+        assert_eq!(eval("null"), Some(ConstantValue::Null));
+        assert_eq!(eval("10n"), bigint(10));
+        assert_eq!(eval("`a${1 + 1}b${null}${1n}`"), string("a2bnull1"));
+        assert_eq!(eval("((1))"), number(1.0));
+        assert_eq!(eval("/a/"), None);
+    }
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(eval("1 + 2 * 3 - 4 / 2"), number(5.0));
+        assert_eq!(eval("-5 % 3"), number(-2.0));
+        assert_eq!(eval("2 ** 10"), number(1024.0));
+        assert!(matches!(eval("1 ** Infinity"), Some(ConstantValue::Number(n)) if n.is_nan()));
+        assert_eq!(eval("1 / 0"), number(f64::INFINITY));
+        assert_eq!(eval("'3' * '4'"), number(12.0));
+        assert_eq!(eval("' 0x10 ' - 0"), number(16.0));
+        assert!(matches!(eval("'inf' - 0"), Some(ConstantValue::Number(n)) if n.is_nan()));
+        assert_eq!(eval("true + null"), number(1.0));
+        assert_eq!(eval("+''"), number(0.0));
+        assert_eq!(eval("+'-Infinity'"), number(f64::NEG_INFINITY));
+    }
+
+    #[test]
+    fn bitwise() {
+        assert_eq!(eval("~5"), number(-6.0));
+        assert_eq!(eval("1 << 31"), number(-2_147_483_648.0));
+        assert_eq!(eval("-1 >>> 0"), number(4_294_967_295.0));
+        assert_eq!(eval("-16 >> 2"), number(-4.0));
+        assert_eq!(eval("2 ** 32 + 5 | 0"), number(5.0));
+        assert_eq!(eval("6 & 3 ^ 1"), number(3.0));
+    }
+
+    #[test]
+    fn bigints() {
+        assert_eq!(eval("2n ** 64n - 1n"), Some(ConstantValue::BigInt(BigInt::from(u64::MAX))));
+        assert_eq!(eval("-7n / 2n"), bigint(-3));
+        assert_eq!(eval("-7n % 2n"), bigint(-1));
+        assert_eq!(eval("~5n"), bigint(-6));
+        assert_eq!(eval("-5n >> 1n"), bigint(-3));
+        assert_eq!(eval("1n << -1n"), bigint(0));
+        assert_eq!(eval("1n / 0n"), None);
+        assert_eq!(eval("2n ** -1n"), None);
+        assert_eq!(eval("1n >>> 0n"), None);
+        assert_eq!(eval("1n + 1"), None);
+        assert_eq!(eval("+1n"), None);
+        assert_eq!(eval("2n ** 100000000n"), None);
+        assert_eq!(eval("1n + ''"), string("1"));
+    }
+
+    #[test]
+    fn strings() {
+        assert_eq!(eval("'a' + 1"), string("a1"));
+        assert_eq!(eval("1 + 2 + 'a'"), string("3a"));
+        assert_eq!(eval("'' + 1e21"), string("1e+21"));
+        assert_eq!(eval("'' + 0.1"), string("0.1"));
+        assert_eq!(eval("'' + -0"), string("0"));
+        assert_eq!(eval("'' + 0 / 0"), string("NaN"));
+        assert_eq!(eval("'' + -1 / 0"), string("-Infinity"));
+        assert_eq!(eval("'' + undefined"), string("undefined"));
+    }
+
+    #[test]
+    fn comparisons() {
+        assert_eq!(eval("1 == '1'"), boolean(true));
+        assert_eq!(eval("1 === '1'"), boolean(false));
+        assert_eq!(eval("null == undefined"), boolean(true));
+        assert_eq!(eval("null == 0"), boolean(false));
+        assert_eq!(eval("true == '1'"), boolean(true));
+        assert_eq!(eval("NaN != NaN"), boolean(true));
+        assert_eq!(eval("0 === -0"), boolean(true));
+        assert_eq!(eval("1n == 1"), boolean(true));
+        assert_eq!(eval("1n == '1'"), boolean(true));
+        assert_eq!(eval("1n === 1"), boolean(false));
+        assert_eq!(eval("'b' > 'a'"), boolean(true));
+        assert_eq!(eval("'10' < '9'"), boolean(true));
+        assert_eq!(eval("'10' < 9"), boolean(false));
+        assert_eq!(eval("'\\uFF61' < '\\u{1F600}'"), boolean(false));
+        assert_eq!(eval("1 <= NaN"), boolean(false));
+        assert_eq!(eval("null >= 0"), boolean(true));
+        assert_eq!(eval("2n > 1n"), boolean(true));
+        assert_eq!(eval("2n > 1"), None);
+        assert_eq!(eval("'a' in b"), None);
+    }
+
+    #[test]
+    fn operators() {
+        assert_eq!(eval("typeof 1"), string("number"));
+        assert_eq!(eval("typeof null"), string("object"));
+        assert_eq!(eval("typeof typeof 1"), string("string"));
+        assert_eq!(eval("typeof function () {}"), string("function"));
+        assert_eq!(eval("typeof []"), string("object"));
+        assert_eq!(eval("typeof x"), None);
+        assert_eq!(eval("typeof { a: [1], b() {} }"), string("object"));
+        assert_eq!(eval("typeof { a: f() }"), None);
+        assert_eq!(eval("typeof { [f()]: 1 }"), None);
+        assert_eq!(eval("typeof [...a]"), None);
+        assert_eq!(eval("typeof class {}"), None);
+        assert_eq!(eval("void 0"), Some(ConstantValue::Undefined));
+        assert_eq!(eval("void function () {}"), Some(ConstantValue::Undefined));
+        assert_eq!(eval("void f()"), None);
+        assert_eq!(eval("void x"), None);
+        assert_eq!(eval("!''"), boolean(true));
+        assert_eq!(eval("0 || 'a'"), string("a"));
+        assert_eq!(eval("0 && x"), number(0.0));
+        assert_eq!(eval("null ?? 1"), number(1.0));
+        assert_eq!(eval("0 ?? x"), number(0.0));
+        assert_eq!(eval("1 ? 'a' : x"), string("a"));
+        assert_eq!(eval("(1, 2)"), number(2.0));
+        assert_eq!(eval("(x, 2)"), None);
+    }
+
+    #[test]
+    fn identifiers() {
+        assert_eq!(eval("undefined"), Some(ConstantValue::Undefined));
+        assert!(matches!(eval("NaN"), Some(ConstantValue::Number(n)) if n.is_nan()));
+        assert_eq!(eval("let undefined = 1; undefined"), None);
+        assert_eq!(eval("const a = 1, b = `${a}b`; b + a"), string("1b1"));
+        assert_eq!(eval("let a = 1; a"), None);
+        assert_eq!(eval("const { a } = { a: 1 }; a"), None);
+        assert_eq!(eval("const a = f(); a"), None);
+        assert_eq!(eval("const a = b, b = 1; a"), None);
+        // Without semantic information identifiers may be shadowed
+        let allocator = Allocator::default();
+        let source_text = "undefined";
+        let program = tester::parse(&allocator, source_text, SourceType::default());
+        let Some(Statement::ExpressionStatement(stmt)) = program.body.last() else {
+            unreachable!()
+        };
+        assert_eq!(ConstantEvaluator::new().eval(&stmt.expression), None);
+    }
+
+    #[test]
+    fn identifiers_outside_of_semantic() {
+        let allocator = Allocator::default();
+        let source_text = "const a = 1; a";
+        let source_type = SourceType::default();
+        let semantic = tester::semantic(&allocator, source_text, source_type, |builder| builder);
+        let program = tester::program(&semantic);
+        let Some(Statement::ExpressionStatement(stmt)) = program.body.last() else {
+            unreachable!()
+        };
+        let evaluator = ConstantEvaluator::with_semantic(&semantic);
+        assert_eq!(evaluator.eval(&stmt.expression), number(1.0));
+        // A new node with the same span is not the reference to `a`
+        let Expression::Identifier(ident) = &stmt.expression else { unreachable!() };
+        let ident = IdentifierReference {
+            span: ident.span,
+            node_id: NodeId::new(&allocator),
+            name: ident.name.clone(),
+        };
+        let expr = Expression::Identifier(Box(allocator.alloc(ident)));
+        assert_eq!(evaluator.eval(&expr), None);
+    }
+}
@@ -0,0 +1,810 @@
+//! Control Flow Graph Builder
+//!
+//! A second AST pass after `SemanticBuilder`, the visited nodes are found in the semantic tree
+//! by their [`NodeId`](oxc_ast::NodeId), see [`AstNodes::ast_node_id`].
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, visit::Visit, AstKind, Atom};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use super::{BasicBlock, BasicBlockId, ControlFlowGraph, EdgeKind, FunctionFlow};
+use crate::node::{AstNodeId, AstNodes};
+
+pub struct ControlFlowGraphBuilder<'s, 'a> {
+    nodes: &'s AstNodes<'a>,
+
+    root: AstNodeId,
+
+    /// Ids of the nodes being visited, `None` for a node missing from the semantic tree
+    node_ids: Vec<Option<AstNodeId>>,
+
+    cfg: ControlFlowGraph,
+
+    current: BasicBlockId,
+
+    /// Stack of the functions being built
+    functions: Vec<FunctionContext>,
+
+    /// Ends of the enclosing `?.` chains
+    chain_ends: Vec<BasicBlockId>,
+
+    /// Edges which are only taken when one of the blocks is reachable,
+    /// e.g. the end of a `finally` block continues to where the `try` block was left.
+    conditions: FxHashMap<(BasicBlockId, BasicBlockId), Vec<BasicBlockId>>,
+}
+
+struct FunctionContext {
+    owner: AstNodeId,
+    exit: BasicBlockId,
+    /// The block to continue with after the function
+    resume: BasicBlockId,
+    jump_targets: Vec<JumpTarget>,
+    handlers: Vec<Handler>,
+    /// Labels of the next statement
+    labels: Vec<Atom>,
+}
+
+struct JumpTarget {
+    labels: Vec<Atom>,
+    break_to: BasicBlockId,
+    continue_to: Option<BasicBlockId>,
+    /// Loops and switch statements are targets of unlabeled `break`s
+    unlabeled: bool,
+    /// Number of exception handlers outside of the target
+    handler_depth: usize,
+}
+
+enum Handler {
+    Catch(BasicBlockId),
+    Finally(FinallyContext),
+}
+
+struct FinallyContext {
+    entry: BasicBlockId,
+    /// Blocks which complete normally into the `finally` block
+    normal: Vec<BasicBlockId>,
+    /// Abrupt completions routed through the `finally` block, with the blocks they come from
+    pending: Vec<(Completion, Vec<BasicBlockId>)>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Completion {
+    Jump { target: BasicBlockId, kind: EdgeKind, handler_depth: usize },
+    Throw,
+}
+
+impl<'s, 'a> ControlFlowGraphBuilder<'s, 'a> {
+    /// `root` is the `AstKind::Root` node of `nodes`
+    #[must_use]
+    pub fn new(nodes: &'s AstNodes<'a>, root: AstNodeId) -> Self {
+        Self {
+            nodes,
+            root,
+            node_ids: vec![Some(root)],
+            cfg: ControlFlowGraph::default(),
+            current: BasicBlockId(0),
+            functions: vec![],
+            chain_ends: vec![],
+            conditions: FxHashMap::default(),
+        }
+    }
+
+    #[must_use]
+    pub fn build(mut self, program: &'a Program<'a>) -> ControlFlowGraph {
+        self.visit_program(program);
+        self.compute_reachability();
+        self.cfg
+    }
+
+    fn context(&mut self) -> &mut FunctionContext {
+        self.functions.last_mut().unwrap()
+    }
+
+    fn new_block(&mut self) -> BasicBlockId {
+        let owner = self.functions.last().unwrap().owner;
+        let id = BasicBlockId(self.cfg.blocks.len());
+        self.cfg.blocks.push(BasicBlock::new(owner));
+        id
+    }
+
+    fn add_edge(&mut self, from: BasicBlockId, to: BasicBlockId, kind: EdgeKind) {
+        if self.cfg.blocks[from.0].successors.contains(&(to, kind)) {
+            return;
+        }
+        self.cfg.blocks[from.0].successors.push((to, kind));
+        self.cfg.blocks[to.0].predecessors.push((from, kind));
+    }
+
+    /// Continue in a new block following the current block
+    fn goto_new_block(&mut self, kind: EdgeKind) -> BasicBlockId {
+        let block = self.new_block();
+        self.add_edge(self.current, block, kind);
+        self.current = block;
+        block
+    }
+
+    /// Continue in a new block which cannot be reached, after an abrupt completion
+    fn start_unreachable_block(&mut self) {
+        self.current = self.new_block();
+    }
+
+    /// Enter the function of the node being visited
+    fn enter_function(&mut self) {
+        let node_id = self.node_ids.last().copied().flatten();
+        // The blocks of a function missing from the semantic tree belong to the enclosing one
+        let owner = node_id
+            .or_else(|| self.functions.last().map(|function| function.owner))
+            .unwrap_or(self.root);
+        let resume = self.current;
+        self.functions.push(FunctionContext {
+            owner,
+            exit: BasicBlockId(0),
+            resume,
+            jump_targets: vec![],
+            handlers: vec![],
+            labels: vec![],
+        });
+        let entry = self.new_block();
+        let exit = self.new_block();
+        self.context().exit = exit;
+        if let Some(node_id) = node_id {
+            self.cfg.functions.insert(node_id, FunctionFlow { entry, exit });
+        }
+        self.current = entry;
+    }
+
+    fn leave_function(&mut self, kind: EdgeKind) {
+        let exit = self.context().exit;
+        self.add_edge(self.current, exit, kind);
+        let context = self.functions.pop().unwrap();
+        self.current = context.resume;
+    }
+
+    /// `break`, `continue` and `return`
+    fn jump(&mut self, target: BasicBlockId, kind: EdgeKind, handler_depth: usize) {
+        let from = self.current;
+        self.complete(from, Completion::Jump { target, kind, handler_depth }, vec![from]);
+        self.start_unreachable_block();
+    }
+
+    fn throw(&mut self) {
+        let from = self.current;
+        self.complete(from, Completion::Throw, vec![from]);
+        self.start_unreachable_block();
+    }
+
+    /// Add the edges of an abrupt completion from `from`,
+    /// going through the enclosing `finally` blocks.
+    /// `sources` are the blocks the completion originates from.
+    fn complete(&mut self, from: BasicBlockId, completion: Completion, sources: Vec<BasicBlockId>) {
+        let context = self.functions.last_mut().unwrap();
+        let exit = context.exit;
+        let (handler_depth, kind) = match completion {
+            Completion::Jump { handler_depth, kind, .. } => (handler_depth, kind),
+            Completion::Throw => (0, EdgeKind::Throw),
+        };
+        let handler = context.handlers[handler_depth..].iter_mut().rev().find(|handler| {
+            matches!(handler, Handler::Finally(_))
+                || (completion == Completion::Throw && matches!(handler, Handler::Catch(_)))
+        });
+        let to = match handler {
+            Some(Handler::Catch(entry)) => *entry,
+            Some(Handler::Finally(finally)) => {
+                finally.pending.push((completion, sources.clone()));
+                finally.entry
+            }
+            None => match completion {
+                Completion::Jump { target, .. } => target,
+                Completion::Throw => exit,
+            },
+        };
+        self.add_edge(from, to, kind);
+        if sources != [from] {
+            self.conditions.entry((from, to)).or_default().extend(sources);
+        }
+    }
+
+    fn push_jump_target(
+        &mut self,
+        break_to: BasicBlockId,
+        continue_to: Option<BasicBlockId>,
+        unlabeled: bool,
+    ) {
+        let context = self.context();
+        let labels = std::mem::take(&mut context.labels);
+        let handler_depth = context.handlers.len();
+        context.jump_targets.push(JumpTarget {
+            labels,
+            break_to,
+            continue_to,
+            unlabeled,
+            handler_depth,
+        });
+    }
+
+    fn pop_jump_target(&mut self) {
+        self.context().jump_targets.pop();
+    }
+
+    /// Mark the blocks reachable from function entries,
+    /// conditional edges are followed once one of their conditions is reachable.
+    fn compute_reachability(&mut self) {
+        let blocks = &mut self.cfg.blocks;
+        let mut stack: Vec<BasicBlockId> =
+            self.cfg.functions.values().map(|flow| flow.entry).collect();
+        let mut deferred: FxHashSet<(BasicBlockId, BasicBlockId)> = FxHashSet::default();
+        loop {
+            while let Some(block) = stack.pop() {
+                if blocks[block.0].reachable {
+                    continue;
+                }
+                blocks[block.0].reachable = true;
+                for &(to, _) in &blocks[block.0].successors {
+                    if self.conditions.contains_key(&(block, to)) {
+                        deferred.insert((block, to));
+                    } else if !blocks[to.0].reachable {
+                        stack.push(to);
+                    }
+                }
+            }
+            deferred.retain(|(from, to)| {
+                let taken = self.conditions[&(*from, *to)].iter().any(|b| blocks[b.0].reachable);
+                if taken {
+                    stack.push(*to);
+                }
+                !taken
+            });
+            if stack.is_empty() {
+                break;
+            }
+        }
+    }
+}
+
+fn is_always_true(expr: &Expression) -> bool {
+    matches!(expr, Expression::BooleanLiteral(lit) if lit.value)
+}
+
+impl<'s, 'a> Visit<'a> for ControlFlowGraphBuilder<'s, 'a> {
+    fn enter_node(&mut self, kind: AstKind<'a>) {
+        let node_id = self.nodes.ast_node_id(kind);
+        self.node_ids.push(node_id);
+        // The `Program` node is added to the entry block once it is created
+        if !self.functions.is_empty() {
+            self.add_current_node();
+        }
+    }
+
+    fn leave_node(&mut self, _kind: AstKind<'a>) {
+        self.node_ids.pop();
+    }
+
+    fn visit_program(&mut self, program: &'a Program<'a>) {
+        let kind = AstKind::Program(program);
+        self.enter_node(kind);
+        self.enter_function();
+        self.add_current_node();
+        for directive in &program.directives {
+            self.visit_directive(directive);
+        }
+        self.visit_statements(&program.body);
+        self.leave_function(EdgeKind::Normal);
+        self.leave_node(kind);
+    }
+
+    /* ----------  Statement ---------- */
+
+    fn visit_break_statement(&mut self, stmt: &'a BreakStatement) {
+        let kind = AstKind::BreakStatement(stmt);
+        self.enter_node(kind);
+        if let Some(label) = &stmt.label {
+            self.visit_label_identifier(label);
+        }
+        let target = self.context().jump_targets.iter().rev().find(|target| {
+            stmt.label
+                .as_ref()
+                .map_or(target.unlabeled, |label| target.labels.contains(&label.name))
+        });
+        if let Some(target) = target {
+            let (break_to, handler_depth) = (target.break_to, target.handler_depth);
+            self.jump(break_to, EdgeKind::Jump, handler_depth);
+        } else {
+            self.start_unreachable_block();
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_continue_statement(&mut self, stmt: &'a ContinueStatement) {
+        let kind = AstKind::ContinueStatement(stmt);
+        self.enter_node(kind);
+        if let Some(label) = &stmt.label {
+            self.visit_label_identifier(label);
+        }
+        let target = self.context().jump_targets.iter().rev().find(|target| {
+            target.continue_to.is_some()
+                && stmt.label.as_ref().map_or(true, |label| target.labels.contains(&label.name))
+        });
+        if let Some(target) = target {
+            let (continue_to, handler_depth) = (target.continue_to.unwrap(), target.handler_depth);
+            self.jump(continue_to, EdgeKind::Jump, handler_depth);
+        } else {
+            self.start_unreachable_block();
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_do_while_statement(&mut self, stmt: &'a DoWhileStatement<'a>) {
+        let kind = AstKind::DoWhileStatement(stmt);
+        self.enter_node(kind);
+        let body = self.goto_new_block(EdgeKind::Normal);
+        let test = self.new_block();
+        let after = self.new_block();
+        self.push_jump_target(after, Some(test), true);
+        self.visit_statement(&stmt.body);
+        self.pop_jump_target();
+        self.add_edge(self.current, test, EdgeKind::Normal);
+
+        self.current = test;
+        self.visit_expression(&stmt.test);
+        self.add_edge(self.current, body, EdgeKind::Backedge);
+        if !is_always_true(&stmt.test) {
+            self.add_edge(self.current, after, EdgeKind::False);
+        }
+        self.current = after;
+        self.leave_node(kind);
+    }
+
+    fn visit_for_statement(&mut self, stmt: &'a ForStatement<'a>) {
+        let kind = AstKind::ForStatement(stmt);
+        self.enter_node(kind);
+        if let Some(init) = &stmt.init {
+            self.visit_for_statement_init(init);
+        }
+        let test = self.goto_new_block(EdgeKind::Normal);
+        if let Some(test) = &stmt.test {
+            self.visit_expression(test);
+        }
+        let after = self.new_block();
+        if !stmt.test.as_ref().map_or(true, is_always_true) {
+            self.add_edge(self.current, after, EdgeKind::False);
+        }
+        self.goto_new_block(EdgeKind::True);
+        let update = self.new_block();
+        self.push_jump_target(after, Some(update), true);
+        self.visit_statement(&stmt.body);
+        self.pop_jump_target();
+        self.add_edge(self.current, update, EdgeKind::Normal);
+
+        self.current = update;
+        if let Some(update) = &stmt.update {
+            self.visit_expression(update);
+        }
+        self.add_edge(self.current, test, EdgeKind::Backedge);
+        self.current = after;
+        self.leave_node(kind);
+    }
+
+    fn visit_for_in_statement(&mut self, stmt: &'a ForInStatement<'a>) {
+        let kind = AstKind::ForInStatement(stmt);
+        self.enter_node(kind);
+        self.visit_expression(&stmt.right);
+        let head = self.goto_new_block(EdgeKind::Normal);
+        let after = self.new_block();
+        self.add_edge(head, after, EdgeKind::False);
+        self.goto_new_block(EdgeKind::True);
+        self.push_jump_target(after, Some(head), true);
+        self.visit_for_statement_left(&stmt.left);
+        self.visit_statement(&stmt.body);
+        self.pop_jump_target();
+        self.add_edge(self.current, head, EdgeKind::Backedge);
+        self.current = after;
+        self.leave_node(kind);
+    }
+
+    fn visit_for_of_statement(&mut self, stmt: &'a ForOfStatement<'a>) {
+        let kind = AstKind::ForOfStatement(stmt);
+        self.enter_node(kind);
+        self.visit_expression(&stmt.right);
+        let head = self.goto_new_block(EdgeKind::Normal);
+        let after = self.new_block();
+        self.add_edge(head, after, EdgeKind::False);
+        self.goto_new_block(EdgeKind::True);
+        self.push_jump_target(after, Some(head), true);
+        self.visit_for_statement_left(&stmt.left);
+        self.visit_statement(&stmt.body);
+        self.pop_jump_target();
+        self.add_edge(self.current, head, EdgeKind::Backedge);
+        self.current = after;
+        self.leave_node(kind);
+    }
+
+    fn visit_if_statement(&mut self, stmt: &'a IfStatement<'a>) {
+        let kind = AstKind::IfStatement(stmt);
+        self.enter_node(kind);
+        self.visit_expression(&stmt.test);
+        let test = self.current;
+        let after = self.new_block();
+        self.goto_new_block(EdgeKind::True);
+        self.visit_statement(&stmt.consequent);
+        self.add_edge(self.current, after, EdgeKind::Normal);
+        if let Some(alternate) = &stmt.alternate {
+            self.current = test;
+            self.goto_new_block(EdgeKind::False);
+            self.visit_statement(alternate);
+            self.add_edge(self.current, after, EdgeKind::Normal);
+        } else {
+            self.add_edge(test, after, EdgeKind::False);
+        }
+        self.current = after;
+        self.leave_node(kind);
+    }
+
+    fn visit_labeled_statement(&mut self, stmt: &'a LabeledStatement<'a>) {
+        let kind = AstKind::LabeledStatement(stmt);
+        self.enter_node(kind);
+        self.visit_label_identifier(&stmt.label);
+        self.context().labels.push(stmt.label.name.clone());
+        if matches!(
+            stmt.body,
+            Statement::DoWhileStatement(_)
+                | Statement::ForInStatement(_)
+                | Statement::ForOfStatement(_)
+                | Statement::ForStatement(_)
+                | Statement::WhileStatement(_)
+                | Statement::SwitchStatement(_)
+                | Statement::LabeledStatement(_)
+        ) {
+            // The labels belong to the inner statement
+            self.visit_statement(&stmt.body);
+        } else {
+            let after = self.new_block();
+            self.push_jump_target(after, None, false);
+            self.visit_statement(&stmt.body);
+            self.pop_jump_target();
+            self.add_edge(self.current, after, EdgeKind::Normal);
+            self.current = after;
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_return_statement(&mut self, stmt: &'a ReturnStatement<'a>) {
+        let kind = AstKind::ReturnStatement(stmt);
+        self.enter_node(kind);
+        if let Some(arg) = &stmt.argument {
+            self.visit_expression(arg);
+        }
+        let exit = self.context().exit;
+        self.jump(exit, EdgeKind::Return, 0);
+        self.leave_node(kind);
+    }
+
+    fn visit_switch_statement(&mut self, stmt: &'a SwitchStatement<'a>) {
+        let kind = AstKind::SwitchStatement(stmt);
+        self.enter_node(kind);
+        self.visit_expression(&stmt.discriminant);
+        let after = self.new_block();
+        let bodies: Vec<_> = stmt.cases.iter().map(|_| self.new_block()).collect();
+
+        // Test the cases in order, then go to `default`
+        let mut default = None;
+        for (case, body) in stmt.cases.iter().zip(&bodies) {
+            if let Some(test) = &case.test {
+                self.visit_expression(test);
+                self.add_edge(self.current, *body, EdgeKind::True);
+                self.goto_new_block(EdgeKind::False);
+            } else {
+                default = Some(*body);
+            }
+        }
+        self.add_edge(self.current, default.unwrap_or(after), EdgeKind::Normal);
+
+        // Case bodies fall through into the next
+        self.push_jump_target(after, None, true);
+        for (i, (case, body)) in stmt.cases.iter().zip(&bodies).enumerate() {
+            if i > 0 {
+                self.add_edge(self.current, *body, EdgeKind::Normal);
+            }
+            self.current = *body;
+            let kind = AstKind::SwitchCase(case);
+            self.enter_node(kind);
+            self.visit_statements(&case.consequent);
+            self.leave_node(kind);
+        }
+        self.pop_jump_target();
+        if !bodies.is_empty() {
+            self.add_edge(self.current, after, EdgeKind::Normal);
+        }
+        self.current = after;
+        self.leave_node(kind);
+    }
+
+    fn visit_throw_statement(&mut self, stmt: &'a ThrowStatement<'a>) {
+        let kind = AstKind::ThrowStatement(stmt);
+        self.enter_node(kind);
+        self.visit_expression(&stmt.argument);
+        self.throw();
+        self.leave_node(kind);
+    }
+
+    fn visit_try_statement(&mut self, stmt: &'a TryStatement<'a>) {
+        let kind = AstKind::TryStatement(stmt);
+        self.enter_node(kind);
+        let after = self.new_block();
+        let finally_entry = stmt.finalizer.as_ref().map(|_| self.new_block());
+        let catch_entry = stmt.handler.as_ref().map(|_| self.new_block());
+        if let Some(entry) = finally_entry {
+            let finally = FinallyContext { entry, normal: vec![], pending: vec![] };
+            self.context().handlers.push(Handler::Finally(finally));
+        }
+        if let Some(entry) = catch_entry {
+            self.context().handlers.push(Handler::Catch(entry));
+        }
+
+        let try_start = self.goto_new_block(EdgeKind::Normal);
+        self.visit_block_statement(&stmt.block);
+        let try_end = self.current;
+        // Any block of the `try` block may throw
+        let owner = self.context().owner;
+        for block in try_start.0..self.cfg.blocks.len() {
+            let block = BasicBlockId(block);
+            if self.cfg.blocks[block.0].owner == owner {
+                self.complete(block, Completion::Throw, vec![block]);
+            }
+        }
+        let mut normal = vec![try_end];
+
+        if let (Some(handler), Some(entry)) = (&stmt.handler, catch_entry) {
+            self.context().handlers.pop();
+            self.current = entry;
+            self.visit_catch_clause(handler);
+            normal.push(self.current);
+        }
+
+        if let Some(finalizer) = &stmt.finalizer {
+            let Some(Handler::Finally(mut finally)) = self.context().handlers.pop() else {
+                unreachable!()
+            };
+            for block in normal {
+                self.add_edge(block, finally.entry, EdgeKind::Normal);
+                finally.normal.push(block);
+            }
+            self.current = finally.entry;
+            self.visit_finally_clause(finalizer);
+            let finally_end = self.current;
+            self.add_edge(finally_end, after, EdgeKind::Normal);
+            self.conditions.entry((finally_end, after)).or_default().extend(finally.normal);
+            for (completion, sources) in finally.pending {
+                self.complete(finally_end, completion, sources);
+            }
+        } else {
+            for block in normal {
+                self.add_edge(block, after, EdgeKind::Normal);
+            }
+        }
+        self.current = after;
+        self.leave_node(kind);
+    }
+
+    fn visit_while_statement(&mut self, stmt: &'a WhileStatement<'a>) {
+        let kind = AstKind::WhileStatement(stmt);
+        self.enter_node(kind);
+        let test = self.goto_new_block(EdgeKind::Normal);
+        self.visit_expression(&stmt.test);
+        let after = self.new_block();
+        if !is_always_true(&stmt.test) {
+            self.add_edge(self.current, after, EdgeKind::False);
+        }
+        self.goto_new_block(EdgeKind::True);
+        self.push_jump_target(after, Some(test), true);
+        self.visit_statement(&stmt.body);
+        self.pop_jump_target();
+        self.add_edge(self.current, test, EdgeKind::Backedge);
+        self.current = after;
+        self.leave_node(kind);
+    }
+
+    /* ----------  Function ---------- */
+
+    fn visit_function(&mut self, func: &'a Function<'a>) {
+        let kind = AstKind::Function(func);
+        self.enter_node(kind);
+        if let Some(ident) = &func.id {
+            self.visit_binding_identifier(ident);
+        }
+        if let Some(body) = &func.body {
+            self.enter_function();
+            self.visit_formal_parameters(&func.params);
+            self.visit_function_body(body);
+            self.leave_function(EdgeKind::Normal);
+        } else {
+            self.visit_formal_parameters(&func.params);
+        }
+        if let Some(parameters) = &func.type_parameters {
+            self.visit_ts_type_parameter_declaration(parameters);
+        }
+        if let Some(annotation) = &func.return_type {
+            self.visit_ts_type_annotation(annotation);
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_arrow_expression(&mut self, expr: &'a ArrowExpression<'a>) {
+        let kind = AstKind::ArrowExpression(expr);
+        self.enter_node(kind);
+        self.enter_function();
+        self.visit_formal_parameters(&expr.params);
+        self.visit_function_body(&expr.body);
+        self.leave_function(if expr.expression { EdgeKind::Return } else { EdgeKind::Normal });
+        if let Some(parameters) = &expr.type_parameters {
+            self.visit_ts_type_parameter_declaration(parameters);
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_static_block(&mut self, block: &'a StaticBlock<'a>) {
+        let kind = AstKind::StaticBlock(block);
+        self.enter_node(kind);
+        self.enter_function();
+        self.visit_statements(&block.body);
+        self.leave_function(EdgeKind::Normal);
+        self.leave_node(kind);
+    }
+
+    fn visit_property_definition(&mut self, def: &'a PropertyDefinition<'a>) {
+        let kind = AstKind::PropertyDefinition(def);
+        self.enter_node(kind);
+        for decorator in &def.decorators {
+            self.visit_decorator(decorator);
+        }
+        self.visit_property_key(&def.key);
+        if let Some(value) = &def.value {
+            // Initializers are evaluated when the class is instantiated
+            self.enter_function();
+            self.visit_expression(value);
+            self.leave_function(EdgeKind::Return);
+        }
+        if let Some(annotation) = &def.type_annotation {
+            self.visit_ts_type_annotation(annotation);
+        }
+        self.leave_node(kind);
+    }
+
+    /* ----------  Expression ---------- */
+
+    fn visit_assignment_expression(&mut self, expr: &'a AssignmentExpression<'a>) {
+        let kind = AstKind::AssignmentExpression(expr);
+        self.enter_node(kind);
+        self.visit_assignment_target(&expr.left);
+        let edges = match expr.operator {
+            AssignmentOperator::LogicalAnd => Some((EdgeKind::True, EdgeKind::False)),
+            AssignmentOperator::LogicalOr => Some((EdgeKind::False, EdgeKind::True)),
+            AssignmentOperator::LogicalNullish => Some((EdgeKind::Normal, EdgeKind::Normal)),
+            _ => None,
+        };
+        if let Some((evaluate, skip)) = edges {
+            self.visit_short_circuit(&expr.right, evaluate, skip);
+        } else {
+            self.visit_expression(&expr.right);
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_call_expression(&mut self, expr: &'a CallExpression<'a>) {
+        let kind = AstKind::CallExpression(expr);
+        self.enter_node(kind);
+        self.visit_expression(&expr.callee);
+        self.optional_chain(expr.optional);
+        for arg in &expr.arguments {
+            self.visit_argument(arg);
+        }
+        if let Some(parameters) = &expr.type_parameters {
+            self.visit_ts_type_parameter_instantiation(parameters);
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_chain_expression(&mut self, expr: &'a ChainExpression<'a>) {
+        let kind = AstKind::ChainExpression(expr);
+        self.enter_node(kind);
+        let after = self.new_block();
+        self.chain_ends.push(after);
+        self.visit_chain_element(&expr.expression);
+        self.chain_ends.pop();
+        self.add_edge(self.current, after, EdgeKind::Normal);
+        self.current = after;
+        self.leave_node(kind);
+    }
+
+    fn visit_conditional_expression(&mut self, expr: &'a ConditionalExpression<'a>) {
+        let kind = AstKind::ConditionalExpression(expr);
+        self.enter_node(kind);
+        self.visit_expression(&expr.test);
+        let test = self.current;
+        let after = self.new_block();
+        self.goto_new_block(EdgeKind::True);
+        self.visit_expression(&expr.consequent);
+        self.add_edge(self.current, after, EdgeKind::Normal);
+        self.current = test;
+        self.goto_new_block(EdgeKind::False);
+        self.visit_expression(&expr.alternate);
+        self.add_edge(self.current, after, EdgeKind::Normal);
+        self.current = after;
+        self.leave_node(kind);
+    }
+
+    fn visit_logical_expression(&mut self, expr: &'a LogicalExpression<'a>) {
+        let kind = AstKind::LogicalExpression(expr);
+        self.enter_node(kind);
+        self.visit_expression(&expr.left);
+        let (evaluate, skip) = match expr.operator {
+            LogicalOperator::And => (EdgeKind::True, EdgeKind::False),
+            LogicalOperator::Or => (EdgeKind::False, EdgeKind::True),
+            LogicalOperator::Coalesce => (EdgeKind::Normal, EdgeKind::Normal),
+        };
+        self.visit_short_circuit(&expr.right, evaluate, skip);
+        self.leave_node(kind);
+    }
+
+    fn visit_computed_member_expression(&mut self, expr: &'a ComputedMemberExpression<'a>) {
+        self.visit_expression(&expr.object);
+        self.optional_chain(expr.optional);
+        self.visit_expression(&expr.expression);
+    }
+
+    fn visit_static_member_expression(&mut self, expr: &'a StaticMemberExpression<'a>) {
+        self.visit_expression(&expr.object);
+        self.optional_chain(expr.optional);
+        self.visit_identifier_name(&expr.property);
+    }
+
+    fn visit_private_field_expression(&mut self, expr: &'a PrivateFieldExpression<'a>) {
+        self.visit_expression(&expr.object);
+        self.optional_chain(expr.optional);
+        self.visit_private_identifier(&expr.field);
+    }
+
+    fn visit_yield_expression(&mut self, expr: &'a YieldExpression<'a>) {
+        let kind = AstKind::YieldExpression(expr);
+        self.enter_node(kind);
+        if let Some(argument) = &expr.argument {
+            self.visit_expression(argument);
+        }
+        // The generator is suspended and resumed here
+        self.goto_new_block(EdgeKind::Normal);
+        self.leave_node(kind);
+    }
+}
+
+impl<'s, 'a> ControlFlowGraphBuilder<'s, 'a> {
+    /// Add the node being visited to the current block
+    fn add_current_node(&mut self) {
+        if let Some(node_id) = self.node_ids.last().copied().flatten() {
+            self.cfg.node_blocks.insert(node_id, self.current);
+            self.cfg.blocks[self.current.0].nodes.push(node_id);
+        }
+    }
+
+    /// `right` is evaluated by the `evaluate` edge, or skipped by the `skip` edge
+    fn visit_short_circuit(
+        &mut self,
+        right: &'a Expression<'a>,
+        evaluate: EdgeKind,
+        skip: EdgeKind,
+    ) {
+        let left = self.current;
+        let after = self.new_block();
+        self.goto_new_block(evaluate);
+        self.visit_expression(right);
+        self.add_edge(self.current, after, EdgeKind::Normal);
+        self.add_edge(left, after, skip);
+        self.current = after;
+    }
+
+    /// `?.` skips to the end of the chain when the object is nullish
+    fn optional_chain(&mut self, optional: bool) {
+        if let (true, Some(end)) = (optional, self.chain_ends.last()) {
+            self.add_edge(self.current, *end, EdgeKind::True);
+            self.goto_new_block(EdgeKind::False);
+        }
+    }
+}
@@ -0,0 +1,356 @@
+//! Control Flow Graph
+//!
+//! Every function, arrow function, class static block, class field initializer and the program
+//! has its own graph of basic blocks, with an entry block and an exit block.
+//! Edges are created for conditionals, loops, labeled `break` / `continue`, `switch` cases,
+//! `try` / `catch` / `finally`, the short-circuit operators `&&` `||` `??` `?:` `?.`,
+//! `return`, `throw` and `yield`.
+//!
+//! Built by [`crate::SemanticBuilder::with_cfg`].
+
+mod builder;
+
+use std::ops::Index;
+
+use rustc_hash::FxHashMap;
+
+pub(crate) use self::builder::ControlFlowGraphBuilder;
+use crate::node::AstNodeId;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BasicBlockId(usize);
+
+impl BasicBlockId {
+    #[must_use]
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EdgeKind {
+    /// Sequential flow, including the implicit `return undefined` at the end of a function
+    Normal,
+    /// The condition is truthy, or nullish for `?.`
+    True,
+    /// The condition is falsy
+    False,
+    /// Back to the head of a loop
+    Backedge,
+    /// `break` or `continue`
+    Jump,
+    /// `return`, or the body of an expression arrow function
+    Return,
+    /// `throw` or an exception from inside a `try` block
+    Throw,
+}
+
+#[derive(Debug)]
+pub struct BasicBlock {
+    /// The function, arrow function, class static block, class property definition or program
+    /// this block belongs to
+    owner: AstNodeId,
+
+    /// AST nodes which start in this block, in evaluation order
+    nodes: Vec<AstNodeId>,
+
+    successors: Vec<(BasicBlockId, EdgeKind)>,
+
+    predecessors: Vec<(BasicBlockId, EdgeKind)>,
+
+    reachable: bool,
+}
+
+impl BasicBlock {
+    fn new(owner: AstNodeId) -> Self {
+        Self { owner, nodes: vec![], successors: vec![], predecessors: vec![], reachable: false }
+    }
+
+    #[must_use]
+    pub fn owner(&self) -> AstNodeId {
+        self.owner
+    }
+
+    #[must_use]
+    pub fn nodes(&self) -> &[AstNodeId] {
+        &self.nodes
+    }
+
+    #[must_use]
+    pub fn successors(&self) -> &[(BasicBlockId, EdgeKind)] {
+        &self.successors
+    }
+
+    #[must_use]
+    pub fn predecessors(&self) -> &[(BasicBlockId, EdgeKind)] {
+        &self.predecessors
+    }
+
+    /// Whether the block can be reached from the entry of its function.
+    #[must_use]
+    pub fn is_reachable(&self) -> bool {
+        self.reachable
+    }
+}
+
+/// Entry and exit blocks of a function
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FunctionFlow {
+    pub entry: BasicBlockId,
+    /// Reached by `return`, uncaught `throw`s and falling off the end of the function
+    pub exit: BasicBlockId,
+}
+
+#[derive(Debug, Default)]
+pub struct ControlFlowGraph {
+    blocks: Vec<BasicBlock>,
+
+    /// Keyed by the owner of the blocks, see [`BasicBlock::owner`]
+    functions: FxHashMap<AstNodeId, FunctionFlow>,
+
+    /// The block each AST node starts in
+    node_blocks: FxHashMap<AstNodeId, BasicBlockId>,
+}
+
+impl Index<BasicBlockId> for ControlFlowGraph {
+    type Output = BasicBlock;
+
+    fn index(&self, id: BasicBlockId) -> &Self::Output {
+        &self.blocks[id.0]
+    }
+}
+
+impl ControlFlowGraph {
+    #[must_use]
+    pub fn blocks(&self) -> &[BasicBlock] {
+        &self.blocks
+    }
+
+    /// Entry and exit blocks of a function, arrow function, class static block,
+    /// class property definition or the program.
+    #[must_use]
+    pub fn function(&self, owner: AstNodeId) -> Option<FunctionFlow> {
+        self.functions.get(&owner).copied()
+    }
+
+    /// The block the AST node starts in.
+    #[must_use]
+    pub fn block_of(&self, node: AstNodeId) -> Option<BasicBlockId> {
+        self.node_blocks.get(&node).copied()
+    }
+
+    /// Whether the AST node can be reached from the entry of its function.
+    #[must_use]
+    pub fn is_reachable(&self, node: AstNodeId) -> bool {
+        self.block_of(node).map_or(true, |block| self[block].reachable)
+    }
+
+    /// Edges from reachable blocks into the exit of the function.
+    pub fn exits(&self, owner: AstNodeId) -> impl Iterator<Item = (BasicBlockId, EdgeKind)> + '_ {
+        self.function(owner)
+            .into_iter()
+            .flat_map(|flow| self[flow.exit].predecessors.iter().copied())
+            .filter(|(block, _)| self[*block].reachable)
+    }
+
+    /// Whether the end of the function can be reached, i.e. it may implicitly return `undefined`.
+    #[must_use]
+    pub fn falls_through(&self, owner: AstNodeId) -> bool {
+        self.exits(owner).any(|(_, kind)| kind == EdgeKind::Normal)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::rc::Rc;
+
+    use oxc_allocator::Allocator;
+    use oxc_ast::{AstKind, GetSpan, SourceType};
+    use oxc_parser::Parser;
+
+    use super::{ControlFlowGraph, ControlFlowGraphBuilder, EdgeKind};
+    use crate::{tester, AstNodes, SemanticBuilder};
+
+    /// Run `f` with the control flow graph of `source_text`
+    fn with_cfg<R>(source_text: &str, f: impl FnOnce(&ControlFlowGraph, &AstNodes) -> R) -> R {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let ret = Parser::new(&allocator, source_text, source_type)
+            .allow_return_outside_function(true)
+            .parse();
+        assert!(ret.errors.is_empty(), "{source_text}");
+        let program = allocator.alloc(ret.program);
+        let semantic = SemanticBuilder::new(source_text, source_type, &Rc::default())
+            .with_cfg(true)
+            .build(program)
+            .semantic;
+        f(semantic.cfg().unwrap(), semantic.nodes())
+    }
+
+    /// Reachability of the expression statements `x();` in `source_text`
+    fn reachable(source_text: &str) -> Vec<bool> {
+        with_cfg(source_text, |cfg, nodes| {
+            nodes
+                .iter()
+                .filter_map(|node| {
+                    let kind = node.get().kind();
+                    let AstKind::ExpressionStatement(stmt) = kind else { return None };
+                    (stmt.span.source_text(source_text) == "x();")
+                        .then(|| cfg.is_reachable(nodes.get_node_id(node).unwrap().into()))
+                })
+                .collect()
+        })
+    }
+
+    /// Whether the first function in `source_text` may fall off its end
+    fn falls_through(source_text: &str) -> bool {
+        with_cfg(source_text, |cfg, nodes| {
+            let node = nodes
+                .iter()
+                .find(|node| {
+                    matches!(node.get().kind(), AstKind::Function(_) | AstKind::ArrowExpression(_))
+                })
+                .unwrap();
+            cfg.falls_through(nodes.get_node_id(node).unwrap().into())
+        })
+    }
+
+    #[test]
+    fn statements() {
+        assert_eq!(reachable("x(); x();"), [true, true]);
+        assert_eq!(reachable("function f() { x(); return; x(); }"), [true, false]);
+        assert_eq!(reachable("function f() { throw 1; x(); }"), [false]);
+        assert_eq!(reachable("if (a) { return } else { return } x();"), [false]);
+        assert_eq!(reachable("if (a) { return } x();"), [true]);
+        assert_eq!(reachable("if (a) return; else x(); x();"), [true, true]);
+        assert_eq!(reachable("{ return; x(); }"), [false]);
+        // hoisted functions are reachable on their own
+        assert_eq!(reachable("return; function f() { x(); }"), [true]);
+    }
+
+    #[test]
+    fn loops() {
+        assert_eq!(reachable("while (true) { x(); } x();"), [true, false]);
+        assert_eq!(reachable("while (true) { if (a) break; } x();"), [true]);
+        assert_eq!(reachable("while (a) { continue; x(); } x();"), [false, true]);
+        assert_eq!(reachable("for (;;) {} x();"), [false]);
+        assert_eq!(reachable("for (;a;) {} x();"), [true]);
+        assert_eq!(reachable("for (const a of b) { break; x(); } x();"), [false, true]);
+        assert_eq!(reachable("do { x(); } while (true); x();"), [true, false]);
+        assert_eq!(reachable("do { break; } while (true); x();"), [true]);
+        assert_eq!(reachable("do { continue; } while (true); x();"), [false]);
+        assert_eq!(
+            reachable("a: while (true) { while (true) { break a; } x(); } x();"),
+            [false, true]
+        );
+        assert_eq!(reachable("a: for (;;) { for (;;) { continue a; } x(); } x();"), [false, false]);
+        assert_eq!(reachable("a: { break a; x(); } x();"), [false, true]);
+    }
+
+    #[test]
+    fn switch() {
+        assert_eq!(reachable("switch (a) { case 1: x(); case 2: x(); } x();"), [true, true, true]);
+        assert_eq!(reachable("switch (a) { case 1: return; default: return; } x();"), [false]);
+        assert_eq!(reachable("switch (a) { case 1: return; } x();"), [true]);
+        assert_eq!(reachable("switch (a) { default: break; x(); } x();"), [false, true]);
+        assert_eq!(reachable("for (;;) { switch (a) { case 1: continue; } } x();"), [false]);
+    }
+
+    #[test]
+    fn try_statement() {
+        assert_eq!(reachable("try { throw 1; } catch { x(); } x();"), [true, true]);
+        assert_eq!(reachable("try { return; } catch { } x();"), [true]);
+        assert_eq!(reachable("try { return; } finally { x(); } x();"), [true, false]);
+        assert_eq!(reachable("try { a(); } finally { x(); } x();"), [true, true]);
+        assert_eq!(reachable("try { return; } catch { return; } finally { } x();"), [false]);
+        assert_eq!(reachable("try { } finally { return; } x();"), [false]);
+        assert_eq!(reachable("for (;;) { try { break; } finally { } } x();"), [true]);
+        assert_eq!(reachable("for (;;) { try { continue; } finally { } } x();"), [false]);
+        assert_eq!(
+            reachable("try { try { return; } finally { x(); } x(); } finally { x(); } x();"),
+            [true, false, true, false]
+        );
+    }
+
+    #[test]
+    fn functions() {
+        assert!(falls_through("function f() {}"));
+        assert!(!falls_through("function f() { return 1 }"));
+        assert!(falls_through("function f() { if (a) return 1 }"));
+        assert!(!falls_through("function f() { if (a) return 1; else return 2 }"));
+        assert!(!falls_through("function f() { throw 1 }"));
+        assert!(!falls_through("function f() { while (true) {} }"));
+        assert!(falls_through("function f() { while (true) { break } }"));
+        assert!(!falls_through("function f() { try { return 1 } catch { return 2 } }"));
+        assert!(falls_through("function f() { try { return 1 } catch { } }"));
+        assert!(!falls_through("function f() { switch (a) { case 1: return; default: throw 1 } }"));
+        assert!(!falls_through("() => 1"));
+        assert!(falls_through("() => { a }"));
+        assert!(!falls_through("function f() { return function g() {} }"));
+    }
+
+    #[test]
+    fn expressions() {
+        with_cfg("a && b; c || d; e ?? f; g ? h : i; j?.k.l;", |cfg, nodes| {
+            let block_of = |name: &str| {
+                let node = nodes
+                    .iter()
+                    .find(|node| {
+                        matches!(node.get().kind(), AstKind::IdentifierReference(ident) if ident.name == name)
+                    })
+                    .unwrap();
+                cfg.block_of(nodes.get_node_id(node).unwrap().into()).unwrap()
+            };
+            let edge = |from: &str, to: &str| {
+                cfg[block_of(from)]
+                    .successors()
+                    .iter()
+                    .find(|(block, _)| *block == block_of(to))
+                    .map(|(_, kind)| *kind)
+            };
+            assert_eq!(edge("a", "b"), Some(EdgeKind::True));
+            assert_eq!(edge("c", "d"), Some(EdgeKind::False));
+            assert_eq!(edge("e", "f"), Some(EdgeKind::Normal));
+            assert_eq!(edge("g", "h"), Some(EdgeKind::True));
+            assert_eq!(edge("g", "i"), Some(EdgeKind::False));
+            // `j` is nullish, or `.k` is evaluated
+            assert_eq!(cfg[block_of("j")].successors().len(), 2);
+        });
+    }
+
+    #[test]
+    fn generators() {
+        with_cfg("function* f() { a; yield; b; }", |cfg, nodes| {
+            let spans: Vec<_> = nodes
+                .iter()
+                .filter(|node| matches!(node.get().kind(), AstKind::ExpressionStatement(_)))
+                .map(|node| {
+                    let id = nodes.get_node_id(node).unwrap().into();
+                    (node.get().kind().span(), cfg.block_of(id).unwrap())
+                })
+                .collect();
+            assert_eq!(spans.len(), 3);
+            assert_ne!(spans[0].1, spans[2].1);
+        });
+    }
+
+    #[test]
+    fn missing_nodes() {
+        // The graph of a program which is not in the semantic tree,
+        // its nodes are not found and its functions are not recorded
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let source_text = "function f() { return a ? b : c }";
+        let semantic = tester::semantic(&allocator, source_text, source_type, |builder| builder);
+        let other = tester::parse(&allocator, "if (a) { x(); } function g() {}", source_type);
+        let nodes = semantic.nodes();
+        let root = nodes.get_node_id(nodes.iter().next().unwrap()).unwrap().into();
+        let cfg = ControlFlowGraphBuilder::new(nodes, root).build(other);
+        assert!(!cfg.blocks().is_empty());
+        assert!(
+            nodes
+                .iter()
+                .all(|node| cfg.function(nodes.get_node_id(node).unwrap().into()).is_none())
+        );
+    }
+}
@@ -0,0 +1,183 @@
+//! Semantic detached from the AST
+//!
+//! `Semantic` borrows the AST for the lifetime of its allocator, so the analyzed program
+//! cannot be mutated afterwards. A transform pass works on a copy of the program instead,
+//! and the semantic of the copy is rebuilt from scratch with the options and the allocations
+//! of the previous build. Nothing of the previous analysis is kept, the scope tree and
+//! the symbol table are not updated incrementally.
+//!
+//! ```ignore
+//! let semantic = SemanticBuilder::new(source_text, source_type, &trivias).build(program).semantic;
+//! // query semantic ...
+//! let detached = semantic.detach();
+//! let program = allocator.alloc(program.clone_in(&allocator));
+//! Compressor::new(&allocator, options).build(program);
+//! let semantic = detached.rebuild(source_text, program).semantic;
+//! ```
+
+use std::rc::Rc;
+
+use oxc_ast::{ast::Program, SourceType, Trivias};
+
+use crate::{
+    builder::SemanticBuilderReturn, scope::ScopeTree, Semantic, SemanticBuilder, SymbolTable,
+};
+
+/// Semantic detached from the AST, created by [`Semantic::detach`].
+#[derive(Debug)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct DetachedSemantic {
+    source_type: SourceType,
+
+    trivias: Rc<Trivias>,
+
+    with_module_record_builder: bool,
+
+    with_cfg: bool,
+
+    with_classes: bool,
+
+    with_this_bindings: bool,
+
+    with_free_variables: bool,
+
+    with_initialization: bool,
+
+    with_check_syntax_error: bool,
+
+    scopes: ScopeTree,
+
+    symbols: SymbolTable,
+
+    nodes_capacity: usize,
+}
+
+impl DetachedSemantic {
+    pub(crate) fn new(semantic: Semantic) -> Self {
+        Self {
+            source_type: semantic.source_type,
+            trivias: semantic.trivias,
+            with_module_record_builder: semantic.with_module_record_builder,
+            with_cfg: semantic.cfg.is_some(),
+            with_classes: semantic.classes.is_some(),
+            with_this_bindings: semantic.this_bindings.is_some(),
+            with_free_variables: semantic.with_free_variables,
+            with_initialization: semantic.with_initialization,
+            with_check_syntax_error: semantic.with_check_syntax_error,
+            scopes: semantic.scopes,
+            symbols: semantic.symbols,
+            nodes_capacity: semantic.nodes.count(),
+        }
+    }
+
+    /// Build the semantic of `program` from scratch with the options of the previous build.
+    /// The scope tree and the symbol table are cleared, only their allocations are reused.
+    #[must_use]
+    pub fn rebuild<'a>(
+        self,
+        source_text: &'a str,
+        program: &'a Program<'a>,
+    ) -> SemanticBuilderReturn<'a> {
+        SemanticBuilder::with_storage(
+            source_text,
+            self.source_type,
+            &self.trivias,
+            self.scopes,
+            self.symbols,
+            self.nodes_capacity,
+        )
+        .with_module_record_builder(self.with_module_record_builder)
+        .with_cfg(self.with_cfg)
+        .with_classes(self.with_classes)
+        .with_this_bindings(self.with_this_bindings)
+        .with_free_variables(self.with_free_variables)
+        .with_initialization(self.with_initialization)
+        .with_check_syntax_error(self.with_check_syntax_error)
+        .build(program)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::rc::Rc;
+
+    use oxc_allocator::Allocator;
+    use oxc_ast::{
+        ast::{BindingIdentifier, IdentifierReference, Program},
+        visit_mut::VisitMut,
+        Atom, CloneIn, SourceType,
+    };
+    use oxc_parser::Parser;
+
+    use crate::SemanticBuilder;
+
+    /// Rename `a` to `b`
+    struct Rename;
+
+    impl<'a, 'b> VisitMut<'a, 'b> for Rename {
+        fn visit_binding_identifier(&mut self, ident: &'b mut BindingIdentifier) {
+            if ident.name == "a" {
+                ident.name = Atom::from("b");
+            }
+        }
+
+        fn visit_identifier_reference(&mut self, ident: &'b mut IdentifierReference) {
+            if ident.name == "a" {
+                ident.name = Atom::from("b");
+            }
+        }
+    }
+
+    fn names<'a>(program: &'a Program<'a>, source_text: &'a str) -> Vec<(String, usize)> {
+        let semantic = SemanticBuilder::new(source_text, SourceType::default(), &Rc::default())
+            .build(program)
+            .semantic;
+        semantic.symbols().iter().map(|s| (s.name().to_string(), s.references().len())).collect()
+    }
+
+    #[test]
+    fn detach() {
+        let source_text = "let a = 1; function f() { return a; } f();";
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, SourceType::default()).parse();
+        let program = allocator.alloc(ret.program);
+
+        let semantic = SemanticBuilder::new(source_text, SourceType::default(), &ret.trivias)
+            .with_classes(true)
+            .with_free_variables(true)
+            .build(program)
+            .semantic;
+        let a = semantic.scopes()[semantic.scopes().root_scope_id()]
+            .get_variable_symbol_id(&"a".into());
+        assert!(a.is_some());
+        let detached = semantic.detach();
+
+        // transform a copy of the program
+        let program = allocator.alloc(program.clone_in(&allocator));
+        Rename.visit_program(program);
+        program.body.pop();
+
+        let ret = detached.rebuild(source_text, program);
+        assert!(ret.errors.is_empty());
+        let semantic = ret.semantic;
+        let root = &semantic.scopes()[semantic.scopes().root_scope_id()];
+        assert!(root.get_variable_symbol_id(&"a".into()).is_none());
+        let b = root.get_variable_symbol_id(&"b".into()).unwrap();
+        assert_eq!(semantic.symbols()[b].references().len(), 1);
+        let f = root.get_variable_symbol_id(&"f".into()).unwrap();
+        assert!(semantic.symbols()[f].references().is_empty());
+
+        // Built with the options of the previous build
+        assert!(semantic.classes().is_some());
+        assert!(semantic.this_bindings().is_none());
+        assert!(semantic.scopes().is_captured(b));
+
+        // Same result as a fresh build
+        let detached_names: Vec<_> = semantic
+            .symbols()
+            .iter()
+            .map(|s| (s.name().to_string(), s.references().len()))
+            .collect();
+        assert_eq!(detached_names, names(program, source_text));
+    }
+}
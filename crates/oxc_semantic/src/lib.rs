@@ -3,19 +3,52 @@
 
 mod binder;
 mod builder;
-mod module_record;
+mod call_graph;
+mod checker;
+mod class;
+mod const_eval;
+pub mod control_flow;
+mod detached;
+pub mod module_record;
 mod node;
+mod rename;
 mod scope;
+mod snapshot;
 mod symbol;
+#[cfg(test)]
+mod tester;
+mod this_binding;
+mod unused;
 
 use std::rc::Rc;
 
 pub use builder::SemanticBuilder;
-pub use node::{AstNode, AstNodes, SemanticNode};
-use oxc_ast::{module_record::ModuleRecord, SourceType, Trivias};
-pub use scope::{Scope, ScopeFlags, ScopeTree};
-pub use symbol::{Reference, ResolvedReference, Symbol, SymbolFlags, SymbolTable};
-
+pub use call_graph::{CallGraph, CallKind, CallSite};
+pub use class::{
+    ClassId, ClassInfo, ClassMember, ClassMemberId, ClassMemberKind, ClassMemberVisibility,
+    ClassTable,
+};
+pub use const_eval::{ConstantEvaluator, ConstantValue};
+use control_flow::ControlFlowGraph;
+pub use detached::DetachedSemantic;
+pub use node::{AstNode, AstNodeId, AstNodes, SemanticNode};
+use oxc_ast::{
+    ast::IdentifierReference, module_record::ModuleRecord, AstKind, Atom, SourceType, Trivias,
+};
+pub use rename::{RenameError, TextEdit};
+use rustc_hash::FxHashMap;
+pub use scope::{CaptureFlags, FreeVariable, Scope, ScopeFlags, ScopeId, ScopeTree};
+pub use snapshot::{ReferenceSnapshot, ScopeSnapshot, SymbolSnapshot};
+pub use symbol::{
+    Initialization, Reference, ReferenceFlag, ResolvedReference, Symbol, SymbolFlags, SymbolId,
+    SymbolTable,
+};
+pub use this_binding::ThisBindings;
+pub use unused::{
+    UnusedArgs, UnusedDeclaration, UnusedDeclarationKind, UnusedOptions, UnusedReason, UnusedVars,
+};
+
+#[allow(clippy::struct_excessive_bools)]
 pub struct Semantic<'a> {
     source_text: &'a str,
 
@@ -27,9 +60,23 @@ pub struct Semantic<'a> {
 
     symbols: SymbolTable,
 
+    classes: Option<ClassTable>,
+
+    this_bindings: Option<ThisBindings>,
+
     trivias: Rc<Trivias>,
 
     module_record: ModuleRecord,
+
+    cfg: Option<ControlFlowGraph>,
+
+    with_module_record_builder: bool,
+
+    with_free_variables: bool,
+
+    with_initialization: bool,
+
+    with_check_syntax_error: bool,
 }
 
 impl<'a> Semantic<'a> {
@@ -53,6 +100,25 @@ impl<'a> Semantic<'a> {
         &self.scopes
     }
 
+    #[must_use]
+    pub fn symbols(&self) -> &SymbolTable {
+        &self.symbols
+    }
+
+    /// Members of classes and the resolution of private names, e.g. `#a` in `this.#a`,
+    /// built by [`SemanticBuilder::with_classes`].
+    #[must_use]
+    pub fn classes(&self) -> Option<&ClassTable> {
+        self.classes.as_ref()
+    }
+
+    /// The functions, class fields and static blocks providing `this`, `super` and `arguments`,
+    /// built by [`SemanticBuilder::with_this_bindings`].
+    #[must_use]
+    pub fn this_bindings(&self) -> Option<&ThisBindings> {
+        self.this_bindings.as_ref()
+    }
+
     #[must_use]
     pub fn trivias(&self) -> &Trivias {
         &self.trivias
@@ -63,8 +129,33 @@ impl<'a> Semantic<'a> {
         &self.module_record
     }
 
+    /// References which do not resolve to any declaration, keyed by name.
+    /// These are references to global variables.
     #[must_use]
-    pub fn symbols(&self) -> &SymbolTable {
-        &self.symbols
+    pub fn unresolved_references(&self) -> &FxHashMap<Atom, Vec<Reference>> {
+        &self.scopes[self.scopes.root_scope_id()].unresolved_references
+    }
+
+    /// A reference is global when it resolves to no symbol,
+    /// it is then left among the unresolved references of the root scope.
+    #[must_use]
+    pub fn is_reference_to_global_variable(&self, ident: &IdentifierReference) -> bool {
+        self.nodes.ast_node_id(AstKind::IdentifierReference(ident)).is_some_and(|id| {
+            self.symbols.get_resolved_reference(id).is_none()
+                && self.unresolved_references().contains_key(&ident.name)
+        })
+    }
+
+    /// Control flow graph, built by [`SemanticBuilder::with_cfg`].
+    #[must_use]
+    pub fn cfg(&self) -> Option<&ControlFlowGraph> {
+        self.cfg.as_ref()
+    }
+
+    /// Keep the options and the allocations of this semantic without the AST,
+    /// for rebuilding the semantic of another program with [`DetachedSemantic::rebuild`].
+    #[must_use]
+    pub fn detach(self) -> DetachedSemantic {
+        DetachedSemantic::new(self)
     }
 }
@@ -73,15 +73,12 @@ impl ModuleRecordBuilder {
         for ee in self.export_entries.drain(..).collect::<Vec<_>>() {
             // a. If ee.[[ModuleRequest]] is null, then
             if ee.module_request.is_none() {
-                let local_name = match &ee.local_name {
-                    ExportLocalName::Name(name) => Some(name),
-                    _ => None,
-                };
+                let local_name = ee.local_name.name();
                 let found_import_entry = self
                     .module_record
                     .import_entries
                     .iter()
-                    .find(|import_entry| Some(&import_entry.local_name) == local_name);
+                    .find(|import_entry| Some(import_entry.local_name.name()) == local_name);
                 match found_import_entry {
                     // i. If ee.[[LocalName]] is not an element of importedBoundNames, then
                     None => {
@@ -111,10 +108,10 @@ impl ModuleRecordBuilder {
                                         }
                                         // `import d from "mod"`
                                         // `export { d }`
-                                        //           ^ this is local_name of ie
-                                        ImportImportName::Default(_) => {
-                                            ExportImportName::Name(ie.local_name.clone())
-                                        }
+                                        //           ^ this re-exports "default" of "mod"
+                                        ImportImportName::Default(span) => ExportImportName::Name(
+                                            NameSpan::new("default".into(), *span),
+                                        ),
                                         ImportImportName::NamespaceObject => unreachable!(),
                                     },
                                     export_name: ee.export_name.clone(),
@@ -232,4 +232,17 @@ mod module_record_tests {
         assert_eq!(module_record.local_export_entries.len(), 1);
         assert_eq!(module_record.local_export_entries[0], export_entry);
     }
+
+    #[test]
+    fn reexport_default_import() {
+        let module_record = build("import d from 'mod'; export { d }");
+        let export_entry = ExportEntry {
+            module_request: Some(NameSpan::new("mod".into(), Span::new(14, 19))),
+            import_name: ExportImportName::Name(NameSpan::new("default".into(), Span::new(7, 8))),
+            export_name: ExportExportName::Name(NameSpan::new("d".into(), Span::new(30, 31))),
+            ..ExportEntry::default()
+        };
+        assert_eq!(module_record.indirect_export_entries.len(), 1);
+        assert_eq!(module_record.indirect_export_entries[0], export_entry);
+    }
 }
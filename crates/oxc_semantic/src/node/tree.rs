@@ -2,6 +2,7 @@ use std::ops::{Deref, DerefMut, Index, IndexMut};
 
 use indextree::{Arena, NodeId};
 use oxc_ast::AstKind;
+use rustc_hash::FxHashMap;
 
 use super::{AstNode, AstNodeId, SemanticNode};
 
@@ -12,6 +13,10 @@ pub struct AstNodes<'a> {
     /// which allows for efficient traversal.
     /// This also allows for parallel traversal by using `rayon`.
     nodes: Arena<SemanticNode<'a>>,
+
+    /// Ids of the AST nodes in the tree, keyed by [`AstKind::address`] and [`AstKind::debug_name`]
+    /// since an enum or a struct may share its address with the node it contains.
+    ast_node_ids: FxHashMap<(usize, &'static str), AstNodeId>,
 }
 
 impl<'a> Index<NodeId> for AstNodes<'a> {
@@ -66,4 +71,83 @@ impl<'a> AstNodes<'a> {
     pub fn parent_kind(&self, node: &AstNode<'a>) -> AstKind<'a> {
         node.parent().map_or(AstKind::Root, |node_id| self.kind(node_id))
     }
+
+    /// Create a node, appended to the children of `parent`.
+    pub fn add_node(&mut self, node: SemanticNode<'a>, parent: Option<AstNodeId>) -> AstNodeId {
+        let kind = node.kind();
+        let node_id = match parent {
+            Some(parent) => parent.append_value(node, &mut self.nodes),
+            None => self.nodes.new_node(node),
+        };
+        let node_id = AstNodeId::new(node_id);
+        if let Some(address) = kind.address() {
+            self.ast_node_ids.entry((address as usize, kind.debug_name())).or_insert(node_id);
+        }
+        node_id
+    }
+
+    pub fn reserve(&mut self, additional: usize) {
+        self.nodes.reserve(additional);
+    }
+
+    /// The id of the AST node `kind` in the tree, found by its address.
+    /// Returns `None` for nodes of other programs.
+    #[must_use]
+    pub fn ast_node_id(&self, kind: AstKind) -> Option<AstNodeId> {
+        let address = kind.address()?;
+        self.ast_node_ids.get(&(address as usize, kind.debug_name())).copied()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::{visit::Visit, AstKind, SourceType};
+
+    use crate::{tester, AstNodeId};
+
+    /// Collect the nodes in visiting order
+    struct Collect<'a>(Vec<AstKind<'a>>);
+
+    impl<'a> Visit<'a> for Collect<'a> {
+        fn enter_node(&mut self, kind: AstKind<'a>) {
+            self.0.push(kind);
+        }
+    }
+
+    #[test]
+    fn ast_node_ids() {
+        let source_text = "
+            let a = [1, , 2]; if (a) { b(a, ...c) } else { class A extends B { #x = 1 } }
+            for (let i = 0; ;) try { a.b = c } finally { ({ d: [e] } = { f }) }
+            export { a }; let g: C.D<typeof a> = <E.F />;
+        ";
+        let allocator = Allocator::default();
+        let source_type = SourceType::from_path("test.tsx").unwrap();
+        let semantic = tester::semantic(&allocator, source_text, source_type, |builder| builder);
+        let program = tester::program(&semantic);
+        let nodes = semantic.nodes();
+
+        // The nodes are created in visiting order, after `AstKind::Root`
+        let mut collect = Collect(vec![]);
+        collect.visit_program(program);
+        let ids: Vec<_> = nodes
+            .iter()
+            .skip(1)
+            .map(|node| AstNodeId::new(nodes.get_node_id(node).unwrap()))
+            .collect();
+        assert_eq!(collect.0.len(), ids.len());
+        for (kind, id) in collect.0.into_iter().zip(ids) {
+            assert_eq!(nodes.ast_node_id(kind), Some(id), "{}", kind.debug_name());
+        }
+
+        // The nodes of another program are not in the tree,
+        // even when they come from another allocator and have the same `NodeId`s
+        let other_allocator = Allocator::default();
+        for allocator in [&allocator, &other_allocator] {
+            let mut collect = Collect(vec![]);
+            collect.visit_program(tester::parse(allocator, source_text, source_type));
+            assert!(collect.0.into_iter().all(|kind| nodes.ast_node_id(kind).is_none()));
+        }
+    }
 }
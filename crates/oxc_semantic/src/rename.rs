@@ -0,0 +1,520 @@
+//! Find all references and rename of a symbol, for editor tooling
+
+use oxc_ast::{
+    ast::{
+        AssignmentTarget, AssignmentTargetPattern, AssignmentTargetProperty,
+        ImportDeclarationSpecifier, ModuleDeclarationKind, ModuleExportName, Property, PropertyKey,
+    },
+    syntax_directed_operations::BoundNames,
+    AstKind, Atom, Span,
+};
+use oxc_diagnostics::thiserror::{self, Error};
+use unicode_id_start::{is_id_continue, is_id_start};
+
+use crate::{scope::ScopeId, symbol::SymbolId, Semantic};
+
+/// Replace the text at `span` with `new_text`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextEdit {
+    pub span: Span,
+    pub new_text: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum RenameError {
+    #[error("`{0}` is not a valid identifier")]
+    InvalidName(String),
+
+    /// The declaring scope already has a binding with the new name, labeled by its span
+    #[error("`{0}` is already declared")]
+    Redeclaration(Atom, Span),
+
+    /// A reference to the symbol would resolve to another binding with the new name
+    #[error("The reference would be shadowed by another declaration of `{0}`")]
+    Shadowed(Atom, Span),
+
+    /// A reference to another binding or global with the new name would resolve to the symbol
+    #[error("The reference to `{0}` would be captured by the renamed declaration")]
+    Captured(Atom, Span),
+
+    /// The declaration is exported directly, e.g. `export let a`, renaming it changes the module's exports
+    #[error("Renaming the declaration changes the name it is exported as")]
+    Exported(Span),
+}
+
+/// Spans of the identifiers which need more than a plain replacement
+#[derive(Default)]
+struct RenameSites {
+    /// `a` in `({ a })`, `let { a } = b` and `({ a } = b)`
+    shorthands: Vec<Span>,
+    /// `a` in `import { a } from "mod"`
+    imports: Vec<Span>,
+    /// `a` in `export { a }`
+    exports: Vec<Span>,
+    /// `a` in `export let a`
+    exported_declarations: Vec<Span>,
+}
+
+impl<'a> Semantic<'a> {
+    /// The symbol declared or referenced by the identifier at `span`, e.g. the cursor position.
+    #[must_use]
+    pub fn symbol_at(&self, span: Span) -> Option<SymbolId> {
+        let contains = |outer: Span| outer.start <= span.start && span.end <= outer.end;
+        let symbols = self.symbols.symbols();
+        symbols
+            .iter()
+            .find(|symbol| contains(symbol.span()))
+            .or_else(|| {
+                symbols.iter().find(|symbol| {
+                    self.symbols.resolved_references(symbol.id()).any(|r| contains(r.span()))
+                })
+            })
+            .map(crate::Symbol::id)
+            .or_else(|| {
+                // Redeclarations such as the second `a` in `var a; var a;` are not the symbol's span
+                self.nodes.iter().find_map(|node| match node.get().kind() {
+                    AstKind::BindingIdentifier(ident) if contains(ident.span) => {
+                        self.binding_symbol(node.get().scope_id(), &ident.name)
+                    }
+                    _ => None,
+                })
+            })
+    }
+
+    /// Spans of all references to the symbol in source order, excluding its declarations.
+    #[must_use]
+    pub fn find_references(&self, symbol_id: SymbolId) -> Vec<Span> {
+        let mut spans: Vec<Span> = self
+            .symbols
+            .resolved_references(symbol_id)
+            .map(crate::ResolvedReference::span)
+            .collect();
+        spans.sort_unstable_by_key(|span| span.start);
+        spans
+    }
+
+    /// Spans of all declarations of the symbol in source order, e.g. both `a` in `var a; var a;`
+    #[must_use]
+    pub fn find_declarations(&self, symbol_id: SymbolId) -> Vec<Span> {
+        let symbol = &self.symbols[symbol_id];
+        let mut spans: Vec<Span> = self
+            .nodes
+            .iter()
+            .filter_map(|node| match node.get().kind() {
+                AstKind::BindingIdentifier(ident)
+                    if ident.name == symbol.name()
+                        && self.binding_symbol(node.get().scope_id(), &ident.name)
+                            == Some(symbol_id) =>
+                {
+                    Some(ident.span)
+                }
+                _ => None,
+            })
+            .collect();
+        if !spans.contains(&symbol.span()) {
+            spans.push(symbol.span());
+        }
+        spans.sort_unstable_by_key(|span| span.start);
+        spans
+    }
+
+    /// Text edits renaming the symbol and all its references to `new_name`, in source order.
+    ///
+    /// Shorthand properties are expanded (`{ a }` to `{ a: b }`) and import and export
+    /// specifiers are aliased (`export { a }` to `export { b as a }`) so the program behaves the same.
+    ///
+    /// # Errors
+    ///
+    /// * `new_name` is not a valid identifier
+    /// * `new_name` collides with or is shadowed by another declaration, or a reference to
+    ///   another `new_name` would resolve to the renamed symbol
+    /// * the declaration is exported directly, e.g. `export function a() {}`
+    pub fn rename(
+        &self,
+        symbol_id: SymbolId,
+        new_name: &str,
+    ) -> Result<Vec<TextEdit>, RenameError> {
+        if !is_valid_identifier(new_name) {
+            return Err(RenameError::InvalidName(new_name.to_string()));
+        }
+        let symbol = &self.symbols[symbol_id];
+        let old_name = symbol.name();
+        if old_name.as_str() == new_name {
+            return Ok(vec![]);
+        }
+        let new_name = Atom::from(new_name);
+        self.check_rename_conflicts(symbol_id, &new_name)?;
+
+        let sites = self.rename_sites();
+        let mut spans = self.find_declarations(symbol_id);
+        spans.extend(self.find_references(symbol_id));
+        spans.sort_unstable_by_key(|span| span.start);
+        spans.dedup();
+
+        spans
+            .into_iter()
+            .map(|span| {
+                if sites.exported_declarations.contains(&span) {
+                    return Err(RenameError::Exported(span));
+                }
+                let new_text = if sites.shorthands.contains(&span) {
+                    format!("{old_name}: {new_name}")
+                } else if sites.imports.contains(&span) {
+                    format!("{old_name} as {new_name}")
+                } else if sites.exports.contains(&span) {
+                    format!("{new_name} as {old_name}")
+                } else {
+                    new_name.to_string()
+                };
+                Ok(TextEdit { span, new_text })
+            })
+            .collect()
+    }
+
+    /// The symbol a binding declared in `scope_id` belongs to,
+    /// `var` and function declarations are hoisted to an enclosing scope.
+    fn binding_symbol(&self, scope_id: ScopeId, name: &Atom) -> Option<SymbolId> {
+        self.scopes
+            .ancestors(scope_id)
+            .find_map(|scope_id| self.scopes[scope_id].get().get_variable_symbol_id(name))
+    }
+
+    /// The scope declaring the symbol,
+    /// `var` declarations are added to every scope up to the function scope.
+    fn symbol_scope(&self, symbol_id: SymbolId) -> Option<ScopeId> {
+        let symbol = &self.symbols[symbol_id];
+        let scope_id = self.nodes[symbol.declaration()].scope_id();
+        self.scopes
+            .ancestors(scope_id)
+            .filter(|scope_id| {
+                self.scopes[*scope_id].get().get_variable_symbol_id(symbol.name())
+                    == Some(symbol_id)
+            })
+            .last()
+            .map(ScopeId::from)
+    }
+
+    /// Whether a reference in `scope_id` reaches `target` before `stop` when walking up the scope chain.
+    fn reaches_scope(&self, scope_id: ScopeId, target: ScopeId, stop: Option<ScopeId>) -> bool {
+        self.scopes
+            .ancestors(scope_id)
+            .take_while(|id| stop.map_or(true, |stop| *id != *stop))
+            .any(|id| id == *target)
+    }
+
+    fn check_rename_conflicts(
+        &self,
+        symbol_id: SymbolId,
+        new_name: &Atom,
+    ) -> Result<(), RenameError> {
+        let Some(declaration_scope) = self.symbol_scope(symbol_id) else { return Ok(()) };
+
+        // `let a, b` renaming `a` to `b`
+        if let Some(other) = self.scopes[declaration_scope].get_variable_symbol_id(new_name) {
+            return Err(RenameError::Redeclaration(new_name.clone(), self.symbols[other].span()));
+        }
+
+        // `let a; function f() { let b; a }` renaming `a` to `b`
+        for reference in self.symbols.resolved_references(symbol_id) {
+            let scope_id = self.nodes[reference.reference.ast_node_id].scope_id();
+            let shadowed = self
+                .scopes
+                .ancestors(scope_id)
+                .take_while(|id| *id != *declaration_scope)
+                .any(|id| self.scopes[id].get().get_variable_symbol_id(new_name).is_some());
+            if shadowed {
+                return Err(RenameError::Shadowed(new_name.clone(), reference.span()));
+            }
+        }
+
+        // `let b; function f() { let a; b }` renaming `a` to `b`
+        for other in self.symbols.symbols().iter().filter(|symbol| symbol.name() == new_name) {
+            let other_scope = self.symbol_scope(other.id());
+            for reference in self.symbols.resolved_references(other.id()) {
+                let scope_id = self.nodes[reference.reference.ast_node_id].scope_id();
+                if self.reaches_scope(scope_id, declaration_scope, other_scope) {
+                    return Err(RenameError::Captured(new_name.clone(), reference.span()));
+                }
+            }
+        }
+
+        // `function f() { let a; console }` renaming `a` to `console`
+        for reference in self.unresolved_references().get(new_name).into_iter().flatten() {
+            let scope_id = self.nodes[reference.ast_node_id].scope_id();
+            if self.reaches_scope(scope_id, declaration_scope, None) {
+                return Err(RenameError::Captured(new_name.clone(), reference.span));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn rename_sites(&self) -> RenameSites {
+        let mut sites = RenameSites::default();
+        for node in self.nodes.iter() {
+            match node.get().kind() {
+                AstKind::Property(Property {
+                    shorthand: true,
+                    key: PropertyKey::Identifier(key),
+                    ..
+                }) => sites.shorthands.push(key.span),
+                AstKind::AssignmentTarget(AssignmentTarget::AssignmentTargetPattern(
+                    AssignmentTargetPattern::ObjectAssignmentTarget(target),
+                )) => {
+                    for property in &target.properties {
+                        if let AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(
+                            property,
+                        ) = property
+                        {
+                            sites.shorthands.push(property.binding.span);
+                        }
+                    }
+                }
+                AstKind::ModuleDeclaration(decl) => match &decl.kind {
+                    ModuleDeclarationKind::ImportDeclaration(decl) => {
+                        for specifier in &decl.specifiers {
+                            if let ImportDeclarationSpecifier::ImportSpecifier(specifier) =
+                                specifier
+                                && matches!(&specifier.imported, ModuleExportName::Identifier(ident) if ident.span == specifier.local.span)
+                            {
+                                sites.imports.push(specifier.local.span);
+                            }
+                        }
+                    }
+                    ModuleDeclarationKind::ExportNamedDeclaration(decl) => {
+                        if let Some(declaration) = &decl.declaration {
+                            sites
+                                .exported_declarations
+                                .extend(declaration.bound_names().iter().map(|ident| ident.span));
+                        }
+                        if decl.source.is_none() {
+                            for specifier in &decl.specifiers {
+                                if let (
+                                    ModuleExportName::Identifier(local),
+                                    ModuleExportName::Identifier(exported),
+                                ) = (&specifier.local, &specifier.exported)
+                                    && local.span == exported.span
+                                {
+                                    sites.exports.push(local.span);
+                                }
+                            }
+                        }
+                    }
+                    _ => {}
+                },
+                _ => {}
+            }
+        }
+        sites
+    }
+}
+
+/// Whether `name` can be used as a binding, reserved words in strict mode code are rejected.
+fn is_valid_identifier(name: &str) -> bool {
+    const RESERVED_WORDS: [&str; 46] = [
+        "await",
+        "break",
+        "case",
+        "catch",
+        "class",
+        "const",
+        "continue",
+        "debugger",
+        "default",
+        "delete",
+        "do",
+        "else",
+        "enum",
+        "export",
+        "extends",
+        "false",
+        "finally",
+        "for",
+        "function",
+        "if",
+        "import",
+        "in",
+        "instanceof",
+        "new",
+        "null",
+        "return",
+        "super",
+        "switch",
+        "this",
+        "throw",
+        "true",
+        "try",
+        "typeof",
+        "var",
+        "void",
+        "while",
+        "with",
+        "yield",
+        "implements",
+        "interface",
+        "let",
+        "package",
+        "private",
+        "protected",
+        "public",
+        "static",
+    ];
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c == '$' || c == '_' || is_id_start(c))
+        && chars.all(|c| c == '$' || c == '\u{200c}' || c == '\u{200d}' || is_id_continue(c))
+        && !RESERVED_WORDS.contains(&name)
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::{SourceType, Span};
+
+    use super::RenameError;
+    use crate::tester;
+
+    /// Rename the binding at the first occurrence of the word `name` in `source_text`
+    fn rename(source_text: &str, name: &str, new_name: &str) -> Result<String, RenameError> {
+        let allocator = Allocator::default();
+        let source_type = *SourceType::default().with_module(true).with_jsx(true);
+        let semantic = tester::semantic(&allocator, source_text, source_type, |builder| builder);
+
+        let is_word = |c: char| c.is_alphanumeric() || c == '_' || c == '$';
+        let (start, _) = source_text
+            .match_indices(name)
+            .find(|(i, _)| {
+                !source_text[..*i].ends_with(is_word)
+                    && !source_text[i + name.len()..].starts_with(is_word)
+            })
+            .unwrap();
+        let start = u32::try_from(start).unwrap();
+        let symbol_id = semantic.symbol_at(Span::new(start, start)).unwrap();
+        let edits = semantic.rename(symbol_id, new_name)?;
+
+        let mut output = source_text.to_string();
+        for edit in edits.iter().rev() {
+            output.replace_range(edit.span.start as usize..edit.span.end as usize, &edit.new_text);
+        }
+        Ok(output)
+    }
+
+    #[test]
+    fn references() {
+        assert_eq!(
+            rename("let a = 1; a + a; function f() { return a; }", "a", "b"),
+            Ok("let b = 1; b + b; function f() { return b; }".to_string())
+        );
+        // From a reference and redeclarations
+        assert_eq!(
+            rename("f(a); var a; var a = a;", "a", "b"),
+            Ok("f(b); var b; var b = b;".to_string())
+        );
+        assert_eq!(
+            rename("function f(a) { return () => a; } let a = 1;", "a", "b"),
+            Ok("function f(b) { return () => b; } let a = 1;".to_string())
+        );
+        assert_eq!(
+            rename("const A = {}; <A.B />;", "A", "C"),
+            Ok("const C = {}; <C.B />;".to_string())
+        );
+    }
+
+    #[test]
+    fn find_references() {
+        let source_text = "let a = 1; a++; { let a; a } export { a }";
+        let allocator = Allocator::default();
+        let source_type = *SourceType::default().with_module(true);
+        let semantic = tester::semantic(&allocator, source_text, source_type, |builder| builder);
+
+        let symbol_id = semantic.symbol_at(Span::new(4, 5)).unwrap();
+        assert_eq!(semantic.find_declarations(symbol_id), vec![Span::new(4, 5)]);
+        assert_eq!(semantic.find_references(symbol_id), vec![Span::new(11, 12), Span::new(38, 39)]);
+        assert_eq!(semantic.symbol_at(Span::new(38, 39)), Some(symbol_id));
+        assert_ne!(semantic.symbol_at(Span::new(26, 27)), Some(symbol_id));
+        assert_eq!(semantic.symbol_at(Span::new(8, 9)), None);
+    }
+
+    #[test]
+    fn shorthand_properties() {
+        assert_eq!(
+            rename("let a; f({ a }); let { a: x } = o; ({ a } = o);", "a", "b"),
+            Ok("let b; f({ a: b }); let { a: x } = o; ({ a: b } = o);".to_string())
+        );
+        assert_eq!(
+            rename("let { a = 1 } = o; a;", "a", "b"),
+            Ok("let { a: b = 1 } = o; b;".to_string())
+        );
+        assert_eq!(
+            rename("let a; ({ a = 1 } = o);", "a", "b"),
+            Ok("let b; ({ a: b = 1 } = o);".to_string())
+        );
+    }
+
+    #[test]
+    fn module_names() {
+        assert_eq!(
+            rename("import { a } from 'mod'; a;", "a", "b"),
+            Ok("import { a as b } from 'mod'; b;".to_string())
+        );
+        assert_eq!(
+            rename("import { x as y } from 'mod'; y;", "y", "z"),
+            Ok("import { x as z } from 'mod'; z;".to_string())
+        );
+        assert_eq!(
+            rename("let a; export { a };", "a", "b"),
+            Ok("let b; export { b as a };".to_string())
+        );
+        assert_eq!(
+            rename("let y; export { y as x };", "y", "z"),
+            Ok("let z; export { z as x };".to_string())
+        );
+        assert_eq!(
+            rename("export let a = 1;", "a", "b"),
+            Err(RenameError::Exported(Span::new(11, 12)))
+        );
+        assert_eq!(
+            rename("export default function f() {}", "f", "g"),
+            Ok("export default function g() {}".to_string())
+        );
+    }
+
+    #[test]
+    fn conflicts() {
+        assert_eq!(
+            rename("let a, b;", "a", "b"),
+            Err(RenameError::Redeclaration("b".into(), Span::new(7, 8)))
+        );
+        assert_eq!(
+            rename("function f() { { var a; } let b; }", "a", "b"),
+            Err(RenameError::Redeclaration("b".into(), Span::new(30, 31)))
+        );
+        assert_eq!(
+            rename("let a; function f() { let b; a; }", "a", "b"),
+            Err(RenameError::Shadowed("b".into(), Span::new(29, 30)))
+        );
+        assert_eq!(
+            rename("let b; function f() { let a; b; }", "a", "b"),
+            Err(RenameError::Captured("b".into(), Span::new(29, 30)))
+        );
+        assert_eq!(
+            rename("function f() { let a; console.log(a); }", "a", "console"),
+            Err(RenameError::Captured("console".into(), Span::new(22, 29)))
+        );
+        // No conflict when the other `b` is not reachable from the references
+        assert_eq!(
+            rename("let a; a; function f() { let b; b; }", "a", "b"),
+            Ok("let b; b; function f() { let b; b; }".to_string())
+        );
+    }
+
+    #[test]
+    fn invalid_names() {
+        for name in ["", "1a", "a-b", "class", "let", "yield"] {
+            assert_eq!(
+                rename("let a;", "a", name),
+                Err(RenameError::InvalidName(name.to_string()))
+            );
+        }
+        assert_eq!(rename("let a;", "a", "$ñ_1"), Ok("let $ñ_1;".to_string()));
+    }
+}
@@ -14,9 +14,15 @@ pub struct ScopeBuilder {
 impl ScopeBuilder {
     #[must_use]
     pub fn new(source_type: SourceType) -> Self {
+        Self::from_tree(source_type, ScopeTree::new(false))
+    }
+
+    /// Build into a previously used scope tree, keeping its allocation.
+    #[must_use]
+    pub fn from_tree(source_type: SourceType, mut scopes: ScopeTree) -> Self {
         // Module code is always strict mode code.
         let strict_mode = source_type.is_module() || source_type.always_strict();
-        let scopes = ScopeTree::new(strict_mode);
+        scopes.reset(strict_mode);
         let current_scope_id = scopes.root_scope_id();
         Self { scopes, current_scope_id }
     }
@@ -58,35 +64,51 @@ impl ScopeBuilder {
         }
     }
 
+    /// Resolve the references of the scope being left against its declarations,
+    /// the remaining references are moved up to the parent scope.
+    /// This runs after all declarations of the scope are bound, so hoisted declarations
+    /// such as `function f() { g() } function g() {}` are found.
+    /// References left in the root scope are references to global variables.
     pub fn resolve_reference(&mut self, symbol_table: &mut SymbolTable) {
         // At the initial stage, all references are unresolved.
         let all_references = {
             let current_scope = self.current_scope_mut();
             std::mem::take(&mut current_scope.unresolved_references)
         };
-        let mut unresolved_references = FxHashMap::default();
-
-        'outer: for (variable, reference) in all_references {
-            // The reference resolves to the first matching symbol in the scope chain
-            let scope_chain = self.scopes.ancestors(self.current_scope_id);
-            for scope in scope_chain {
-                let scope = &self.scopes[scope];
-                if let Some(symbol_id) = scope.get().get_variable_symbol_id(&variable) {
-                    // We have resolved this reference.
-                    let symbol = &mut symbol_table[symbol_id];
-                    symbol.add_references(&reference);
-                    for r in reference {
-                        symbol_table.resolve_reference(r.ast_node_id, r.resolve_to(symbol_id));
+        let mut unresolved_references: FxHashMap<Atom, Vec<Reference>> = FxHashMap::default();
+
+        for (variable, mut references) in all_references {
+            if let Some(symbol_id) = self.current_scope().get_variable_symbol_id(&variable) {
+                // Type references skip symbols which only exist in the value space,
+                // value references skip symbols which only exist in the type space.
+                // `export { a }` exports both meanings.
+                let symbol = &mut symbol_table[symbol_id];
+                let (resolved, rest): (Vec<_>, Vec<_>) = references.into_iter().partition(|r| {
+                    if r.is_type() {
+                        symbol.is_type_referenceable()
+                    } else {
+                        r.is_export() || symbol.is_value_referenceable()
                     }
-                    continue 'outer;
+                });
+                // We have resolved these references.
+                symbol.add_references(&resolved);
+                for r in resolved {
+                    symbol_table.resolve_reference(r.ast_node_id, r.resolve_to(symbol_id));
                 }
+                references = rest;
+            }
+            if !references.is_empty() {
+                unresolved_references.insert(variable, references);
             }
-
-            unresolved_references.insert(variable, reference);
         }
 
-        let current_scope = self.current_scope_mut();
-        current_scope.unresolved_references = unresolved_references;
+        let scope = match self.scopes[self.current_scope_id.indextree_id()].parent() {
+            Some(parent_id) => self.scopes[parent_id].get_mut(),
+            None => self.current_scope_mut(),
+        };
+        for (variable, references) in unresolved_references {
+            scope.unresolved_references.entry(variable).or_default().extend(references);
+        }
     }
 
     pub fn reference_identifier(&mut self, name: &Atom, reference: Reference) {
@@ -109,13 +131,75 @@ impl ScopeBuilder {
                 // E.g., `let c = class A { foo() { console.log(A) } }`
                 Some(ScopeFlags::empty())
             }
+            // Scopes for type parameters, e.g. `T` in `interface A<T> {}`
+            AstKind::Class(class) if class.type_parameters.is_some() => Some(ScopeFlags::empty()),
             AstKind::BlockStatement(_)
             | AstKind::CatchClause(_)
             | AstKind::ForStatement(_)
             | AstKind::ForInStatement(_)
             | AstKind::ForOfStatement(_)
-            | AstKind::SwitchStatement(_) => Some(ScopeFlags::empty()),
+            | AstKind::SwitchStatement(_)
+            | AstKind::TSInterfaceDeclaration(_)
+            | AstKind::TSTypeAliasDeclaration(_)
+            | AstKind::TSMethodSignature(_) => Some(ScopeFlags::empty()),
             _ => None,
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::SourceType;
+
+    use crate::{tester, ResolvedReference};
+
+    #[test]
+    fn unresolved_references() {
+        let source_text = "
+            function f() { g(); h(); { let a; a; } a; }
+            function g() {}
+            class A { m() { return [A, b, this.c]; } }
+        ";
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let semantic = tester::semantic(&allocator, source_text, source_type, |builder| builder);
+
+        let mut unresolved: Vec<_> = semantic
+            .unresolved_references()
+            .iter()
+            .map(|(name, references)| (name.as_str(), references.len()))
+            .collect();
+        unresolved.sort_unstable();
+        assert_eq!(unresolved, vec![("a", 1), ("b", 1), ("h", 1)]);
+
+        // Hoisted declarations are resolved
+        let root = &semantic.scopes()[semantic.scopes().root_scope_id()];
+        let g = root.get_variable_symbol_id(&"g".into()).unwrap();
+        assert_eq!(semantic.symbols()[g].references().len(), 1);
+    }
+
+    #[test]
+    fn references_by_meaning() {
+        let source_text = "const A = 1; { type A = string; A; let a: A; }";
+        let allocator = Allocator::default();
+        let source_type = SourceType::from_path("test.ts").unwrap();
+        let semantic = tester::semantic(&allocator, source_text, source_type, |builder| builder);
+
+        let symbols = semantic.symbols();
+        let references = |is_type: bool| {
+            let symbol = symbols
+                .iter()
+                .find(|symbol| symbol.name() == &"A" && symbol.is_type_only() == is_type)
+                .unwrap();
+            symbols
+                .resolved_references(symbol.id())
+                .map(ResolvedReference::is_type)
+                .collect::<Vec<_>>()
+        };
+        // `A;` skips the type alias of the inner block
+        assert_eq!(references(false), vec![false]);
+        assert_eq!(references(true), vec![true]);
+        assert!(semantic.unresolved_references().is_empty());
+    }
+}
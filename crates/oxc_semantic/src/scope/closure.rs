@@ -0,0 +1,333 @@
+//! Free variables of functions, i.e. the bindings a closure captures from its enclosing scopes
+
+use bitflags::bitflags;
+use indextree::NodeId;
+use oxc_ast::{AstKind, GetSpan};
+use rustc_hash::FxHashMap;
+
+use super::{ScopeFlags, ScopeId, ScopeTree};
+use crate::{
+    node::{AstNode, AstNodes},
+    symbol::{SymbolId, SymbolTable},
+};
+
+bitflags! {
+    #[derive(Default)]
+    pub struct CaptureFlags: u8 {
+        /// The closure is created inside a loop while the binding is declared outside of it,
+        /// so the closures of all iterations share the binding,
+        /// e.g. `i` in `for (var i = 0; i < 3; i++) { fns.push(() => i) }`
+        const InLoop = 1 << 0;
+        /// The binding is assigned outside of the closure after it is created,
+        /// the closure sees the new value.
+        /// Writes in other functions and in a loop around the closure count as later assignments.
+        const Reassigned = 1 << 1;
+    }
+}
+
+/// A binding captured by a function from an enclosing scope
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FreeVariable {
+    pub symbol_id: SymbolId,
+    pub flags: CaptureFlags,
+}
+
+impl FreeVariable {
+    #[must_use]
+    pub fn is_in_loop(&self) -> bool {
+        self.flags.contains(CaptureFlags::InLoop)
+    }
+
+    #[must_use]
+    pub fn is_reassigned(&self) -> bool {
+        self.flags.contains(CaptureFlags::Reassigned)
+    }
+}
+
+impl ScopeTree {
+    /// The bindings captured by the function or arrow function of `scope_id`, ordered by [`SymbolId`].
+    /// Bindings used by nested functions are captured by the outer function as well.
+    /// Type references do not capture.
+    /// Computed by [`crate::SemanticBuilder::with_free_variables`], empty otherwise.
+    #[must_use]
+    pub fn free_variables(&self, scope_id: ScopeId) -> &[FreeVariable] {
+        self.free_variables.get(&scope_id).map_or(&[], Vec::as_slice)
+    }
+
+    /// Whether the binding is captured by any function.
+    #[must_use]
+    pub fn is_captured(&self, symbol_id: SymbolId) -> bool {
+        self.captures.contains_key(&symbol_id)
+    }
+
+    /// The function scopes capturing the binding, in no particular order.
+    #[must_use]
+    pub fn capturing_functions(&self, symbol_id: SymbolId) -> &[ScopeId] {
+        self.captures.get(&symbol_id).map_or(&[], Vec::as_slice)
+    }
+
+    /// Compute the free variables of all functions from the resolved references.
+    pub(crate) fn compute_free_variables(&mut self, nodes: &AstNodes, symbols: &SymbolTable) {
+        self.free_variables.clear();
+        self.captures.clear();
+
+        let symbol_scopes = self.symbol_scopes();
+        let function_nodes: FxHashMap<ScopeId, &AstNode> = nodes
+            .iter()
+            .filter(|node| {
+                matches!(node.get().kind(), AstKind::Function(_) | AstKind::ArrowExpression(_))
+            })
+            .map(|node| (node.get().scope_id(), node))
+            .collect();
+
+        let mut free_variables: FxHashMap<ScopeId, Vec<FreeVariable>> = FxHashMap::default();
+        let mut captures: FxHashMap<SymbolId, Vec<ScopeId>> = FxHashMap::default();
+        for symbol in symbols.symbols() {
+            let symbol_id = symbol.id();
+            let Some(&declaration_scope) = symbol_scopes.get(&symbol_id) else { continue };
+            let mut capturing_functions: Vec<ScopeId> = vec![];
+            let mut writes: Vec<Write> = vec![];
+            for reference in symbols.resolved_references(symbol_id).filter(|r| !r.is_type()) {
+                let reference_scope = nodes[reference.reference.ast_node_id].scope_id();
+                if reference.is_write() {
+                    let node = &nodes[reference.reference.ast_node_id.indextree_id()];
+                    writes.push(Write {
+                        start: reference.span().start,
+                        scope_id: reference_scope,
+                        loops: self.enclosing_loops(nodes, node, declaration_scope),
+                        in_function: self
+                            .ancestors(reference_scope)
+                            .map(ScopeId::from)
+                            .take_while(|scope_id| *scope_id != declaration_scope)
+                            .any(|scope_id| self.is_function_scope(scope_id, &function_nodes)),
+                    });
+                }
+                for scope_id in self.ancestors(reference_scope).map(ScopeId::from) {
+                    // the enclosing functions are already found from another reference
+                    if scope_id == declaration_scope || capturing_functions.contains(&scope_id) {
+                        break;
+                    }
+                    if self.is_function_scope(scope_id, &function_nodes) {
+                        capturing_functions.push(scope_id);
+                    }
+                }
+            }
+            for &scope_id in &capturing_functions {
+                let function = function_nodes[&scope_id];
+                let loops = self.enclosing_loops(nodes, function, declaration_scope);
+                let end = function.get().kind().span().end;
+                let mut flags = CaptureFlags::empty();
+                if !loops.is_empty() {
+                    flags |= CaptureFlags::InLoop;
+                }
+                // writes inside the function itself do not change the captured binding
+                if writes.iter().any(|write| {
+                    !self.ancestors(write.scope_id).any(|id| id == *scope_id)
+                        && (write.start >= end
+                            || write.in_function
+                            || write.loops.iter().any(|id| loops.contains(id)))
+                }) {
+                    flags |= CaptureFlags::Reassigned;
+                }
+                free_variables.entry(scope_id).or_default().push(FreeVariable { symbol_id, flags });
+            }
+            if !capturing_functions.is_empty() {
+                captures.insert(symbol_id, capturing_functions);
+            }
+        }
+        for variables in free_variables.values_mut() {
+            variables.sort_unstable_by_key(|variable| variable.symbol_id.index0());
+        }
+        self.free_variables = free_variables;
+        self.captures = captures;
+    }
+
+    fn is_function_scope(
+        &self,
+        scope_id: ScopeId,
+        function_nodes: &FxHashMap<ScopeId, &AstNode>,
+    ) -> bool {
+        self[scope_id].flags.contains(ScopeFlags::Function)
+            && function_nodes.contains_key(&scope_id)
+    }
+
+    /// The loops around `node` which are inside `declaration_scope`, innermost first.
+    fn enclosing_loops(
+        &self,
+        nodes: &AstNodes,
+        node: &AstNode,
+        declaration_scope: ScopeId,
+    ) -> Vec<NodeId> {
+        let mut loops = vec![];
+        let mut parent_id = node.parent();
+        while let Some(node_id) = parent_id {
+            let node = &nodes[node_id];
+            let scope_id = node.get().scope_id();
+            // `for` statements create the scope of their per-iteration `let` bindings
+            let loop_scope_id = match node.get().kind() {
+                AstKind::ForStatement(_)
+                | AstKind::ForInStatement(_)
+                | AstKind::ForOfStatement(_) => self[*scope_id].parent().map(ScopeId::from),
+                AstKind::WhileStatement(_) | AstKind::DoWhileStatement(_) => Some(scope_id),
+                _ => None,
+            };
+            if let Some(loop_scope_id) = loop_scope_id
+                && self.ancestors(loop_scope_id).any(|id| id == *declaration_scope)
+            {
+                loops.push(node_id);
+            }
+            if scope_id == declaration_scope {
+                break;
+            }
+            parent_id = node.parent();
+        }
+        loops
+    }
+}
+
+/// A write to a captured binding, which reassigns it for the closures created before it
+/// or in the same loop, see [`CaptureFlags::Reassigned`]
+struct Write {
+    start: u32,
+    scope_id: ScopeId,
+    /// See [`ScopeTree::enclosing_loops`]
+    loops: Vec<NodeId>,
+    /// Inside of a function within the declaration scope, which may be called at any time
+    in_function: bool,
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::{AstKind, SourceType};
+
+    use crate::tester;
+
+    /// The free variables of each function in source order, as names with flags `L` (in loop) and `R` (reassigned)
+    fn free_variables(source_text: &str) -> Vec<Vec<String>> {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let semantic = tester::semantic(&allocator, source_text, source_type, |builder| {
+            builder.with_free_variables(true)
+        });
+
+        semantic
+            .nodes()
+            .iter()
+            .filter(|node| {
+                matches!(node.get().kind(), AstKind::Function(_) | AstKind::ArrowExpression(_))
+            })
+            .map(|node| {
+                semantic
+                    .scopes()
+                    .free_variables(node.get().scope_id())
+                    .iter()
+                    .map(|variable| {
+                        let mut name = semantic.symbols()[variable.symbol_id].name().to_string();
+                        if variable.is_in_loop() {
+                            name.push_str(":L");
+                        }
+                        if variable.is_reassigned() {
+                            name.push_str(":R");
+                        }
+                        name
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn captures() {
+        assert_eq!(
+            free_variables(
+                "let a = 1, b = 2; function f(c) { let d; return () => a + c + d + g; }"
+            ),
+            vec![vec!["a"], vec!["a", "c", "d"]]
+        );
+        // Nested functions capture through the outer function, recursion captures the function itself
+        assert_eq!(
+            free_variables("let a; function f() { function g() { return a + f(); } }"),
+            vec![vec!["a", "f"], vec!["a", "f"]]
+        );
+        // Type references do not capture
+        let allocator = Allocator::default();
+        let source_type = *SourceType::default().with_typescript(true);
+        let source_text = "type T = 1; let a: T; function f(b: T) { return a }";
+        let semantic = tester::semantic(&allocator, source_text, source_type, |builder| {
+            builder.with_free_variables(true)
+        });
+        let captured: Vec<_> = semantic
+            .symbols()
+            .symbols()
+            .iter()
+            .filter(|symbol| semantic.scopes().is_captured(symbol.id()))
+            .map(|symbol| symbol.name().as_str())
+            .collect();
+        assert_eq!(captured, vec!["a"]);
+        let a = semantic
+            .symbols()
+            .symbols()
+            .iter()
+            .find(|symbol| symbol.name().as_str() == "a")
+            .unwrap();
+        assert_eq!(semantic.scopes().capturing_functions(a.id()).len(), 1);
+    }
+
+    #[test]
+    fn reassigned() {
+        assert_eq!(
+            free_variables("let a = 1, b = 1; b = 2; const f = () => a + b; a = 2;"),
+            vec![vec!["a:R", "b"]]
+        );
+        // Only the writes outside of the capturing function
+        assert_eq!(free_variables("let a; function f() { a++ }"), vec![vec!["a"]]);
+        assert_eq!(
+            free_variables("let a; function f() { const g = () => a; a = 1; }"),
+            vec![vec!["a"], vec!["a:R"]]
+        );
+        // Writes by other functions may run at any time
+        assert_eq!(
+            free_variables("let a; function set() { a = 1; } const get = () => a;"),
+            vec![vec!["a"], vec!["a:R"]]
+        );
+        assert_eq!(
+            free_variables("let a; function f() { a = 1; const g = () => a; }"),
+            vec![vec!["a"], vec!["a:R"]]
+        );
+    }
+
+    #[test]
+    fn loops() {
+        assert_eq!(
+            free_variables("for (var i = 0; i < 3; i++) { fns.push(() => i); }"),
+            vec![vec!["i:L:R"]]
+        );
+        // Writes before the closure in the same loop run after it is created
+        assert_eq!(
+            free_variables("let i; while (c) { i++; fns.push(() => i); }"),
+            vec![vec!["i:L:R"]]
+        );
+        assert_eq!(free_variables("let i; while (c) { fns.push(() => i); }"), vec![vec!["i:L"]]);
+        assert_eq!(free_variables("let i; while (c) { i++; } const f = () => i;"), vec![vec!["i"]]);
+        // Per-iteration bindings
+        assert_eq!(
+            free_variables(
+                "for (let i = 0; i < 3; i++) { let j = i; fns.push(() => i + j); }
+                 for (const k of ks) { fns.push(() => k); }"
+            ),
+            vec![vec!["i", "j"], vec!["k"]]
+        );
+        assert_eq!(
+            free_variables(
+                "let i = 0; while (i < 3) { fns.push(function () { return i; }); i++; }"
+            ),
+            vec![vec!["i:L:R"]]
+        );
+        // The loop is outside of the declaration
+        assert_eq!(
+            free_variables("for (;;) { function f() { let a; do { g(() => a); } while (0) } }"),
+            vec![vec![], vec!["a:L"]]
+        );
+    }
+}
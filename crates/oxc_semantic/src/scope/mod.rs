@@ -4,6 +4,7 @@
 #![allow(non_upper_case_globals)]
 
 mod builder;
+mod closure;
 mod id;
 mod tree;
 
@@ -11,7 +12,12 @@ use bitflags::bitflags;
 use oxc_ast::Atom;
 use rustc_hash::FxHashMap;
 
-pub use self::{builder::ScopeBuilder, id::ScopeId, tree::ScopeTree};
+pub use self::{
+    builder::ScopeBuilder,
+    closure::{CaptureFlags, FreeVariable},
+    id::ScopeId,
+    tree::ScopeTree,
+};
 use crate::symbol::{Reference, SymbolId};
 
 #[derive(Debug, Clone)]
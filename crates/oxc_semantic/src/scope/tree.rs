@@ -1,15 +1,22 @@
 use std::ops::{Deref, DerefMut, Index, IndexMut};
 
 use indextree::{Ancestors, Arena, Node, NodeId};
+use rustc_hash::FxHashMap;
 
-use super::{Scope, ScopeFlags, ScopeId};
-use crate::node::AstNode;
+use super::{FreeVariable, Scope, ScopeFlags, ScopeId};
+use crate::{node::AstNode, symbol::SymbolId};
 
 #[derive(Debug)]
 pub struct ScopeTree {
     scopes: Arena<Scope>,
 
     root_scope_id: ScopeId,
+
+    /// Keyed by function scopes, see [`ScopeTree::free_variables`]
+    pub(super) free_variables: FxHashMap<ScopeId, Vec<FreeVariable>>,
+
+    /// The function scopes capturing each binding, see [`ScopeTree::capturing_functions`]
+    pub(super) captures: FxHashMap<SymbolId, Vec<ScopeId>>,
 }
 
 impl ScopeTree {
@@ -18,7 +25,21 @@ impl ScopeTree {
         let mut scopes = Arena::new();
         let root_scope = Scope::new(ScopeFlags::Top, root_strict_mode);
         let root_scope_id = scopes.new_node(root_scope).into();
-        Self { scopes, root_scope_id }
+        Self {
+            scopes,
+            root_scope_id,
+            free_variables: FxHashMap::default(),
+            captures: FxHashMap::default(),
+        }
+    }
+
+    /// Remove all scopes except for a new root scope.
+    pub fn reset(&mut self, root_strict_mode: bool) {
+        self.scopes.clear();
+        self.free_variables.clear();
+        self.captures.clear();
+        let root_scope = Scope::new(ScopeFlags::Top, root_strict_mode);
+        self.root_scope_id = self.scopes.new_node(root_scope).into();
     }
 
     #[must_use]
@@ -61,6 +82,20 @@ impl ScopeTree {
         self.scopes[parent_id].get_mut()
     }
 
+    /// The outermost scope binding each symbol.
+    /// `var` declarations are added to every scope up to the function scope,
+    /// parents come before their children in the arena so the outermost scope is kept.
+    pub(crate) fn symbol_scopes(&self) -> FxHashMap<SymbolId, ScopeId> {
+        let mut symbol_scopes: FxHashMap<SymbolId, ScopeId> = FxHashMap::default();
+        for scope in self.scopes.iter() {
+            let scope_id = ScopeId::from(self.scopes.get_node_id(scope).unwrap());
+            for symbol_id in scope.get().variables.values() {
+                symbol_scopes.entry(*symbol_id).or_insert(scope_id);
+            }
+        }
+        symbol_scopes
+    }
+
     #[must_use]
     pub fn strict_mode(&self, node: &AstNode) -> bool {
         let scope = self.node_scope(node);
@@ -0,0 +1,311 @@
+//! A structured dump of the scope tree for debugging, coverage snapshots and the playground
+
+use std::fmt::{self, Display, Write};
+
+use oxc_ast::{AstKind, GetSpan, Span};
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::{
+    scope::{ScopeFlags, ScopeId},
+    symbol::{Initialization, Reference, ReferenceFlag, SymbolFlags},
+    Semantic,
+};
+
+/// A scope with its declared symbols, the references inside of it and its child scopes.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(rename_all = "camelCase"))]
+pub struct ScopeSnapshot {
+    /// Index of the scope, scopes are numbered in creation order starting from the root
+    pub id: usize,
+    pub flags: Vec<&'static str>,
+    pub strict_mode: bool,
+    /// Kind of the node creating the scope
+    pub node: &'static str,
+    pub span: Span,
+    pub symbols: Vec<SymbolSnapshot>,
+    pub references: Vec<ReferenceSnapshot>,
+    pub children: Vec<ScopeSnapshot>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(rename_all = "camelCase"))]
+pub struct SymbolSnapshot {
+    pub id: usize,
+    pub name: String,
+    pub flags: Vec<&'static str>,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(rename_all = "camelCase"))]
+pub struct ReferenceSnapshot {
+    pub name: String,
+    pub flags: Vec<&'static str>,
+    pub span: Span,
+    /// The symbol the reference resolves to, `None` for references to global variables
+    pub symbol_id: Option<usize>,
+    pub initialization: Option<Initialization>,
+}
+
+const SCOPE_FLAGS: &[(&str, ScopeFlags)] = &[
+    ("Top", ScopeFlags::Top),
+    ("Function", ScopeFlags::Function),
+    ("Arrow", ScopeFlags::Arrow),
+    ("ClassStaticBlock", ScopeFlags::ClassStaticBlock),
+    ("TsModuleBlock", ScopeFlags::TsModuleBlock),
+    ("Constructor", ScopeFlags::Constructor),
+    ("GetAccessor", ScopeFlags::GetAccessor),
+    ("SetAccessor", ScopeFlags::SetAccessor),
+];
+
+const SYMBOL_FLAGS: &[(&str, SymbolFlags)] = &[
+    ("FunctionScopedVariable", SymbolFlags::FunctionScopedVariable),
+    ("BlockScopedVariable", SymbolFlags::BlockScopedVariable),
+    ("ConstVariable", SymbolFlags::ConstVariable),
+    ("Import", SymbolFlags::Import),
+    ("Export", SymbolFlags::Export),
+    ("Class", SymbolFlags::Class),
+    ("CatchVariable", SymbolFlags::CatchVariable),
+    ("Function", SymbolFlags::Function),
+    ("RegularEnum", SymbolFlags::RegularEnum),
+    ("ConstEnum", SymbolFlags::ConstEnum),
+    ("Interface", SymbolFlags::Interface),
+    ("TypeAlias", SymbolFlags::TypeAlias),
+    ("TypeParameter", SymbolFlags::TypeParameter),
+    ("ValueModule", SymbolFlags::ValueModule),
+    ("NameSpaceModule", SymbolFlags::NameSpaceModule),
+];
+
+const REFERENCE_FLAGS: &[(&str, ReferenceFlag)] = &[
+    ("Read", ReferenceFlag::Read),
+    ("Write", ReferenceFlag::Write),
+    ("Type", ReferenceFlag::Type),
+    ("Typeof", ReferenceFlag::Typeof),
+    ("Export", ReferenceFlag::Export),
+];
+
+/// The names of the single bit flags contained in `flags`, composite flags are left out.
+fn flag_names<T: Copy>(
+    names: &[(&'static str, T)],
+    contains: impl Fn(T) -> bool,
+) -> Vec<&'static str> {
+    names.iter().filter(|(_, flag)| contains(*flag)).map(|(name, _)| *name).collect()
+}
+
+impl<'a> Semantic<'a> {
+    /// Snapshot of the scope tree starting from the root scope.
+    #[must_use]
+    pub fn scope_snapshot(&self) -> ScopeSnapshot {
+        let mut references: Vec<Vec<ReferenceSnapshot>> = vec![vec![]; self.scopes.count()];
+        let mut add_reference = |reference: &Reference, name: &str, symbol_id, initialization| {
+            let scope_id = self.nodes[reference.ast_node_id].scope_id();
+            references[Self::scope_index(scope_id)].push(ReferenceSnapshot {
+                name: name.to_string(),
+                flags: flag_names(REFERENCE_FLAGS, |flag| reference.flag().contains(flag)),
+                span: reference.span,
+                symbol_id,
+                initialization,
+            });
+        };
+        for symbol in self.symbols.iter() {
+            for reference in self.symbols.resolved_references(symbol.id()) {
+                add_reference(
+                    &reference.reference,
+                    symbol.name(),
+                    Some(symbol.id().index0()),
+                    Some(reference.initialization),
+                );
+            }
+        }
+        for (name, unresolved) in self.unresolved_references() {
+            for reference in unresolved {
+                add_reference(reference, name, None, None);
+            }
+        }
+        for references in &mut references {
+            references.sort_by_key(|reference| reference.span.start);
+        }
+
+        // The first node inside of each scope is the node creating it
+        let mut scope_nodes: Vec<Option<AstKind<'a>>> = vec![None; self.scopes.count()];
+        for node in self.nodes.iter() {
+            let kind = node.get().kind();
+            let index = Self::scope_index(node.get().scope_id());
+            if !matches!(kind, AstKind::Root) && scope_nodes[index].is_none() {
+                scope_nodes[index] = Some(kind);
+            }
+        }
+
+        self.snapshot_scope(self.scopes.root_scope_id(), &mut references, &scope_nodes)
+    }
+
+    fn snapshot_scope(
+        &self,
+        scope_id: ScopeId,
+        references: &mut [Vec<ReferenceSnapshot>],
+        scope_nodes: &[Option<AstKind<'a>>],
+    ) -> ScopeSnapshot {
+        let scope = &self.scopes[scope_id];
+        let index = Self::scope_index(scope_id);
+
+        // `var` declarations are bound to every scope up to the function scope,
+        // they are listed in the outermost one only
+        let parent_variables =
+            self.scopes[*scope_id].parent().map(|id| &self.scopes[id].get().variables);
+        let mut symbols: Vec<SymbolSnapshot> = scope
+            .variables
+            .iter()
+            .filter(|(name, symbol_id)| {
+                parent_variables.map_or(true, |variables| variables.get(*name) != Some(*symbol_id))
+            })
+            .map(|(_, symbol_id)| {
+                let symbol = &self.symbols[*symbol_id];
+                SymbolSnapshot {
+                    id: symbol_id.index0(),
+                    name: symbol.name().to_string(),
+                    flags: flag_names(SYMBOL_FLAGS, |flag| symbol.flags().contains(flag)),
+                    span: symbol.span(),
+                }
+            })
+            .collect();
+        symbols.sort_by_key(|symbol| symbol.id);
+
+        let children = scope_id
+            .children(&self.scopes)
+            .map(|child_id| self.snapshot_scope(child_id.into(), references, scope_nodes))
+            .collect();
+
+        let kind = scope_nodes[index];
+        ScopeSnapshot {
+            id: index,
+            flags: flag_names(SCOPE_FLAGS, |flag| scope.flags.contains(flag)),
+            strict_mode: scope.strict_mode(),
+            node: kind.map_or("Root", |kind| kind.debug_name()),
+            span: kind.map_or_else(Span::default, |kind| kind.span()),
+            symbols,
+            references: std::mem::take(&mut references[index]),
+            children,
+        }
+    }
+
+    fn scope_index(scope_id: ScopeId) -> usize {
+        let index: usize = scope_id.indextree_id().into();
+        index - 1
+    }
+}
+
+#[cfg(feature = "serde")]
+impl ScopeSnapshot {
+    /// # Panics
+    /// Serializing the snapshot does not fail.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
+    }
+}
+
+impl ScopeSnapshot {
+    fn write(&self, f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+        let indent = "  ".repeat(depth);
+        write!(
+            f,
+            "{indent}Scope {} {}({}..{})",
+            self.id, self.node, self.span.start, self.span.end
+        )?;
+        if !self.flags.is_empty() {
+            write!(f, " [{}]", self.flags.join(" | "))?;
+        }
+        if self.strict_mode {
+            f.write_str(" strict")?;
+        }
+        f.write_char('\n')?;
+        for symbol in &self.symbols {
+            writeln!(
+                f,
+                "{indent}  symbol #{} {}({}..{}) [{}]",
+                symbol.id,
+                symbol.name,
+                symbol.span.start,
+                symbol.span.end,
+                symbol.flags.join(" | ")
+            )?;
+        }
+        for reference in &self.references {
+            write!(
+                f,
+                "{indent}  reference {}({}..{}) [{}] ",
+                reference.name,
+                reference.span.start,
+                reference.span.end,
+                reference.flags.join(" | ")
+            )?;
+            match reference.symbol_id {
+                Some(symbol_id) => write!(f, "-> #{symbol_id}")?,
+                None => f.write_str("-> unresolved")?,
+            }
+            match reference.initialization {
+                Some(Initialization::Initialized) | None => {}
+                Some(initialization) => write!(f, " {initialization:?}")?,
+            }
+            f.write_char('\n')?;
+        }
+        for child in &self.children {
+            child.write(f, depth + 1)?;
+        }
+        Ok(())
+    }
+}
+
+/// An indented tree of scopes, e.g.
+///
+/// ```text
+/// Scope 0 Program(0..21) [Top]
+///   symbol #0 f(9..10) [FunctionScopedVariable | Function]
+///   Scope 1 Function(0..21) [Function]
+///     reference f(15..16) [Read] -> #0
+/// ```
+impl Display for ScopeSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write(f, 0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::SourceType;
+
+    use crate::tester;
+
+    fn snapshot(source_text: &str) -> String {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let semantic = tester::semantic(&allocator, source_text, source_type, |builder| {
+            builder.with_initialization(true)
+        });
+        semantic.scope_snapshot().to_string()
+    }
+
+    #[test]
+    fn text() {
+        assert_eq!(
+            snapshot("a; let a; function f(b) { var c = b; { let d; x(d, c); } }"),
+            "\
+Scope 0 Program(0..58) [Top]
+  symbol #0 a(7..8) [BlockScopedVariable]
+  symbol #1 f(19..20) [FunctionScopedVariable | Function]
+  reference a(0..1) [Read] -> #0 TemporalDeadZone
+  Scope 1 Function(10..58) [Function]
+    symbol #2 b(21..22) [FunctionScopedVariable]
+    symbol #3 c(30..31) [FunctionScopedVariable]
+    reference b(34..35) [Read] -> #2
+    Scope 2 BlockStatement(37..56)
+      symbol #4 d(43..44) [BlockScopedVariable]
+      reference x(46..47) [Read] -> unresolved
+      reference d(48..49) [Read] -> #4
+      reference c(51..52) [Read] -> #3
+"
+        );
+    }
+}
@@ -0,0 +1,233 @@
+//! Whether bindings are initialized when their references are evaluated,
+//! see [Declarations and the Variable Statement](https://tc39.es/ecma262/#sec-declarations-and-the-variable-statement)
+
+use oxc_ast::{AstKind, GetSpan};
+use rustc_hash::FxHashMap;
+
+use super::{Initialization, SymbolFlags, SymbolTable};
+use crate::{
+    node::{AstNode, AstNodeId, AstNodes},
+    scope::{ScopeFlags, ScopeId, ScopeTree},
+};
+
+/// How a binding becomes initialized.
+enum Binding {
+    /// `let`, `const` and `class`, which throw before the initialization point
+    Lexical { initialized_at: u32 },
+    /// `var`, which is `undefined` before the initialization point
+    Var { initialized_at: u32 },
+    /// A block-level function bound to the enclosing function by Annex B,
+    /// which is `undefined` outside of the block until the declaration is evaluated
+    BlockFunction { block_scope_id: ScopeId, initialized_at: u32 },
+}
+
+impl SymbolTable {
+    /// Compute the [`Initialization`] of all resolved references.
+    ///
+    /// The analysis is by position, a reference inside of a loop is before the initialization
+    /// when it comes before the declaration in the source text.
+    pub(crate) fn compute_initialization(&mut self, nodes: &AstNodes, scopes: &ScopeTree) {
+        let symbol_scopes = scopes.symbol_scopes();
+        let function_nodes: FxHashMap<ScopeId, &AstNode> = nodes
+            .iter()
+            .filter(|node| {
+                matches!(node.get().kind(), AstKind::Function(_) | AstKind::ArrowExpression(_))
+            })
+            .map(|node| (node.get().scope_id(), node))
+            .collect();
+
+        let mut initializations = vec![];
+        for symbol in self.symbols() {
+            let Some(&declaration_scope) = symbol_scopes.get(&symbol.id()) else { continue };
+            let declaration = symbol.declaration();
+            let Some(binding) =
+                Self::binding(nodes, scopes, symbol.flags(), declaration, declaration_scope)
+            else {
+                continue;
+            };
+            let declaration_start = nodes.kind(declaration).span().start;
+
+            for reference in self.resolved_references(symbol.id()) {
+                if reference.is_type() || reference.is_export() {
+                    continue;
+                }
+                let node_id = reference.reference.ast_node_id;
+                let start = reference.span().start;
+                let reference_scope = nodes[node_id].scope_id();
+
+                // The outermost function between the reference and the declaration
+                let function = scopes
+                    .ancestors(reference_scope)
+                    .map(ScopeId::from)
+                    .take_while(|scope_id| *scope_id != declaration_scope)
+                    .filter(|scope_id| scopes[*scope_id].flags.contains(ScopeFlags::Function))
+                    .last()
+                    .and_then(|scope_id| function_nodes.get(&scope_id));
+
+                let initialization = match binding {
+                    Binding::Lexical { initialized_at } => match function {
+                        None if start < initialized_at => Initialization::TemporalDeadZone,
+                        None => continue,
+                        Some(function) => {
+                            let function_start = function.get().kind().span().start;
+                            // Functions created after the initialization, or by the initializer itself,
+                            // e.g. `let f = () => f()`, cannot be called before it
+                            if function_start >= declaration_start {
+                                continue;
+                            }
+                            Initialization::MaybeTemporalDeadZone
+                        }
+                    },
+                    Binding::Var { initialized_at } => {
+                        if function.is_some() || start >= initialized_at || !reference.is_read() {
+                            continue;
+                        }
+                        Initialization::Hoisted
+                    }
+                    Binding::BlockFunction { block_scope_id, initialized_at } => {
+                        if function.is_some()
+                            || start >= initialized_at
+                            || !reference.is_read()
+                            || scopes.ancestors(reference_scope).any(|id| id == *block_scope_id)
+                        {
+                            continue;
+                        }
+                        Initialization::Hoisted
+                    }
+                };
+                initializations.push((node_id, initialization));
+            }
+        }
+
+        for (node_id, initialization) in initializations {
+            if let Some(reference) = self.resolved_references.get_mut(&node_id) {
+                reference.initialization = initialization;
+            }
+        }
+    }
+
+    fn binding(
+        nodes: &AstNodes,
+        scopes: &ScopeTree,
+        flags: SymbolFlags,
+        declaration: AstNodeId,
+        declaration_scope: ScopeId,
+    ) -> Option<Binding> {
+        let node = &nodes[*declaration];
+        match node.get().kind() {
+            // Function declarations are initialized when their scope is entered
+            AstKind::Function(func) if flags.contains(SymbolFlags::Function) => {
+                let block_scope_id = scopes.parent_node_id(node.get().scope_id()).into();
+                (block_scope_id != declaration_scope).then_some(Binding::BlockFunction {
+                    block_scope_id,
+                    initialized_at: func.span.start,
+                })
+            }
+            AstKind::Class(class) => {
+                Some(Binding::Lexical { initialized_at: class.body.span.start })
+            }
+            AstKind::VariableDeclarator(declarator) => {
+                let mut initialized_at = declarator.span.end;
+                // `for (let a of a)`, the binding is in its TDZ while evaluating the iterated expression
+                let statement = node.parent().and_then(|id| nodes[id].parent());
+                match statement.map(|id| nodes[id].get().kind()) {
+                    Some(AstKind::ForInStatement(stmt)) => initialized_at = stmt.right.span().end,
+                    Some(AstKind::ForOfStatement(stmt)) => initialized_at = stmt.right.span().end,
+                    _ => {}
+                }
+                if flags.contains(SymbolFlags::BlockScopedVariable) {
+                    Some(Binding::Lexical { initialized_at })
+                } else {
+                    Some(Binding::Var { initialized_at })
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::{AstKind, SourceType};
+
+    use crate::{tester, Initialization};
+
+    /// The initialization of each resolved reference in source order, keyed by `name@start`
+    fn initializations(source_text: &str) -> Vec<(String, Initialization)> {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let semantic = tester::semantic(&allocator, source_text, source_type, |builder| {
+            builder.with_initialization(true)
+        });
+
+        semantic
+            .nodes()
+            .iter()
+            .filter_map(|node| {
+                let AstKind::IdentifierReference(ident) = node.get().kind() else { return None };
+                let node_id = semantic.nodes().get_node_id(node).unwrap().into();
+                let reference = semantic.symbols().get_resolved_reference(node_id)?;
+                Some((format!("{}@{}", ident.name, ident.span.start), reference.initialization))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn temporal_dead_zone() {
+        use Initialization::{Initialized, MaybeTemporalDeadZone, TemporalDeadZone};
+        assert_eq!(
+            initializations("a; let a = a; a;"),
+            vec![
+                ("a@0".into(), TemporalDeadZone),
+                ("a@11".into(), TemporalDeadZone),
+                ("a@14".into(), Initialized)
+            ]
+        );
+        assert_eq!(
+            initializations("new A(); class A extends A { m() { return A; } }"),
+            vec![
+                ("A@4".into(), TemporalDeadZone),
+                ("A@25".into(), TemporalDeadZone),
+                ("A@42".into(), Initialized)
+            ]
+        );
+        assert_eq!(
+            initializations("function f() { a; } const g = () => a; const a = 1; () => a;"),
+            vec![
+                ("a@15".into(), MaybeTemporalDeadZone),
+                ("a@36".into(), MaybeTemporalDeadZone),
+                ("a@58".into(), Initialized)
+            ]
+        );
+        assert_eq!(
+            initializations("const f = () => f(); for (let a of a) {}"),
+            vec![("f@16".into(), Initialized), ("a@35".into(), TemporalDeadZone)]
+        );
+    }
+
+    #[test]
+    fn hoisted() {
+        use Initialization::{Hoisted, Initialized};
+        assert_eq!(
+            initializations("a; a = 1; var a = a; function f() { a; }"),
+            vec![
+                ("a@0".into(), Hoisted),
+                ("a@3".into(), Initialized),
+                ("a@18".into(), Hoisted),
+                ("a@36".into(), Initialized)
+            ]
+        );
+        // Function declarations are initialized on entry, block-level functions outside of the block
+        // are `undefined` until the declaration is evaluated
+        assert_eq!(
+            initializations("f(); function f() {} g(); { g(); function g() {} } g();"),
+            vec![
+                ("f@0".into(), Initialized),
+                ("g@21".into(), Hoisted),
+                ("g@28".into(), Initialized),
+                ("g@51".into(), Initialized)
+            ]
+        );
+    }
+}
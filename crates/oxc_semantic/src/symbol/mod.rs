@@ -2,6 +2,7 @@
 #![allow(non_upper_case_globals)]
 
 mod id;
+mod initialization;
 mod reference;
 mod table;
 
@@ -10,7 +11,7 @@ use oxc_ast::{Atom, Span};
 
 pub use self::{
     id::SymbolId,
-    reference::{Reference, ReferenceFlag, ResolvedReference},
+    reference::{Initialization, Reference, ReferenceFlag, ResolvedReference},
     table::SymbolTable,
 };
 use crate::node::AstNodeId;
@@ -50,19 +51,46 @@ bitflags! {
         const Export                  = 1 << 4;
         const Class                   = 1 << 5;
         const CatchVariable           = 1 << 6; // try {} catch(catch_variable) {}
+        /// A function declaration, in addition to its variable flags
+        const Function                = 1 << 7;
+        const RegularEnum             = 1 << 8;
+        const ConstEnum               = 1 << 9;
+        const Interface               = 1 << 10;
+        const TypeAlias               = 1 << 11;
+        const TypeParameter           = 1 << 12;
+        /// An instantiated namespace, which exists at runtime
+        const ValueModule             = 1 << 13;
+        /// A namespace which only contains types
+        const NameSpaceModule         = 1 << 14;
 
         const Variable = Self::FunctionScopedVariable.bits | Self::BlockScopedVariable.bits;
-        const Value = Self::Variable.bits | Self::Class.bits;
+        const Enum = Self::RegularEnum.bits | Self::ConstEnum.bits;
+        const Value = Self::Variable.bits | Self::Class.bits | Self::Enum.bits | Self::ValueModule.bits;
+        const Type = Self::Class.bits | Self::Interface.bits | Self::Enum.bits | Self::TypeAlias.bits | Self::TypeParameter.bits;
+        const Namespace = Self::ValueModule.bits | Self::NameSpaceModule.bits | Self::Enum.bits;
 
         /// Variables can be redeclared, but can not redeclare a block-scoped declaration with the
-        /// same name, or any other value that is not a variable, e.g. ValueModule or Class
-        const FunctionScopedVariableExcludes = Self::Value.bits - Self::FunctionScopedVariable.bits;
+        /// same name, an import, or any other value that is not a variable, e.g. ValueModule or Class
+        const FunctionScopedVariableExcludes = (Self::Value.bits & !Self::FunctionScopedVariable.bits) | Self::Import.bits;
 
         /// Block-scoped declarations are not allowed to be re-declared
-        /// they can not merge with anything in the value space
-        const BlockScopedVariableExcludes = Self::Value.bits;
-
-        const ClassExcludes = Self::Value.bits;
+        /// they can not merge with anything in the value space, nor with imports
+        const BlockScopedVariableExcludes = Self::Value.bits | Self::Import.bits;
+
+        /// Imports, including `import a = require("a")`, can not merge with values or other imports
+        const ImportExcludes = Self::Value.bits | Self::Import.bits;
+
+        /// Classes merge with interfaces and namespaces
+        const ClassExcludes = ((Self::Value.bits | Self::Type.bits) & !(Self::ValueModule.bits | Self::Interface.bits)) | Self::Import.bits;
+        const RegularEnumExcludes = (Self::Value.bits | Self::Type.bits) & !(Self::RegularEnum.bits | Self::ValueModule.bits);
+        const ConstEnumExcludes = (Self::Value.bits | Self::Type.bits) & !Self::ConstEnum.bits;
+        /// Interfaces merge with other interfaces and classes
+        const InterfaceExcludes = Self::Type.bits & !(Self::Interface.bits | Self::Class.bits);
+        const TypeAliasExcludes = Self::Type.bits;
+        const TypeParameterExcludes = Self::Type.bits & !Self::TypeParameter.bits;
+        /// Namespaces merge with functions, classes, enums and other namespaces
+        const ValueModuleExcludes = Self::Value.bits & !(Self::Class.bits | Self::RegularEnum.bits | Self::ValueModule.bits);
+        const NameSpaceModuleExcludes = 0;
     }
 }
 
@@ -119,6 +147,31 @@ impl Symbol {
         self.flags.contains(SymbolFlags::Export)
     }
 
+    /// Is this symbol only declared in the type space, e.g. an interface or a type alias
+    #[must_use]
+    pub fn is_type_only(&self) -> bool {
+        self.flags.intersects(SymbolFlags::Type | SymbolFlags::NameSpaceModule)
+            && !self.flags.intersects(SymbolFlags::Value | SymbolFlags::Import)
+    }
+
+    /// Can a reference in the type space, e.g. `let a: A` or `A.B`, resolve to this symbol
+    #[must_use]
+    pub fn is_type_referenceable(&self) -> bool {
+        self.flags.intersects(SymbolFlags::Type | SymbolFlags::Namespace | SymbolFlags::Import)
+    }
+
+    /// Can a reference in the value space, e.g. `a` in `a + 1`, resolve to this symbol
+    #[must_use]
+    pub fn is_value_referenceable(&self) -> bool {
+        self.flags.intersects(SymbolFlags::Value | SymbolFlags::Import)
+    }
+
+    /// Merge the flags of another declaration of this symbol,
+    /// e.g. `interface A {}` into `class A {}`.
+    pub(crate) fn merge_flags(&mut self, flags: SymbolFlags) {
+        self.flags |= flags;
+    }
+
     pub fn add_references(&mut self, new_references: &[Reference]) {
         self.references.extend(new_references.iter().map(|r| r.ast_node_id));
     }
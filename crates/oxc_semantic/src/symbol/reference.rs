@@ -2,6 +2,8 @@
 
 use bitflags::bitflags;
 use oxc_ast::Span;
+#[cfg(feature = "serde")]
+use serde::Serialize;
 
 use super::SymbolId;
 use crate::node::AstNodeId;
@@ -20,6 +22,12 @@ bitflags! {
         const Read = 1 << 0;
         const Write = 1 << 1;
         const ReadWrite = Self::Read.bits | Self::Write.bits;
+        /// A reference in the type space, e.g. `A` in `let a: A`
+        const Type = 1 << 2;
+        /// The operand of `typeof`, which does not throw for undeclared variables, always with `Read`
+        const Typeof = 1 << 3;
+        /// The local name of `export { a }`, which neither reads nor writes the variable
+        const Export = 1 << 4;
     }
 }
 
@@ -39,28 +47,77 @@ impl Reference {
         self.flag.contains(ReferenceFlag::Write)
     }
 
+    /// Reads or writes the variable
     #[must_use]
     pub const fn is_read_write(&self) -> bool {
         self.flag.intersects(ReferenceFlag::ReadWrite)
     }
 
+    /// Both reads and writes the variable, e.g. `a += 1` and `a++`
+    #[must_use]
+    pub const fn is_read_and_write(&self) -> bool {
+        self.flag.contains(ReferenceFlag::ReadWrite)
+    }
+
+    #[must_use]
+    pub const fn is_type(&self) -> bool {
+        self.flag.contains(ReferenceFlag::Type)
+    }
+
+    #[must_use]
+    pub const fn is_typeof(&self) -> bool {
+        self.flag.contains(ReferenceFlag::Typeof)
+    }
+
+    #[must_use]
+    pub const fn is_export(&self) -> bool {
+        self.flag.contains(ReferenceFlag::Export)
+    }
+
+    #[must_use]
+    pub const fn flag(&self) -> ReferenceFlag {
+        self.flag
+    }
+
     #[must_use]
     pub fn resolve_to(self, symbol: SymbolId) -> ResolvedReference {
         ResolvedReference::new(self, symbol)
     }
 }
 
+/// Whether the binding is initialized when a reference is evaluated
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum Initialization {
+    #[default]
+    Initialized,
+    /// `a` in `a; var a = 1;`, a hoisted `var` which is `undefined` until its declaration
+    Hoisted,
+    /// `a` in `a; let a;` and `let a = a;`, which always throws a `ReferenceError`
+    TemporalDeadZone,
+    /// `a` in `function f() { a } let a;`, which throws if the function is called before the declaration
+    MaybeTemporalDeadZone,
+}
+
 #[derive(Debug, Clone)]
 pub struct ResolvedReference {
     pub reference: Reference,
     // The Symbol the reference refers to.
     pub resolved_symbol_id: SymbolId,
+    /// Computed by [`crate::SemanticBuilder::with_initialization`], `Initialized` otherwise
+    pub initialization: Initialization,
 }
 
 impl ResolvedReference {
     #[must_use]
     pub fn new(reference: Reference, resolved_symbol_id: SymbolId) -> Self {
-        Self { reference, resolved_symbol_id }
+        Self { reference, resolved_symbol_id, initialization: Initialization::Initialized }
+    }
+
+    /// Whether the reference may be evaluated before the binding is initialized.
+    #[must_use]
+    pub fn is_before_initialization(&self) -> bool {
+        self.initialization != Initialization::Initialized
     }
 
     #[must_use]
@@ -78,6 +135,26 @@ impl ResolvedReference {
         self.reference.is_read_write()
     }
 
+    #[must_use]
+    pub const fn is_read_and_write(&self) -> bool {
+        self.reference.is_read_and_write()
+    }
+
+    #[must_use]
+    pub const fn is_type(&self) -> bool {
+        self.reference.is_type()
+    }
+
+    #[must_use]
+    pub const fn is_typeof(&self) -> bool {
+        self.reference.is_typeof()
+    }
+
+    #[must_use]
+    pub const fn is_export(&self) -> bool {
+        self.reference.is_export()
+    }
+
     #[must_use]
     pub fn span(&self) -> Span {
         self.reference.span
@@ -10,7 +10,7 @@ use crate::ResolvedReference;
 #[derive(Debug, Default)]
 pub struct SymbolTable {
     symbols: Vec<Symbol>,
-    resolved_references: FxHashMap<AstNodeId, ResolvedReference>,
+    pub(super) resolved_references: FxHashMap<AstNodeId, ResolvedReference>,
 }
 
 impl Index<SymbolId> for SymbolTable {
@@ -60,6 +60,12 @@ impl SymbolTable {
         symbol_id
     }
 
+    /// Remove all symbols and references, keeping the allocations.
+    pub fn clear(&mut self) {
+        self.symbols.clear();
+        self.resolved_references.clear();
+    }
+
     #[must_use]
     pub fn get_resolved_reference(&self, id: AstNodeId) -> Option<&ResolvedReference> {
         self.resolved_references.get(&id)
@@ -68,4 +74,110 @@ impl SymbolTable {
     pub fn resolve_reference(&mut self, id: AstNodeId, reference: ResolvedReference) {
         self.resolved_references.insert(id, reference);
     }
+
+    /// The resolved references of a symbol, in source order.
+    pub fn resolved_references(
+        &self,
+        symbol_id: SymbolId,
+    ) -> impl Iterator<Item = &ResolvedReference> + '_ {
+        self[symbol_id].references().iter().filter_map(|id| self.resolved_references.get(id))
+    }
+
+    /// Whether the symbol is written after its declaration, e.g. `a = 1`, `a++` or `[a] = b`.
+    #[must_use]
+    pub fn is_reassigned(&self, symbol_id: SymbolId) -> bool {
+        self.resolved_references(symbol_id).any(ResolvedReference::is_write)
+    }
+
+    /// Whether the value of the symbol is read, `export { a }` and type references do not count.
+    #[must_use]
+    pub fn is_read(&self, symbol_id: SymbolId) -> bool {
+        self.resolved_references(symbol_id).any(ResolvedReference::is_read)
+    }
+
+    #[must_use]
+    pub fn write_count(&self, symbol_id: SymbolId) -> usize {
+        self.resolved_references(symbol_id).filter(|r| r.is_write()).count()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::SourceType;
+
+    use crate::{
+        symbol::{Reference, ReferenceFlag},
+        tester,
+    };
+
+    /// Returns the references to `a`, whether `a` is read and its write count
+    fn references_of_a(source_text: &str) -> (Vec<Reference>, bool, usize) {
+        let allocator = Allocator::default();
+        let source_type = SourceType::from_path("test.mjs").unwrap();
+        let semantic = tester::semantic(&allocator, source_text, source_type, |builder| builder);
+        let symbols = semantic.symbols();
+        let a = symbols.iter().find(|s| s.name() == &"a").unwrap().id();
+        let references = symbols.resolved_references(a).map(|r| r.reference.clone()).collect();
+        assert_eq!(symbols.is_reassigned(a), symbols.write_count(a) > 0);
+        (references, symbols.is_read(a), symbols.write_count(a))
+    }
+
+    #[test]
+    fn reference_flags() {
+        let read = ReferenceFlag::Read;
+        let write = ReferenceFlag::Write;
+        let read_write = ReferenceFlag::ReadWrite;
+        let cases = [
+            ("let a; a", read),
+            ("let a; a = 1", write),
+            ("let a; a += 1", read_write),
+            ("let a; a ||= 1", read_write),
+            ("let a; a++", read_write),
+            ("let a; --a", read_write),
+            ("let a; (a) = 1", write),
+            ("let a; [a] = b", write),
+            ("let a; [a = 1] = b", write),
+            ("let a; [...a] = b", write),
+            ("let a; ({ a } = b)", write),
+            ("let a; ({ a = 1 } = b)", write),
+            ("let a; ({ k: a = 1 } = b)", write),
+            ("let a; ({ ...a } = b)", write),
+            ("let a; ({ b = a } = c)", read),
+            ("let a; for (a of b);", write),
+            ("let a; for (a in b);", write),
+            ("let a; a.b = 1", read),
+            ("let a; b[a] = 1", read),
+            ("let a; typeof a", read | ReferenceFlag::Typeof),
+            ("let a; typeof (a)", read | ReferenceFlag::Typeof),
+            ("let a; delete a", read),
+            ("let a; export { a }", ReferenceFlag::Export),
+            ("let a; export { a as b }", ReferenceFlag::Export),
+        ];
+        for (source_text, flag) in cases {
+            let (references, ..) = references_of_a(source_text);
+            let flags: Vec<_> = references.iter().map(Reference::flag).collect();
+            assert_eq!(flags, vec![flag], "{source_text}");
+        }
+    }
+
+    #[test]
+    fn symbol_queries() {
+        let (_, is_read, write_count) = references_of_a("let a = 1; export { a }");
+        assert!(!is_read);
+        assert_eq!(write_count, 0);
+
+        let (_, is_read, write_count) = references_of_a("let a; a = 1; [a] = b; a++; typeof a");
+        assert!(is_read);
+        assert_eq!(write_count, 3);
+
+        let (_, is_read, write_count) = references_of_a("let a; delete a");
+        assert!(is_read);
+        assert_eq!(write_count, 0);
+
+        let (references, ..) = references_of_a("let a; a; a = 1; a++");
+        assert!(references.iter().all(Reference::is_read_write));
+        let read_and_write: Vec<_> = references.iter().map(Reference::is_read_and_write).collect();
+        assert_eq!(read_and_write, vec![false, false, true]);
+    }
 }
@@ -0,0 +1,48 @@
+//! Scaffolding shared by the unit tests
+
+use std::rc::Rc;
+
+use oxc_allocator::Allocator;
+use oxc_ast::{ast::Program, AstKind, SourceType};
+use oxc_parser::Parser;
+
+use crate::{Semantic, SemanticBuilder};
+
+/// Parse `source_text`, which must not have syntax errors
+pub fn parse<'a>(
+    allocator: &'a Allocator,
+    source_text: &'a str,
+    source_type: SourceType,
+) -> &'a Program<'a> {
+    let ret = Parser::new(allocator, source_text, source_type).parse();
+    assert!(ret.errors.is_empty(), "{source_text}: {:?}", ret.errors);
+    allocator.alloc(ret.program)
+}
+
+/// Parse `source_text` and build its semantic with the analyses enabled by `configure`
+pub fn semantic<'a, F>(
+    allocator: &'a Allocator,
+    source_text: &'a str,
+    source_type: SourceType,
+    configure: F,
+) -> Semantic<'a>
+where
+    F: FnOnce(SemanticBuilder<'a>) -> SemanticBuilder<'a>,
+{
+    let program = parse(allocator, source_text, source_type);
+    configure(SemanticBuilder::new(source_text, source_type, &Rc::default()))
+        .build(program)
+        .semantic
+}
+
+/// The `Program` node of `semantic`
+pub fn program<'a>(semantic: &Semantic<'a>) -> &'a Program<'a> {
+    semantic
+        .nodes()
+        .iter()
+        .find_map(|node| match node.get().kind() {
+            AstKind::Program(program) => Some(program),
+            _ => None,
+        })
+        .unwrap()
+}
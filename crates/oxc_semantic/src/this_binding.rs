@@ -0,0 +1,211 @@
+//! Resolution of `this`, `super` and `arguments` to the environment providing their binding
+//! See [GetThisEnvironment](https://tc39.es/ecma262/#sec-getthisenvironment)
+
+use oxc_ast::AstKind;
+use rustc_hash::FxHashMap;
+
+use crate::{
+    node::{AstNode, AstNodeId, AstNodes},
+    symbol::SymbolTable,
+};
+
+/// The environments providing the bindings of `this`, `super` and `arguments`.
+///
+/// An environment is one of
+/// * a non-arrow `Function`, arrow functions are transparent
+/// * a `PropertyDefinition` or `AccessorProperty`, for uses inside of the initializer of a class field
+/// * a `StaticBlock`
+/// * the `Program`, for uses outside of any function
+///
+/// `arguments` is an error inside of class field initializers and static blocks,
+/// and refers to a global variable inside of the `Program`.
+#[derive(Debug, Default)]
+pub struct ThisBindings {
+    /// Keyed by the `ThisExpression`, `Super` or `IdentifierReference` node of the use
+    environments: FxHashMap<AstNodeId, AstNodeId>,
+
+    /// Uses keyed by environment, in source order
+    references: FxHashMap<AstNodeId, Vec<AstNodeId>>,
+}
+
+impl ThisBindings {
+    /// The environment of a `ThisExpression`, `Super` or `arguments` node.
+    /// Returns `None` for other nodes and for `arguments` shadowed by a declaration,
+    /// e.g. `function f(arguments) { arguments }`.
+    #[must_use]
+    pub fn environment(&self, node_id: AstNodeId) -> Option<AstNodeId> {
+        self.environments.get(&node_id).copied()
+    }
+
+    /// The uses of `this`, `super` and `arguments` resolved to the environment `node_id`.
+    #[must_use]
+    pub fn references(&self, node_id: AstNodeId) -> &[AstNodeId] {
+        self.references.get(&node_id).map_or(&[], Vec::as_slice)
+    }
+
+    pub(crate) fn build(nodes: &AstNodes, symbols: &SymbolTable) -> Self {
+        let mut bindings = Self::default();
+        for node in nodes.iter() {
+            let node_id = AstNodeId::new(nodes.get_node_id(node).unwrap());
+            let environment_id = match node.get().kind() {
+                AstKind::ThisExpression(_) | AstKind::Super(_) => {
+                    Self::find_environment(nodes, node)
+                }
+                AstKind::IdentifierReference(ident) if ident.name == "arguments" => {
+                    let environment_id = Self::find_environment(nodes, node);
+                    if Self::is_shadowed(nodes, symbols, node_id, environment_id) {
+                        continue;
+                    }
+                    environment_id
+                }
+                _ => continue,
+            };
+            bindings.environments.insert(node_id, environment_id);
+            bindings.references.entry(environment_id).or_default().push(node_id);
+        }
+        bindings
+    }
+
+    fn find_environment<'a>(nodes: &AstNodes<'a>, node: &AstNode<'a>) -> AstNodeId {
+        let mut child_id = nodes.get_node_id(node).unwrap();
+        let mut parent_id = node.parent();
+        while let Some(node_id) = parent_id {
+            let parent = &nodes[node_id];
+            match parent.get().kind() {
+                AstKind::Function(_) | AstKind::StaticBlock(_) | AstKind::Program(_) => {
+                    return AstNodeId::new(node_id);
+                }
+                // Keys and decorators of class fields are evaluated outside of the initializer
+                AstKind::PropertyDefinition(_) | AstKind::AccessorProperty(_)
+                    if !matches!(
+                        nodes.kind(child_id),
+                        AstKind::PropertyKey(_) | AstKind::Decorator(_)
+                    ) =>
+                {
+                    return AstNodeId::new(node_id);
+                }
+                _ => {}
+            }
+            child_id = node_id;
+            parent_id = parent.parent();
+        }
+        unreachable!("the program is the root of all nodes")
+    }
+
+    /// Whether `arguments` resolves to a declaration inside of its environment,
+    /// rather than the `arguments` object of the function.
+    fn is_shadowed(
+        nodes: &AstNodes,
+        symbols: &SymbolTable,
+        node_id: AstNodeId,
+        environment_id: AstNodeId,
+    ) -> bool {
+        let Some(reference) = symbols.get_resolved_reference(node_id) else { return false };
+        let declaration = symbols[reference.resolved_symbol_id].declaration();
+        declaration.ancestors(nodes).skip(1).any(|id| id == *environment_id)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::{AstKind, GetSpan, SourceType};
+
+    use crate::tester;
+
+    /// The environments of each use of `this`, `super` and `arguments` in source order,
+    /// as the kind and start of the environment node.
+    fn environments(source_text: &str) -> Vec<String> {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let semantic = tester::semantic(&allocator, source_text, source_type, |builder| {
+            builder.with_this_bindings(true)
+        });
+
+        semantic
+            .nodes()
+            .iter()
+            .filter_map(|node| {
+                let node_id = semantic.nodes().get_node_id(node).unwrap().into();
+                semantic.this_bindings().unwrap().environment(node_id)
+            })
+            .map(|environment_id| {
+                let kind = semantic.nodes().kind(environment_id);
+                format!("{}@{}", kind.debug_name(), kind.span().start)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn arrows() {
+        assert_eq!(
+            environments("this; function f() { return () => () => this; }"),
+            vec!["Program@0", "Function@6"]
+        );
+        assert_eq!(
+            environments("const o = { m() { return [super.a, () => arguments]; } }"),
+            vec!["Function@13", "Function@13"]
+        );
+    }
+
+    #[test]
+    fn classes() {
+        assert_eq!(
+            environments(
+                "class A extends (this.B) {
+                    [this.a] = this;
+                    accessor b = () => super.b;
+                    static { this; }
+                    m(a = this) { super.m(); }
+                }"
+            ),
+            vec![
+                "Program@0",
+                "Program@0",
+                "PropertyDefinition@47",
+                "AccessorProperty@84",
+                "StaticBlock@132",
+                "Function@170",
+                "Function@170",
+            ]
+        );
+    }
+
+    #[test]
+    fn arguments() {
+        assert_eq!(
+            environments(
+                "arguments;
+                 function f() { arguments; }
+                 function g(arguments) { arguments; }
+                 function h() { let arguments; arguments; }
+                 let arguments; function i() { arguments; }"
+            ),
+            vec!["Function@28", "Function@202"]
+        );
+    }
+
+    #[test]
+    fn references() {
+        let source_text = "function f() { this; () => arguments; function g() { this } }";
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let semantic = tester::semantic(&allocator, source_text, source_type, |builder| {
+            builder.with_this_bindings(true)
+        });
+        let f = semantic
+            .nodes()
+            .iter()
+            .find(|node| matches!(node.get().kind(), AstKind::Function(_)))
+            .unwrap();
+        let f = semantic.nodes().get_node_id(f).unwrap().into();
+        let references: Vec<_> = semantic
+            .this_bindings()
+            .unwrap()
+            .references(f)
+            .iter()
+            .map(|id| semantic.nodes().kind(*id).span().start)
+            .collect();
+        assert_eq!(references, vec![15, 27]);
+    }
+}
@@ -0,0 +1,521 @@
+//! Unused declarations, the analysis behind `no-unused-vars` and dead code elimination
+
+use oxc_ast::{
+    ast::{
+        BindingPattern, BindingPatternKind, FormalParameters, ImportOrExportKind, ModifierKind,
+        ModuleDeclarationKind, ObjectPatternProperty, PropertyValue,
+    },
+    syntax_directed_operations::BoundNames,
+    AstKind, GetSpan, Span,
+};
+use regex::Regex;
+
+use crate::{
+    node::AstNodeId,
+    symbol::{ResolvedReference, Symbol, SymbolId},
+    Semantic,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UnusedVars {
+    /// Check all variables
+    #[default]
+    All,
+    /// Top level declarations are not checked, they may be used by other scripts
+    Local,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UnusedArgs {
+    /// Parameters before the last used parameter are not checked
+    #[default]
+    AfterUsed,
+    All,
+    None,
+}
+
+/// Options of [`Semantic::unused_declarations`], with the semantics of the `no-unused-vars` rule of `ESLint`
+#[derive(Debug, Clone)]
+pub struct UnusedOptions {
+    pub vars: UnusedVars,
+    /// Variables, functions, classes and imports with matching names are not checked
+    pub vars_ignore_pattern: Option<Regex>,
+    pub args: UnusedArgs,
+    pub args_ignore_pattern: Option<Regex>,
+    /// Whether catch parameters are checked
+    pub caught_errors: bool,
+    pub caught_errors_ignore_pattern: Option<Regex>,
+    /// Siblings of a rest element are used to omit properties from it,
+    /// e.g. `a` in `const { a, ...rest } = obj`
+    pub ignore_rest_siblings: bool,
+}
+
+impl Default for UnusedOptions {
+    fn default() -> Self {
+        Self {
+            vars: UnusedVars::default(),
+            vars_ignore_pattern: None,
+            args: UnusedArgs::default(),
+            args_ignore_pattern: None,
+            caught_errors: true,
+            caught_errors_ignore_pattern: None,
+            ignore_rest_siblings: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnusedDeclarationKind {
+    Variable,
+    Function,
+    Class,
+    Parameter,
+    CatchParameter,
+    Import,
+    /// `import type { A } from 'a'`
+    TypeImport,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnusedReason {
+    /// Never referenced
+    Unreferenced,
+    /// Assigned but never read, e.g. `let a; a = 1;` and `let b = 0; b++;`
+    OnlyWritten,
+    /// Only read inside of its own declaration, e.g. `function f() { f() }`
+    SelfReferenceOnly,
+    /// A value import only referenced by types, which is erased from the output.
+    /// The import is used, but not at runtime.
+    OnlyUsedAsType,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnusedDeclaration {
+    pub symbol_id: SymbolId,
+    pub kind: UnusedDeclarationKind,
+    pub reason: UnusedReason,
+}
+
+impl<'a> Semantic<'a> {
+    /// The declarations which are not used, in the order of declaration.
+    /// Exported declarations are always used.
+    #[must_use]
+    pub fn unused_declarations(&self, options: &UnusedOptions) -> Vec<UnusedDeclaration> {
+        let mut unused = vec![];
+        for symbol in self.symbols().symbols() {
+            let symbol_id = symbol.id();
+            if symbol.is_export() {
+                continue;
+            }
+            let declaration = symbol.declaration();
+            let Some(kind) = self.unused_declaration_kind(declaration) else { continue };
+            let Some(reason) = self.unused_reason(symbol_id, declaration, kind) else { continue };
+            if self.is_ignored(symbol_id, declaration, kind, options) {
+                continue;
+            }
+            unused.push(UnusedDeclaration { symbol_id, kind, reason });
+        }
+        unused
+    }
+
+    /// The kind of the declarations which are checked.
+    /// Ambient declarations, function and class expression names and TypeScript types are not checked.
+    fn unused_declaration_kind(&self, declaration: AstNodeId) -> Option<UnusedDeclarationKind> {
+        let kind = match self.nodes().kind(declaration) {
+            AstKind::VariableDeclarator(_) => {
+                let node = &self.nodes()[*declaration];
+                match self.nodes().parent_kind(node) {
+                    AstKind::VariableDeclaration(decl)
+                        if decl.modifiers.contains(ModifierKind::Declare) =>
+                    {
+                        return None;
+                    }
+                    _ => UnusedDeclarationKind::Variable,
+                }
+            }
+            AstKind::Function(func) if func.body.is_some() => UnusedDeclarationKind::Function,
+            AstKind::Class(class) if class.is_declaration() => UnusedDeclarationKind::Class,
+            AstKind::FormalParameters(_) => {
+                let node = &self.nodes()[*declaration];
+                match self.nodes().parent_kind(node) {
+                    AstKind::Function(func) if func.body.is_none() => return None,
+                    _ => UnusedDeclarationKind::Parameter,
+                }
+            }
+            AstKind::CatchClause(_) => UnusedDeclarationKind::CatchParameter,
+            AstKind::ModuleDeclaration(decl) => match &decl.kind {
+                ModuleDeclarationKind::ImportDeclaration(decl)
+                    if decl.import_kind == Some(ImportOrExportKind::Type) =>
+                {
+                    UnusedDeclarationKind::TypeImport
+                }
+                ModuleDeclarationKind::ImportDeclaration(_) => UnusedDeclarationKind::Import,
+                _ => return None,
+            },
+            AstKind::TSImportEqualsDeclaration(decl) if decl.import_kind.is_value() => {
+                UnusedDeclarationKind::Import
+            }
+            _ => return None,
+        };
+        Some(kind)
+    }
+
+    fn unused_reason(
+        &self,
+        symbol_id: SymbolId,
+        declaration: AstNodeId,
+        kind: UnusedDeclarationKind,
+    ) -> Option<UnusedReason> {
+        let references: Vec<_> = self.symbols().resolved_references(symbol_id).collect();
+        let uses: Vec<_> = references.iter().filter(|reference| self.is_use(reference)).collect();
+        if uses.is_empty() {
+            return Some(if references.is_empty() {
+                UnusedReason::Unreferenced
+            } else {
+                UnusedReason::OnlyWritten
+            });
+        }
+        if let Some(span) = self.self_reference_span(symbol_id, declaration, kind) {
+            if uses.iter().all(|reference| {
+                let reference = reference.span();
+                span.start <= reference.start && reference.end <= span.end
+            }) {
+                return Some(UnusedReason::SelfReferenceOnly);
+            }
+        }
+        if kind == UnusedDeclarationKind::Import && uses.iter().all(|reference| reference.is_type())
+        {
+            return Some(UnusedReason::OnlyUsedAsType);
+        }
+        None
+    }
+
+    /// The code in which references to the declaration only refer to itself:
+    /// the declaration of a function or class, the initializer of a variable,
+    /// or the default value of a destructured variable, e.g. `() => b()` in `const { b = () => b() } = c`.
+    /// Parameters and imports are declared outside of the nodes which can reference them.
+    fn self_reference_span(
+        &self,
+        symbol_id: SymbolId,
+        declaration: AstNodeId,
+        kind: UnusedDeclarationKind,
+    ) -> Option<Span> {
+        match kind {
+            UnusedDeclarationKind::Function | UnusedDeclarationKind::Class => {
+                Some(self.nodes().kind(declaration).span())
+            }
+            UnusedDeclarationKind::Variable => {
+                let AstKind::VariableDeclarator(decl) = self.nodes().kind(declaration) else {
+                    return None;
+                };
+                match &decl.id.kind {
+                    BindingPatternKind::BindingIdentifier(_) => {
+                        decl.init.as_ref().map(GetSpan::span)
+                    }
+                    _ => default_value_span(&decl.id, self.symbols()[symbol_id].span()),
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether a reference uses the value, an update whose result is discarded is not a use,
+    /// e.g. `a++;` and `a += 1;`
+    fn is_use(&self, reference: &ResolvedReference) -> bool {
+        if reference.is_export() || reference.is_type() {
+            return true;
+        }
+        if !reference.is_read() {
+            return false;
+        }
+        if !reference.is_read_and_write() {
+            return true;
+        }
+        let nodes = self.nodes();
+        let mut ancestors = reference.reference.ast_node_id.ancestors(nodes).skip(1);
+        let Some(update_id) = ancestors.find(|id| {
+            matches!(nodes.kind(*id), AstKind::UpdateExpression(_) | AstKind::AssignmentExpression(_))
+        }) else {
+            return true;
+        };
+        let update = &nodes[update_id];
+        !matches!(
+            nodes.parent_kind(update),
+            AstKind::ExpressionStatement(_) | AstKind::ForStatement(_)
+        )
+    }
+
+    fn is_ignored(
+        &self,
+        symbol_id: SymbolId,
+        declaration: AstNodeId,
+        kind: UnusedDeclarationKind,
+        options: &UnusedOptions,
+    ) -> bool {
+        let symbol = &self.symbols()[symbol_id];
+        let name = symbol.name().as_str();
+        let matches = |pattern: &Option<Regex>| pattern.as_ref().is_some_and(|p| p.is_match(name));
+        match kind {
+            UnusedDeclarationKind::Parameter => {
+                matches(&options.args_ignore_pattern)
+                    || match options.args {
+                        UnusedArgs::All => false,
+                        UnusedArgs::None => true,
+                        UnusedArgs::AfterUsed => self.is_before_used_parameter(symbol, declaration),
+                    }
+            }
+            UnusedDeclarationKind::CatchParameter => {
+                !options.caught_errors || matches(&options.caught_errors_ignore_pattern)
+            }
+            _ => {
+                if matches(&options.vars_ignore_pattern) {
+                    return true;
+                }
+                if options.vars == UnusedVars::Local
+                    && self.scopes()[self.scopes().root_scope_id()]
+                        .get_variable_symbol_id(symbol.name())
+                        == Some(symbol_id)
+                {
+                    return true;
+                }
+                options.ignore_rest_siblings
+                    && matches!(
+                        self.nodes().kind(declaration),
+                        AstKind::VariableDeclarator(decl) if has_rest_sibling(&decl.id, symbol.span())
+                    )
+            }
+        }
+    }
+
+    /// Whether a parameter after the parameter `symbol` is used.
+    fn is_before_used_parameter(&self, symbol: &Symbol, declaration: AstNodeId) -> bool {
+        let AstKind::FormalParameters(params) = self.nodes().kind(declaration) else {
+            return false;
+        };
+        let scope = &self.scopes()[self.nodes()[declaration].scope_id()];
+        let Some(index) = parameter_index(params, symbol.span()) else { return false };
+        params.items.iter().skip(index + 1).any(|param| {
+            param.pattern.bound_names().iter().any(|ident| {
+                scope.get_variable_symbol_id(&ident.name).is_some_and(|symbol_id| {
+                    self.symbols().resolved_references(symbol_id).any(|r| self.is_use(r))
+                })
+            })
+        })
+    }
+}
+
+/// The index of the parameter binding `span`
+fn parameter_index(params: &FormalParameters, span: Span) -> Option<usize> {
+    params
+        .items
+        .iter()
+        .position(|param| param.pattern.bound_names().iter().any(|ident| ident.span == span))
+}
+
+/// Whether the binding `span` is a property of an object pattern with a rest element
+fn has_rest_sibling(pattern: &BindingPattern, span: Span) -> bool {
+    match &pattern.kind {
+        BindingPatternKind::BindingIdentifier(_) => false,
+        BindingPatternKind::ObjectPattern(object) => {
+            let has_rest = object
+                .properties
+                .iter()
+                .any(|prop| matches!(prop, ObjectPatternProperty::RestElement(_)));
+            object.properties.iter().any(|prop| match prop {
+                ObjectPatternProperty::Property(prop) => match &prop.value {
+                    PropertyValue::Pattern(pattern) => {
+                        (has_rest
+                            && matches!(&pattern.kind, BindingPatternKind::BindingIdentifier(ident) if ident.span == span))
+                            || has_rest_sibling(pattern, span)
+                    }
+                    PropertyValue::Expression(_) => false,
+                },
+                ObjectPatternProperty::RestElement(rest) => has_rest_sibling(&rest.argument, span),
+            })
+        }
+        BindingPatternKind::ArrayPattern(array) => {
+            array.elements.iter().flatten().any(|element| has_rest_sibling(element, span))
+        }
+        BindingPatternKind::RestElement(rest) => has_rest_sibling(&rest.argument, span),
+        BindingPatternKind::AssignmentPattern(assignment) => {
+            has_rest_sibling(&assignment.left, span)
+        }
+    }
+}
+
+/// The default value of the binding at `span` in `pattern`, e.g. `1` in `{ a = 1 }`
+fn default_value_span(pattern: &BindingPattern, span: Span) -> Option<Span> {
+    match &pattern.kind {
+        BindingPatternKind::BindingIdentifier(_) => None,
+        BindingPatternKind::ObjectPattern(object) => {
+            object.properties.iter().find_map(|prop| match prop {
+                ObjectPatternProperty::Property(prop) => match &prop.value {
+                    PropertyValue::Pattern(pattern) => default_value_span(pattern, span),
+                    PropertyValue::Expression(_) => None,
+                },
+                ObjectPatternProperty::RestElement(rest) => {
+                    default_value_span(&rest.argument, span)
+                }
+            })
+        }
+        BindingPatternKind::ArrayPattern(array) => {
+            array.elements.iter().flatten().find_map(|element| default_value_span(element, span))
+        }
+        BindingPatternKind::RestElement(rest) => default_value_span(&rest.argument, span),
+        BindingPatternKind::AssignmentPattern(assignment) => match &assignment.left.kind {
+            BindingPatternKind::BindingIdentifier(ident) if ident.span == span => {
+                Some(assignment.right.span())
+            }
+            _ => default_value_span(&assignment.left, span),
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::SourceType;
+    use regex::Regex;
+
+    use super::{UnusedArgs, UnusedDeclarationKind, UnusedOptions, UnusedReason, UnusedVars};
+    use crate::tester;
+
+    fn unused_with(
+        source_text: &str,
+        source_type: SourceType,
+        options: &UnusedOptions,
+    ) -> Vec<String> {
+        let allocator = Allocator::default();
+        let semantic = tester::semantic(&allocator, source_text, source_type, |builder| builder);
+        semantic
+            .unused_declarations(options)
+            .iter()
+            .map(|unused| {
+                let kind = match unused.kind {
+                    UnusedDeclarationKind::Variable => "var",
+                    UnusedDeclarationKind::Function => "function",
+                    UnusedDeclarationKind::Class => "class",
+                    UnusedDeclarationKind::Parameter => "param",
+                    UnusedDeclarationKind::CatchParameter => "catch",
+                    UnusedDeclarationKind::Import => "import",
+                    UnusedDeclarationKind::TypeImport => "type import",
+                };
+                let reason = match unused.reason {
+                    UnusedReason::Unreferenced => "unreferenced",
+                    UnusedReason::OnlyWritten => "only written",
+                    UnusedReason::SelfReferenceOnly => "self reference",
+                    UnusedReason::OnlyUsedAsType => "type",
+                };
+                format!("{} {kind} {reason}", semantic.symbols()[unused.symbol_id].name())
+            })
+            .collect()
+    }
+
+    fn unused(source_text: &str) -> Vec<String> {
+        unused_with(source_text, SourceType::default(), &UnusedOptions::default())
+    }
+
+    #[test]
+    fn variables() {
+        assert_eq!(
+            unused(
+                "let a = 1, b = 2, c, d = 0, e = 0, f = 0;
+                 c = b; d++; e += 1; g(f++);
+                 export let h; let i; export { i };"
+            ),
+            vec![
+                "a var unreferenced",
+                "c var only written",
+                "d var only written",
+                "e var only written"
+            ]
+        );
+        assert_eq!(
+            unused(
+                "function f() { return f(); }
+                 const g = () => g;
+                 class A { m() { return A; } }
+                 function h() {} h();"
+            ),
+            vec!["f function self reference", "g var self reference", "A class self reference"]
+        );
+        // Only references in the binding's own initializer are self references
+        assert_eq!(unused("const { a, b = a } = o; export { b };"), Vec::<String>::new());
+        assert_eq!(
+            unused("const { a = () => a(), b = 1 } = o; export { b };"),
+            vec!["a var self reference"]
+        );
+        // Function and class expression names are not checked
+        assert_eq!(unused("g(function f() {}, class A {});"), Vec::<String>::new());
+    }
+
+    #[test]
+    fn parameters() {
+        assert_eq!(
+            unused("function f(a, b, c, d) { return b; } f(); g((e) => 1);"),
+            vec!["c param unreferenced", "d param unreferenced", "e param unreferenced"]
+        );
+        let options = UnusedOptions {
+            args: UnusedArgs::All,
+            args_ignore_pattern: Some(Regex::new("^_\\w*$").unwrap()),
+            ..UnusedOptions::default()
+        };
+        assert_eq!(
+            unused_with(
+                "function f(a, _b, { c, d }) { return d; } f();",
+                SourceType::default(),
+                &options
+            ),
+            vec!["a param unreferenced", "c param unreferenced"]
+        );
+        let options = UnusedOptions { args: UnusedArgs::None, ..UnusedOptions::default() };
+        assert_eq!(
+            unused_with("function f(a) {} f();", SourceType::default(), &options),
+            Vec::<String>::new()
+        );
+    }
+
+    #[test]
+    fn catch_parameters() {
+        assert_eq!(unused("try {} catch (e) {}"), vec!["e catch unreferenced"]);
+        let options = UnusedOptions { caught_errors: false, ..UnusedOptions::default() };
+        assert_eq!(
+            unused_with("try {} catch (e) {}", SourceType::default(), &options),
+            Vec::<String>::new()
+        );
+    }
+
+    #[test]
+    fn imports() {
+        let source_type = *SourceType::default().with_module(true).with_typescript(true);
+        assert_eq!(
+            unused_with(
+                "import a, { b, c } from 'a';
+                 import type { D, E } from 'b';
+                 import f = require('f');
+                 let x: b = c; let y: D = f; export { x, y };",
+                source_type,
+                &UnusedOptions::default()
+            ),
+            vec!["a import unreferenced", "b import type", "E type import unreferenced"]
+        );
+    }
+
+    #[test]
+    fn options() {
+        let options = UnusedOptions {
+            vars: UnusedVars::Local,
+            vars_ignore_pattern: Some(Regex::new("^ignored[A-Z]").unwrap()),
+            ignore_rest_siblings: true,
+            ..UnusedOptions::default()
+        };
+        assert_eq!(
+            unused_with(
+                "let a; function f() { let ignoredB, c; const { d, ...rest } = g(); return rest; } f();",
+                SourceType::default(),
+                &options
+            ),
+            vec!["c var unreferenced"]
+        );
+    }
+}
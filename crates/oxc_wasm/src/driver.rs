@@ -5,9 +5,16 @@ use oxc_allocator::Allocator;
 use oxc_ast::SourceType;
 use oxc_linter::Linter;
 use oxc_parser::Parser;
-use oxc_semantic::SemanticBuilder;
+use oxc_semantic::{ScopeSnapshot, SemanticBuilder};
+use serde::Serialize;
 use wasm_bindgen::JsValue;
 
+#[derive(Serialize)]
+struct Scopes {
+    scopes: ScopeSnapshot,
+    text: String,
+}
+
 pub struct Driver {
     allocator: Allocator,
 }
@@ -33,17 +40,19 @@ impl Driver {
 
         let program = self.allocator.alloc(ret.program);
 
-        let semantic_ret =
-            SemanticBuilder::new(source_text, source_type, &ret.trivias).build(program);
+        let semantic_ret = Linter::semantic_builder(source_text, source_type, &ret.trivias)
+            .with_check_syntax_error(true)
+            .build(program);
         diagnostics.extend(semantic_ret.errors);
 
         let source = Arc::new(NamedSource::new(path, source_text.to_string()));
 
-        let messages =
-            Linter::from_json_str(eslintrc).with_fix(false).run(&Rc::new(semantic_ret.semantic));
-
-        diagnostics
-            .extend(messages.into_iter().map(|m| m.error.with_source_code(Arc::clone(&source))));
+        match Linter::from_json_str(eslintrc).with_fix(false).run(&Rc::new(semantic_ret.semantic)) {
+            Ok(messages) => diagnostics.extend(
+                messages.into_iter().map(|m| m.error.with_source_code(Arc::clone(&source))),
+            ),
+            Err(error) => diagnostics.push(error.into()),
+        }
 
         if diagnostics.is_empty() {
             if let Ok(ast) = JsValue::from_serde(program) {
@@ -59,4 +68,20 @@ impl Driver {
 
         JsValue::from_str(&diagnostics)
     }
+
+    #[allow(deprecated)]
+    pub fn scopes(&self, source_text: &str, source_type: SourceType) -> JsValue {
+        let ret = Parser::new(&self.allocator, source_text, source_type)
+            .allow_return_outside_function(true)
+            .parse();
+        let program = self.allocator.alloc(ret.program);
+        let semantic = SemanticBuilder::new(source_text, source_type, &ret.trivias)
+            .with_initialization(true)
+            .build(program)
+            .semantic;
+
+        let scopes = semantic.scope_snapshot();
+        let text = scopes.to_string();
+        JsValue::from_serde(&Scopes { scopes, text }).unwrap_or(JsValue::NULL)
+    }
 }
@@ -30,17 +30,23 @@ pub struct Options {
     pub eslintrc: Option<String>,
 }
 
+impl Options {
+    fn path(&self) -> String {
+        format!(
+            "test.{}{}",
+            if matches!(self.language, Some(Language::TypeScript)) { "ts" } else { "js" },
+            if self.jsx.unwrap_or_default() { "x" } else { "" }
+        )
+    }
+}
+
 #[wasm_bindgen]
 #[must_use]
 #[allow(deprecated)]
 pub fn main(text: &str, js_options: &JsValue) -> JsValue {
     set_panic_hook();
     let options: Options = js_options.into_serde().unwrap_or_default();
-    let path_str = format!(
-        "test.{}{}",
-        if matches!(options.language, Some(Language::TypeScript)) { "ts" } else { "js" },
-        if options.jsx.unwrap_or_default() { "x" } else { "" }
-    );
+    let path_str = options.path();
 
     let source_type = SourceType::from_path(&path_str).unwrap_or_default();
 
@@ -48,3 +54,18 @@ pub fn main(text: &str, js_options: &JsValue) -> JsValue {
 
     driver.run(&path_str, text, source_type, &options.eslintrc.unwrap_or_default())
 }
+
+/// The scope tree for the scope inspector of the playground,
+/// as `{ scopes, text }` with the structured tree and its readable dump.
+#[wasm_bindgen]
+#[must_use]
+#[allow(deprecated)]
+pub fn scopes(text: &str, js_options: &JsValue) -> JsValue {
+    set_panic_hook();
+    let options: Options = js_options.into_serde().unwrap_or_default();
+    let source_type = SourceType::from_path(options.path()).unwrap_or_default();
+
+    let driver = Driver::new();
+
+    driver.scopes(text, source_type)
+}
@@ -1,5 +1,6 @@
 mod babel;
 mod printer;
+mod scope;
 mod suite;
 mod test262;
 mod typescript;
@@ -11,6 +12,7 @@ use std::{
 
 pub use crate::babel::{BabelCase, BabelSuite};
 pub use crate::printer::PrinterTest262Case;
+pub use crate::scope::ScopeTest262Case;
 pub use crate::suite::Suite;
 pub use crate::test262::{Test262Case, Test262Suite};
 pub use crate::typescript::{TypeScriptCase, TypeScriptSuite};
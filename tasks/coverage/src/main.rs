@@ -1,6 +1,6 @@
 use oxc_coverage::{
-    AppArgs, BabelCase, BabelSuite, PrinterTest262Case, Suite, Test262Case, Test262Suite,
-    TypeScriptCase, TypeScriptSuite,
+    AppArgs, BabelCase, BabelSuite, PrinterTest262Case, ScopeTest262Case, Suite, Test262Case,
+    Test262Suite, TypeScriptCase, TypeScriptSuite,
 };
 use pico_args::Arguments;
 
@@ -32,16 +32,22 @@ fn main() {
         Test262Suite::<PrinterTest262Case>::new().run("Printer", &args);
     };
 
+    let run_scope = || {
+        Test262Suite::<ScopeTest262Case>::new().run("Scope", &args);
+    };
+
     match task {
         "js" | "test262" => run_test262(),
         "babel" => run_babel(),
         "ts" | "typescript" => run_typescript(),
         "printer" => run_printer(),
+        "scope" => run_scope(),
         _ => {
             run_test262();
             run_babel();
             run_typescript();
             run_printer();
+            run_scope();
         }
     };
 }
@@ -0,0 +1,102 @@
+use std::{
+    path::{Path, PathBuf},
+    rc::Rc,
+};
+
+use oxc_allocator::Allocator;
+use oxc_ast::SourceType;
+use oxc_parser::Parser;
+use oxc_semantic::{ScopeSnapshot, SemanticBuilder};
+use rustc_hash::FxHashSet;
+
+use crate::suite::{Case, TestResult};
+use crate::test262::{Test262Case, TestFlag};
+
+/// Checks the scope snapshot of each test262 file,
+/// every resolved reference must point to a symbol listed in an enclosing scope.
+pub struct ScopeTest262Case {
+    base: Test262Case,
+}
+
+impl Case for ScopeTest262Case {
+    fn new(path: PathBuf, code: String) -> Self {
+        Self { base: Test262Case::new(path, code) }
+    }
+
+    fn code(&self) -> &str {
+        self.base.code()
+    }
+
+    fn path(&self) -> &Path {
+        self.base.path()
+    }
+
+    fn test_result(&self) -> &TestResult {
+        self.base.test_result()
+    }
+
+    fn skip_test_case(&self) -> bool {
+        self.base.should_fail()
+    }
+
+    fn run(&mut self) {
+        let result = self.get_result();
+        self.base.set_result(result);
+    }
+}
+
+impl ScopeTest262Case {
+    fn get_result(&self) -> TestResult {
+        let allocator = Allocator::default();
+        let source_text = self.base.code();
+        let is_module = self.base.meta().flags.contains(&TestFlag::Module);
+        let source_type = *SourceType::default().with_module(is_module);
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let program = allocator.alloc(ret.program);
+        let semantic = SemanticBuilder::new(source_text, source_type, &Rc::default())
+            .with_initialization(true)
+            .build(program)
+            .semantic;
+
+        let snapshot = semantic.scope_snapshot();
+        let mut listed = FxHashSet::default();
+        match check_scope(&snapshot, &mut vec![], &mut listed) {
+            Ok(()) if listed.len() == semantic.symbols().len() => TestResult::Passed,
+            Ok(()) => TestResult::Mismatch(
+                snapshot.to_string(),
+                format!("{} of {} symbols listed", listed.len(), semantic.symbols().len()),
+            ),
+            Err(message) => TestResult::Mismatch(snapshot.to_string(), message),
+        }
+    }
+}
+
+fn check_scope(
+    scope: &ScopeSnapshot,
+    visible: &mut Vec<usize>,
+    listed: &mut FxHashSet<usize>,
+) -> Result<(), String> {
+    let len = visible.len();
+    for symbol in &scope.symbols {
+        if !listed.insert(symbol.id) {
+            return Err(format!("symbol #{} is listed twice", symbol.id));
+        }
+        visible.push(symbol.id);
+    }
+    for reference in &scope.references {
+        match reference.symbol_id {
+            Some(symbol_id) if !visible.contains(&symbol_id) => {
+                return Err(format!(
+                    "reference {}({}..{}) resolves to #{symbol_id} outside of its scope",
+                    reference.name, reference.span.start, reference.span.end
+                ));
+            }
+            _ => {}
+        }
+    }
+    for child in &scope.children {
+        check_scope(child, visible, listed)?;
+    }
+    visible.truncate(len);
+    Ok(())
+}
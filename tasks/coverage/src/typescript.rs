@@ -18,8 +18,26 @@ lazy_static::lazy_static! {
     // Returns a match for a test option. Test options have the form `// @name: value`
     static ref META_OPTIONS: Regex = Regex::new(r"(?m)^/{2}\s*@(?P<name>\w+)\s*:\s*(?P<value>[^\r\n]*)").unwrap();
     static ref TEST_BRACES: Regex = Regex::new(r"^\s*[{|}]\s*$").unwrap();
+    // Returns a match for each error reported in an error baseline, e.g. `error TS1005: ';' expected.`
+    static ref ERROR_CODE: Regex = Regex::new(r"error TS(?P<code>\d+):").unwrap();
 }
 
+/// Error codes `tsc` reports without type information, in addition to the syntax errors `TS1xxx`.
+/// Test cases whose baselines only contain type errors are expected to pass.
+const GRAMMAR_ERROR_CODES: &[u32] = &[
+    2300,  // Duplicate identifier
+    2309,  // An export assignment cannot be used in a module with other exported elements
+    2369,  // A parameter property is only allowed in a constructor implementation
+    2383,  // Overload signatures must all be exported or non-exported
+    2385,  // Overload signatures must all be public, private or protected
+    2387,  // Function overload must be static
+    2388,  // Function overload must not be static
+    2389,  // Function implementation name must be '{0}'
+    2391,  // Function implementation is missing or not immediately following the declaration
+    18010, // An accessibility modifier cannot be used with a private identifier
+    18019, // '{0}' modifier cannot be used with a private identifier
+];
+
 pub struct TypeScriptSuite<T: Case> {
     test_root: PathBuf,
     test_cases: Vec<T>,
@@ -92,7 +110,7 @@ impl Case for TypeScriptCase {
     }
 
     fn should_fail(&self) -> bool {
-        !self.meta.error_files.is_empty()
+        !self.meta.error_codes.is_empty()
     }
 
     fn skip_test_case(&self) -> bool {
@@ -118,7 +136,8 @@ impl Case for TypeScriptCase {
 struct TypeScriptTestMeta {
     pub tests: Vec<TestUnitData>,
     pub options: CompilerOptions,
-    error_files: Vec<String>,
+    /// Grammar error codes of the error baselines, see [`GRAMMAR_ERROR_CODES`]
+    error_codes: Vec<u32>,
 }
 
 impl TypeScriptTestMeta {
@@ -170,8 +189,13 @@ impl TypeScriptTestMeta {
         });
 
         let options = CompilerOptions::new(&current_file_options);
-        let error_files = Self::get_error_files(path, &options);
-        Self { tests: test_unit_data, options, error_files }
+        let error_codes = Self::get_error_files(path, &options)
+            .iter()
+            .flat_map(|error_file| ERROR_CODE.captures_iter(error_file))
+            .filter_map(|captures| captures.name("code").unwrap().as_str().parse().ok())
+            .filter(|code| (1000..2000).contains(code) || GRAMMAR_ERROR_CODES.contains(code))
+            .collect();
+        Self { tests: test_unit_data, options, error_codes }
     }
 
     // TypeScript error files can be: